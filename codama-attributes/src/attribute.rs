@@ -1,7 +1,8 @@
 use crate::{
-    AttributeContext, CodamaAttribute, DeriveAttribute, ReprAttribute, UnsupportedAttribute,
+    AttributeContext, CodamaAttribute, DeriveAttribute, DocAttribute, ReprAttribute,
+    UnsupportedAttribute,
 };
-use codama_syn_helpers::extensions::*;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 use derive_more::derive::From;
 
 #[derive(Debug, PartialEq, From)]
@@ -10,6 +11,8 @@ pub enum Attribute<'a> {
     Codama(CodamaAttribute<'a>),
     // E.g. `#[derive(Debug, CodamaType)]`.
     Derive(DeriveAttribute<'a>),
+    // E.g. `#[doc = "Some documentation"]` or `/// Some documentation`.
+    Doc(DocAttribute<'a>),
     // E.g. `#[repr(u32, align(8))]`.
     Repr(ReprAttribute<'a>),
     // E.g. `#[some_unsupported_attribute = 42]`.
@@ -20,7 +23,7 @@ impl<'a> Attribute<'a> {
     pub fn parse(ast: &'a syn::Attribute, ctx: &AttributeContext) -> syn::Result<Self> {
         let unfeatured = ast.unfeatured();
         let effective = unfeatured.as_ref().unwrap_or(ast);
-        Self::parse_from(ast, effective, ctx)
+        Self::parse_from(ast, effective, ctx, &ConstEvaluator::default())
     }
 
     /// Parse an attribute using the effective attribute for content extraction.
@@ -30,13 +33,17 @@ impl<'a> Attribute<'a> {
         ast: &'a syn::Attribute,
         effective: &syn::Attribute,
         ctx: &AttributeContext,
+        constants: &ConstEvaluator,
     ) -> syn::Result<Self> {
         let path = effective.path();
         match (path.prefix().as_str(), path.last_str().as_str()) {
             ("" | "codama_macros" | "codama", "codama") => {
-                Ok(CodamaAttribute::parse_from(ast, effective, ctx)?.into())
+                Ok(CodamaAttribute::parse_from(ast, effective, ctx, constants)?.into())
             }
             ("", "derive") => Ok(DeriveAttribute::parse_from(ast, effective)?.into()),
+            ("", "doc") if DocAttribute::is_literal_doc(effective) => {
+                Ok(DocAttribute::parse_from(ast, effective)?.into())
+            }
             ("", "repr") => Ok(ReprAttribute::parse_from(ast, effective)?.into()),
             _ => Ok(UnsupportedAttribute::new(ast).into()),
         }
@@ -46,6 +53,7 @@ impl<'a> Attribute<'a> {
         match self {
             Attribute::Codama(a) => a.ast,
             Attribute::Derive(a) => a.ast,
+            Attribute::Doc(a) => a.ast,
             Attribute::Repr(a) => a.ast,
             Attribute::Unsupported(a) => a.ast,
         }
@@ -1,8 +1,11 @@
 use crate::{
-    Attribute, AttributeContext, CodamaAttribute, CodamaDirective, DeriveAttribute, TryFromFilter,
+    is_shank_attribute, parse_shank_attribute, validate_shank_account_indexes, Attribute,
+    AttributeContext, CodamaAttribute, CodamaDirective, DeriveAttribute, DocAttribute,
+    ReprAttribute, TryFromFilter,
 };
 use codama_errors::IteratorCombineErrors;
-use codama_syn_helpers::extensions::*;
+use codama_nodes::Docs;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 
 #[derive(Debug, PartialEq)]
@@ -10,24 +13,62 @@ pub struct Attributes<'a>(pub Vec<Attribute<'a>>);
 
 impl<'a> Attributes<'a> {
     pub fn parse(attrs: &'a [syn::Attribute], ctx: AttributeContext<'a>) -> syn::Result<Self> {
+        Self::parse_with(attrs, ctx, &ConstEvaluator::default(), false)
+    }
+
+    /// Same as `parse` but also converts Shank attributes — e.g. `#[seeds(...)]` or
+    /// `#[padding]` — into Codama directives. Used on items that derive Shank macros.
+    pub fn parse_shank(
+        attrs: &'a [syn::Attribute],
+        ctx: AttributeContext<'a>,
+    ) -> syn::Result<Self> {
+        Self::parse_with(attrs, ctx, &ConstEvaluator::default(), true)
+    }
+
+    /// Parse attributes whose constant expressions may refer to the given constants,
+    /// converting Shank attributes if `shank` is true.
+    pub fn parse_with(
+        attrs: &'a [syn::Attribute],
+        ctx: AttributeContext<'a>,
+        constants: &ConstEvaluator,
+        shank: bool,
+    ) -> syn::Result<Self> {
+        let effective_attrs = attrs
+            .iter()
+            // Expand multi-attr cfg_attr into (ast, effective) pairs
+            .flat_map(|ast| {
+                let inners = ast.unfeatured_all();
+                if inners.len() <= 1 {
+                    // Not a multi-attr cfg_attr - use standard parsing
+                    let unfeatured = ast.unfeatured();
+                    let effective = unfeatured.unwrap_or_else(|| (*ast).clone());
+                    vec![(ast, effective)]
+                } else {
+                    // Multi-attr cfg_attr - expand each inner attribute
+                    inners.into_iter().map(|inner| (ast, inner)).collect()
+                }
+            })
+            .collect::<Vec<_>>();
+        if shank {
+            validate_shank_account_indexes(effective_attrs.iter().map(|(_, e)| e), &ctx)?;
+        }
+
         let attributes = Self(
-            attrs
-                .iter()
-                // Expand multi-attr cfg_attr into (ast, effective) pairs
-                .flat_map(|ast| {
-                    let inners = ast.unfeatured_all();
-                    if inners.len() <= 1 {
-                        // Not a multi-attr cfg_attr - use standard parsing
-                        let unfeatured = ast.unfeatured();
-                        let effective = unfeatured.unwrap_or_else(|| (*ast).clone());
-                        vec![(ast, effective)]
-                    } else {
-                        // Multi-attr cfg_attr - expand each inner attribute
-                        inners.into_iter().map(|inner| (ast, inner)).collect()
-                    }
-                })
-                .map(|(ast, effective)| Attribute::parse_from(ast, &effective, &ctx))
-                .collect_and_combine_errors()?,
+            effective_attrs
+                .into_iter()
+                // Shank attributes may expand into multiple Codama attributes.
+                .map(
+                    |(ast, effective)| match shank && is_shank_attribute(&effective, &ctx) {
+                        true => parse_shank_attribute(ast, &effective, &ctx, constants)
+                            .map(|attrs| attrs.into_iter().map(Attribute::from).collect()),
+                        false => Attribute::parse_from(ast, &effective, &ctx, constants)
+                            .map(|attr| vec![attr]),
+                    },
+                )
+                .collect_and_combine_errors()?
+                .into_iter()
+                .flatten()
+                .collect(),
         );
         attributes.validate_codama_type_attributes()?;
         Ok(attributes)
@@ -82,6 +123,16 @@ impl<'a> Attributes<'a> {
         self.has_derive(&["", "codama", "codama_macros"], derive)
     }
 
+    pub fn has_any_shank_derive(&self) -> bool {
+        self.has_shank_derive("ShankAccount")
+            || self.has_shank_derive("ShankInstruction")
+            || self.has_shank_derive("ShankType")
+    }
+
+    pub fn has_shank_derive(&self, derive: &str) -> bool {
+        self.has_derive(&["", "shank"], derive)
+    }
+
     pub fn has_derive(&self, prefixes: &[&str], last: &str) -> bool {
         self.iter().filter_map(DeriveAttribute::filter).any(|attr| {
             attr.derives
@@ -90,12 +141,67 @@ impl<'a> Attributes<'a> {
         })
     }
 
+    pub fn has_repr(&self, name: &str) -> bool {
+        self.iter()
+            .filter_map(ReprAttribute::filter)
+            .any(|attr| attr.has(name))
+    }
+
+    /// Whether the item is read directly from memory — e.g. a `#[repr(C)]` struct,
+    /// a bytemuck `Pod` or an Anchor `#[account(zero_copy)]` — rather than serialized with Borsh.
+    /// Anchor `#[account]` structs without `zero_copy` are serialized with Borsh.
+    pub fn is_zero_copy(&self) -> bool {
+        let anchor_account = self.get_unsupported("account");
+        let is_anchor_zero_copy = self.get_unsupported("zero_copy").is_some()
+            || anchor_account.is_some_and(|ast| {
+                ast.meta.require_list().is_ok_and(|list| {
+                    list.parse_metas()
+                        .is_ok_and(|metas| metas.iter().any(|meta| meta.path_str() == "zero_copy"))
+                })
+            });
+        if is_anchor_zero_copy {
+            return true;
+        }
+        let is_borsh = anchor_account.is_some()
+            || self.has_derive(&["", "borsh"], "BorshSerialize")
+            || self.has_derive(&["", "anchor_lang"], "AnchorSerialize");
+        let is_bytemuck = ["Pod", "Zeroable", "AnyBitPattern", "NoUninit"]
+            .iter()
+            .any(|derive| self.has_derive(&["", "bytemuck"], derive));
+        !is_borsh && (is_bytemuck || self.has_repr("C") || self.has_repr("transparent"))
+    }
+
+    /// The first unsupported attribute with the given name, optionally prefixed with `anchor_lang`.
+    fn get_unsupported(&self, name: &str) -> Option<&syn::Attribute> {
+        self.iter().find_map(|attr| match attr {
+            Attribute::Unsupported(attr) if attr.ast.path().is(&format!("anchor_lang::{name}")) => {
+                Some(attr.ast)
+            }
+            _ => None,
+        })
+    }
+
     pub fn has_codama_attribute(&self, name: &str) -> bool {
         self.iter()
             .filter_map(CodamaAttribute::filter)
             .any(|a| a.directive.name() == name)
     }
 
+    /// Gather all `#[doc = "..."]` attributes — including `///` comments —
+    /// into a `Docs` node, trimming any leading or trailing empty lines.
+    pub fn docs(&self) -> Docs {
+        let mut lines = self
+            .iter()
+            .filter_map(DocAttribute::filter)
+            .flat_map(DocAttribute::lines)
+            .skip_while(|line| line.is_empty())
+            .collect::<Vec<_>>();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        lines.into()
+    }
+
     pub fn get_all<B: 'a, F>(&'a self, f: F) -> Vec<&'a B>
     where
         F: Fn(&'a Attribute<'a>) -> Option<&'a B>,
@@ -256,6 +362,58 @@ mod tests {
         assert_eq!(names, vec!["first", "second", "third"]);
     }
 
+    #[test]
+    fn parse_doc_attributes() {
+        let file = file_ctx();
+        let attrs: Vec<syn::Attribute> = vec![
+            parse_quote! { #[doc = " First line"] },
+            parse_quote! { #[codama(type = boolean)] },
+            parse_quote! { #[doc(hidden)] },
+        ];
+        let ctx = AttributeContext::File(&file);
+        let attributes = Attributes::parse(&attrs, ctx).unwrap();
+
+        assert_eq!(attributes.len(), 3);
+        assert!(matches!(&attributes[0], Attribute::Doc(_)));
+        assert!(matches!(&attributes[1], Attribute::Codama(_)));
+        assert!(matches!(&attributes[2], Attribute::Unsupported(_)));
+    }
+
+    #[test]
+    fn docs() {
+        let file = file_ctx();
+        let attrs: Vec<syn::Attribute> = vec![
+            parse_quote! { #[doc = ""] },
+            parse_quote! { #[doc = " The first paragraph."] },
+            parse_quote! { #[doc = ""] },
+            parse_quote! { #[derive(Debug)] },
+            parse_quote! { #[doc = " The second paragraph."] },
+            parse_quote! { #[doc = ""] },
+        ];
+        let ctx = AttributeContext::File(&file);
+        let attributes = Attributes::parse(&attrs, ctx).unwrap();
+
+        assert_eq!(
+            attributes.docs(),
+            vec![
+                "The first paragraph.".to_string(),
+                "".to_string(),
+                "The second paragraph.".to_string(),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn docs_empty() {
+        let file = file_ctx();
+        let attrs: Vec<syn::Attribute> = vec![parse_quote! { #[derive(Debug)] }];
+        let ctx = AttributeContext::File(&file);
+        let attributes = Attributes::parse(&attrs, ctx).unwrap();
+
+        assert_eq!(attributes.docs(), Docs::default());
+    }
+
     #[test]
     fn parse_multiple_separate_cfg_attr_and_multi_attr() {
         let file = file_ctx();
@@ -275,4 +433,32 @@ mod tests {
         assert!(matches!(&attributes[2], Attribute::Codama(_)));
         assert!(matches!(&attributes[3], Attribute::Codama(_)));
     }
+
+    #[test]
+    fn is_zero_copy() {
+        let file = file_ctx();
+        let parse = |attrs: Vec<syn::Attribute>| {
+            let attributes = Attributes::parse(&attrs, AttributeContext::File(&file)).unwrap();
+            attributes.is_zero_copy()
+        };
+
+        assert!(parse(vec![parse_quote! { #[repr(C)] }]));
+        assert!(parse(vec![
+            parse_quote! { #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)] }
+        ]));
+        assert!(!parse(vec![parse_quote! { #[derive(Debug)] }]));
+        assert!(!parse(vec![
+            parse_quote! { #[derive(BorshSerialize)] },
+            parse_quote! { #[repr(C)] },
+        ]));
+        assert!(!parse(vec![
+            parse_quote! { #[account] },
+            parse_quote! { #[repr(C)] },
+        ]));
+        assert!(parse(vec![parse_quote! { #[account(zero_copy)] }]));
+        assert!(parse(vec![
+            parse_quote! { #[anchor_lang::account(zero_copy(unsafe))] }
+        ]));
+        assert!(parse(vec![parse_quote! { #[zero_copy] }]));
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{utils::SetOnce, Attribute, AttributeContext, CodamaDirective};
 use codama_errors::CodamaError;
-use codama_syn_helpers::extensions::*;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 
 #[derive(Debug, PartialEq)]
 pub struct CodamaAttribute<'a> {
@@ -12,16 +12,18 @@ impl<'a> CodamaAttribute<'a> {
     pub fn parse(ast: &'a syn::Attribute, ctx: &AttributeContext) -> syn::Result<Self> {
         let unfeatured = ast.unfeatured();
         let effective = unfeatured.as_ref().unwrap_or(ast);
-        Self::parse_from(ast, effective, ctx)
+        Self::parse_from(ast, effective, ctx, &ConstEvaluator::default())
     }
 
     /// Parse a codama attribute using the effective attribute for content extraction.
     /// `ast` is stored as the original attribute reference (for error spans).
     /// `effective` is used to parse the actual directive content.
+    /// Constant expressions, e.g. `fixed_size = MAX_LEN + 2`, are resolved using `constants`.
     pub fn parse_from(
         ast: &'a syn::Attribute,
         effective: &syn::Attribute,
         ctx: &AttributeContext,
+        constants: &ConstEvaluator,
     ) -> syn::Result<Self> {
         let list = effective.meta.require_list()?;
         if !list.path.is_strict("codama") {
@@ -29,7 +31,10 @@ impl<'a> CodamaAttribute<'a> {
         };
 
         let mut directive = SetOnce::<CodamaDirective>::new("codama");
-        list.each(|ref meta| directive.set(CodamaDirective::parse(meta, ctx)?, meta))?;
+        list.each(|meta| {
+            let meta = constants.resolve_meta(meta);
+            directive.set(CodamaDirective::parse(&meta, ctx)?, &meta)
+        })?;
         Ok(Self {
             ast,
             directive: Box::new(directive.take(effective)?),
@@ -83,4 +88,20 @@ mod tests {
             CodamaDirective::Type(_)
         ));
     }
+
+    #[test]
+    fn test_codama_attribute_with_constants() {
+        let ast = parse_quote! { #[codama(fixed_size = MAX_LEN + 2)] };
+        let file = syn::File::empty();
+        let ctx = AttributeContext::File(&file);
+        let mut constants = ConstEvaluator::new();
+        constants.add_constant("MAX_LEN", parse_quote! { 32 });
+        let attribute = CodamaAttribute::parse_from(&ast, &ast, &ctx, &constants).unwrap();
+
+        assert!(matches!(
+            attribute.directive.as_ref(),
+            CodamaDirective::FixedSize(directive) if directive.size == 34
+        ));
+        assert!(CodamaAttribute::parse(&ast, &ctx).is_err());
+    }
 }
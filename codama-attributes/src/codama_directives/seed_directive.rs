@@ -106,7 +106,10 @@ impl<'a> TryFrom<&'a Attribute<'a>> for &'a SeedDirective {
 
 #[cfg(test)]
 mod tests {
-    use codama_nodes::{NumberFormat::U8, NumberTypeNode, NumberValueNode, PublicKeyTypeNode};
+    use codama_nodes::{
+        BytesTypeNode, BytesValueNode, NumberFormat::U8, NumberTypeNode, NumberValueNode,
+        PublicKeyTypeNode,
+    };
 
     use super::*;
 
@@ -127,6 +130,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn defined_constant_bytes() {
+        let meta: Meta = syn::parse_quote! { seed(type = bytes, value = bytes("metadata", utf8)) };
+        let item = syn::parse_quote! { struct Foo; };
+        let ctx = AttributeContext::Item(&item);
+        let directive = SeedDirective::parse(&meta, &ctx).unwrap();
+        assert_eq!(
+            directive,
+            SeedDirective {
+                seed: SeedDirectiveType::Defined(
+                    ConstantPdaSeedNode::new(
+                        BytesTypeNode::new(),
+                        BytesValueNode::utf8("metadata")
+                    )
+                    .into()
+                ),
+            }
+        );
+    }
+
     #[test]
     fn defined_variable() {
         let meta: Meta = syn::parse_quote! { seed(name = "authority", type = public_key) };
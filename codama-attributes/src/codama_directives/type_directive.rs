@@ -1,19 +1,21 @@
 use crate::{utils::FromMeta, Attribute, CodamaAttribute, CodamaDirective};
 use codama_errors::CodamaError;
-use codama_nodes::RegisteredTypeNode;
+use codama_nodes::{DefinedTypeLinkNode, Node, RegisteredTypeNode};
 use codama_syn_helpers::Meta;
 
 #[derive(Debug, PartialEq)]
 pub struct TypeDirective {
-    pub node: RegisteredTypeNode,
+    pub node: Node,
 }
 
 impl TypeDirective {
     pub fn parse(meta: &Meta) -> syn::Result<Self> {
         let value = meta.assert_directive("type")?.as_value()?;
-        Ok(Self {
-            node: RegisteredTypeNode::from_meta(value)?,
-        })
+        let node = match value.path_str().as_str() {
+            "defined_type_link" => DefinedTypeLinkNode::from_meta(value)?.into(),
+            _ => RegisteredTypeNode::from_meta(value)?.into(),
+        };
+        Ok(Self { node })
     }
 }
 
@@ -53,6 +55,14 @@ mod tests {
         assert_eq!(node, NumberTypeNode::le(U16).into());
     }
 
+    #[test]
+    fn defined_type_link() {
+        let meta: Meta = parse_quote! { type = defined_type_link("myType") };
+        let node = TypeDirective::parse(&meta).unwrap().node;
+
+        assert_eq!(node, DefinedTypeLinkNode::new("myType").into());
+    }
+
     #[test]
     fn no_input() {
         let meta: Meta = parse_quote! { type =  };
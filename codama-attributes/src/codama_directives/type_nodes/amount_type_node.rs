@@ -0,0 +1,79 @@
+use crate::{
+    codama_directives::type_nodes::nested_number_type_node_from_meta,
+    utils::{FromMeta, SetOnce},
+};
+use codama_nodes::{AmountTypeNode, NestedTypeNode, NumberTypeNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for AmountTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("amount")?.as_path_list()?;
+        let mut number = SetOnce::<NestedTypeNode<NumberTypeNode>>::new("number");
+        let mut decimals = SetOnce::<u8>::new("decimals");
+        let mut unit = SetOnce::<String>::new("unit");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "number" if !meta.is_path_or_list() => number.set(
+                nested_number_type_node_from_meta(meta.as_value()?, "number")?,
+                meta,
+            ),
+            "decimals" => decimals.set(meta.as_value()?.as_expr()?.as_unsigned_integer()?, meta),
+            "unit" => unit.set(meta.as_value()?.as_expr()?.as_string()?, meta),
+            _ => {
+                if meta.is_path_or_list() {
+                    return number.set(nested_number_type_node_from_meta(meta, "number")?, meta);
+                }
+                if let Ok(value) = meta.as_expr().and_then(|e| e.as_string()) {
+                    return unit.set(value, meta);
+                }
+                if let Ok(value) = meta.as_expr().and_then(|e| e.as_unsigned_integer()) {
+                    return decimals.set(value, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(
+            number.take(meta)?,
+            decimals.take(meta)?,
+            unit.option(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::NumberFormat::U64;
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { amount(number(u64), 2) },
+            AmountTypeNode::new(NumberTypeNode::le(U64), 2, None).into()
+        );
+        assert_type!(
+            { amount(number(u64), 2, "USD") },
+            AmountTypeNode::new(NumberTypeNode::le(U64), 2, Some("USD".into())).into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { amount(number = number(u64), decimals = 2, unit = "USD") },
+            AmountTypeNode::new(NumberTypeNode::le(U64), 2, Some("USD".into())).into()
+        );
+    }
+
+    #[test]
+    fn missing_decimals() {
+        assert_type_err!({ amount(number(u64)) }, "decimals is missing");
+    }
+
+    #[test]
+    fn number_must_be_a_number() {
+        assert_type_err!({ amount(public_key, 2) }, "number must be a NumberTypeNode");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    codama_directives::type_nodes::{count_node_from_meta, is_count_node_meta},
+    utils::{FromMeta, SetOnce},
+};
+use codama_nodes::{
+    ArrayTypeNode, CountNode, NumberFormat::U32, NumberTypeNode, PrefixedCountNode, TypeNode,
+};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for ArrayTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("array")?.as_path_list()?;
+        let mut item = SetOnce::<TypeNode>::new("item");
+        let mut count = SetOnce::<CountNode>::new("count")
+            .initial_value(PrefixedCountNode::new(NumberTypeNode::le(U32)).into());
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "item" => item.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "count" => count.set(count_node_from_meta(meta.as_value()?)?, meta),
+            _ => {
+                if is_count_node_meta(meta) {
+                    return count.set(count_node_from_meta(meta)?, meta);
+                }
+                if meta.is_path_or_list() {
+                    return item.set(TypeNode::from_meta(meta)?, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(item.take(meta)?, count.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{BooleanTypeNode, NumberFormat::U16};
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { array(boolean, fixed(42)) },
+            ArrayTypeNode::fixed(BooleanTypeNode::default(), 42).into()
+        );
+        assert_type!(
+            { array(prefixed(number(u16)), boolean) },
+            ArrayTypeNode::prefixed(BooleanTypeNode::default(), NumberTypeNode::le(U16)).into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { array(item = boolean, count = remainder) },
+            ArrayTypeNode::remainder(BooleanTypeNode::default()).into()
+        );
+        assert_type!(
+            { array(count = 42, item = boolean) },
+            ArrayTypeNode::fixed(BooleanTypeNode::default(), 42).into()
+        );
+    }
+
+    #[test]
+    fn defaults_to_u32_prefix() {
+        assert_type!(
+            { array(boolean) },
+            ArrayTypeNode::prefixed(BooleanTypeNode::default(), NumberTypeNode::le(U32)).into()
+        );
+    }
+
+    #[test]
+    fn missing_item() {
+        assert_type_err!({ array(fixed(42)) }, "item is missing");
+    }
+
+    #[test]
+    fn unrecognized_type() {
+        assert_type_err!({ array(unrecognized) }, "unrecognized type");
+    }
+
+    #[test]
+    fn unrecognized_attribute() {
+        assert_type_err!({ array(boolean, foo = 42) }, "unrecognized attribute");
+    }
+}
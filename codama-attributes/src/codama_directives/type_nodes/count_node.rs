@@ -0,0 +1,146 @@
+use crate::{
+    codama_directives::type_nodes::nested_number_type_node_from_meta,
+    utils::{FromMeta, SetOnce},
+};
+use codama_nodes::{
+    CountNode, FixedCountNode, NestedTypeNode, NumberTypeNode, PrefixedCountNode,
+    RemainderCountNode,
+};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for CountNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        match meta.path_str().as_str() {
+            "fixed" => FixedCountNode::from_meta(meta).map(Self::from),
+            "prefixed" => PrefixedCountNode::from_meta(meta).map(Self::from),
+            "remainder" => RemainderCountNode::from_meta(meta).map(Self::from),
+            _ => Err(meta.error("unrecognized count")),
+        }
+    }
+}
+
+impl FromMeta for FixedCountNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("fixed")?.as_path_list()?;
+        let mut value = SetOnce::<usize>::new("value");
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "value" => value.set(meta.as_value()?.as_expr()?.as_unsigned_integer()?, meta),
+            _ => value.set(meta.as_expr()?.as_unsigned_integer()?, meta),
+        })?;
+        Ok(Self::new(value.take(meta)?))
+    }
+}
+
+impl FromMeta for PrefixedCountNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("prefixed")?.as_path_list()?;
+        let mut prefix = SetOnce::<NestedTypeNode<NumberTypeNode>>::new("prefix");
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "prefix" => prefix.set(
+                nested_number_type_node_from_meta(meta.as_value()?, "prefix")?,
+                meta,
+            ),
+            _ => prefix.set(nested_number_type_node_from_meta(meta, "prefix")?, meta),
+        })?;
+        Ok(Self::new(prefix.take(meta)?))
+    }
+}
+
+impl FromMeta for RemainderCountNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        meta.assert_directive("remainder")?;
+        if !meta.is_path_or_empty_list() {
+            return Err(meta.error("remainder does not accept any input"));
+        }
+        Ok(Self::new())
+    }
+}
+
+/// Whether the given meta describes a count node — e.g. `fixed(42)` or `42`.
+pub(crate) fn is_count_node_meta(meta: &Meta) -> bool {
+    matches!(meta, Meta::Expr(syn::Expr::Lit(_)))
+        || matches!(meta.path_str().as_str(), "fixed" | "prefixed" | "remainder")
+}
+
+/// Parses a count node from either an explicit count node
+/// or an unsigned integer used as a shortcut for a fixed count.
+pub(crate) fn count_node_from_meta(meta: &Meta) -> syn::Result<CountNode> {
+    match meta {
+        Meta::Expr(syn::Expr::Lit(_)) => {
+            Ok(FixedCountNode::new(meta.as_expr()?.as_unsigned_integer()?).into())
+        }
+        _ => CountNode::from_meta(meta),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{ArrayTypeNode, BooleanTypeNode, NumberFormat::U32};
+
+    #[test]
+    fn fixed() {
+        assert_type!(
+            { array(boolean, fixed(42)) },
+            ArrayTypeNode::fixed(BooleanTypeNode::default(), 42).into()
+        );
+        assert_type!(
+            { array(boolean, fixed(value = 42)) },
+            ArrayTypeNode::fixed(BooleanTypeNode::default(), 42).into()
+        );
+    }
+
+    #[test]
+    fn fixed_shortcut() {
+        assert_type!(
+            { array(boolean, 42) },
+            ArrayTypeNode::fixed(BooleanTypeNode::default(), 42).into()
+        );
+    }
+
+    #[test]
+    fn prefixed() {
+        assert_type!(
+            { array(boolean, prefixed(number(u32))) },
+            ArrayTypeNode::prefixed(BooleanTypeNode::default(), NumberTypeNode::le(U32)).into()
+        );
+        assert_type!(
+            { array(boolean, prefixed(prefix = number(u32))) },
+            ArrayTypeNode::prefixed(BooleanTypeNode::default(), NumberTypeNode::le(U32)).into()
+        );
+    }
+
+    #[test]
+    fn remainder() {
+        assert_type!(
+            { array(boolean, remainder) },
+            ArrayTypeNode::remainder(BooleanTypeNode::default()).into()
+        );
+        assert_type!(
+            { array(boolean, remainder()) },
+            ArrayTypeNode::remainder(BooleanTypeNode::default()).into()
+        );
+    }
+
+    #[test]
+    fn missing_fixed_value() {
+        assert_type_err!({ array(boolean, fixed()) }, "value is missing");
+    }
+
+    #[test]
+    fn prefix_must_be_a_number() {
+        assert_type_err!(
+            { array(boolean, prefixed(boolean)) },
+            "prefix must be a NumberTypeNode"
+        );
+    }
+
+    #[test]
+    fn remainder_with_input() {
+        assert_type_err!(
+            { array(boolean, remainder(42)) },
+            "remainder does not accept any input"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    codama_directives::type_nodes::nested_number_type_node_from_meta,
+    utils::{FromMeta, SetOnce},
+};
+use codama_nodes::{DateTimeTypeNode, NestedTypeNode, NumberTypeNode};
+use codama_syn_helpers::Meta;
+
+impl FromMeta for DateTimeTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("date_time")?.as_path_list()?;
+        let mut number = SetOnce::<NestedTypeNode<NumberTypeNode>>::new("number");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "number" if !meta.is_path_or_list() => number.set(
+                nested_number_type_node_from_meta(meta.as_value()?, "number")?,
+                meta,
+            ),
+            _ => number.set(nested_number_type_node_from_meta(meta, "number")?, meta),
+        })?;
+
+        Ok(Self::new(number.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::NumberFormat::I64;
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { date_time(number(i64)) },
+            DateTimeTypeNode::new(NumberTypeNode::le(I64)).into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { date_time(number = number(i64)) },
+            DateTimeTypeNode::new(NumberTypeNode::le(I64)).into()
+        );
+    }
+
+    #[test]
+    fn missing_number() {
+        assert_type_err!({ date_time() }, "number is missing");
+    }
+
+    #[test]
+    fn number_must_be_a_number() {
+        assert_type_err!({ date_time(public_key) }, "number must be a NumberTypeNode");
+    }
+}
@@ -0,0 +1,58 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{CamelCaseString, DefinedTypeLinkNode, ProgramLinkNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for DefinedTypeLinkNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("defined_type_link")?.as_path_list()?;
+        let mut name = SetOnce::<CamelCaseString>::new("name");
+        let mut program = SetOnce::<CamelCaseString>::new("program");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "name" => name.set(meta.as_value()?.as_expr()?.as_string()?.into(), meta),
+            "program" => program.set(meta.as_value()?.as_expr()?.as_string()?.into(), meta),
+            _ => name.set(meta.as_expr()?.as_string()?.into(), meta),
+        })?;
+
+        Ok(DefinedTypeLinkNode {
+            name: name.take(meta)?,
+            program: program.option().map(ProgramLinkNode::new),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { defined_type_link("myType") },
+            DefinedTypeLinkNode::new("myType").into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { defined_type_link(name = "myType", program = "myProgram") },
+            DefinedTypeLinkNode::new_from_program("myType", ProgramLinkNode::new("myProgram"))
+                .into()
+        );
+    }
+
+    #[test]
+    fn missing_name() {
+        assert_type_err!(
+            { defined_type_link(program = "myProgram") },
+            "name is missing"
+        );
+    }
+
+    #[test]
+    fn expected_a_string() {
+        assert_type_err!({ defined_type_link(42) }, "expected a string");
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    codama_directives::type_nodes::nested_number_type_node_from_meta,
+    utils::{FromMeta, SetOnce},
+};
+use codama_nodes::{
+    EnumTypeNode, EnumVariantTypeNode, NestedTypeNode, NumberFormat::U8, NumberTypeNode,
+};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for EnumTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        meta.assert_directive("enum")?;
+        if meta.is_path_or_empty_list() {
+            return Ok(EnumTypeNode::new(vec![]));
+        }
+
+        let mut size = SetOnce::<NestedTypeNode<NumberTypeNode>>::new("size")
+            .initial_value(NumberTypeNode::le(U8).into());
+        let mut variants: Vec<EnumVariantTypeNode> = vec![];
+
+        meta.as_path_list()?
+            .each(|ref meta| match meta.path_str().as_str() {
+                "variant" => {
+                    variants.push(EnumVariantTypeNode::from_meta(meta)?);
+                    Ok(())
+                }
+                "size" => size.set(
+                    nested_number_type_node_from_meta(meta.as_value()?, "size")?,
+                    meta,
+                ),
+                _ => {
+                    if meta.is_path_or_list() {
+                        return size.set(nested_number_type_node_from_meta(meta, "size")?, meta);
+                    }
+                    Err(meta.error("unrecognized attribute"))
+                }
+            })?;
+
+        Ok(EnumTypeNode {
+            variants,
+            size: size.take(meta)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{
+        EnumEmptyVariantTypeNode, EnumStructVariantTypeNode, EnumTupleVariantTypeNode,
+        NumberFormat::U16, StructFieldTypeNode, StructTypeNode, TupleTypeNode,
+    };
+
+    #[test]
+    fn empty() {
+        assert_type!({ enum }, EnumTypeNode::new(vec![]).into());
+        assert_type!({ enum() }, EnumTypeNode::new(vec![]).into());
+    }
+
+    #[test]
+    fn empty_variants() {
+        assert_type!(
+            { enum(variant("small"), variant(name = "large", discriminator = 42)) },
+            EnumTypeNode::new(vec![
+                EnumEmptyVariantTypeNode::new("small").into(),
+                EnumEmptyVariantTypeNode {
+                    discriminator: Some(42),
+                    ..EnumEmptyVariantTypeNode::new("large")
+                }
+                .into(),
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn data_variants() {
+        assert_type!(
+            {
+                enum(
+                    variant("coordinates", struct(field("x", number(u16)))),
+                    variant("point", 5, tuple(number(u16), number(u16))),
+                )
+            },
+            EnumTypeNode::new(vec![
+                EnumStructVariantTypeNode::new(
+                    "coordinates",
+                    StructTypeNode::new(vec![StructFieldTypeNode::new(
+                        "x",
+                        NumberTypeNode::le(U16)
+                    )])
+                )
+                .into(),
+                EnumTupleVariantTypeNode {
+                    discriminator: Some(5),
+                    ..EnumTupleVariantTypeNode::new(
+                        "point",
+                        TupleTypeNode::new(vec![
+                            NumberTypeNode::le(U16).into(),
+                            NumberTypeNode::le(U16).into()
+                        ])
+                    )
+                }
+                .into(),
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn custom_size() {
+        assert_type!(
+            { enum(variant("a"), number(u16)) },
+            EnumTypeNode {
+                size: NumberTypeNode::le(U16).into(),
+                ..EnumTypeNode::new(vec![EnumEmptyVariantTypeNode::new("a").into()])
+            }
+            .into()
+        );
+        assert_type!(
+            { enum(size = number(u16), variant("a")) },
+            EnumTypeNode {
+                size: NumberTypeNode::le(U16).into(),
+                ..EnumTypeNode::new(vec![EnumEmptyVariantTypeNode::new("a").into()])
+            }
+            .into()
+        );
+    }
+
+    #[test]
+    fn missing_variant_name() {
+        assert_type_err!({ enum(variant(42)) }, "name is missing");
+    }
+
+    #[test]
+    fn invalid_variant_data() {
+        assert_type_err!(
+            { enum(variant("a", boolean)) },
+            "variant data must be a StructTypeNode or a TupleTypeNode"
+        );
+    }
+
+    #[test]
+    fn unrecognized_attribute() {
+        assert_type_err!(
+            { enum(variant("a"), 42) },
+            "unrecognized attribute"
+        );
+    }
+}
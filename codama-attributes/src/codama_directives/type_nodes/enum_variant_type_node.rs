@@ -0,0 +1,63 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{
+    CamelCaseString, EnumEmptyVariantTypeNode, EnumStructVariantTypeNode, EnumTupleVariantTypeNode,
+    EnumVariantTypeNode, NestedTypeNode, StructTypeNode, TupleTypeNode, TypeNode,
+};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for EnumVariantTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("variant")?.as_path_list()?;
+        let mut name = SetOnce::<CamelCaseString>::new("name");
+        let mut discriminator = SetOnce::<usize>::new("discriminator");
+        let mut data = SetOnce::<TypeNode>::new("data");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "name" => name.set(meta.as_value()?.as_expr()?.as_string()?.into(), meta),
+            "discriminator" => {
+                discriminator.set(meta.as_value()?.as_expr()?.as_unsigned_integer()?, meta)
+            }
+            "data" => data.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            _ => {
+                if meta.is_path_or_list() {
+                    return data.set(TypeNode::from_meta(meta)?, meta);
+                }
+                if let Ok(value) = meta.as_expr().and_then(|e| e.as_string()) {
+                    return name.set(value.into(), meta);
+                }
+                if let Ok(value) = meta.as_expr().and_then(|e| e.as_unsigned_integer()) {
+                    return discriminator.set(value, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        let name = name.take(meta)?;
+        let discriminator = discriminator.option();
+        let Some(data) = data.option() else {
+            return Ok(EnumEmptyVariantTypeNode {
+                name,
+                discriminator,
+            }
+            .into());
+        };
+
+        if let Ok(r#struct) = NestedTypeNode::<StructTypeNode>::try_from(data.clone()) {
+            return Ok(EnumStructVariantTypeNode {
+                name,
+                discriminator,
+                r#struct,
+            }
+            .into());
+        }
+        if let Ok(tuple) = NestedTypeNode::<TupleTypeNode>::try_from(data) {
+            return Ok(EnumTupleVariantTypeNode {
+                name,
+                discriminator,
+                tuple,
+            }
+            .into());
+        }
+        Err(meta.error("variant data must be a StructTypeNode or a TupleTypeNode"))
+    }
+}
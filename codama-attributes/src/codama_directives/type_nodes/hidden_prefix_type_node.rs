@@ -0,0 +1,90 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_errors::IteratorCombineErrors;
+use codama_nodes::{ConstantValueNode, HiddenPrefixTypeNode, TypeNode};
+use codama_syn_helpers::{extensions::*, Meta, PathList};
+
+impl FromMeta for HiddenPrefixTypeNode<TypeNode> {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("hidden_prefix")?.as_path_list()?;
+        let mut r#type = SetOnce::<TypeNode>::new("type");
+        let mut prefix = SetOnce::<Vec<ConstantValueNode>>::new("prefix");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "type" => r#type.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "prefix" => prefix.set(parse_constant_value_nodes(meta.as_path_list()?)?, meta),
+            _ => {
+                if let Meta::Expr(syn::Expr::Array(array)) = meta {
+                    return prefix.set(parse_constant_value_nodes(&array.as_path_list())?, meta);
+                }
+                if meta.is_path_or_list() {
+                    return r#type.set(TypeNode::from_meta(meta)?, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(r#type.take(meta)?, prefix.take(meta)?))
+    }
+}
+
+fn parse_constant_value_nodes(pl: &PathList) -> syn::Result<Vec<ConstantValueNode>> {
+    pl.parse_metas()?
+        .iter()
+        .map(ConstantValueNode::from_meta)
+        .collect_and_combine_errors()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{NumberFormat::U8, NumberTypeNode, NumberValueNode, StringTypeNode};
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            {
+                hidden_prefix(
+                    string(utf8),
+                    [constant(number(u8), 1), constant(number(u8), 2)],
+                )
+            },
+            HiddenPrefixTypeNode::new(
+                StringTypeNode::utf8(),
+                vec![
+                    ConstantValueNode::new(NumberTypeNode::le(U8), NumberValueNode::new(1u8)),
+                    ConstantValueNode::new(NumberTypeNode::le(U8), NumberValueNode::new(2u8)),
+                ]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { hidden_prefix(prefix = [constant(number(u8), 1)], type = string(utf8)) },
+            HiddenPrefixTypeNode::new(
+                StringTypeNode::utf8(),
+                vec![ConstantValueNode::new(
+                    NumberTypeNode::le(U8),
+                    NumberValueNode::new(1u8)
+                )]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn missing_prefix() {
+        assert_type_err!({ hidden_prefix(string(utf8)) }, "prefix is missing");
+    }
+
+    #[test]
+    fn expected_an_array() {
+        assert_type_err!(
+            { hidden_prefix(string(utf8), prefix = 42) },
+            "expected a list: `prefix = (...)`"
+        );
+    }
+}
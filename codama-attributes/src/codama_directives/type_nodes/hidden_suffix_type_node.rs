@@ -0,0 +1,90 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_errors::IteratorCombineErrors;
+use codama_nodes::{ConstantValueNode, HiddenSuffixTypeNode, TypeNode};
+use codama_syn_helpers::{extensions::*, Meta, PathList};
+
+impl FromMeta for HiddenSuffixTypeNode<TypeNode> {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("hidden_suffix")?.as_path_list()?;
+        let mut r#type = SetOnce::<TypeNode>::new("type");
+        let mut suffix = SetOnce::<Vec<ConstantValueNode>>::new("suffix");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "type" => r#type.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "suffix" => suffix.set(parse_constant_value_nodes(meta.as_path_list()?)?, meta),
+            _ => {
+                if let Meta::Expr(syn::Expr::Array(array)) = meta {
+                    return suffix.set(parse_constant_value_nodes(&array.as_path_list())?, meta);
+                }
+                if meta.is_path_or_list() {
+                    return r#type.set(TypeNode::from_meta(meta)?, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(r#type.take(meta)?, suffix.take(meta)?))
+    }
+}
+
+fn parse_constant_value_nodes(pl: &PathList) -> syn::Result<Vec<ConstantValueNode>> {
+    pl.parse_metas()?
+        .iter()
+        .map(ConstantValueNode::from_meta)
+        .collect_and_combine_errors()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{NumberFormat::U8, NumberTypeNode, NumberValueNode, StringTypeNode};
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            {
+                hidden_suffix(
+                    string(utf8),
+                    [constant(number(u8), 1), constant(number(u8), 2)],
+                )
+            },
+            HiddenSuffixTypeNode::new(
+                StringTypeNode::utf8(),
+                vec![
+                    ConstantValueNode::new(NumberTypeNode::le(U8), NumberValueNode::new(1u8)),
+                    ConstantValueNode::new(NumberTypeNode::le(U8), NumberValueNode::new(2u8)),
+                ]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { hidden_suffix(suffix = [constant(number(u8), 1)], type = string(utf8)) },
+            HiddenSuffixTypeNode::new(
+                StringTypeNode::utf8(),
+                vec![ConstantValueNode::new(
+                    NumberTypeNode::le(U8),
+                    NumberValueNode::new(1u8)
+                )]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn missing_suffix() {
+        assert_type_err!({ hidden_suffix(string(utf8)) }, "suffix is missing");
+    }
+
+    #[test]
+    fn expected_an_array() {
+        assert_type_err!(
+            { hidden_suffix(string(utf8), suffix = 42) },
+            "expected a list: `suffix = (...)`"
+        );
+    }
+}
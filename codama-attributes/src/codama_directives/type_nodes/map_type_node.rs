@@ -0,0 +1,104 @@
+use crate::{
+    codama_directives::type_nodes::{count_node_from_meta, is_count_node_meta},
+    utils::{FromMeta, SetOnce},
+};
+use codama_nodes::{
+    CountNode, MapTypeNode, NumberFormat::U32, NumberTypeNode, PrefixedCountNode, TypeNode,
+};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for MapTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("map")?.as_path_list()?;
+        let mut key = SetOnce::<TypeNode>::new("key");
+        let mut value = SetOnce::<TypeNode>::new("value");
+        let mut count = SetOnce::<CountNode>::new("count")
+            .initial_value(PrefixedCountNode::new(NumberTypeNode::le(U32)).into());
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "key" => key.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "value" => value.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "count" => count.set(count_node_from_meta(meta.as_value()?)?, meta),
+            _ => {
+                if is_count_node_meta(meta) {
+                    return count.set(count_node_from_meta(meta)?, meta);
+                }
+                if meta.is_path_or_list() {
+                    let node = TypeNode::from_meta(meta)?;
+                    return match key.is_set() {
+                        false => key.set(node, meta),
+                        true => value.set(node, meta),
+                    };
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(
+            key.take(meta)?,
+            value.take(meta)?,
+            count.take(meta)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{BooleanTypeNode, PublicKeyTypeNode};
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { map(public_key, boolean, fixed(42)) },
+            MapTypeNode::fixed(PublicKeyTypeNode::new(), BooleanTypeNode::default(), 42).into()
+        );
+        assert_type!(
+            { map(remainder, public_key, boolean) },
+            MapTypeNode::remainder(PublicKeyTypeNode::new(), BooleanTypeNode::default()).into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { map(value = boolean, key = public_key, count = 42) },
+            MapTypeNode::fixed(PublicKeyTypeNode::new(), BooleanTypeNode::default(), 42).into()
+        );
+    }
+
+    #[test]
+    fn defaults_to_u32_prefix() {
+        assert_type!(
+            { map(public_key, boolean) },
+            MapTypeNode::prefixed(
+                PublicKeyTypeNode::new(),
+                BooleanTypeNode::default(),
+                NumberTypeNode::le(U32)
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn missing_value() {
+        assert_type_err!({ map(public_key) }, "value is missing");
+    }
+
+    #[test]
+    fn value_already_set() {
+        assert_type_err!(
+            { map(public_key, boolean, boolean) },
+            "value is already set"
+        );
+    }
+
+    #[test]
+    fn unrecognized_attribute() {
+        assert_type_err!(
+            { map(public_key, boolean, foo = 42) },
+            "unrecognized attribute"
+        );
+    }
+}
@@ -1,12 +1,34 @@
+mod amount_type_node;
+mod array_type_node;
 mod boolean_type_node;
 mod bytes_type_node;
+mod count_node;
+mod date_time_type_node;
+mod defined_type_link_node;
+mod enum_type_node;
+mod enum_variant_type_node;
 mod fixed_size_type_node;
+mod hidden_prefix_type_node;
+mod hidden_suffix_type_node;
+mod map_type_node;
 mod number_type_node;
+mod option_type_node;
+mod post_offset_type_node;
+mod pre_offset_type_node;
 mod public_key_type_node;
+mod remainder_option_type_node;
+mod sentinel_type_node;
+mod set_type_node;
+mod size_prefix_type_node;
+mod sol_amount_type_node;
 mod string_type_node;
 mod struct_field_meta_consumer;
 mod struct_field_type_node;
 mod struct_type_node;
+mod tuple_type_node;
 mod type_node;
+mod zeroable_option_type_node;
 
+pub(crate) use count_node::*;
+pub(crate) use number_type_node::*;
 pub(crate) use struct_field_meta_consumer::*;
@@ -1,5 +1,5 @@
 use crate::utils::{FromMeta, SetOnce};
-use codama_nodes::{Endian, NumberFormat, NumberTypeNode};
+use codama_nodes::{Endian, NestedTypeNode, NumberFormat, NumberTypeNode, TypeNode};
 use codama_syn_helpers::{extensions::*, Meta};
 
 impl FromMeta for NumberTypeNode {
@@ -40,6 +40,15 @@ impl FromMeta for NumberTypeNode {
     }
 }
 
+/// Parses a type node that must resolve to a — potentially nested — `NumberTypeNode`.
+pub(crate) fn nested_number_type_node_from_meta(
+    meta: &Meta,
+    ident: &str,
+) -> syn::Result<NestedTypeNode<NumberTypeNode>> {
+    NestedTypeNode::<NumberTypeNode>::try_from(TypeNode::from_meta(meta)?)
+        .map_err(|_| meta.error(format!("{ident} must be a NumberTypeNode")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
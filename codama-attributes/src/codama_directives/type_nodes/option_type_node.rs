@@ -0,0 +1,106 @@
+use crate::{
+    codama_directives::type_nodes::nested_number_type_node_from_meta,
+    utils::{FromMeta, SetOnce},
+};
+use codama_nodes::{NestedTypeNode, NumberFormat::U8, NumberTypeNode, OptionTypeNode, TypeNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for OptionTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("option")?.as_path_list()?;
+        let mut item = SetOnce::<TypeNode>::new("item");
+        let mut prefix = SetOnce::<NestedTypeNode<NumberTypeNode>>::new("prefix")
+            .initial_value(NumberTypeNode::le(U8).into());
+        let mut fixed = SetOnce::<bool>::new("fixed").initial_value(false);
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "item" => item.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "prefix" => prefix.set(
+                nested_number_type_node_from_meta(meta.as_value()?, "prefix")?,
+                meta,
+            ),
+            "fixed" => match meta {
+                Meta::Expr(_) => fixed.set(true, meta),
+                _ => fixed.set(meta.as_value()?.as_expr()?.as_bool()?, meta),
+            },
+            _ => {
+                if meta.is_path_or_list() {
+                    return match item.is_set() {
+                        false => item.set(TypeNode::from_meta(meta)?, meta),
+                        true => {
+                            prefix.set(nested_number_type_node_from_meta(meta, "prefix")?, meta)
+                        }
+                    };
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(OptionTypeNode {
+            fixed: fixed.take(meta)?,
+            item: Box::new(item.take(meta)?),
+            prefix: prefix.take(meta)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{NumberFormat::U32, PublicKeyTypeNode};
+
+    #[test]
+    fn default() {
+        assert_type!(
+            { option(public_key) },
+            OptionTypeNode::new(PublicKeyTypeNode::new()).into()
+        );
+    }
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { option(public_key, number(u32), fixed) },
+            OptionTypeNode {
+                prefix: NumberTypeNode::le(U32).into(),
+                ..OptionTypeNode::fixed(PublicKeyTypeNode::new())
+            }
+            .into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { option(item = public_key, prefix = number(u32), fixed = true) },
+            OptionTypeNode {
+                prefix: NumberTypeNode::le(U32).into(),
+                ..OptionTypeNode::fixed(PublicKeyTypeNode::new())
+            }
+            .into()
+        );
+        assert_type!(
+            { option(fixed = false, item = public_key) },
+            OptionTypeNode::new(PublicKeyTypeNode::new()).into()
+        );
+    }
+
+    #[test]
+    fn missing_item() {
+        assert_type_err!({ option(fixed) }, "item is missing");
+    }
+
+    #[test]
+    fn prefix_must_be_a_number() {
+        assert_type_err!(
+            { option(public_key, public_key) },
+            "prefix must be a NumberTypeNode"
+        );
+    }
+
+    #[test]
+    fn unrecognized_attribute() {
+        assert_type_err!({ option(public_key, foo = 42) }, "unrecognized attribute");
+    }
+}
@@ -0,0 +1,101 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{PostOffsetStrategy, PostOffsetTypeNode, TypeNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for PostOffsetTypeNode<TypeNode> {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("post_offset")?.as_path_list()?;
+        let mut r#type = SetOnce::<TypeNode>::new("type");
+        let mut offset = SetOnce::<i32>::new("offset");
+        let mut strategy = SetOnce::<PostOffsetStrategy>::new("strategy")
+            .initial_value(PostOffsetStrategy::Relative);
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "type" => r#type.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "offset" => offset.set(meta.as_value()?.as_expr()?.as_signed_integer()?, meta),
+            "strategy" => {
+                let path = meta.as_value()?.as_path()?;
+                match parse_strategy(&path.to_string()) {
+                    Some(value) => strategy.set(value, meta),
+                    None => Err(path.error("invalid strategy")),
+                }
+            }
+            _ => {
+                if let Some(value) = parse_strategy(&meta.path_str()) {
+                    return strategy.set(value, meta);
+                }
+                if meta.is_path_or_list() {
+                    return r#type.set(TypeNode::from_meta(meta)?, meta);
+                }
+                if let Ok(value) = meta.as_expr().and_then(|e| e.as_signed_integer()) {
+                    return offset.set(value, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(
+            r#type.take(meta)?,
+            strategy.take(meta)?,
+            offset.take(meta)?,
+        ))
+    }
+}
+
+fn parse_strategy(value: &str) -> Option<PostOffsetStrategy> {
+    match value {
+        "absolute" => Some(PostOffsetStrategy::Absolute),
+        "padded" => Some(PostOffsetStrategy::Padded),
+        "pre_offset" => Some(PostOffsetStrategy::PreOffset),
+        "relative" => Some(PostOffsetStrategy::Relative),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{NumberFormat::U8, NumberTypeNode};
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { post_offset(number(u8), 4) },
+            PostOffsetTypeNode::relative(NumberTypeNode::le(U8), 4).into()
+        );
+        assert_type!(
+            { post_offset(number(u8), -4, padded) },
+            PostOffsetTypeNode::padded(NumberTypeNode::le(U8), -4).into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { post_offset(strategy = absolute, offset = 8, type = number(u8)) },
+            PostOffsetTypeNode::absolute(NumberTypeNode::le(U8), 8).into()
+        );
+    }
+
+    #[test]
+    fn missing_offset() {
+        assert_type_err!({ post_offset(number(u8)) }, "offset is missing");
+    }
+
+    #[test]
+    fn invalid_strategy() {
+        assert_type_err!(
+            { post_offset(number(u8), 4, strategy = unknown) },
+            "invalid strategy"
+        );
+    }
+
+    #[test]
+    fn unrecognized_attribute() {
+        assert_type_err!(
+            { post_offset(number(u8), 4, foo = 42) },
+            "unrecognized attribute"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{RemainderOptionTypeNode, TypeNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for RemainderOptionTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("remainder_option")?.as_path_list()?;
+        let mut item = SetOnce::<TypeNode>::new("item");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "item" => item.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            _ => {
+                if meta.is_path_or_list() {
+                    return item.set(TypeNode::from_meta(meta)?, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(item.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::PublicKeyTypeNode;
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { remainder_option(public_key) },
+            RemainderOptionTypeNode::new(PublicKeyTypeNode::new()).into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { remainder_option(item = public_key) },
+            RemainderOptionTypeNode::new(PublicKeyTypeNode::new()).into()
+        );
+    }
+
+    #[test]
+    fn missing_item() {
+        assert_type_err!({ remainder_option() }, "item is missing");
+    }
+
+    #[test]
+    fn item_already_set() {
+        assert_type_err!(
+            { remainder_option(public_key, public_key) },
+            "item is already set"
+        );
+    }
+}
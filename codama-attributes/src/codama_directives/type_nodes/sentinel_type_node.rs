@@ -0,0 +1,69 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{ConstantValueNode, SentinelTypeNode, TypeNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for SentinelTypeNode<TypeNode> {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("sentinel")?.as_path_list()?;
+        let mut r#type = SetOnce::<TypeNode>::new("type");
+        let mut sentinel = SetOnce::<ConstantValueNode>::new("sentinel");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "type" => r#type.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "sentinel" => sentinel.set(ConstantValueNode::from_meta(meta.as_value()?)?, meta),
+            "constant" => sentinel.set(ConstantValueNode::from_meta(meta)?, meta),
+            _ => {
+                if meta.is_path_or_list() {
+                    return r#type.set(TypeNode::from_meta(meta)?, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(r#type.take(meta)?, sentinel.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{NumberFormat::U8, NumberTypeNode, NumberValueNode, StringTypeNode};
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { sentinel(string(utf8), constant(number(u8), 0)) },
+            SentinelTypeNode::new(
+                StringTypeNode::utf8(),
+                ConstantValueNode::new(NumberTypeNode::le(U8), NumberValueNode::new(0u8))
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { sentinel(sentinel = constant(number(u8), 0), type = string(utf8)) },
+            SentinelTypeNode::new(
+                StringTypeNode::utf8(),
+                ConstantValueNode::new(NumberTypeNode::le(U8), NumberValueNode::new(0u8))
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn missing_sentinel() {
+        assert_type_err!({ sentinel(string(utf8)) }, "sentinel is missing");
+    }
+
+    #[test]
+    fn unrecognized_attribute() {
+        assert_type_err!(
+            { sentinel(string(utf8), foo = 42) },
+            "unrecognized attribute"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    codama_directives::type_nodes::{count_node_from_meta, is_count_node_meta},
+    utils::{FromMeta, SetOnce},
+};
+use codama_nodes::{
+    CountNode, NumberFormat::U32, NumberTypeNode, PrefixedCountNode, SetTypeNode, TypeNode,
+};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for SetTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("set")?.as_path_list()?;
+        let mut item = SetOnce::<TypeNode>::new("item");
+        let mut count = SetOnce::<CountNode>::new("count")
+            .initial_value(PrefixedCountNode::new(NumberTypeNode::le(U32)).into());
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "item" => item.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "count" => count.set(count_node_from_meta(meta.as_value()?)?, meta),
+            _ => {
+                if is_count_node_meta(meta) {
+                    return count.set(count_node_from_meta(meta)?, meta);
+                }
+                if meta.is_path_or_list() {
+                    return item.set(TypeNode::from_meta(meta)?, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(item.take(meta)?, count.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{BooleanTypeNode, NumberFormat::U16};
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { set(boolean, fixed(42)) },
+            SetTypeNode::fixed(BooleanTypeNode::default(), 42).into()
+        );
+        assert_type!(
+            { set(prefixed(number(u16)), boolean) },
+            SetTypeNode::prefixed(BooleanTypeNode::default(), NumberTypeNode::le(U16)).into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { set(item = boolean, count = remainder) },
+            SetTypeNode::remainder(BooleanTypeNode::default()).into()
+        );
+        assert_type!(
+            { set(count = 42, item = boolean) },
+            SetTypeNode::fixed(BooleanTypeNode::default(), 42).into()
+        );
+    }
+
+    #[test]
+    fn defaults_to_u32_prefix() {
+        assert_type!(
+            { set(boolean) },
+            SetTypeNode::prefixed(BooleanTypeNode::default(), NumberTypeNode::le(U32)).into()
+        );
+    }
+
+    #[test]
+    fn missing_item() {
+        assert_type_err!({ set(fixed(42)) }, "item is missing");
+    }
+
+    #[test]
+    fn unrecognized_type() {
+        assert_type_err!({ set(unrecognized) }, "unrecognized type");
+    }
+
+    #[test]
+    fn unrecognized_attribute() {
+        assert_type_err!({ set(boolean, foo = 42) }, "unrecognized attribute");
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    codama_directives::type_nodes::nested_number_type_node_from_meta,
+    utils::{FromMeta, SetOnce},
+};
+use codama_nodes::{NestedTypeNode, NumberTypeNode, SizePrefixTypeNode, TypeNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for SizePrefixTypeNode<TypeNode> {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("size_prefix")?.as_path_list()?;
+        let mut r#type = SetOnce::<TypeNode>::new("type");
+        let mut prefix = SetOnce::<NestedTypeNode<NumberTypeNode>>::new("prefix");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "type" => r#type.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "prefix" => prefix.set(
+                nested_number_type_node_from_meta(meta.as_value()?, "prefix")?,
+                meta,
+            ),
+            _ => {
+                if meta.is_path_or_list() {
+                    return match r#type.is_set() {
+                        false => r#type.set(TypeNode::from_meta(meta)?, meta),
+                        true => {
+                            prefix.set(nested_number_type_node_from_meta(meta, "prefix")?, meta)
+                        }
+                    };
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(Self::new(r#type.take(meta)?, prefix.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{NumberFormat::U32, StringTypeNode};
+
+    #[test]
+    fn implicit() {
+        assert_type!(
+            { size_prefix(string(utf8), number(u32)) },
+            SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U32)).into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_type!(
+            { size_prefix(prefix = number(u32), type = string(utf8)) },
+            SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U32)).into()
+        );
+    }
+
+    #[test]
+    fn missing_prefix() {
+        assert_type_err!({ size_prefix(string(utf8)) }, "prefix is missing");
+    }
+
+    #[test]
+    fn prefix_must_be_a_number() {
+        assert_type_err!(
+            { size_prefix(string(utf8), public_key) },
+            "prefix must be a NumberTypeNode"
+        );
+    }
+
+    #[test]
+    fn unrecognized_attribute() {
+        assert_type_err!(
+            { size_prefix(string(utf8), number(u32), 42) },
+            "unrecognized attribute"
+        );
+    }
+}
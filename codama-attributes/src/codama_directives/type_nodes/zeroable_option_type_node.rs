@@ -0,0 +1,89 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{ConstantValueNode, TypeNode, ZeroableOptionTypeNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for ZeroableOptionTypeNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("zeroable_option")?.as_path_list()?;
+        let mut item = SetOnce::<TypeNode>::new("item");
+        let mut zero_value = SetOnce::<ConstantValueNode>::new("zero_value");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "item" => item.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "zero_value" => zero_value.set(ConstantValueNode::from_meta(meta.as_value()?)?, meta),
+            "constant" => zero_value.set(ConstantValueNode::from_meta(meta)?, meta),
+            _ => {
+                if meta.is_path_or_list() {
+                    return item.set(TypeNode::from_meta(meta)?, meta);
+                }
+                Err(meta.error("unrecognized attribute"))
+            }
+        })?;
+
+        Ok(ZeroableOptionTypeNode {
+            item: Box::new(item.take(meta)?),
+            zero_value: zero_value.option(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type, assert_type_err};
+    use codama_nodes::{NumberFormat::U32, NumberTypeNode, NumberValueNode, PublicKeyTypeNode};
+
+    #[test]
+    fn default() {
+        assert_type!(
+            { zeroable_option(public_key) },
+            ZeroableOptionTypeNode::new(PublicKeyTypeNode::new()).into()
+        );
+        assert_type!(
+            { zeroable_option(item = public_key) },
+            ZeroableOptionTypeNode::new(PublicKeyTypeNode::new()).into()
+        );
+    }
+
+    #[test]
+    fn implicit_zero_value() {
+        assert_type!(
+            { zeroable_option(number(u32), constant(number(u32), 42)) },
+            ZeroableOptionTypeNode::custom(
+                NumberTypeNode::le(U32),
+                ConstantValueNode::new(NumberTypeNode::le(U32), NumberValueNode::new(42u32))
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn explicit_zero_value() {
+        assert_type!(
+            {
+                zeroable_option(
+                    item = number(u32),
+                    zero_value = constant(type = number(u32), value = 42),
+                )
+            },
+            ZeroableOptionTypeNode::custom(
+                NumberTypeNode::le(U32),
+                ConstantValueNode::new(NumberTypeNode::le(U32), NumberValueNode::new(42u32))
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn missing_item() {
+        assert_type_err!({ zeroable_option() }, "item is missing");
+    }
+
+    #[test]
+    fn missing_constant_value() {
+        assert_type_err!(
+            { zeroable_option(number(u32), constant(number(u32))) },
+            "value is missing"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::utils::FromMeta;
+use codama_errors::IteratorCombineErrors;
+use codama_nodes::{ArrayValueNode, ValueNode};
+use codama_syn_helpers::Meta;
+
+impl FromMeta for ArrayValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        meta.assert_directive("array")?;
+        if meta.is_path_or_empty_list() {
+            return Ok(Self::new(vec![]));
+        }
+
+        let items = meta
+            .as_path_list()?
+            .parse_metas()?
+            .iter()
+            .map(ValueNode::from_meta)
+            .collect_and_combine_errors()?;
+
+        Ok(Self::new(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_value, assert_value_err};
+    use codama_nodes::{BooleanValueNode, NumberValueNode, StringValueNode};
+
+    #[test]
+    fn empty() {
+        assert_value!({ array }, ArrayValueNode::new(vec![]).into());
+        assert_value!({ array() }, ArrayValueNode::new(vec![]).into());
+    }
+
+    #[test]
+    fn multiple_items() {
+        assert_value!(
+            { array(42, "hello", true) },
+            ArrayValueNode::new(vec![
+                NumberValueNode::new(42u64).into(),
+                StringValueNode::new("hello").into(),
+                BooleanValueNode::new(true).into(),
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn nested_items() {
+        assert_value!(
+            { array(array(1), array()) },
+            ArrayValueNode::new(vec![
+                ArrayValueNode::new(vec![NumberValueNode::new(1u64).into()]).into(),
+                ArrayValueNode::new(vec![]).into(),
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn unrecognized_value() {
+        assert_value_err!({ array(42, banana) }, "unrecognized value");
+    }
+}
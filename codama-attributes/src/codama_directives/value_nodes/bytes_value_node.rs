@@ -0,0 +1,76 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{BytesEncoding, BytesValueNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for BytesValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("bytes")?.as_path_list()?;
+        let mut data = SetOnce::<String>::new("data");
+        let mut encoding =
+            SetOnce::<BytesEncoding>::new("encoding").initial_value(BytesEncoding::Base16);
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "data" => data.set(meta.as_value()?.as_expr()?.as_string()?, meta),
+            "encoding" => {
+                let path = meta.as_value()?.as_path()?;
+                match BytesEncoding::try_from(path.to_string()) {
+                    Ok(value) => encoding.set(value, meta),
+                    _ => Err(path.error("invalid encoding")),
+                }
+            }
+            _ => {
+                if let Ok(path) = meta.as_path() {
+                    return match BytesEncoding::try_from(path.to_string()) {
+                        Ok(value) => encoding.set(value, meta),
+                        _ => Err(path.error("invalid encoding")),
+                    };
+                }
+                data.set(meta.as_expr()?.as_string()?, meta)
+            }
+        })?;
+
+        Ok(Self::new(encoding.take(meta)?, data.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_value, assert_value_err};
+
+    #[test]
+    fn implicit() {
+        assert_value!(
+            { bytes("3yZe7d", base58) },
+            BytesValueNode::base58("3yZe7d").into()
+        );
+        assert_value!(
+            { bytes(utf8, "hello") },
+            BytesValueNode::utf8("hello").into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_value!(
+            { bytes(data = "aGVsbG8=", encoding = base64) },
+            BytesValueNode::base64("aGVsbG8=").into()
+        );
+    }
+
+    #[test]
+    fn defaults_to_base16() {
+        assert_value!({ bytes("ff00") }, BytesValueNode::base16("ff00").into());
+    }
+
+    #[test]
+    fn missing_data() {
+        assert_value_err!({ bytes(base58) }, "data is missing");
+    }
+
+    #[test]
+    fn invalid_encoding() {
+        assert_value_err!({ bytes("ff", base32) }, "invalid encoding");
+        assert_value_err!({ bytes("ff", encoding = base32) }, "invalid encoding");
+    }
+}
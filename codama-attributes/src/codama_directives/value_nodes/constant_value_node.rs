@@ -0,0 +1,70 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{ConstantValueNode, TypeNode, ValueNode};
+use codama_syn_helpers::Meta;
+
+impl FromMeta for ConstantValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("constant")?.as_path_list()?;
+        let mut r#type = SetOnce::<TypeNode>::new("type");
+        let mut value = SetOnce::<ValueNode>::new("value");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "type" => r#type.set(TypeNode::from_meta(meta.as_value()?)?, meta),
+            "value" => value.set(ValueNode::from_meta(meta.as_value()?)?, meta),
+            _ => match r#type.is_set() {
+                false => r#type.set(TypeNode::from_meta(meta)?, meta),
+                true => value.set(ValueNode::from_meta(meta)?, meta),
+            },
+        })?;
+
+        Ok(Self::new(r#type.take(meta)?, value.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_value, assert_value_err};
+    use codama_nodes::{
+        BytesEncoding, BytesTypeNode, BytesValueNode, NumberFormat::U32, NumberTypeNode,
+        NumberValueNode,
+    };
+
+    #[test]
+    fn implicit() {
+        assert_value!(
+            { constant(number(u32), 42) },
+            ConstantValueNode::new(NumberTypeNode::le(U32), NumberValueNode::new(42u64)).into()
+        );
+        assert_value!(
+            { constant(bytes, bytes("ff00", base16)) },
+            ConstantValueNode::bytes(BytesEncoding::Base16, "ff00").into()
+        );
+    }
+
+    #[test]
+    fn explicit() {
+        assert_value!(
+            { constant(value = 42, type = number(u32)) },
+            ConstantValueNode::new(NumberTypeNode::le(U32), NumberValueNode::new(42u64)).into()
+        );
+        assert_value!(
+            { constant(type = bytes, value = bytes("hello", utf8)) },
+            ConstantValueNode::new(
+                BytesTypeNode::new(),
+                BytesValueNode::utf8("hello")
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn missing_value() {
+        assert_value_err!({ constant(number(u32)) }, "value is missing");
+    }
+
+    #[test]
+    fn unrecognized_type() {
+        assert_value_err!({ constant(banana, 42) }, "unrecognized type");
+    }
+}
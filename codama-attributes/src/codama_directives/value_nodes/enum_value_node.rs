@@ -0,0 +1,93 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{
+    CamelCaseString, DefinedTypeLinkNode, EnumValueNode, EnumVariantData, StructValueNode,
+    TupleValueNode,
+};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for EnumValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("enum")?.as_path_list()?;
+        let mut r#enum = SetOnce::<CamelCaseString>::new("enum");
+        let mut variant = SetOnce::<CamelCaseString>::new("variant");
+        let mut value = SetOnce::<EnumVariantData>::new("value");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "enum" => r#enum.set(meta.as_value()?.as_expr()?.as_string()?.into(), meta),
+            "variant" => variant.set(meta.as_value()?.as_expr()?.as_string()?.into(), meta),
+            "struct" => value.set(StructValueNode::from_meta(meta)?.into(), meta),
+            "tuple" => value.set(TupleValueNode::from_meta(meta)?.into(), meta),
+            _ => {
+                let name = meta.as_expr()?.as_string()?;
+                match r#enum.is_set() {
+                    false => r#enum.set(name.into(), meta),
+                    true => variant.set(name.into(), meta),
+                }
+            }
+        })?;
+
+        Ok(Self::new(
+            DefinedTypeLinkNode::new(r#enum.take(meta)?),
+            variant.take(meta)?,
+            value.option(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_value, assert_value_err};
+    use codama_nodes::{NumberValueNode, StringValueNode, StructFieldValueNode};
+
+    #[test]
+    fn empty_variant() {
+        assert_value!(
+            { enum("direction", "north") },
+            EnumValueNode::empty("direction", "north").into()
+        );
+        assert_value!(
+            { enum(variant = "north", enum = "direction") },
+            EnumValueNode::empty("direction", "north").into()
+        );
+    }
+
+    #[test]
+    fn struct_variant() {
+        assert_value!(
+            { enum("command", "move", struct(x = 1, y = 2)) },
+            EnumValueNode::fields(
+                "command",
+                "move",
+                vec![
+                    StructFieldValueNode::new("x", NumberValueNode::new(1u64)),
+                    StructFieldValueNode::new("y", NumberValueNode::new(2u64)),
+                ]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn tuple_variant() {
+        assert_value!(
+            { enum("command", "write", tuple("hello")) },
+            EnumValueNode::tuple(
+                "command",
+                "write",
+                vec![StringValueNode::new("hello").into()]
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn missing_variant() {
+        assert_value_err!({ enum("direction") }, "variant is missing");
+    }
+
+    #[test]
+    fn expected_a_string() {
+        assert_value_err!({ enum(direction, north) }, "expected a string");
+    }
+}
@@ -0,0 +1,91 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_errors::IteratorCombineErrors;
+use codama_nodes::{MapEntryValueNode, MapValueNode, ValueNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for MapValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        meta.assert_directive("map")?;
+        if meta.is_path_or_empty_list() {
+            return Ok(Self::new(vec![]));
+        }
+
+        let entries = meta
+            .as_path_list()?
+            .parse_metas()?
+            .iter()
+            .map(MapEntryValueNode::from_meta)
+            .collect_and_combine_errors()?;
+
+        Ok(Self::new(entries))
+    }
+}
+
+impl FromMeta for MapEntryValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = match meta.path_str().as_str() {
+            "entry" => meta.as_path_list()?,
+            _ => return Err(meta.error("expected entry(...) attribute")),
+        };
+        let mut key = SetOnce::<ValueNode>::new("key");
+        let mut value = SetOnce::<ValueNode>::new("value");
+
+        pl.each(|ref meta| match meta.path_str().as_str() {
+            "key" => key.set(ValueNode::from_meta(meta.as_value()?)?, meta),
+            "value" => value.set(ValueNode::from_meta(meta.as_value()?)?, meta),
+            _ => match key.is_set() {
+                false => key.set(ValueNode::from_meta(meta)?, meta),
+                true => value.set(ValueNode::from_meta(meta)?, meta),
+            },
+        })?;
+
+        Ok(Self::new(key.take(meta)?, value.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_value, assert_value_err};
+    use codama_nodes::{NumberValueNode, StringValueNode};
+
+    #[test]
+    fn empty() {
+        assert_value!({ map }, MapValueNode::new(vec![]).into());
+        assert_value!({ map() }, MapValueNode::new(vec![]).into());
+    }
+
+    #[test]
+    fn implicit_entries() {
+        assert_value!(
+            { map(entry("alice", 42), entry("bob", 7)) },
+            MapValueNode::new(vec![
+                MapEntryValueNode::new(StringValueNode::new("alice"), NumberValueNode::new(42u64)),
+                MapEntryValueNode::new(StringValueNode::new("bob"), NumberValueNode::new(7u64)),
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn explicit_entries() {
+        assert_value!(
+            { map(entry(value = 42, key = "alice")) },
+            MapValueNode::new(vec![MapEntryValueNode::new(
+                StringValueNode::new("alice"),
+                NumberValueNode::new(42u64)
+            )])
+            .into()
+        );
+    }
+
+    #[test]
+    fn missing_value() {
+        assert_value_err!({ map(entry("alice")) }, "value is missing");
+    }
+
+    #[test]
+    fn expected_entry() {
+        assert_value_err!({ map(42) }, "expected entry(...) attribute");
+    }
+}
@@ -1,13 +1,23 @@
 mod account_value_node;
 mod argument_value_node;
+mod array_value_node;
 mod boolean_value_node;
+mod bytes_value_node;
+mod constant_value_node;
+mod enum_value_node;
 mod instruction_input_value_node;
+mod map_value_node;
+mod none_value_node;
 mod number_value_node;
 mod payer_value_node;
 mod pda_seed_value_node;
 mod pda_value_node;
 mod program;
 mod public_key_value_node;
+mod set_value_node;
+mod some_value_node;
 mod string_value_node;
+mod struct_value_node;
 mod sysvar;
+mod tuple_value_node;
 mod value_node;
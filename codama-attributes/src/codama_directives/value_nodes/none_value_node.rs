@@ -0,0 +1,30 @@
+use crate::utils::FromMeta;
+use codama_nodes::NoneValueNode;
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for NoneValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        meta.assert_directive("none")?;
+        if !meta.is_path_or_empty_list() {
+            return Err(meta.error("none does not accept any input"));
+        }
+        Ok(Self::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_value, assert_value_err};
+
+    #[test]
+    fn ok() {
+        assert_value!({ none }, NoneValueNode::new().into());
+        assert_value!({ none() }, NoneValueNode::new().into());
+    }
+
+    #[test]
+    fn unexpected_input() {
+        assert_value_err!({ none(42) }, "none does not accept any input");
+    }
+}
@@ -0,0 +1,65 @@
+use crate::utils::FromMeta;
+use codama_errors::IteratorCombineErrors;
+use codama_nodes::{SetValueNode, ValueNode};
+use codama_syn_helpers::Meta;
+
+impl FromMeta for SetValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        meta.assert_directive("set")?;
+        if meta.is_path_or_empty_list() {
+            return Ok(Self::new(vec![]));
+        }
+
+        let items = meta
+            .as_path_list()?
+            .parse_metas()?
+            .iter()
+            .map(ValueNode::from_meta)
+            .collect_and_combine_errors()?;
+
+        Ok(Self::new(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_value, assert_value_err};
+    use codama_nodes::{BooleanValueNode, NumberValueNode, StringValueNode};
+
+    #[test]
+    fn empty() {
+        assert_value!({ set }, SetValueNode::new(vec![]).into());
+        assert_value!({ set() }, SetValueNode::new(vec![]).into());
+    }
+
+    #[test]
+    fn multiple_items() {
+        assert_value!(
+            { set(42, "hello", true) },
+            SetValueNode::new(vec![
+                NumberValueNode::new(42u64).into(),
+                StringValueNode::new("hello").into(),
+                BooleanValueNode::new(true).into(),
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn nested_items() {
+        assert_value!(
+            { set(set(1), set()) },
+            SetValueNode::new(vec![
+                SetValueNode::new(vec![NumberValueNode::new(1u64).into()]).into(),
+                SetValueNode::new(vec![]).into(),
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn unrecognized_value() {
+        assert_value_err!({ set(42, banana) }, "unrecognized value");
+    }
+}
@@ -0,0 +1,41 @@
+use crate::utils::{FromMeta, SetOnce};
+use codama_nodes::{SomeValueNode, ValueNode};
+use codama_syn_helpers::Meta;
+
+impl FromMeta for SomeValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let pl = meta.assert_directive("some")?.as_path_list()?;
+        let mut value = SetOnce::<ValueNode>::new("value");
+        pl.each(|ref meta| value.set(ValueNode::from_meta(meta)?, meta))?;
+        Ok(Self::new(value.take(meta)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_value, assert_value_err};
+    use codama_nodes::{NoneValueNode, NumberValueNode};
+
+    #[test]
+    fn ok() {
+        assert_value!(
+            { some(42) },
+            SomeValueNode::new(NumberValueNode::new(42u64)).into()
+        );
+        assert_value!(
+            { some(none) },
+            SomeValueNode::new(NoneValueNode::new()).into()
+        );
+    }
+
+    #[test]
+    fn missing_value() {
+        assert_value_err!({ some() }, "value is missing");
+    }
+
+    #[test]
+    fn value_already_set() {
+        assert_value_err!({ some(1, 2) }, "value is already set");
+    }
+}
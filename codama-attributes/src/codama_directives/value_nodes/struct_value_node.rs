@@ -0,0 +1,65 @@
+use crate::utils::FromMeta;
+use codama_errors::IteratorCombineErrors;
+use codama_nodes::{StructFieldValueNode, StructValueNode, ValueNode};
+use codama_syn_helpers::{extensions::*, Meta};
+
+impl FromMeta for StructValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        meta.assert_directive("struct")?;
+        if meta.is_path_or_empty_list() {
+            return Ok(Self::new(vec![]));
+        }
+
+        let fields = meta
+            .as_path_list()?
+            .parse_metas()?
+            .iter()
+            .map(StructFieldValueNode::from_meta)
+            .collect_and_combine_errors()?;
+
+        Ok(Self::new(fields))
+    }
+}
+
+impl FromMeta for StructFieldValueNode {
+    fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let Meta::PathValue(pv) = meta else {
+            return Err(meta.error("expected a field value: `my_field = ...`"));
+        };
+        let value = ValueNode::from_meta(&pv.value)?;
+        Ok(Self::new(pv.path.to_string(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_value, assert_value_err};
+    use codama_nodes::{NumberValueNode, StringValueNode};
+
+    #[test]
+    fn empty() {
+        assert_value!({ struct }, StructValueNode::new(vec![]).into());
+        assert_value!({ struct() }, StructValueNode::new(vec![]).into());
+    }
+
+    #[test]
+    fn fields() {
+        assert_value!(
+            { struct(name = "alice", max_age = 42) },
+            StructValueNode::new(vec![
+                StructFieldValueNode::new("name", StringValueNode::new("alice")),
+                StructFieldValueNode::new("maxAge", NumberValueNode::new(42u64)),
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn expected_a_field_value() {
+        assert_value_err!(
+            { struct(42) },
+            "expected a field value: `my_field = ...`"
+        );
+    }
+}
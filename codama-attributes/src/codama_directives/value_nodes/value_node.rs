@@ -3,13 +3,25 @@ use crate::codama_directives::value_nodes::{
 };
 use crate::utils::FromMeta;
 use codama_nodes::{
-    BooleanValueNode, NumberValueNode, PublicKeyValueNode, StringValueNode, ValueNode,
+    ArrayValueNode, BooleanValueNode, BytesValueNode, ConstantValueNode, EnumValueNode,
+    MapValueNode, NoneValueNode, NumberValueNode, PublicKeyValueNode, SetValueNode, SomeValueNode,
+    StringValueNode, StructValueNode, TupleValueNode, ValueNode,
 };
 use codama_syn_helpers::{extensions::*, Meta};
 
 impl FromMeta for ValueNode {
     fn from_meta(meta: &Meta) -> syn::Result<Self> {
         match meta.path_str().as_str() {
+            "array" => ArrayValueNode::from_meta(meta).map(Self::from),
+            "bytes" => BytesValueNode::from_meta(meta).map(Self::from),
+            "constant" => ConstantValueNode::from_meta(meta).map(Self::from),
+            "enum" => EnumValueNode::from_meta(meta).map(Self::from),
+            "map" => MapValueNode::from_meta(meta).map(Self::from),
+            "none" => NoneValueNode::from_meta(meta).map(Self::from),
+            "set" => SetValueNode::from_meta(meta).map(Self::from),
+            "some" => SomeValueNode::from_meta(meta).map(Self::from),
+            "struct" => StructValueNode::from_meta(meta).map(Self::from),
+            "tuple" => TupleValueNode::from_meta(meta).map(Self::from),
             "public_key" => PublicKeyValueNode::from_meta(meta).map(Self::from),
             "program" => public_key_value_node_from_program(meta).map(Self::from),
             "sysvar" => public_key_value_node_from_sysvar(meta).map(Self::from),
@@ -0,0 +1,138 @@
+use crate::Attribute;
+use codama_errors::CodamaError;
+use codama_syn_helpers::extensions::*;
+
+#[derive(Debug, PartialEq)]
+pub struct DocAttribute<'a> {
+    pub ast: &'a syn::Attribute,
+    pub value: String,
+}
+
+impl<'a> DocAttribute<'a> {
+    pub fn parse(ast: &'a syn::Attribute) -> syn::Result<Self> {
+        let unfeatured = ast.unfeatured();
+        let effective = unfeatured.as_ref().unwrap_or(ast);
+        Self::parse_from(ast, effective)
+    }
+
+    /// Parse a doc attribute using the effective attribute for content extraction.
+    /// `ast` is stored as the original attribute reference (for error spans).
+    /// `effective` is used to parse the actual doc string.
+    pub fn parse_from(ast: &'a syn::Attribute, effective: &syn::Attribute) -> syn::Result<Self> {
+        let name_value = effective.meta.require_name_value()?;
+        if !name_value.path.is_strict("doc") {
+            return Err(name_value.path.error("expected #[doc = \"...\"]"));
+        };
+
+        let value = name_value.value.as_string()?;
+        Ok(Self { ast, value })
+    }
+
+    /// Whether the given attribute is a `#[doc = "..."]` attribute using a string literal.
+    /// Other forms such as `#[doc(hidden)]` or `#[doc = include_str!("...")]` are not supported.
+    pub fn is_literal_doc(effective: &syn::Attribute) -> bool {
+        matches!(
+            &effective.meta,
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value: syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(_),
+                    ..
+                }),
+                ..
+            })
+        )
+    }
+
+    /// Returns the lines of this doc attribute, without the
+    /// leading space that `///` comments introduce.
+    pub fn lines(&self) -> Vec<String> {
+        self.value
+            .split('\n')
+            .map(|line| {
+                line.strip_prefix(' ')
+                    .unwrap_or(line)
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+}
+
+impl<'a> TryFrom<&'a Attribute<'a>> for &'a DocAttribute<'a> {
+    type Error = CodamaError;
+
+    fn try_from(attribute: &'a Attribute) -> Result<Self, Self::Error> {
+        match attribute {
+            Attribute::Doc(a) => Ok(a),
+            _ => Err(CodamaError::InvalidAttribute {
+                expected: "doc".to_string(),
+                actual: attribute.name(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_doc_attribute() {
+        let ast = parse_quote! { #[doc = " Hello world"] };
+        let attribute = DocAttribute::parse(&ast).unwrap();
+
+        assert_eq!(attribute.ast, &ast);
+        assert_eq!(attribute.value, " Hello world");
+        assert_eq!(attribute.lines(), vec!["Hello world".to_string()]);
+    }
+
+    #[test]
+    fn test_doc_attribute_from_comment() {
+        let item: syn::ItemStruct = parse_quote! {
+            /// Hello world
+            struct Foo;
+        };
+        let attribute = DocAttribute::parse(&item.attrs[0]).unwrap();
+
+        assert_eq!(attribute.lines(), vec!["Hello world".to_string()]);
+    }
+
+    #[test]
+    fn test_feature_gated_doc_attribute() {
+        let ast = parse_quote! { #[cfg_attr(feature = "some_feature", doc = "Hello")] };
+        let attribute = DocAttribute::parse(&ast).unwrap();
+
+        assert_eq!(attribute.ast, &ast);
+        assert_eq!(attribute.value, "Hello");
+    }
+
+    #[test]
+    fn test_multiline_doc_attribute() {
+        let ast = parse_quote! { #[doc = " First line\n Second line\n\n   Indented"] };
+        let attribute = DocAttribute::parse(&ast).unwrap();
+
+        assert_eq!(
+            attribute.lines(),
+            vec![
+                "First line".to_string(),
+                "Second line".to_string(),
+                "".to_string(),
+                "  Indented".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_literal_doc() {
+        assert!(DocAttribute::is_literal_doc(
+            &parse_quote! { #[doc = "Hello"] }
+        ));
+        assert!(!DocAttribute::is_literal_doc(
+            &parse_quote! { #[doc(hidden)] }
+        ));
+        assert!(!DocAttribute::is_literal_doc(
+            &parse_quote! { #[doc = include_str!("README.md")] }
+        ));
+    }
+}
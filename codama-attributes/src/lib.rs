@@ -6,7 +6,9 @@ mod attributes;
 mod codama_attribute;
 mod codama_directives;
 mod derive_attribute;
+mod doc_attribute;
 mod repr_attribute;
+mod shank_attributes;
 mod try_from_filter;
 mod unsupported_attribute;
 
@@ -16,6 +18,9 @@ pub use attributes::*;
 pub use codama_attribute::*;
 pub use codama_directives::*;
 pub use derive_attribute::*;
+pub use doc_attribute::*;
 pub use repr_attribute::*;
+pub use shank_attributes::*;
 pub use try_from_filter::*;
 pub use unsupported_attribute::*;
+pub use utils::FromMeta;
@@ -38,6 +38,25 @@ impl<'a> ReprAttribute<'a> {
             _ => None,
         })
     }
+
+    /// Whether the representation includes the given path, e.g. `C` or `packed`.
+    pub fn has(&self, name: &str) -> bool {
+        self.metas.iter().any(|meta| match meta {
+            syn::Meta::Path(p) => p.is_strict(name),
+            syn::Meta::List(list) => list.path.is_strict(name),
+            _ => false,
+        })
+    }
+
+    /// The minimum alignment requested with `align(N)`, if any.
+    pub fn get_align(&self) -> Option<usize> {
+        self.metas.iter().find_map(|meta| match meta {
+            syn::Meta::List(list) if list.path.is_strict("align") => {
+                list.parse_args::<syn::LitInt>().ok()?.base10_parse().ok()
+            }
+            _ => None,
+        })
+    }
 }
 
 impl<'a> TryFrom<&'a Attribute<'a>> for &'a ReprAttribute<'a> {
@@ -94,4 +113,25 @@ mod tests {
             Some(NumberTypeNode::le(U32))
         );
     }
+
+    #[test]
+    fn test_has() {
+        let ast = parse_quote! { #[repr(C, packed(2))] };
+        let attribute = ReprAttribute::parse(&ast).unwrap();
+
+        assert!(attribute.has("C"));
+        assert!(attribute.has("packed"));
+        assert!(!attribute.has("transparent"));
+    }
+
+    #[test]
+    fn test_get_align() {
+        let ast = parse_quote! { #[repr(C, align(16))] };
+        let attribute = ReprAttribute::parse(&ast).unwrap();
+        assert_eq!(attribute.get_align(), Some(16));
+
+        let ast = parse_quote! { #[repr(C)] };
+        let attribute = ReprAttribute::parse(&ast).unwrap();
+        assert_eq!(attribute.get_align(), None);
+    }
 }
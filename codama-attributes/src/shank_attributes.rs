@@ -0,0 +1,385 @@
+use crate::{
+    utils::SetOnce, AccountDirective, AttributeContext, CodamaAttribute, CodamaDirective,
+    DefaultValueDirective, SeedDirective, SeedDirectiveType, TypeDirective,
+};
+use codama_errors::IteratorCombineErrors;
+use codama_nodes::{
+    BooleanTypeNode, BytesTypeNode, BytesValueNode, CamelCaseString, ConstantPdaSeedNode,
+    DefaultValueStrategy, Docs, FixedSizeTypeNode, InstructionAccountNode, IsAccountSigner,
+    NumberFormat::*, NumberTypeNode, ProgramIdValueNode, PublicKeyTypeNode, StringTypeNode,
+    StringValueNode, TypeNode, VariablePdaSeedNode,
+};
+use codama_syn_helpers::{extensions::*, ConstEvaluator, Meta};
+
+/// Whether the given attribute is a Shank attribute that can be expressed with Codama directives.
+/// I.e. `#[account(0, ...)]` on enum variants, `#[seeds(...)]` on items and `#[padding]` on fields.
+pub fn is_shank_attribute(effective: &syn::Attribute, ctx: &AttributeContext) -> bool {
+    let path = effective.path();
+    match (path.prefix().as_str(), path.last_str().as_str(), ctx) {
+        ("" | "shank", "account", AttributeContext::Variant(_)) => {
+            // Shank accounts always start with their index — e.g. `#[account(0, writable)]`.
+            let Ok(list) = effective.meta.require_list() else {
+                return false;
+            };
+            matches!(
+                list.parse_metas().ok().as_deref(),
+                Some([
+                    Meta::Expr(syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(_),
+                        ..
+                    })),
+                    ..
+                ])
+            )
+        }
+        ("" | "shank", "seeds", AttributeContext::Item(_)) => true,
+        ("" | "shank", "padding", AttributeContext::Field(_)) => true,
+        _ => false,
+    }
+}
+
+/// Parse a Shank attribute into one or more Codama attributes.
+pub fn parse_shank_attribute<'a>(
+    ast: &'a syn::Attribute,
+    effective: &syn::Attribute,
+    ctx: &AttributeContext,
+    constants: &ConstEvaluator,
+) -> syn::Result<Vec<CodamaAttribute<'a>>> {
+    let directives: Vec<CodamaDirective> = match effective.path().last_str().as_str() {
+        "account" => vec![parse_shank_account(effective)?.into()],
+        "seeds" => parse_shank_seeds(effective)?
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        "padding" => parse_shank_padding(effective, ctx, constants)?,
+        _ => return Err(effective.error("unrecognized shank attribute")),
+    };
+
+    Ok(directives
+        .into_iter()
+        .map(|directive| CodamaAttribute {
+            ast,
+            directive: Box::new(directive),
+        })
+        .collect())
+}
+
+/// Shank lists the accounts of an instruction in order so the index
+/// of each `#[account(...)]` attribute must match its position.
+pub fn validate_shank_account_indexes<'a>(
+    attrs: impl IntoIterator<Item = &'a syn::Attribute>,
+    ctx: &AttributeContext,
+) -> syn::Result<()> {
+    attrs
+        .into_iter()
+        .filter(|attr| is_shank_attribute(attr, ctx) && attr.path().last_str() == "account")
+        .enumerate()
+        .map(|(position, attr)| {
+            let metas = attr.meta.require_list()?.parse_metas()?;
+            let index = metas[0].as_expr()?;
+            match index.as_unsigned_integer::<usize>()? {
+                i if i == position => Ok(()),
+                i => Err(index.error(format!("expected account index {position}, found {i}"))),
+            }
+        })
+        .collect_and_combine_errors()
+        .map(|_| ())
+}
+
+/// E.g. `#[account(0, writable, signer, name = "payer", desc = "The payer")]`.
+/// The index is checked by `validate_shank_account_indexes`.
+fn parse_shank_account(effective: &syn::Attribute) -> syn::Result<AccountDirective> {
+    let list = effective.meta.require_list()?;
+    let mut name = SetOnce::<CamelCaseString>::new("name");
+    let mut is_writable = false;
+    let mut is_signer = IsAccountSigner::False;
+    let mut is_optional = false;
+    let mut docs = SetOnce::<Docs>::new("desc");
+
+    for (i, meta) in list.parse_metas()?.iter().enumerate() {
+        match (i, meta.path_str().as_str()) {
+            (0, _) => {}
+            (_, "name") => name.set(meta.as_value()?.as_expr()?.as_string()?.into(), meta)?,
+            (_, "desc" | "description" | "docs") => {
+                docs.set(vec![meta.as_value()?.as_expr()?.as_string()?].into(), meta)?
+            }
+            (_, "writable" | "write" | "writ" | "w" | "mut") => is_writable = true,
+            (_, "signer" | "sign" | "sig" | "s") => is_signer = IsAccountSigner::True,
+            (_, "optional_signer") => is_signer = IsAccountSigner::Either,
+            (_, "optional" | "option" | "opt") => is_optional = true,
+            _ => return Err(meta.error("unrecognized shank account attribute")),
+        }
+    }
+
+    Ok(AccountDirective {
+        account: InstructionAccountNode {
+            is_optional,
+            docs: docs.option().unwrap_or_default(),
+            ..InstructionAccountNode::new(name.take(effective)?, is_writable, is_signer)
+        },
+    })
+}
+
+/// E.g. `#[seeds("counter", authority("The authority"), index("The index", u8))]`.
+fn parse_shank_seeds(effective: &syn::Attribute) -> syn::Result<Vec<SeedDirective>> {
+    let list = effective.meta.require_list()?;
+    list.parse_metas()?
+        .iter()
+        .map(|meta| {
+            let seed = match meta {
+                Meta::Expr(syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                })) => ConstantPdaSeedNode::new(
+                    StringTypeNode::utf8(),
+                    StringValueNode::new(value.value()),
+                )
+                .into(),
+                Meta::Expr(syn::Expr::Path(path)) if path.path.is_strict("program_id") => {
+                    ConstantPdaSeedNode::new(PublicKeyTypeNode::new(), ProgramIdValueNode::new())
+                        .into()
+                }
+                Meta::PathList(pl) => {
+                    let args = pl.parse_metas()?;
+                    let (docs, r#type) = match args.as_slice() {
+                        [desc] => (desc, None),
+                        [desc, r#type] => (desc, Some(r#type)),
+                        _ => return Err(meta.error("expected `name(\"description\", Type)`")),
+                    };
+                    let r#type = match r#type {
+                        Some(r#type) => get_shank_seed_type_node(r#type)?,
+                        None => PublicKeyTypeNode::new().into(),
+                    };
+                    VariablePdaSeedNode {
+                        docs: vec![docs.as_expr()?.as_string()?].into(),
+                        ..VariablePdaSeedNode::new(pl.path.to_string(), r#type)
+                    }
+                    .into()
+                }
+                _ => return Err(meta.error("expected a string literal or `name(\"description\")`")),
+            };
+            Ok(SeedDirective {
+                seed: SeedDirectiveType::Defined(seed),
+            })
+        })
+        .collect()
+}
+
+fn get_shank_seed_type_node(meta: &Meta) -> syn::Result<TypeNode> {
+    let path = meta.as_path()?;
+    match path.last_str().as_str() {
+        "Pubkey" => Ok(PublicKeyTypeNode::new().into()),
+        "String" | "str" => Ok(StringTypeNode::utf8().into()),
+        "bool" => Ok(BooleanTypeNode::default().into()),
+        "u8" => Ok(NumberTypeNode::le(U8).into()),
+        "u16" => Ok(NumberTypeNode::le(U16).into()),
+        "u32" => Ok(NumberTypeNode::le(U32).into()),
+        "u64" => Ok(NumberTypeNode::le(U64).into()),
+        "u128" => Ok(NumberTypeNode::le(U128).into()),
+        "i8" => Ok(NumberTypeNode::le(I8).into()),
+        "i16" => Ok(NumberTypeNode::le(I16).into()),
+        "i32" => Ok(NumberTypeNode::le(I32).into()),
+        "i64" => Ok(NumberTypeNode::le(I64).into()),
+        "i128" => Ok(NumberTypeNode::le(I128).into()),
+        _ => Err(path.error("unsupported shank seed type")),
+    }
+}
+
+/// E.g. `#[padding] _padding: [u8; 32]`, which becomes an omitted, zero-filled bytes field.
+fn parse_shank_padding(
+    effective: &syn::Attribute,
+    ctx: &AttributeContext,
+    constants: &ConstEvaluator,
+) -> syn::Result<Vec<CodamaDirective>> {
+    effective.meta.require_path_only()?;
+    let size = match ctx {
+        AttributeContext::Field(syn::Field {
+            ty: syn::Type::Array(syn::TypeArray { elem, len, .. }),
+            ..
+        }) if matches!(elem.as_ref(), syn::Type::Path(p) if p.path.is_strict("u8")) => {
+            len.as_unsigned_integer_with::<usize>(constants)?
+        }
+        _ => return Err(effective.error("padding fields must be `[u8; N]` arrays")),
+    };
+
+    Ok(vec![
+        TypeDirective {
+            node: FixedSizeTypeNode::<TypeNode>::new(BytesTypeNode::new(), size).into(),
+        }
+        .into(),
+        DefaultValueDirective {
+            node: BytesValueNode::base16("00".repeat(size)).into(),
+            default_value_strategy: Some(DefaultValueStrategy::Omitted),
+        }
+        .into(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Attribute, Attributes};
+    use syn::parse_quote;
+
+    #[test]
+    fn shank_account() {
+        let variant: syn::Variant = parse_quote! {
+            #[account(0, writable, signer, name = "payer", desc = "The payer")]
+            #[account(1, optional, name = "authority")]
+            Create
+        };
+        let attributes = Attributes::parse_shank(&variant.attrs, (&variant).into()).unwrap();
+        let accounts = attributes
+            .iter()
+            .filter_map(|attr| match attr {
+                Attribute::Codama(attr) => match attr.directive.as_ref() {
+                    CodamaDirective::Account(directive) => Some(directive.account.clone()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            accounts,
+            vec![
+                InstructionAccountNode {
+                    docs: vec!["The payer".to_string()].into(),
+                    ..InstructionAccountNode::new("payer", true, true)
+                },
+                InstructionAccountNode {
+                    is_optional: true,
+                    ..InstructionAccountNode::new("authority", false, false)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn shank_account_without_name() {
+        let variant: syn::Variant = parse_quote! {
+            #[account(0, writable)]
+            Create
+        };
+        let error = Attributes::parse_shank(&variant.attrs, (&variant).into()).unwrap_err();
+        assert_eq!(error.to_string(), "name is missing");
+    }
+
+    #[test]
+    fn shank_account_out_of_order() {
+        let variant: syn::Variant = parse_quote! {
+            #[account(0, writable, name = "payer")]
+            #[account(2, name = "authority")]
+            Create
+        };
+        let error = Attributes::parse_shank(&variant.attrs, (&variant).into()).unwrap_err();
+        assert_eq!(error.to_string(), "expected account index 1, found 2");
+    }
+
+    #[test]
+    fn non_shank_account_attributes_are_unsupported() {
+        let field: syn::Field = parse_quote! {
+            #[account(mut)]
+            pub counter: Account<'info, Counter>
+        };
+        let attributes = Attributes::parse_shank(&field.attrs, (&field).into()).unwrap();
+        assert!(matches!(attributes[0], Attribute::Unsupported(_)));
+    }
+
+    #[test]
+    fn shank_seeds() {
+        let item: syn::Item = parse_quote! {
+            #[seeds("counter", program_id, authority("The authority"), index("The index", u8))]
+            struct Counter;
+        };
+        let syn::Item::Struct(syn::ItemStruct { attrs, .. }) = &item else {
+            unreachable!()
+        };
+        let attributes = Attributes::parse_shank(attrs, (&item).into()).unwrap();
+        let seeds = attributes
+            .iter()
+            .filter_map(|attr| match attr {
+                Attribute::Codama(attr) => match attr.directive.as_ref() {
+                    CodamaDirective::Seed(SeedDirective {
+                        seed: SeedDirectiveType::Defined(seed),
+                    }) => Some(seed.clone()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            seeds,
+            vec![
+                ConstantPdaSeedNode::new(StringTypeNode::utf8(), StringValueNode::new("counter"))
+                    .into(),
+                ConstantPdaSeedNode::new(PublicKeyTypeNode::new(), ProgramIdValueNode::new())
+                    .into(),
+                VariablePdaSeedNode {
+                    docs: vec!["The authority".to_string()].into(),
+                    ..VariablePdaSeedNode::new("authority", PublicKeyTypeNode::new())
+                }
+                .into(),
+                VariablePdaSeedNode {
+                    docs: vec!["The index".to_string()].into(),
+                    ..VariablePdaSeedNode::new("index", NumberTypeNode::le(U8))
+                }
+                .into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn shank_padding() {
+        let field: syn::Field = parse_quote! {
+            #[padding]
+            pub padding: [u8; 3]
+        };
+        let attributes = Attributes::parse_shank(&field.attrs, (&field).into()).unwrap();
+        assert_eq!(attributes.len(), 2);
+        assert!(matches!(
+            &attributes[0],
+            Attribute::Codama(CodamaAttribute { directive, .. })
+                if matches!(directive.as_ref(), CodamaDirective::Type(_))
+        ));
+        assert!(matches!(
+            &attributes[1],
+            Attribute::Codama(CodamaAttribute { directive, .. })
+                if matches!(
+                    directive.as_ref(),
+                    CodamaDirective::DefaultValue(DefaultValueDirective {
+                        default_value_strategy: Some(DefaultValueStrategy::Omitted),
+                        ..
+                    })
+                )
+        ));
+    }
+
+    #[test]
+    fn shank_padding_on_non_byte_arrays() {
+        let field: syn::Field = parse_quote! {
+            #[padding]
+            pub padding: u64
+        };
+        let error = Attributes::parse_shank(&field.attrs, (&field).into()).unwrap_err();
+        assert_eq!(error.to_string(), "padding fields must be `[u8; N]` arrays");
+    }
+
+    #[test]
+    fn shank_attributes_are_ignored_outside_shank_items() {
+        let field: syn::Field = parse_quote! {
+            #[padding]
+            pub padding: u64
+        };
+        let attributes = Attributes::parse(&field.attrs, (&field).into()).unwrap();
+        assert!(matches!(attributes[0], Attribute::Unsupported(_)));
+
+        let variant: syn::Variant = parse_quote! {
+            #[account(0, writable)]
+            Create
+        };
+        let attributes = Attributes::parse(&variant.attrs, (&variant).into()).unwrap();
+        assert!(matches!(attributes[0], Attribute::Unsupported(_)));
+    }
+}
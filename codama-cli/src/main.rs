@@ -1,7 +1,9 @@
-use clap::{Parser, Subcommand};
-use codama::Codama;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use codama::{
+    validate, CfgContext, Codama, JsRenderer, NodeTrait, RootNode, RustRenderer, TypeRegistry,
+};
 use codama_errors::CodamaResult;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -26,7 +28,120 @@ enum Commands {
         /// Prettyprint the JSON output
         #[arg(short, long)]
         pretty: bool,
+
+        /// Format of the generated IDL
+        #[arg(short, long, value_enum, default_value_t = Format::Codama)]
+        format: Format,
+
+        /// File mapping Rust types to type nodes, e.g. `Amount -> amount(number(u64), decimals = 6)`
+        #[arg(long)]
+        types: Option<PathBuf>,
+
+        #[command(flatten)]
+        cfg: CfgArgs,
     },
+
+    /// Validate the IDL of a Rust project or an existing IDL file
+    #[command(name = "validate")]
+    Validate {
+        /// Path to the Rust project or to a JSON IDL file (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Fail on warnings as well as errors
+        #[arg(long)]
+        deny_warnings: bool,
+
+        #[command(flatten)]
+        cfg: CfgArgs,
+    },
+
+    /// Compare two versions of an IDL and report breaking changes
+    #[command(name = "diff")]
+    Diff {
+        /// Path to the old Rust project or JSON IDL file
+        old: PathBuf,
+
+        /// Path to the new Rust project or JSON IDL file
+        new: PathBuf,
+
+        #[command(flatten)]
+        cfg: CfgArgs,
+    },
+
+    /// Generate a client from a Rust project or an existing IDL file
+    #[command(name = "generate-client")]
+    GenerateClient {
+        /// Path to the Rust project or to a JSON IDL file (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Language of the generated client
+        #[arg(short, long, value_enum, default_value_t = Language::Js)]
+        language: Language,
+
+        /// Output directory
+        #[arg(short, long)]
+        output: PathBuf,
+
+        #[command(flatten)]
+        cfg: CfgArgs,
+    },
+}
+
+/// The `cfg` configuration used to read Rust projects. All features are enabled by default.
+#[derive(Args)]
+struct CfgArgs {
+    /// Only enable the given comma-separated features
+    #[arg(long, value_delimiter = ',', conflicts_with_all = ["all_features", "default_features"])]
+    features: Option<Vec<String>>,
+
+    /// Enable all features
+    #[arg(long, conflicts_with = "default_features")]
+    all_features: bool,
+
+    /// Only enable the default features of the crate
+    #[arg(long)]
+    default_features: bool,
+
+    /// Enable a custom `cfg` option, e.g. `test` or `network="devnet"`
+    #[arg(long = "cfg", value_name = "CFG")]
+    options: Vec<String>,
+}
+
+impl CfgArgs {
+    fn cfg_context(&self) -> CfgContext {
+        let mut cfg = match (&self.features, self.default_features) {
+            (Some(features), _) => CfgContext::new().with_features(features),
+            (None, true) => CfgContext::new().with_default_features(),
+            (None, false) => CfgContext::new().with_all_features(),
+        };
+        for option in &self.options {
+            cfg = match option.split_once('=') {
+                Some((name, value)) => {
+                    cfg.with_cfg_value(name.trim(), value.trim().trim_matches('"'))
+                }
+                None => cfg.with_cfg(option.trim()),
+            };
+        }
+        cfg
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Codama IDL
+    Codama,
+    /// Anchor IDL, following the Anchor 0.30 specification
+    Anchor,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Language {
+    /// TypeScript client for @solana/kit
+    Js,
+    /// Rust client module
+    Rust,
 }
 
 fn main() -> CodamaResult<()> {
@@ -37,14 +152,30 @@ fn main() -> CodamaResult<()> {
             path,
             output,
             pretty,
+            format,
+            types,
+            cfg,
         } => {
-            let codama = Codama::load(&path)?;
+            let codama = Codama::load_with_cfg(&path, &cfg.cfg_context())?;
+            let codama = match types {
+                Some(types) => codama.with_type_registry(TypeRegistry::load(types)?),
+                None => codama,
+            };
             let idl = codama.get_idl()?;
 
-            let json = if pretty {
-                serde_json::to_string_pretty(&idl)?
-            } else {
-                serde_json::to_string(&idl)?
+            let json = match format {
+                Format::Codama if pretty => serde_json::to_string_pretty(&idl)?,
+                Format::Codama => serde_json::to_string(&idl)?,
+                Format::Anchor => {
+                    let export = idl.to_anchor_idl();
+                    for warning in &export.warnings {
+                        eprintln!("{}", warning);
+                    }
+                    match pretty {
+                        true => export.idl.to_json_pretty()?,
+                        false => export.idl.to_json()?,
+                    }
+                }
             };
 
             match output {
@@ -57,7 +188,74 @@ fn main() -> CodamaResult<()> {
                 }
             }
         }
+        Commands::Validate {
+            path,
+            deny_warnings,
+            cfg,
+        } => {
+            let idl = load_idl(&path, &cfg.cfg_context())?;
+            let diagnostics = validate(&idl);
+            for diagnostic in &diagnostics {
+                println!("{}", diagnostic);
+            }
+
+            let failed = diagnostics
+                .iter()
+                .any(|diagnostic| deny_warnings || diagnostic.is_error());
+            if failed {
+                eprintln!(
+                    "✗ IDL validation failed with {} issue(s)",
+                    diagnostics.len()
+                );
+                std::process::exit(1);
+            }
+            eprintln!("✓ IDL is valid");
+        }
+        Commands::Diff { old, new, cfg } => {
+            let cfg = cfg.cfg_context();
+            let changes = load_idl(&old, &cfg)?.diff(&load_idl(&new, &cfg)?);
+            for change in &changes {
+                println!("{}", change);
+            }
+
+            let breaking = changes.iter().filter(|change| change.breaking).count();
+            if breaking > 0 {
+                eprintln!("✗ Found {} breaking change(s)", breaking);
+                std::process::exit(1);
+            }
+            eprintln!("✓ No breaking changes");
+        }
+        Commands::GenerateClient {
+            path,
+            language,
+            output,
+            cfg,
+        } => {
+            let idl = load_idl(&path, &cfg.cfg_context())?;
+            match language {
+                Language::Js => JsRenderer::new().render_to_directory(&idl, &output)?,
+                Language::Rust => {
+                    let client = RustRenderer::new().render_client(&idl)?;
+                    for warning in &client.warnings {
+                        eprintln!("{}", warning);
+                    }
+                    client.files.write_to_directory(&output)?
+                }
+            }
+            eprintln!("✓ Client written to: {}", output.display());
+        }
     }
 
     Ok(())
 }
+
+/// Load an IDL from a JSON file or generate it from a Rust project using the given `cfg` context.
+fn load_idl(path: &Path, cfg: &CfgContext) -> CodamaResult<RootNode> {
+    match path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        true => RootNode::from_json(&std::fs::read_to_string(path)?),
+        false => Codama::load_with_cfg(path, cfg)?.get_idl(),
+    }
+}
@@ -7,3 +7,8 @@ pub struct Counter {
     pub authority: Pubkey,
     pub count: u64,
 }
+
+#[cfg_attr(feature = "codama", derive(CodamaAccount))]
+pub struct Config {
+    pub admin: Pubkey,
+}
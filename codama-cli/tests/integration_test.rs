@@ -76,6 +76,82 @@ fn test_generate_idl_with_output_flag() {
     fs::remove_file(&output_file).unwrap();
 }
 
+#[test]
+fn test_generate_idl_with_anchor_format() {
+    let mut cmd = get_cli_command();
+    let crate_path = simple_account_fixture_path();
+
+    cmd.arg("generate-idl")
+        .arg(crate_path)
+        .arg("--format")
+        .arg("anchor")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""name":"test_simple_account""#))
+        .stdout(predicate::str::contains(r#""spec":"0.1.0""#))
+        .stdout(predicate::str::contains(r#""name":"Counter""#))
+        .stderr(predicate::str::contains("warning: missing discriminator"));
+}
+
+#[test]
+fn test_generate_idl_with_types_flag() {
+    let mut cmd = get_cli_command();
+    let crate_path = simple_account_fixture_path();
+    let types_path = crate_path.parent().unwrap().join("types.codama");
+
+    cmd.arg("generate-idl")
+        .arg(crate_path)
+        .arg("--types")
+        .arg(types_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#""kind":"amountTypeNode","decimals":6"#,
+        ));
+}
+
+#[test]
+fn test_generate_idl_with_cfg_flags() {
+    let crate_path = simple_account_fixture_path();
+
+    // All features are enabled by default.
+    get_cli_command()
+        .arg("generate-idl")
+        .arg(&crate_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""name":"config""#));
+
+    get_cli_command()
+        .arg("generate-idl")
+        .arg(&crate_path)
+        .arg("--default-features")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""name":"counter""#))
+        .stdout(predicate::str::contains(r#""name":"config""#).not());
+
+    get_cli_command()
+        .arg("generate-idl")
+        .arg(&crate_path)
+        .arg("--features")
+        .arg("codama")
+        .arg("--cfg")
+        .arg("network=\"devnet\"")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""name":"config""#));
+
+    get_cli_command()
+        .arg("generate-idl")
+        .arg(&crate_path)
+        .arg("--features")
+        .arg("codama")
+        .arg("--all-features")
+        .assert()
+        .failure();
+}
+
 #[test]
 fn test_version_flag() {
     let mut cmd = get_cli_command();
@@ -84,3 +160,108 @@ fn test_version_flag() {
         .success()
         .stdout(predicate::str::contains(env!("CARGO_PKG_VERSION")));
 }
+
+#[test]
+fn test_validate_simple_account() {
+    let mut cmd = get_cli_command();
+    let crate_path = simple_account_fixture_path();
+
+    cmd.arg("validate")
+        .arg(crate_path)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("IDL is valid"));
+}
+
+#[test]
+fn test_validate_invalid_idl_file() {
+    let temp_dir = std::env::temp_dir();
+    let idl_file = temp_dir.join(format!(
+        "test_invalid_idl_{:?}.json",
+        std::thread::current().id()
+    ));
+    fs::write(
+        &idl_file,
+        r#"{"kind":"rootNode","standard":"codama","version":"1.0.0","program":{"kind":"programNode","name":"test","publicKey":"","version":"","accounts":[],"instructions":[],"definedTypes":[{"kind":"definedTypeNode","name":"wrapper","type":{"kind":"definedTypeLinkNode","name":"missing"}}],"pdas":[],"errors":[]},"additionalPrograms":[]}"#,
+    )
+    .unwrap();
+
+    let mut cmd = get_cli_command();
+    cmd.arg("validate")
+        .arg(&idl_file)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "error: Unresolved defined type link `missing`",
+        ));
+
+    fs::remove_file(&idl_file).unwrap();
+}
+
+#[test]
+fn test_diff_same_idl() {
+    let mut cmd = get_cli_command();
+    let crate_path = simple_account_fixture_path();
+
+    cmd.arg("diff")
+        .arg(&crate_path)
+        .arg(&crate_path)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No breaking changes"));
+}
+
+#[test]
+fn test_diff_breaking_changes() {
+    let temp_dir = std::env::temp_dir();
+    let thread_id = std::thread::current().id();
+    let old_file = temp_dir.join(format!("test_diff_old_{:?}.json", thread_id));
+    let new_file = temp_dir.join(format!("test_diff_new_{:?}.json", thread_id));
+    fs::write(
+        &old_file,
+        r#"{"kind":"rootNode","standard":"codama","version":"1.0.0","program":{"kind":"programNode","name":"test","publicKey":"","version":"","accounts":[],"instructions":[],"definedTypes":[{"kind":"definedTypeNode","name":"amount","type":{"kind":"numberTypeNode","format":"u32","endian":"le"}}],"pdas":[],"errors":[]},"additionalPrograms":[]}"#,
+    )
+    .unwrap();
+    fs::write(
+        &new_file,
+        r#"{"kind":"rootNode","standard":"codama","version":"1.0.0","program":{"kind":"programNode","name":"test","publicKey":"","version":"","accounts":[],"instructions":[],"definedTypes":[{"kind":"definedTypeNode","name":"amount","type":{"kind":"numberTypeNode","format":"u64","endian":"le"}}],"pdas":[],"errors":[]},"additionalPrograms":[]}"#,
+    )
+    .unwrap();
+
+    let mut cmd = get_cli_command();
+    cmd.arg("diff")
+        .arg(&old_file)
+        .arg(&new_file)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "breaking: number format changed from u32 to u64",
+        ));
+
+    fs::remove_file(&old_file).unwrap();
+    fs::remove_file(&new_file).unwrap();
+}
+
+#[test]
+fn test_generate_client_js() {
+    let output = std::env::temp_dir().join(format!(
+        "test_generate_client_js_{:?}",
+        std::thread::current().id()
+    ));
+    let _ = fs::remove_dir_all(&output);
+
+    let mut cmd = get_cli_command();
+    cmd.arg("generate-client")
+        .arg(simple_account_fixture_path())
+        .arg("--output")
+        .arg(&output)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Client written to"));
+
+    let index = fs::read_to_string(output.join("index.ts")).unwrap();
+    assert!(index.contains("export * from './programs';"));
+    assert!(output.join("shared/index.ts").exists());
+
+    fs::remove_dir_all(&output).unwrap();
+}
@@ -38,6 +38,21 @@ pub enum CodamaError {
 
     #[error("Invalid Codama directive, Expected {expected}, got {actual}")]
     InvalidCodamaDirective { expected: String, actual: String },
+
+    #[error("Could not encode value: {0}")]
+    Encoding(String),
+
+    #[error("Could not decode bytes: {0}")]
+    Decoding(String),
+
+    #[error("Could not derive program address: {0}")]
+    ProgramAddress(String),
+
+    #[error("Invalid Anchor IDL: {0}")]
+    AnchorIdl(String),
+
+    #[error("Could not render code: {0}")]
+    Rendering(String),
 }
 
 pub type CodamaResult<T> = Result<T, CodamaError>;
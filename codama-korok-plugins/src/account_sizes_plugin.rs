@@ -0,0 +1,34 @@
+use crate::KorokPlugin;
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{KorokVisitable, SetAccountSizesVisitor};
+
+/// Fills the `size` of fixed-size accounts once the root node is set.
+/// The `DefaultPlugin` already fills sizes, so this plugin is mostly useful
+/// with size discriminators or when the default plugin is disabled.
+#[derive(Default)]
+pub struct AccountSizesPlugin {
+    size_discriminators: bool,
+}
+
+impl AccountSizesPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also add a `SizeDiscriminatorNode` to fixed-size accounts that have no discriminators.
+    pub fn with_size_discriminators() -> Self {
+        Self {
+            size_discriminators: true,
+        }
+    }
+}
+
+impl KorokPlugin for AccountSizesPlugin {
+    fn on_root_node_set(&self, visitable: &mut dyn KorokVisitable) -> CodamaResult<()> {
+        let mut visitor = match self.size_discriminators {
+            true => SetAccountSizesVisitor::with_size_discriminators(),
+            false => SetAccountSizesVisitor::new(),
+        };
+        visitable.accept(&mut visitor)
+    }
+}
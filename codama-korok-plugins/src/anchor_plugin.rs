@@ -0,0 +1,19 @@
+use crate::KorokPlugin;
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{
+    KorokVisitable, SetAnchorAccountsVisitor, SetAnchorDefinedTypesVisitor, SetAnchorErrorsVisitor,
+    SetAnchorInstructionsVisitor,
+};
+
+/// Extracts accounts, instructions, errors and types from Anchor programs.
+/// It relies on the `DefaultPlugin` to identify field types and combine modules.
+pub struct AnchorPlugin;
+impl KorokPlugin for AnchorPlugin {
+    fn on_program_items_set(&self, visitable: &mut dyn KorokVisitable) -> CodamaResult<()> {
+        visitable.accept(&mut SetAnchorDefinedTypesVisitor::new())?;
+        visitable.accept(&mut SetAnchorAccountsVisitor::new())?;
+        visitable.accept(&mut SetAnchorInstructionsVisitor::new())?;
+        visitable.accept(&mut SetAnchorErrorsVisitor::new())?;
+        Ok(())
+    }
+}
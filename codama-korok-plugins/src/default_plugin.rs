@@ -2,15 +2,34 @@ use crate::KorokPlugin;
 use codama_errors::CodamaResult;
 use codama_korok_visitors::{
     ApplyTypeModifiersVisitor, ApplyTypeOverridesVisitor, CombineModulesVisitor,
-    IdentifyFieldTypesVisitor, KorokVisitable, SetAccountsVisitor, SetDefaultValuesVisitor,
-    SetDefinedTypesVisitor, SetErrorsVisitor, SetInstructionsVisitor, SetPdasVisitor,
-    SetProgramMetadataVisitor,
+    IdentifyFieldTypesVisitor, KorokVisitable, SetAccountSizesVisitor, SetAccountsVisitor,
+    SetDefaultValuesVisitor, SetDefinedTypesVisitor, SetErrorsVisitor, SetInstructionsVisitor,
+    SetPdasVisitor, SetProgramMetadataVisitor, TypeRegistry,
 };
 
-pub struct DefaultPlugin;
+/// Identifies field types, sets program items and combines them into a root node.
+/// Field types are identified using the given registry before the built-in mappings,
+/// e.g. to map domain wrapper types to `AmountTypeNode`s once for all programs.
+#[derive(Default)]
+pub struct DefaultPlugin {
+    registry: TypeRegistry,
+}
+
+impl DefaultPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_registry(mut self, registry: TypeRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+}
+
 impl KorokPlugin for DefaultPlugin {
     fn on_fields_set(&self, visitable: &mut dyn KorokVisitable) -> CodamaResult<()> {
-        visitable.accept(&mut IdentifyFieldTypesVisitor::new())?;
+        let registry = self.registry.clone();
+        visitable.accept(&mut IdentifyFieldTypesVisitor::new().with_registry(registry))?;
         visitable.accept(&mut ApplyTypeOverridesVisitor::new())?;
         visitable.accept(&mut ApplyTypeModifiersVisitor::new())?;
         visitable.accept(&mut SetDefaultValuesVisitor::new())?;
@@ -29,6 +48,7 @@ impl KorokPlugin for DefaultPlugin {
     fn on_root_node_set(&self, visitable: &mut dyn KorokVisitable) -> CodamaResult<()> {
         visitable.accept(&mut SetProgramMetadataVisitor::new())?;
         visitable.accept(&mut CombineModulesVisitor::new())?;
+        visitable.accept(&mut SetAccountSizesVisitor::new())?;
         Ok(())
     }
 }
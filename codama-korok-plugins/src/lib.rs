@@ -1,5 +1,11 @@
+mod account_sizes_plugin;
+mod anchor_plugin;
 mod default_plugin;
 mod plugin;
+mod shank_plugin;
 
+pub use account_sizes_plugin::*;
+pub use anchor_plugin::*;
 pub use default_plugin::*;
 pub use plugin::*;
+pub use shank_plugin::*;
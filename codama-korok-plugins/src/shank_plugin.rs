@@ -0,0 +1,19 @@
+use crate::KorokPlugin;
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{
+    KorokVisitable, SetAccountsVisitor, SetDefinedTypesVisitor, SetInstructionsVisitor,
+};
+
+/// Extracts accounts, instructions and types from programs annotated with Shank —
+/// i.e. `#[derive(ShankAccount)]`, `#[derive(ShankInstruction)]` and `#[derive(ShankType)]`.
+/// Shank attributes such as `#[account(0, writable)]` or `#[seeds(...)]` are parsed
+/// as Codama directives, so this plugin relies on the `DefaultPlugin` for everything else.
+pub struct ShankPlugin;
+impl KorokPlugin for ShankPlugin {
+    fn on_program_items_set(&self, visitable: &mut dyn KorokVisitable) -> CodamaResult<()> {
+        visitable.accept(&mut SetDefinedTypesVisitor::shank())?;
+        visitable.accept(&mut SetAccountsVisitor::shank())?;
+        visitable.accept(&mut SetInstructionsVisitor::shank())?;
+        Ok(())
+    }
+}
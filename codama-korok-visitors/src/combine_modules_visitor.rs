@@ -26,13 +26,13 @@ impl KorokVisitor for CombineModulesVisitor {
         }
 
         self.visit_children(korok)?;
-        korok.node = combine_koroks(&korok.node, &korok.crates);
+        korok.node = combine_koroks(&korok.node, &korok.crates, should_merge_crate_program_nodes);
         Ok(())
     }
 
     fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
         self.visit_children(korok)?;
-        korok.node = combine_koroks(&korok.node, &korok.items);
+        korok.node = combine_koroks(&korok.node, &korok.items, should_merge_program_nodes);
         Ok(())
     }
 
@@ -41,19 +41,26 @@ impl KorokVisitor for CombineModulesVisitor {
         korok: &mut codama_koroks::FileModuleKorok,
     ) -> CodamaResult<()> {
         self.visit_children(korok)?;
-        korok.node = combine_koroks(&korok.node, &korok.items);
+        korok.node = combine_koroks(&korok.node, &korok.items, should_merge_program_nodes);
         Ok(())
     }
 
     fn visit_module(&mut self, korok: &mut codama_koroks::ModuleKorok) -> CodamaResult<()> {
         self.visit_children(korok)?;
-        korok.node = combine_koroks(&korok.node, &korok.items);
+        korok.node = combine_koroks(&korok.node, &korok.items, should_merge_program_nodes);
         Ok(())
     }
 }
 
+/// Whether two ProgramNodes should be merged, given whether `that` comes from a child of `this`.
+type ShouldMerge = fn(&ProgramNode, &ProgramNode, bool) -> bool;
+
 /// Create a single RootNode from an initial node and a list of nodes to merge.
-fn combine_koroks<T: KorokTrait>(initial_node: &Option<Node>, koroks: &[T]) -> Option<Node> {
+fn combine_koroks<T: KorokTrait>(
+    initial_node: &Option<Node>,
+    koroks: &[T],
+    should_merge: ShouldMerge,
+) -> Option<Node> {
     // Create the new RootNode to bind all items together from the exisiting node, in any.
     // - If there is already a RootNode or ProgramNode, use this as a starting point.
     // - If there is no existing node, use None and let the merging create a new one if needed.
@@ -74,7 +81,12 @@ fn combine_koroks<T: KorokTrait>(initial_node: &Option<Node>, koroks: &[T]) -> O
     // Convert all nodes into RootNodes and merge them with the binding root node.
     let from_parent = this_root_node.is_some();
     for that_root_node in get_root_nodes_to_merge(nodes_to_merge) {
-        merge_root_nodes(&mut this_root_node, that_root_node, from_parent);
+        merge_root_nodes(
+            &mut this_root_node,
+            that_root_node,
+            from_parent,
+            should_merge,
+        );
     }
 
     this_root_node.map(Into::into)
@@ -142,7 +154,12 @@ fn get_scraps_root_node(nodes: Vec<Node>) -> Option<RootNode> {
 }
 
 /// Merge `that` RootNode into `this` RootNode.
-fn merge_root_nodes(this: &mut Option<RootNode>, that: RootNode, from_parent: bool) {
+fn merge_root_nodes(
+    this: &mut Option<RootNode>,
+    that: RootNode,
+    from_parent: bool,
+    should_merge: ShouldMerge,
+) {
     // If there is no root node yet, set it to the one provided.
     let Some(this) = this else {
         *this = Some(that);
@@ -157,7 +174,7 @@ fn merge_root_nodes(this: &mut Option<RootNode>, that: RootNode, from_parent: bo
     // For each program to merge.
     for that_program in those_programs {
         // Check if it can be merged with the main root program.
-        if should_merge_program_nodes(&this.program, &that_program, from_parent) {
+        if should_merge(&this.program, &that_program, from_parent) {
             merge_program_nodes(&mut this.program, that_program);
             continue;
         }
@@ -166,7 +183,7 @@ fn merge_root_nodes(this: &mut Option<RootNode>, that: RootNode, from_parent: bo
         let found = this
             .additional_programs
             .iter_mut()
-            .find(|p| should_merge_program_nodes(p, &that_program, from_parent));
+            .find(|p| should_merge(p, &that_program, from_parent));
 
         if let Some(additional_program) = found {
             // If so, merge it with the additional program found.
@@ -183,6 +200,20 @@ fn should_merge_program_nodes(this: &ProgramNode, that: &ProgramNode, from_paren
     this.public_key == that.public_key || (from_parent && that.public_key.is_empty())
 }
 
+/// Check if two ProgramNodes from different crates should be merged together.
+/// Unlike modules, crates without public keys — e.g. the members of a workspace — are kept apart by name.
+fn should_merge_crate_program_nodes(
+    this: &ProgramNode,
+    that: &ProgramNode,
+    from_parent: bool,
+) -> bool {
+    let both_named = !this.name.is_empty() && !that.name.is_empty();
+    match this.public_key.is_empty() && that.public_key.is_empty() && both_named {
+        true => this.name == that.name,
+        false => should_merge_program_nodes(this, that, from_parent),
+    }
+}
+
 /// Merge `that` ProgramNode into `this` ProgramNode.
 fn merge_program_nodes(this: &mut ProgramNode, that: ProgramNode) {
     if this.name.is_empty() {
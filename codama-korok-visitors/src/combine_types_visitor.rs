@@ -1,13 +1,19 @@
 use crate::KorokVisitor;
 use codama_attributes::{Attributes, FieldDirective, ReprAttribute, TryFromFilter};
 use codama_errors::{CodamaResult, IteratorCombineErrors};
-use codama_koroks::{EnumVariantKorok, FieldKorok, KorokTrait};
+use codama_koroks::{CrateKorok, EnumVariantKorok, FieldKorok, ItemKorok, KorokTrait, StructKorok};
 use codama_nodes::{
-    DefinedTypeNode, EnumEmptyVariantTypeNode, EnumStructVariantTypeNode, EnumTupleVariantTypeNode,
-    EnumTypeNode, EnumVariantTypeNode, NestedTypeNode, Node, NumberFormat::U8, NumberTypeNode,
-    RegisteredTypeNode, StructFieldTypeNode, StructTypeNode, TupleTypeNode, TypeNode,
+    CamelCaseString, CountNode, DefinedTypeLinkNode, DefinedTypeNode, EnumEmptyVariantTypeNode,
+    EnumStructVariantTypeNode, EnumTupleVariantTypeNode, EnumTypeNode, EnumVariantTypeNode,
+    NestedTypeNode, Node, NumberFormat::U8, NumberTypeNode, PostOffsetStrategy, PostOffsetTypeNode,
+    RegisteredTypeNode, StructFieldTypeNode, StructTypeNode, TupleTypeNode, TypeCodec, TypeNode,
 };
-use codama_syn_helpers::extensions::*;
+use codama_syn_helpers::{extensions::*, ConstEvaluator, Imports, PathResolver};
+use quote::ToTokens;
+use std::collections::HashMap;
+
+/// The size and alignment of a type in memory.
+type Layout = (usize, usize);
 
 pub struct CombineTypesVisitor {
     pub r#override: bool,
@@ -18,6 +24,12 @@ pub struct CombineTypesVisitor {
     pub get_unnammed_field:
         fn(korok: &FieldKorok, parent: &str, index: usize) -> Option<CodamaResult<TypeNode>>,
     pub parent_enum: String,
+    /// The size and alignment of the padded structs of the current crate.
+    pub layouts: CrateLayouts,
+    /// The path of the module being visited from the crate root, e.g. `["state"]`.
+    pub module: Vec<String>,
+    /// The constants of the current crate, used to resolve variant discriminants.
+    pub constants: ConstEvaluator,
 }
 
 impl Default for CombineTypesVisitor {
@@ -28,6 +40,9 @@ impl Default for CombineTypesVisitor {
             get_nammed_field: |x, _| Self::get_default_named_field(x),
             get_unnammed_field: |x, _, _| Self::get_default_unnamed_field(x),
             parent_enum: String::new(),
+            layouts: CrateLayouts::default(),
+            module: Vec::new(),
+            constants: ConstEvaluator::new(),
         }
     }
 }
@@ -36,6 +51,48 @@ impl CombineTypesVisitor {
     pub fn new() -> Self {
         Self::default()
     }
+    /// Collect the constants and the layouts of the zero-copy structs of a crate, so that
+    /// discriminants can be resolved and fields can be padded. Called when visiting a crate.
+    pub fn enter_crate(&mut self, korok: &CrateKorok) {
+        self.constants = korok.constants.clone();
+        self.layouts = CrateLayouts::new(&korok.items);
+        self.module.clear();
+    }
+
+    /// Visit the items of a crate with a visitor that combines types using this visitor,
+    /// after entering the crate. E.g. to resolve discriminants or pad zero-copy structs
+    /// referring to other items of the crate.
+    pub fn visit_crate_with<V: KorokVisitor>(
+        visitor: &mut V,
+        korok: &mut CrateKorok,
+        combine_types: fn(&mut V) -> &mut Self,
+    ) -> CodamaResult<()> {
+        combine_types(visitor).enter_crate(korok);
+        korok
+            .items
+            .iter_mut()
+            .map(|item_korok| visitor.visit_item(item_korok))
+            .collect_and_combine_errors()?;
+        Ok(())
+    }
+
+    /// Visit the items of a module with a visitor that combines types using this visitor,
+    /// so that the fields of zero-copy structs are resolved from that module.
+    pub fn visit_module_with<V: KorokVisitor>(
+        visitor: &mut V,
+        name: &syn::Ident,
+        items: &mut [ItemKorok],
+        combine_types: fn(&mut V) -> &mut Self,
+    ) -> CodamaResult<()> {
+        combine_types(visitor).module.push(name.to_string());
+        let result = items
+            .iter_mut()
+            .map(|item_korok| visitor.visit_item(item_korok))
+            .collect_and_combine_errors();
+        combine_types(visitor).module.pop();
+        result.map(|_| ())
+    }
+
     pub fn strict() -> Self {
         Self {
             get_enum_variant: Self::get_strict_enum_variant,
@@ -119,10 +176,20 @@ impl CombineTypesVisitor {
         fields: &[FieldKorok],
         attributes: &Attributes,
         parent: &str,
+        paddings: &[usize],
     ) -> CodamaResult<Vec<StructFieldTypeNode>> {
         let fields = fields
             .iter()
-            .filter_map(|field| (self.get_nammed_field)(field, parent))
+            .enumerate()
+            .filter_map(|(index, field)| {
+                let padding = paddings.get(index).copied().unwrap_or(0);
+                (self.get_nammed_field)(field, parent).map(|result| {
+                    result.map(|field| StructFieldTypeNode {
+                        r#type: pad(field.r#type, padding),
+                        ..field
+                    })
+                })
+            })
             .collect_and_combine_errors()?;
 
         let (before, after): (Vec<_>, Vec<_>) = attributes
@@ -139,11 +206,16 @@ impl CombineTypesVisitor {
         &self,
         fields: &[FieldKorok],
         parent: &str,
+        paddings: &[usize],
     ) -> CodamaResult<Vec<TypeNode>> {
         let items = fields
             .iter()
             .enumerate()
-            .filter_map(|(index, field)| (self.get_unnammed_field)(field, parent, index))
+            .filter_map(|(index, field)| {
+                let padding = paddings.get(index).copied().unwrap_or(0);
+                (self.get_unnammed_field)(field, parent, index)
+                    .map(|result| result.map(|item| pad(item, padding)))
+            })
             .collect_and_combine_errors()?;
 
         Ok(items)
@@ -151,6 +223,27 @@ impl CombineTypesVisitor {
 }
 
 impl KorokVisitor for CombineTypesVisitor {
+    fn visit_crate(&mut self, korok: &mut CrateKorok) -> CodamaResult<()> {
+        self.enter_crate(korok);
+        korok
+            .items
+            .iter_mut()
+            .map(|item_korok| self.visit_item(item_korok))
+            .collect_and_combine_errors()?;
+        Ok(())
+    }
+
+    fn visit_file_module(
+        &mut self,
+        korok: &mut codama_koroks::FileModuleKorok,
+    ) -> CodamaResult<()> {
+        Self::visit_module_with(self, &korok.ast.ident, &mut korok.items, |visitor| visitor)
+    }
+
+    fn visit_module(&mut self, korok: &mut codama_koroks::ModuleKorok) -> CodamaResult<()> {
+        Self::visit_module_with(self, &korok.ast.ident, &mut korok.items, |visitor| visitor)
+    }
+
     fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> CodamaResult<()> {
         if korok.node.is_some() && !self.r#override {
             return Ok(());
@@ -159,13 +252,15 @@ impl KorokVisitor for CombineTypesVisitor {
         self.visit_children(korok)?;
 
         let parent = format!("struct `{}`", korok.ast.ident);
+        let paddings = get_paddings(korok, &self.layouts, &self.module)?;
         let type_node: TypeNode = match korok.ast.fields {
             syn::Fields::Named(_) => {
-                let fields = self.parse_named_fields(&korok.fields, &korok.attributes, &parent)?;
+                let fields =
+                    self.parse_named_fields(&korok.fields, &korok.attributes, &parent, &paddings)?;
                 StructTypeNode::new(fields).into()
             }
             syn::Fields::Unnamed(_) => {
-                let items = self.parse_unnamed_fields(&korok.fields, &parent)?;
+                let items = self.parse_unnamed_fields(&korok.fields, &parent, &paddings)?;
                 if items.len() == 1 {
                     items.first().unwrap().clone()
                 } else {
@@ -173,12 +268,19 @@ impl KorokVisitor for CombineTypesVisitor {
                 }
             }
             _ => {
-                let fields = self.parse_named_fields(&korok.fields, &korok.attributes, &parent)?;
+                let fields =
+                    self.parse_named_fields(&korok.fields, &korok.attributes, &parent, &[])?;
                 StructTypeNode::new(fields).into()
             }
         };
 
-        korok.set_node(Some(DefinedTypeNode::new(korok.name(), type_node).into()));
+        korok.set_node(Some(
+            DefinedTypeNode {
+                docs: korok.docs(),
+                ..DefinedTypeNode::new(korok.name(), type_node)
+            }
+            .into(),
+        ));
         Ok(())
     }
 
@@ -205,13 +307,16 @@ impl KorokVisitor for CombineTypesVisitor {
             .unwrap_or(NumberTypeNode::le(U8));
 
         korok.node = Some(
-            DefinedTypeNode::new(
-                korok.name(),
-                EnumTypeNode {
-                    variants,
-                    size: NestedTypeNode::Value(size),
-                },
-            )
+            DefinedTypeNode {
+                docs: korok.docs(),
+                ..DefinedTypeNode::new(
+                    korok.name(),
+                    EnumTypeNode {
+                        variants,
+                        size: NestedTypeNode::Value(size),
+                    },
+                )
+            }
             .into(),
         );
         Ok(())
@@ -235,11 +340,12 @@ impl KorokVisitor for CombineTypesVisitor {
             .ast
             .discriminant
             .as_ref()
-            .and_then(|(_, x)| x.as_unsigned_integer::<usize>().ok());
+            .and_then(|(_, x)| x.as_unsigned_integer_with::<usize>(&self.constants).ok());
 
         korok.node = match korok.ast.fields {
             syn::Fields::Named(_) => {
-                let fields = self.parse_named_fields(&korok.fields, &korok.attributes, &parent)?;
+                let fields =
+                    self.parse_named_fields(&korok.fields, &korok.attributes, &parent, &[])?;
                 Some(
                     EnumStructVariantTypeNode {
                         name: korok.name(),
@@ -250,7 +356,7 @@ impl KorokVisitor for CombineTypesVisitor {
                 )
             }
             syn::Fields::Unnamed(_) => {
-                let items = self.parse_unnamed_fields(&korok.fields, &parent)?;
+                let items = self.parse_unnamed_fields(&korok.fields, &parent, &[])?;
                 Some(
                     EnumTupleVariantTypeNode {
                         name: korok.name(),
@@ -261,7 +367,8 @@ impl KorokVisitor for CombineTypesVisitor {
                 )
             }
             _ => {
-                let fields = self.parse_named_fields(&korok.fields, &korok.attributes, &parent)?;
+                let fields =
+                    self.parse_named_fields(&korok.fields, &korok.attributes, &parent, &[])?;
                 if !fields.is_empty() {
                     Some(
                         EnumStructVariantTypeNode {
@@ -285,3 +392,314 @@ impl KorokVisitor for CombineTypesVisitor {
         Ok(())
     }
 }
+
+/// The trailing padding of each field of a `#[repr(C)]` zero-copy struct, so that the
+/// type nodes match its in-memory layout. Fields whose layout is unknown — e.g. enums or
+/// links to structs of the crate that are not padded — cannot be padded and return an error.
+fn get_paddings(
+    korok: &StructKorok,
+    layouts: &CrateLayouts,
+    module: &[String],
+) -> CodamaResult<Vec<usize>> {
+    if !is_padded(&korok.attributes) {
+        return Ok(vec![]);
+    }
+    if let Some((paddings, _)) = get_struct_layout(korok, layouts, module) {
+        return Ok(paddings);
+    }
+    // Fields without a type node are reported by the strict visitors.
+    let unresolved = korok.fields.iter().find_map(|field| {
+        let node = get_field_type_node(field)?;
+        match get_field_layout(field, layouts, module) {
+            Some(_) => None,
+            None => Some((field, node)),
+        }
+    });
+    let Some((field, node)) = unresolved else {
+        return Ok(vec![]);
+    };
+    let unknown = match find_link(&node) {
+        Some(link) if layouts.get(link, &field.ast.ty, module).is_none() => {
+            format!("`{}`", link.name.as_str())
+        }
+        _ => format!("`{}`", field.ast.ty.to_token_stream()),
+    };
+    Err(field
+        .ast
+        .error(format!(
+            "Cannot compute the padding of zero-copy struct `{}` because the layout of {} is unknown",
+            korok.ast.ident, unknown
+        ))
+        .into())
+}
+
+/// Whether the fields of a struct are laid out in memory with C alignment rules.
+fn is_padded(attributes: &Attributes) -> bool {
+    attributes.is_zero_copy() && attributes.has_repr("C") && !attributes.has_repr("packed")
+}
+
+/// The trailing padding of each field of a padded struct, along with its own layout.
+/// Returns `None` if the layout of a field is unknown.
+fn get_struct_layout(
+    korok: &StructKorok,
+    layouts: &CrateLayouts,
+    module: &[String],
+) -> Option<(Vec<usize>, Layout)> {
+    let attributes = &korok.attributes;
+    if !is_padded(attributes) {
+        return None;
+    }
+
+    let field_layouts = korok
+        .fields
+        .iter()
+        .map(|field| get_field_layout(field, layouts, module))
+        .collect::<Option<Vec<_>>>()?;
+    let mut paddings = vec![0; field_layouts.len()];
+    let mut offset = 0usize;
+    let mut alignment = attributes
+        .get_all(ReprAttribute::filter)
+        .iter()
+        .filter_map(|attr| attr.get_align())
+        .max()
+        .unwrap_or(1);
+    for (index, (size, align)) in field_layouts.into_iter().enumerate() {
+        let padding = offset.next_multiple_of(align) - offset;
+        if let Some(previous) = index.checked_sub(1) {
+            paddings[previous] += padding;
+        }
+        offset += padding + size;
+        alignment = alignment.max(align);
+    }
+    let trailing = offset.next_multiple_of(alignment) - offset;
+    if let Some(last) = paddings.last_mut() {
+        *last += trailing;
+    }
+    Some((paddings, (offset + trailing, alignment)))
+}
+
+/// The size and alignment of the padded structs of a crate, keyed by their path from
+/// the crate root — e.g. `state::Inner` — along with the imports of each module.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CrateLayouts {
+    layouts: HashMap<String, Layout>,
+    imports: Imports,
+}
+
+impl CrateLayouts {
+    /// The layouts of all padded structs of a crate, including those of nested modules.
+    pub fn new(items: &[ItemKorok]) -> Self {
+        let mut layouts = Self::default();
+        let mut structs = vec![];
+        collect_structs(items, &mut vec![], &mut structs, &mut layouts.imports);
+        // Structs may link to structs declared after them, so resolve until no new layout is found.
+        loop {
+            let count = layouts.layouts.len();
+            for (module, korok) in &structs {
+                let key = [module.as_slice(), &[korok.ast.ident.to_string()]]
+                    .concat()
+                    .join("::");
+                if layouts.layouts.contains_key(&key) {
+                    continue;
+                }
+                if let Some((_, layout)) = get_struct_layout(korok, &layouts, module) {
+                    layouts.layouts.insert(key, layout);
+                }
+            }
+            if layouts.layouts.len() == count {
+                return layouts;
+            }
+        }
+    }
+
+    /// The layout of a struct linked from the Rust type of a field in the given module.
+    /// Links that do not appear in the Rust type — e.g. from inlined type aliases — are
+    /// resolved by name as long as a single struct matches.
+    fn get(&self, link: &DefinedTypeLinkNode, ty: &syn::Type, module: &[String]) -> Option<Layout> {
+        if link.program.is_some() {
+            return None;
+        }
+        if let Some(segments) = find_type_path(ty, &link.name) {
+            return PathResolver::new(&self.layouts, &self.imports)
+                .resolve(&segments, module, None)
+                .ok()
+                .copied();
+        }
+        let mut matches = self.layouts.iter().filter(|(key, _)| {
+            let name = key.rsplit("::").next().unwrap_or(key);
+            CamelCaseString::from(name) == link.name
+        });
+        match (matches.next(), matches.next()) {
+            (Some((_, layout)), None) => Some(*layout),
+            _ => None,
+        }
+    }
+}
+
+fn collect_structs<'a, 'b>(
+    items: &'b [ItemKorok<'a>],
+    module: &mut Vec<String>,
+    structs: &mut Vec<(Vec<String>, &'b StructKorok<'a>)>,
+    imports: &mut Imports,
+) {
+    for item in items {
+        match item {
+            ItemKorok::Struct(korok) => structs.push((module.clone(), korok)),
+            ItemKorok::Use(korok) => imports.add(module, &korok.ast.tree),
+            ItemKorok::Module(korok) => {
+                module.push(korok.ast.ident.to_string());
+                collect_structs(&korok.items, module, structs, imports);
+                module.pop();
+            }
+            ItemKorok::FileModule(korok) => {
+                module.push(korok.ast.ident.to_string());
+                collect_structs(&korok.items, module, structs, imports);
+                module.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The segments of the path within a Rust type that a link was created from, if any.
+/// E.g. `["state", "Inner"]` for the `inner` link of `[Option<state::Inner>; 2]`.
+fn find_type_path(ty: &syn::Type, name: &CamelCaseString) -> Option<Vec<String>> {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            if CamelCaseString::from(path.last_str()) == *name {
+                return Some(
+                    path.segments
+                        .iter()
+                        .map(|segment| segment.ident.to_string())
+                        .collect(),
+                );
+            }
+            path.generic_types()
+                .into_iter()
+                .find_map(|ty| find_type_path(ty, name))
+        }
+        syn::Type::Array(syn::TypeArray { elem, .. })
+        | syn::Type::Group(syn::TypeGroup { elem, .. })
+        | syn::Type::Paren(syn::TypeParen { elem, .. }) => find_type_path(elem, name),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().find_map(|ty| find_type_path(ty, name)),
+        _ => None,
+    }
+}
+
+fn get_field_type_node(field: &FieldKorok) -> Option<TypeNode> {
+    match &field.node {
+        Some(Node::Type(RegisteredTypeNode::StructField(field))) => Some(field.r#type.clone()),
+        node => TypeNode::try_from(node.clone()).ok(),
+    }
+}
+
+/// The defined type link of a field, possibly wrapped in arrays or options.
+fn find_link(node: &TypeNode) -> Option<&DefinedTypeLinkNode> {
+    match node {
+        TypeNode::Link(link) if link.program.is_none() => Some(link),
+        TypeNode::Array(node) => find_link(&node.item),
+        TypeNode::ZeroableOption(node) => find_link(&node.item),
+        TypeNode::PostOffset(node) => find_link(&node.r#type),
+        _ => None,
+    }
+}
+
+/// The layout of the struct a link refers to, if it is known.
+type LinkLayout<'a> = &'a dyn Fn(&DefinedTypeLinkNode) -> Option<Layout>;
+
+/// The size and alignment of a field of the given module.
+fn get_field_layout(
+    field: &FieldKorok,
+    layouts: &CrateLayouts,
+    module: &[String],
+) -> Option<Layout> {
+    let node = get_field_type_node(field)?;
+    let layouts = |link: &DefinedTypeLinkNode| layouts.get(link, &field.ast.ty, module);
+    let size = get_type_size(&node, &layouts)?;
+    Some((size, get_alignment(&field.ast.ty, &node, &layouts)?))
+}
+
+/// The size of a type node, using the given layouts to resolve links to structs of the crate.
+fn get_type_size(node: &TypeNode, layouts: LinkLayout) -> Option<usize> {
+    match node {
+        TypeNode::Link(link) => layouts(link).map(|(size, _)| size),
+        TypeNode::Array(node) => match &node.count {
+            CountNode::Fixed(count) => count.value.checked_mul(get_type_size(&node.item, layouts)?),
+            _ => None,
+        },
+        TypeNode::ZeroableOption(node) if node.zero_value.is_none() => {
+            get_type_size(&node.item, layouts)
+        }
+        TypeNode::PostOffset(node) if node.strategy == PostOffsetStrategy::Padded => {
+            get_type_size(&node.r#type, layouts)?.checked_add_signed(node.offset as isize)
+        }
+        _ => TypeCodec::new().fixed_size(node).ok().flatten(),
+    }
+}
+
+/// The alignment of a field, using its Rust type to detect Pod wrappers which are byte arrays.
+fn get_alignment(ty: &syn::Type, node: &TypeNode, layouts: LinkLayout) -> Option<usize> {
+    match (ty, node) {
+        (syn::Type::Path(syn::TypePath { path, .. }), _) if is_pod_wrapper(path) => Some(1),
+        (syn::Type::Array(syn::TypeArray { elem, .. }), TypeNode::Array(node)) => {
+            get_alignment(elem, &node.item, layouts)
+        }
+        _ => get_type_alignment(node, layouts),
+    }
+}
+
+/// Whether the path refers to one of the `spl_pod` wrappers, which are stored as byte arrays.
+fn is_pod_wrapper(path: &syn::Path) -> bool {
+    matches!(
+        path.prefix().as_str(),
+        "" | "spl_pod" | "spl_pod::primitives"
+    ) && matches!(
+        path.last_str().as_str(),
+        "PodBool"
+            | "PodU16"
+            | "PodU32"
+            | "PodU64"
+            | "PodU128"
+            | "PodI16"
+            | "PodI32"
+            | "PodI64"
+            | "PodI128"
+    )
+}
+
+/// The alignment of a type node on SBF, where 128-bit integers are 8-byte aligned.
+fn get_type_alignment(node: &TypeNode, layouts: LinkLayout) -> Option<usize> {
+    let max_alignment = |nodes: Vec<&TypeNode>| {
+        nodes.into_iter().try_fold(1, |max, node| {
+            Some(max.max(get_type_alignment(node, layouts)?))
+        })
+    };
+    match node {
+        TypeNode::Number(_) => TypeCodec::new()
+            .fixed_size(node)
+            .ok()
+            .flatten()
+            .map(|size| size.min(8)),
+        TypeNode::Amount(node) => get_type_alignment(&node.number.clone().into(), layouts),
+        TypeNode::Boolean(node) => get_type_alignment(&node.size.clone().into(), layouts),
+        TypeNode::DateTime(node) => get_type_alignment(&node.number.clone().into(), layouts),
+        TypeNode::SolAmount(node) => get_type_alignment(&node.number.clone().into(), layouts),
+        TypeNode::Bytes(_) | TypeNode::PublicKey(_) | TypeNode::String(_) => Some(1),
+        TypeNode::Link(link) => layouts(link).map(|(_, alignment)| alignment),
+        TypeNode::Array(node) => get_type_alignment(&node.item, layouts),
+        TypeNode::FixedSize(node) => get_type_alignment(&node.r#type, layouts),
+        TypeNode::PostOffset(node) => get_type_alignment(&node.r#type, layouts),
+        TypeNode::ZeroableOption(node) => get_type_alignment(&node.item, layouts),
+        TypeNode::Struct(node) => max_alignment(node.fields.iter().map(|f| &f.r#type).collect()),
+        TypeNode::Tuple(node) => max_alignment(node.items.iter().collect()),
+        _ => None,
+    }
+}
+
+/// Add trailing padding bytes to a type node.
+fn pad(node: TypeNode, padding: usize) -> TypeNode {
+    match padding {
+        0 => node,
+        _ => PostOffsetTypeNode::padded(node, padding as i32).into(),
+    }
+}
@@ -116,6 +116,18 @@ impl KorokVisitor for DebugVisitor {
         self.write("Const", Some(&ident.to_string()), korok.into())
     }
 
+    fn visit_type_alias(&mut self, korok: &mut codama_koroks::TypeAliasKorok) -> CodamaResult<()> {
+        self.write(
+            "TypeAlias",
+            Some(&korok.ast.ident.to_string()),
+            korok.into(),
+        )
+    }
+
+    fn visit_use(&mut self, korok: &mut codama_koroks::UseKorok) -> CodamaResult<()> {
+        self.write("Use", None, korok.into())
+    }
+
     fn visit_unsupported_item(
         &mut self,
         korok: &mut codama_koroks::UnsupportedItemKorok,
@@ -1,34 +1,114 @@
-use crate::KorokVisitor;
-use codama_errors::CodamaResult;
+use crate::{KorokVisitor, SymbolTable, TypeRegistry};
+use codama_errors::{CodamaResult, IteratorCombineErrors};
+use codama_koroks::ItemKorok;
 use codama_nodes::{
-    ArrayTypeNode, BooleanTypeNode, DefinedTypeLinkNode, FixedCountNode, MapTypeNode,
-    NumberFormat::*, NumberTypeNode, OptionTypeNode, PrefixedCountNode, PublicKeyTypeNode,
-    SetTypeNode, SizePrefixTypeNode, StringTypeNode, TypeNode,
+    ArrayTypeNode, BooleanTypeNode, BytesTypeNode, DefinedTypeLinkNode, FixedCountNode,
+    MapTypeNode, NumberFormat::*, NumberTypeNode, OptionTypeNode, PrefixedCountNode,
+    PublicKeyTypeNode, SetTypeNode, SizePrefixTypeNode, StringTypeNode, TupleTypeNode, TypeNode,
+    ZeroableOptionTypeNode,
 };
-use codama_syn_helpers::extensions::*;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 
+/// Identify the type node of each field from its Rust type,
+/// following the type aliases and renamed imports of the crate.
+/// Options of zero-copy structs, including arrays of options, are identified as
+/// `ZeroableOptionTypeNode`s.
 #[derive(Default)]
-pub struct IdentifyFieldTypesVisitor;
+pub struct IdentifyFieldTypesVisitor {
+    registry: TypeRegistry,
+    symbols: SymbolTable,
+    zero_copy: bool,
+}
 
 impl IdentifyFieldTypesVisitor {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Use the given registry to map Rust paths to type nodes before the built-in mappings.
+    pub fn with_registry(mut self, registry: TypeRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    fn visit_scope(
+        &mut self,
+        module: Option<&syn::Ident>,
+        items: &mut [ItemKorok],
+    ) -> CodamaResult<()> {
+        self.symbols.push_scope(module);
+        let result = items
+            .iter_mut()
+            .map(|item| self.visit_item(item))
+            .collect_and_combine_errors();
+        self.symbols.pop_scope();
+        result.map(|_| ())
     }
 }
 
 impl KorokVisitor for IdentifyFieldTypesVisitor {
+    fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
+        self.symbols = SymbolTable::new(&korok.items).with_constants(korok.constants.clone());
+        self.visit_scope(None, &mut korok.items)
+    }
+
+    fn visit_file_module(
+        &mut self,
+        korok: &mut codama_koroks::FileModuleKorok,
+    ) -> CodamaResult<()> {
+        self.visit_scope(Some(&korok.ast.ident), &mut korok.items)
+    }
+
+    fn visit_module(&mut self, korok: &mut codama_koroks::ModuleKorok) -> CodamaResult<()> {
+        self.visit_scope(Some(&korok.ast.ident), &mut korok.items)
+    }
+
+    fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> CodamaResult<()> {
+        self.zero_copy = korok.attributes.is_zero_copy();
+        let result = self.visit_children(korok);
+        self.zero_copy = false;
+        result
+    }
+
     fn visit_field(&mut self, korok: &mut codama_koroks::FieldKorok) -> CodamaResult<()> {
         if korok.node.is_some() {
             return Ok(());
         }
-        if let Some(node) = get_type_node(&korok.ast.ty) {
-            korok.set_type_node(node);
+        match self.symbols.get_type_node(&korok.ast.ty, &self.registry) {
+            Some(node) if self.zero_copy => korok.set_type_node(zeroable(node)),
+            Some(node) => korok.set_type_node(node),
+            None => {}
         }
         Ok(())
     }
 }
 
+/// Replace options — including those of arrays — with zeroable options, e.g. `[Option<T>; N]`.
+fn zeroable(node: TypeNode) -> TypeNode {
+    match node {
+        TypeNode::Option(node) => ZeroableOptionTypeNode::new(*node.item).into(),
+        TypeNode::Array(node) => ArrayTypeNode::new(zeroable(*node.item), node.count).into(),
+        node => node,
+    }
+}
+
 pub fn get_type_node(ty: &syn::Type) -> Option<TypeNode> {
+    get_type_node_with(ty, &ConstEvaluator::default())
+}
+
+/// Same as `get_type_node` but evaluates array lengths using the given constants.
+pub fn get_type_node_with(ty: &syn::Type, constants: &ConstEvaluator) -> Option<TypeNode> {
+    get_builtin_type_node(ty, &TypeRegistry::default(), constants)
+}
+
+/// The built-in mappings from Rust types to type nodes, using
+/// the given registry to identify the types they contain.
+pub(crate) fn get_builtin_type_node(
+    ty: &syn::Type,
+    registry: &TypeRegistry,
+    constants: &ConstEvaluator,
+) -> Option<TypeNode> {
+    let get_type_node = |ty: &syn::Type| registry.get_type_node_with(ty, constants);
     match ty {
         syn::Type::Path(syn::TypePath { path, .. }) => {
             if path.leading_colon.is_some() {
@@ -58,21 +138,56 @@ pub fn get_type_node(ty: &syn::Type) -> Option<TypeNode> {
                 ("" | "std::primitive", "f32", []) => Some(NumberTypeNode::le(F32).into()),
                 ("" | "std::primitive", "f64", []) => Some(NumberTypeNode::le(F64).into()),
                 (_, "ShortU16", []) => Some(NumberTypeNode::le(ShortU16).into()),
+                ("" | "spl_pod" | "spl_pod::primitives", "PodBool", []) => {
+                    Some(BooleanTypeNode::default().into())
+                }
+                ("" | "spl_pod" | "spl_pod::primitives", "PodU16", []) => {
+                    Some(NumberTypeNode::le(U16).into())
+                }
+                ("" | "spl_pod" | "spl_pod::primitives", "PodU32", []) => {
+                    Some(NumberTypeNode::le(U32).into())
+                }
+                ("" | "spl_pod" | "spl_pod::primitives", "PodU64", []) => {
+                    Some(NumberTypeNode::le(U64).into())
+                }
+                ("" | "spl_pod" | "spl_pod::primitives", "PodU128", []) => {
+                    Some(NumberTypeNode::le(U128).into())
+                }
+                ("" | "spl_pod" | "spl_pod::primitives", "PodI16", []) => {
+                    Some(NumberTypeNode::le(I16).into())
+                }
+                ("" | "spl_pod" | "spl_pod::primitives", "PodI32", []) => {
+                    Some(NumberTypeNode::le(I32).into())
+                }
+                ("" | "spl_pod" | "spl_pod::primitives", "PodI64", []) => {
+                    Some(NumberTypeNode::le(I64).into())
+                }
+                ("" | "spl_pod" | "spl_pod::primitives", "PodI128", []) => {
+                    Some(NumberTypeNode::le(I128).into())
+                }
                 ("" | "solana_sdk::pubkey" | "solana_program" | "solana_pubkey", "Pubkey", []) => {
                     Some(PublicKeyTypeNode::new().into())
                 }
                 ("" | "solana_address" | "solana_address::address", "Address", []) => {
                     Some(PublicKeyTypeNode::new().into())
                 }
-                ("" | "std::string", "String", []) => Some(
+                ("" | "std::string", "String", []) | ("" | "std::primitive", "str", []) => Some(
                     SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U32)).into(),
                 ),
+                ("" | "std::boxed" | "alloc::boxed", "Box", [t])
+                | ("" | "std::rc" | "alloc::rc", "Rc", [t])
+                | ("" | "std::sync" | "alloc::sync", "Arc", [t])
+                | ("" | "std::borrow" | "alloc::borrow", "Cow", [t]) => get_type_node(t),
                 ("" | "std::option", "Option", [t]) => {
                     get_type_node(t).map(|item| OptionTypeNode::new(item).into())
                 }
-                ("" | "std::vec", "Vec", [t]) => get_type_node(t).map(|item| {
-                    ArrayTypeNode::new(item, PrefixedCountNode::new(NumberTypeNode::le(U32))).into()
-                }),
+                ("" | "std::vec", "Vec", [t]) => get_vec_type_node(t, registry, constants),
+                ("" | "std::collections", "VecDeque" | "LinkedList", [t]) => {
+                    get_type_node(t).map(|item| {
+                        ArrayTypeNode::new(item, PrefixedCountNode::new(NumberTypeNode::le(U32)))
+                            .into()
+                    })
+                }
                 ("" | "std::collections", "HashSet" | "BTreeSet", [t]) => {
                     get_type_node(t).map(|item| {
                         SetTypeNode::new(item, PrefixedCountNode::new(NumberTypeNode::le(U32)))
@@ -97,12 +212,39 @@ pub fn get_type_node(ty: &syn::Type) -> Option<TypeNode> {
             }
         }
         syn::Type::Array(syn::TypeArray { elem, len, .. }) => {
-            let Ok(size) = len.as_unsigned_integer::<usize>() else {
+            let Ok(size) = len.as_unsigned_integer_with::<usize>(constants) else {
                 return None;
             };
             get_type_node(elem)
                 .map(|item| ArrayTypeNode::new(item, FixedCountNode::new(size)).into())
         }
+        // E.g. `&[u8]` or `Box<[u8]>`, which are serialized like vectors.
+        syn::Type::Slice(syn::TypeSlice { elem, .. }) => {
+            get_vec_type_node(elem, registry, constants)
+        }
+        syn::Type::Reference(syn::TypeReference { elem, .. })
+        | syn::Type::Paren(syn::TypeParen { elem, .. })
+        | syn::Type::Group(syn::TypeGroup { elem, .. }) => get_type_node(elem),
+        syn::Type::Tuple(syn::TypeTuple { elems, .. }) if !elems.is_empty() => elems
+            .iter()
+            .map(get_type_node)
+            .collect::<Option<Vec<_>>>()
+            .map(|items| TupleTypeNode::new(items).into()),
         _ => None,
     }
 }
+
+/// The type node of a `u32`-prefixed list of items, using bytes for lists of `u8`.
+fn get_vec_type_node(
+    item: &syn::Type,
+    registry: &TypeRegistry,
+    constants: &ConstEvaluator,
+) -> Option<TypeNode> {
+    let prefix = NumberTypeNode::le(U32);
+    match registry.get_type_node_with(item, constants)? {
+        TypeNode::Number(number) if number == NumberTypeNode::le(U8) => {
+            Some(SizePrefixTypeNode::new(BytesTypeNode::new(), prefix).into())
+        }
+        item => Some(ArrayTypeNode::new(item, PrefixedCountNode::new(prefix)).into()),
+    }
+}
@@ -6,13 +6,20 @@ mod compose_visitor;
 mod debug_visitor;
 mod filter_items_visitor;
 mod identify_field_types_visitor;
+mod set_account_sizes_visitor;
 mod set_accounts_visitor;
+mod set_anchor_accounts_visitor;
+mod set_anchor_defined_types_visitor;
+mod set_anchor_errors_visitor;
+mod set_anchor_instructions_visitor;
 mod set_default_values_visitor;
 mod set_defined_types_visitor;
 mod set_errors_visitor;
 mod set_instructions_visitors;
 mod set_pdas_visitor;
 mod set_program_metadata_visitor;
+mod symbol_table;
+mod type_registry;
 mod uniform_visitor;
 mod visitable;
 mod visitor;
@@ -25,13 +32,20 @@ pub use compose_visitor::*;
 pub use debug_visitor::*;
 pub use filter_items_visitor::*;
 pub use identify_field_types_visitor::*;
+pub use set_account_sizes_visitor::*;
 pub use set_accounts_visitor::*;
+pub use set_anchor_accounts_visitor::*;
+pub use set_anchor_defined_types_visitor::*;
+pub use set_anchor_errors_visitor::*;
+pub use set_anchor_instructions_visitor::*;
 pub use set_default_values_visitor::*;
 pub use set_defined_types_visitor::*;
 pub use set_errors_visitor::*;
 pub use set_instructions_visitors::*;
 pub use set_pdas_visitor::*;
 pub use set_program_metadata_visitor::*;
+pub use symbol_table::*;
+pub use type_registry::*;
 pub use uniform_visitor::*;
 pub use visitable::*;
 pub use visitor::*;
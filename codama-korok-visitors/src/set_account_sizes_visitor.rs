@@ -0,0 +1,81 @@
+use crate::KorokVisitor;
+use codama_errors::CodamaResult;
+use codama_nodes::{Node, ProgramNode, SizeDiscriminatorNode, TypeCodec, TypeNode};
+
+/// Fill the `size` of accounts whose data has a fixed layout.
+/// Defined type links are resolved against all programs of the root node.
+#[derive(Default)]
+pub struct SetAccountSizesVisitor {
+    size_discriminators: bool,
+}
+
+impl SetAccountSizesVisitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also add a `SizeDiscriminatorNode` to fixed-size accounts that have no discriminators.
+    pub fn with_size_discriminators() -> Self {
+        Self {
+            size_discriminators: true,
+        }
+    }
+
+    /// Compute the sizes of all accounts of the program without mutating it,
+    /// so the codec can borrow defined types from the same node.
+    /// Accounts whose size cannot be computed — e.g. linking to unknown types — are left unsized.
+    fn get_sizes(program: &ProgramNode, codec: &TypeCodec) -> Vec<Option<usize>> {
+        program
+            .accounts
+            .iter()
+            .map(|account| match account.size {
+                Some(size) => Some(size),
+                None => codec
+                    .fixed_size(&TypeNode::from(account.data.clone()))
+                    .ok()
+                    .flatten(),
+            })
+            .collect()
+    }
+
+    fn set_sizes(&self, program: &mut ProgramNode, sizes: Vec<Option<usize>>) {
+        for (account, size) in program.accounts.iter_mut().zip(sizes) {
+            account.size = size;
+            if let (true, true, Some(size)) = (
+                self.size_discriminators,
+                account.discriminators.is_empty(),
+                account.size,
+            ) {
+                account
+                    .discriminators
+                    .push(SizeDiscriminatorNode::new(size).into());
+            }
+        }
+    }
+}
+
+impl KorokVisitor for SetAccountSizesVisitor {
+    fn visit_root(&mut self, korok: &mut codama_koroks::RootKorok) -> CodamaResult<()> {
+        match &mut korok.node {
+            Some(Node::Root(root)) => {
+                let codec = TypeCodec::from_root(root);
+                let sizes = Self::get_sizes(&root.program, &codec);
+                let additional_sizes = root
+                    .additional_programs
+                    .iter()
+                    .map(|program| Self::get_sizes(program, &codec))
+                    .collect::<Vec<_>>();
+                self.set_sizes(&mut root.program, sizes);
+                for (program, sizes) in root.additional_programs.iter_mut().zip(additional_sizes) {
+                    self.set_sizes(program, sizes);
+                }
+            }
+            Some(Node::Program(program)) => {
+                let sizes = Self::get_sizes(program, &TypeCodec::from_program(program));
+                self.set_sizes(program, sizes);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ pub struct SetAccountsVisitor {
     enum_name: String,
     enum_discriminator: EnumDiscriminatorDirective,
     enum_current_discriminator: usize,
+    shank: bool,
 }
 
 impl Default for SetAccountsVisitor {
@@ -25,6 +26,7 @@ impl Default for SetAccountsVisitor {
             enum_name: "".to_string(),
             enum_discriminator: EnumDiscriminatorDirective::default(),
             enum_current_discriminator: 0,
+            shank: false,
         }
     }
 }
@@ -33,17 +35,33 @@ impl SetAccountsVisitor {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Use `#[derive(ShankAccount)]` structs instead of Codama derives.
+    pub fn shank() -> Self {
+        Self {
+            shank: true,
+            ..Self::default()
+        }
+    }
 }
 
 impl KorokVisitor for SetAccountsVisitor {
+    fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_crate_with(self, korok, |visitor| &mut visitor.combine_types)
+    }
+
     fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> CodamaResult<()> {
         // No overrides.
         if korok.node.is_some() {
             return Ok(());
         };
 
-        // Ensure the struct has the `CodamaAccount` attribute.
-        if !korok.attributes.has_codama_derive("CodamaAccount") {
+        // Ensure the struct has the `CodamaAccount` or `ShankAccount` attribute.
+        let is_account = match self.shank {
+            true => korok.attributes.has_shank_derive("ShankAccount"),
+            false => korok.attributes.has_codama_derive("CodamaAccount"),
+        };
+        if !is_account {
             return Ok(());
         };
 
@@ -53,6 +71,7 @@ impl KorokVisitor for SetAccountsVisitor {
         // Transform the defined type into an account node.
         let (name, data) = parse_struct(korok)?;
         let account = AccountNode {
+            docs: korok.docs(),
             discriminators: DiscriminatorDirective::nodes(&korok.attributes),
             pda: parse_pda_link_node(&korok.attributes),
             ..AccountNode::new(name, data)
@@ -73,7 +92,7 @@ impl KorokVisitor for SetAccountsVisitor {
         };
 
         // Ensure the struct has the `CodamaAccounts` attribute.
-        if !korok.attributes.has_codama_derive("CodamaAccounts") {
+        if self.shank || !korok.attributes.has_codama_derive("CodamaAccounts") {
             return Ok(());
         };
 
@@ -133,7 +152,7 @@ impl KorokVisitor for SetAccountsVisitor {
     ) -> CodamaResult<()> {
         // Update current discriminator.
         let current_discriminator = match &korok.ast.discriminant {
-            Some((_, expr)) => expr.as_unsigned_integer()?,
+            Some((_, expr)) => expr.as_unsigned_integer_with(&self.combine_types.constants)?,
             _ => self.enum_current_discriminator,
         };
         self.enum_current_discriminator = current_discriminator + 1;
@@ -155,6 +174,7 @@ impl KorokVisitor for SetAccountsVisitor {
         discriminators.insert(0, FieldDiscriminatorNode::new(discriminator_name, 0).into());
 
         let account = AccountNode {
+            docs: korok.docs(),
             discriminators,
             pda: parse_pda_link_node(&korok.attributes),
             ..AccountNode::new(name, data)
@@ -0,0 +1,181 @@
+use crate::{CombineTypesVisitor, KorokVisitor};
+use codama_attributes::{Attribute, Attributes, UnsupportedAttribute};
+use codama_errors::CodamaResult;
+use codama_koroks::ItemKorok;
+use codama_nodes::{
+    AccountNode, BytesTypeNode, BytesValueNode, CamelCaseString, DefaultValueStrategy,
+    FieldDiscriminatorNode, FixedSizeTypeNode, NestedTypeNode, NestedTypeNodeTrait, Node,
+    StructFieldTypeNode, StructTypeNode,
+};
+use codama_syn_helpers::extensions::*;
+use sha2::{Digest, Sha256};
+
+pub struct SetAnchorAccountsVisitor {
+    combine_types: CombineTypesVisitor,
+    anchor_crate: bool,
+}
+
+impl Default for SetAnchorAccountsVisitor {
+    fn default() -> Self {
+        Self {
+            combine_types: CombineTypesVisitor::strict(),
+            anchor_crate: true,
+        }
+    }
+}
+
+impl SetAnchorAccountsVisitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl KorokVisitor for SetAnchorAccountsVisitor {
+    fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
+        self.anchor_crate = is_anchor_crate(&korok.items);
+        CombineTypesVisitor::visit_crate_with(self, korok, |visitor| &mut visitor.combine_types)
+    }
+
+    fn visit_file_module(
+        &mut self,
+        korok: &mut codama_koroks::FileModuleKorok,
+    ) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_module_with(
+            self,
+            &korok.ast.ident,
+            &mut korok.items,
+            |visitor| &mut visitor.combine_types,
+        )
+    }
+
+    fn visit_module(&mut self, korok: &mut codama_koroks::ModuleKorok) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_module_with(
+            self,
+            &korok.ast.ident,
+            &mut korok.items,
+            |visitor| &mut visitor.combine_types,
+        )
+    }
+
+    fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> CodamaResult<()> {
+        // No overrides.
+        if korok.node.is_some() {
+            return Ok(());
+        };
+
+        // Ensure the struct has the `#[account]` Anchor attribute.
+        if get_anchor_attribute(&korok.attributes, "account", self.anchor_crate).is_none() {
+            return Ok(());
+        };
+
+        // Create a `DefinedTypeNode` from the struct.
+        self.combine_types.visit_struct(korok)?;
+
+        // Transform the defined type into an account node
+        // prefixed with the "account:<Name>" discriminator.
+        let discriminator = get_anchor_discriminator_field(&format!("account:{}", korok.ast.ident));
+        let discriminator_name = discriminator.name.clone();
+        let (name, data) = parse_struct(korok)?;
+        let data = data.map_nested_type_node(|node| {
+            let mut fields = node.fields;
+            fields.insert(0, discriminator);
+            StructTypeNode { fields }
+        });
+
+        korok.node = Some(
+            AccountNode {
+                docs: korok.docs(),
+                discriminators: vec![FieldDiscriminatorNode::new(discriminator_name, 0).into()],
+                ..AccountNode::new(name, data)
+            }
+            .into(),
+        );
+        Ok(())
+    }
+}
+
+/// Returns the first 8 bytes of the SHA-256 hash of the given preimage,
+/// as used by Anchor for account (`account:<Name>`) and
+/// instruction (`global:<name>`) discriminators.
+pub fn get_anchor_discriminator(preimage: &str) -> [u8; 8] {
+    let hash = Sha256::digest(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+/// Returns the omitted `discriminator` field that prefixes Anchor accounts and instructions.
+pub fn get_anchor_discriminator_field(preimage: &str) -> StructFieldTypeNode {
+    let data = get_anchor_discriminator(preimage)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    StructFieldTypeNode {
+        default_value_strategy: Some(DefaultValueStrategy::Omitted),
+        default_value: Some(BytesValueNode::base16(data).into()),
+        ..StructFieldTypeNode::new(
+            "discriminator",
+            FixedSizeTypeNode::new(BytesTypeNode::new(), 8),
+        )
+    }
+}
+
+/// Returns the first Anchor attribute with the given name — e.g. `#[anchor_lang::account]`
+/// or `#[anchor_lang::prelude::account]` — if any. Bare attributes — e.g. `#[account]` —
+/// are only returned when `anchor_crate` is true, see `is_anchor_crate`.
+pub fn get_anchor_attribute<'a>(
+    attributes: &'a Attributes,
+    name: &str,
+    anchor_crate: bool,
+) -> Option<&'a syn::Attribute> {
+    attributes.iter().find_map(|attr| match attr {
+        Attribute::Unsupported(UnsupportedAttribute { ast }) => {
+            let path = ast.path();
+            let is_anchor_path = match path.segments.len() {
+                1 => anchor_crate,
+                _ => path.segments[0].ident == "anchor_lang",
+            };
+            match is_anchor_path && path.last_str() == name {
+                true => Some(*ast),
+                false => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+/// Whether the given items — or their nested modules — declare an Anchor program
+/// through a `declare_id!` macro or a `#[program]` module.
+pub fn is_anchor_crate(items: &[ItemKorok]) -> bool {
+    items.iter().any(|item| match item {
+        ItemKorok::Unsupported(korok) => matches!(
+            korok.ast,
+            syn::Item::Macro(item) if item.mac.path.last_str() == "declare_id"
+        ),
+        ItemKorok::Module(korok) => {
+            get_anchor_attribute(&korok.attributes, "program", true).is_some()
+                || is_anchor_crate(&korok.items)
+        }
+        ItemKorok::FileModule(korok) => is_anchor_crate(&korok.items),
+        _ => false,
+    })
+}
+
+fn parse_struct(
+    korok: &codama_koroks::StructKorok,
+) -> CodamaResult<(CamelCaseString, NestedTypeNode<StructTypeNode>)> {
+    // Ensure we have a `DefinedTypeNode` to work with.
+    if let Some(Node::DefinedType(node)) = &korok.node {
+        // Ensure the data type is a struct.
+        if let Ok(data) = NestedTypeNode::<StructTypeNode>::try_from(node.r#type.clone()) {
+            return Ok((node.name.clone(), data));
+        };
+    };
+
+    // Handle error.
+    let message = format!(
+        "The \"{}\" struct could not be used as an Anchor account because its type is not a `NestedTypeNode<StructTypeNode>`.",
+        korok.ast.ident,
+    );
+    Err(korok.ast.error(message).into())
+}
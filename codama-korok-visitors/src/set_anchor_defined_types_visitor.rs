@@ -0,0 +1,82 @@
+use crate::{get_anchor_attribute, is_anchor_crate, CombineTypesVisitor, KorokVisitor};
+use codama_attributes::Attributes;
+use codama_errors::CodamaResult;
+
+pub struct SetAnchorDefinedTypesVisitor {
+    combine_types: CombineTypesVisitor,
+    anchor_crate: bool,
+}
+
+impl Default for SetAnchorDefinedTypesVisitor {
+    fn default() -> Self {
+        Self {
+            combine_types: CombineTypesVisitor::strict(),
+            anchor_crate: true,
+        }
+    }
+}
+
+impl SetAnchorDefinedTypesVisitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl KorokVisitor for SetAnchorDefinedTypesVisitor {
+    fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
+        self.anchor_crate = is_anchor_crate(&korok.items);
+        CombineTypesVisitor::visit_crate_with(self, korok, |visitor| &mut visitor.combine_types)
+    }
+
+    fn visit_file_module(
+        &mut self,
+        korok: &mut codama_koroks::FileModuleKorok,
+    ) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_module_with(
+            self,
+            &korok.ast.ident,
+            &mut korok.items,
+            |visitor| &mut visitor.combine_types,
+        )
+    }
+
+    fn visit_module(&mut self, korok: &mut codama_koroks::ModuleKorok) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_module_with(
+            self,
+            &korok.ast.ident,
+            &mut korok.items,
+            |visitor| &mut visitor.combine_types,
+        )
+    }
+
+    fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> CodamaResult<()> {
+        // Ensure the struct is serializable with Anchor and is not an account.
+        if !is_anchor_defined_type(&korok.attributes, self.anchor_crate) {
+            return Ok(());
+        };
+
+        // Create a `DefinedTypeNode` from the struct, if it doesn't already exist.
+        self.combine_types.visit_struct(korok)?;
+
+        Ok(())
+    }
+
+    fn visit_enum(&mut self, korok: &mut codama_koroks::EnumKorok) -> CodamaResult<()> {
+        // Ensure the enum is serializable with Anchor.
+        if !is_anchor_defined_type(&korok.attributes, self.anchor_crate) {
+            return Ok(());
+        };
+
+        // Create a `DefinedTypeNode` from the enum, if it doesn't already exist.
+        self.combine_types.visit_enum(korok)?;
+
+        Ok(())
+    }
+}
+
+fn is_anchor_defined_type(attributes: &Attributes, anchor_crate: bool) -> bool {
+    let prefixes = ["", "anchor_lang", "anchor_lang::prelude"];
+    let is_serializable = attributes.has_derive(&prefixes, "AnchorSerialize")
+        || attributes.has_derive(&prefixes, "AnchorDeserialize");
+    is_serializable && get_anchor_attribute(attributes, "account", anchor_crate).is_none()
+}
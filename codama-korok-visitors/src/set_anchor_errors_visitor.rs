@@ -0,0 +1,144 @@
+use crate::{get_anchor_attribute, is_anchor_crate, KorokVisitor};
+use codama_attributes::Attributes;
+use codama_errors::{CodamaResult, IteratorCombineErrors};
+use codama_nodes::{ErrorNode, Node, ProgramNode};
+use codama_syn_helpers::{extensions::*, ConstEvaluator, Meta};
+
+/// The default offset Anchor adds to custom error codes.
+pub const ANCHOR_ERROR_CODE_OFFSET: usize = 6000;
+
+pub struct SetAnchorErrorsVisitor {
+    anchor_crate: bool,
+    constants: ConstEvaluator,
+    offset: usize,
+    enum_current_discriminator: usize,
+}
+
+impl Default for SetAnchorErrorsVisitor {
+    fn default() -> Self {
+        Self {
+            anchor_crate: true,
+            constants: ConstEvaluator::default(),
+            offset: 0,
+            enum_current_discriminator: 0,
+        }
+    }
+}
+
+impl SetAnchorErrorsVisitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl KorokVisitor for SetAnchorErrorsVisitor {
+    fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
+        // Offsets and discriminants may refer to constants of the crate.
+        self.constants = korok.constants.clone();
+        self.anchor_crate = is_anchor_crate(&korok.items);
+        korok
+            .items
+            .iter_mut()
+            .map(|item_korok| self.visit_item(item_korok))
+            .collect_and_combine_errors()?;
+        Ok(())
+    }
+
+    fn visit_enum(&mut self, korok: &mut codama_koroks::EnumKorok) -> CodamaResult<()> {
+        // No overrides.
+        if korok.node.is_some() {
+            return Ok(());
+        };
+
+        // Ensure the enum has the `#[error_code]` Anchor attribute.
+        let Some(attribute) =
+            get_anchor_attribute(&korok.attributes, "error_code", self.anchor_crate)
+        else {
+            return Ok(());
+        };
+
+        // Transform each variant into an `ErrorNode`.
+        self.offset = get_error_code_offset(attribute, &self.constants)?;
+        self.enum_current_discriminator = 0;
+        self.visit_children(korok)?;
+        self.enum_current_discriminator = 0;
+
+        // Gather all errors in a `ProgramNode`.
+        let errors = korok
+            .variants
+            .iter()
+            .filter_map(|variant| match &variant.node {
+                Some(Node::Error(error)) => Some(error.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        korok.node = Some(
+            ProgramNode {
+                errors,
+                ..ProgramNode::default()
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    fn visit_enum_variant(
+        &mut self,
+        korok: &mut codama_koroks::EnumVariantKorok,
+    ) -> CodamaResult<()> {
+        // Update current discriminator.
+        let current_discriminator = match &korok.ast.discriminant {
+            Some((_, expr)) => expr.as_unsigned_integer_with(&self.constants)?,
+            _ => self.enum_current_discriminator,
+        };
+        self.enum_current_discriminator = current_discriminator + 1;
+
+        korok.node = Some(
+            ErrorNode {
+                name: korok.name(),
+                code: self.offset + current_discriminator,
+                message: get_message_from_msg_attribute(&korok.attributes)?.unwrap_or_default(),
+                docs: korok.docs(),
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+}
+
+/// Parse the optional `offset` of an `#[error_code(offset = 42)]` attribute.
+fn get_error_code_offset(
+    attribute: &syn::Attribute,
+    constants: &ConstEvaluator,
+) -> syn::Result<usize> {
+    let syn::Meta::List(list) = &attribute.meta else {
+        return Ok(ANCHOR_ERROR_CODE_OFFSET);
+    };
+
+    let mut offset = ANCHOR_ERROR_CODE_OFFSET;
+    list.each(|ref meta| match meta.path_str().as_str() {
+        "offset" => {
+            offset = meta
+                .as_value()?
+                .as_expr()?
+                .as_unsigned_integer_with(constants)?;
+            Ok(())
+        }
+        _ => Err(meta.error("unrecognized attribute")),
+    })?;
+    Ok(offset)
+}
+
+/// Get the message of an `#[msg("...")]` attribute, if any.
+/// Variants of `#[error_code]` enums always belong to Anchor crates.
+fn get_message_from_msg_attribute(attributes: &Attributes) -> syn::Result<Option<String>> {
+    let Some(attribute) = get_anchor_attribute(attributes, "msg", true) else {
+        return Ok(None);
+    };
+    let list = attribute.meta.require_list()?;
+    let message = list.parse_args::<Meta>()?.as_expr()?.as_string()?;
+    Ok(Some(message))
+}
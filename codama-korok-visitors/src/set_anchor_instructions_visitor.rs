@@ -0,0 +1,512 @@
+use crate::{
+    get_anchor_attribute, get_anchor_discriminator_field, is_anchor_crate, KorokVisitor,
+    SymbolTable, TypeRegistry,
+};
+use codama_attributes::{Attribute, Attributes, UnsupportedAttribute};
+use codama_errors::{CodamaResult, IteratorCombineErrors};
+use codama_koroks::{FieldKorok, ItemKorok};
+use codama_nodes::{
+    AccountValueNode, ArgumentValueNode, BytesTypeNode, BytesValueNode, CamelCaseString,
+    ConstantPdaSeedNode, FieldDiscriminatorNode, InstructionAccountNode, InstructionArgumentNode,
+    InstructionInputValueNode, InstructionNode, IsAccountSigner, PdaNode, PdaSeedNode,
+    PdaSeedValueNode, PdaValueNode, PublicKeyTypeNode, PublicKeyValueNode, TypeNode,
+    VariablePdaSeedNode,
+};
+use codama_syn_helpers::{extensions::*, Meta};
+use std::collections::HashMap;
+
+/// Creates `InstructionNodes` from the public functions of an Anchor `#[program]` module.
+/// Their accounts are resolved from the `#[derive(Accounts)]` struct of their `Context<T>`
+/// argument, which may be defined anywhere in the crate.
+/// Argument and seed types are identified like field types, following
+/// the type aliases, imports and constants of the crate.
+pub struct SetAnchorInstructionsVisitor {
+    accounts_structs: HashMap<String, Vec<AnchorAccountField>>,
+    anchor_crate: bool,
+    in_program_module: bool,
+    registry: TypeRegistry,
+    symbols: SymbolTable,
+}
+
+impl Default for SetAnchorInstructionsVisitor {
+    fn default() -> Self {
+        Self {
+            accounts_structs: HashMap::new(),
+            anchor_crate: true,
+            in_program_module: false,
+            registry: TypeRegistry::default(),
+            symbols: SymbolTable::default(),
+        }
+    }
+}
+
+impl SetAnchorInstructionsVisitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the given registry to map Rust paths to type nodes before the built-in mappings.
+    pub fn with_registry(mut self, registry: TypeRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    fn visit_scope(
+        &mut self,
+        module: Option<&syn::Ident>,
+        items: &mut [ItemKorok],
+    ) -> CodamaResult<()> {
+        self.symbols.push_scope(module);
+        let result = items
+            .iter_mut()
+            .map(|item| self.visit_item(item))
+            .collect_and_combine_errors();
+        self.symbols.pop_scope();
+        result.map(|_| ())
+    }
+}
+
+impl KorokVisitor for SetAnchorInstructionsVisitor {
+    fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
+        self.symbols = SymbolTable::new(&korok.items).with_constants(korok.constants.clone());
+        self.anchor_crate = is_anchor_crate(&korok.items);
+
+        // Gather all `#[derive(Accounts)]` structs of the crate first.
+        let mut collector = CollectAnchorAccountsVisitor {
+            accounts_structs: HashMap::new(),
+            registry: &self.registry,
+            symbols: self.symbols.clone(),
+        };
+        collector.visit_scope(None, &mut korok.items)?;
+        self.accounts_structs = collector.accounts_structs;
+
+        self.visit_scope(None, &mut korok.items)
+    }
+
+    fn visit_file_module(
+        &mut self,
+        korok: &mut codama_koroks::FileModuleKorok,
+    ) -> CodamaResult<()> {
+        self.visit_scope(Some(&korok.ast.ident), &mut korok.items)
+    }
+
+    fn visit_module(&mut self, korok: &mut codama_koroks::ModuleKorok) -> CodamaResult<()> {
+        // Ensure the module has the `#[program]` Anchor attribute.
+        if get_anchor_attribute(&korok.attributes, "program", self.anchor_crate).is_none() {
+            return self.visit_scope(Some(&korok.ast.ident), &mut korok.items);
+        };
+
+        self.in_program_module = true;
+        let result = self.visit_scope(Some(&korok.ast.ident), &mut korok.items);
+        self.in_program_module = false;
+        result
+    }
+
+    fn visit_unsupported_item(
+        &mut self,
+        korok: &mut codama_koroks::UnsupportedItemKorok,
+    ) -> CodamaResult<()> {
+        // No overrides.
+        if korok.node.is_some() {
+            return Ok(());
+        };
+
+        // Ensure we have a public function inside a `#[program]` module.
+        let syn::Item::Fn(item_fn) = korok.ast else {
+            return Ok(());
+        };
+        if !self.in_program_module || !matches!(item_fn.vis, syn::Visibility::Public(_)) {
+            return Ok(());
+        }
+
+        let name = item_fn.sig.ident.to_string();
+        let discriminator = get_anchor_discriminator_field(&format!("global:{name}"));
+        let mut arguments = vec![InstructionArgumentNode::from(discriminator.clone())];
+        let mut accounts = vec![];
+
+        for (i, input) in item_fn.sig.inputs.iter().enumerate() {
+            let syn::FnArg::Typed(pat_type) = input else {
+                return Err(input
+                    .error("Anchor instructions cannot have a receiver")
+                    .into());
+            };
+
+            // The first argument is the `Context<T>` of the instruction.
+            if i == 0 {
+                let accounts_struct = get_context_accounts_struct(&pat_type.ty)?;
+                accounts = self.resolve_accounts(&accounts_struct, &pat_type.ty)?;
+                continue;
+            }
+
+            let syn::Pat::Ident(pat_ident) = pat_type.pat.as_ref() else {
+                return Err(pat_type.pat.error("expected an argument name").into());
+            };
+            let Some(r#type) = self.symbols.get_type_node(&pat_type.ty, &self.registry) else {
+                let message = format!(
+                    "The \"{}\" argument of the \"{name}\" instruction could not be resolved to a `TypeNode`.",
+                    pat_ident.ident
+                );
+                return Err(pat_type.ty.error(message).into());
+            };
+            arguments.push(InstructionArgumentNode::new(
+                pat_ident.ident.to_string(),
+                r#type,
+            ));
+        }
+
+        korok.node = Some(
+            InstructionNode {
+                name: name.into(),
+                docs: korok.attributes.docs(),
+                accounts,
+                arguments,
+                discriminators: vec![FieldDiscriminatorNode::new(discriminator.name, 0).into()],
+                ..InstructionNode::default()
+            }
+            .into(),
+        );
+        Ok(())
+    }
+}
+
+impl SetAnchorInstructionsVisitor {
+    /// Flatten the accounts of the given `#[derive(Accounts)]` struct,
+    /// including the accounts of any nested `#[derive(Accounts)]` struct.
+    fn resolve_accounts(
+        &self,
+        accounts_struct: &str,
+        span: &syn::Type,
+    ) -> syn::Result<Vec<InstructionAccountNode>> {
+        let Some(fields) = self.accounts_structs.get(accounts_struct) else {
+            let message = format!(
+                "Could not find the \"{accounts_struct}\" struct deriving `Accounts` in this crate."
+            );
+            return Err(span.error(message));
+        };
+
+        let mut accounts = vec![];
+        for field in fields {
+            match &field.composite {
+                Some(composite) if self.accounts_structs.contains_key(composite) => {
+                    accounts.extend(self.resolve_accounts(composite, span)?)
+                }
+                _ => accounts.push(field.account.clone()),
+            }
+        }
+        Ok(accounts)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AnchorAccountField {
+    /// The name of the field type, when it may refer to a nested `#[derive(Accounts)]` struct.
+    composite: Option<String>,
+    account: InstructionAccountNode,
+}
+
+struct CollectAnchorAccountsVisitor<'a> {
+    accounts_structs: HashMap<String, Vec<AnchorAccountField>>,
+    registry: &'a TypeRegistry,
+    symbols: SymbolTable,
+}
+
+impl CollectAnchorAccountsVisitor<'_> {
+    fn visit_scope(
+        &mut self,
+        module: Option<&syn::Ident>,
+        items: &mut [ItemKorok],
+    ) -> CodamaResult<()> {
+        self.symbols.push_scope(module);
+        let result = items
+            .iter_mut()
+            .map(|item| self.visit_item(item))
+            .collect_and_combine_errors();
+        self.symbols.pop_scope();
+        result.map(|_| ())
+    }
+}
+
+impl KorokVisitor for CollectAnchorAccountsVisitor<'_> {
+    fn visit_file_module(
+        &mut self,
+        korok: &mut codama_koroks::FileModuleKorok,
+    ) -> CodamaResult<()> {
+        self.visit_scope(Some(&korok.ast.ident), &mut korok.items)
+    }
+
+    fn visit_module(&mut self, korok: &mut codama_koroks::ModuleKorok) -> CodamaResult<()> {
+        self.visit_scope(Some(&korok.ast.ident), &mut korok.items)
+    }
+
+    fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> CodamaResult<()> {
+        // Ensure the struct derives the Anchor `Accounts` trait.
+        if !korok
+            .attributes
+            .has_derive(&["", "anchor_lang"], "Accounts")
+        {
+            return Ok(());
+        };
+
+        let arguments = get_instruction_attribute_arguments(&korok.attributes)?;
+        let account_names = korok
+            .fields
+            .iter()
+            .filter_map(|field| field.ast.ident.as_ref().map(|ident| ident.to_string()))
+            .collect::<Vec<_>>();
+
+        let get_type_node = |ty: &syn::Type| self.symbols.get_type_node(ty, self.registry);
+        let fields = korok
+            .fields
+            .iter()
+            .map(|field| parse_account_field(field, &account_names, &arguments, &get_type_node))
+            .collect_and_combine_errors()?;
+
+        self.accounts_structs
+            .insert(korok.ast.ident.to_string(), fields);
+        Ok(())
+    }
+}
+
+/// Get the name of `T` in the `Context<T>` type of an instruction.
+fn get_context_accounts_struct(ty: &syn::Type) -> syn::Result<String> {
+    if let syn::Type::Path(syn::TypePath { path, .. }) = ty {
+        if path.last_str() == "Context" {
+            if let [syn::Type::Path(syn::TypePath { path, .. })] = path.generic_types().as_slice() {
+                return Ok(path.last_str());
+            }
+        }
+    }
+    Err(ty.error("expected a `Context<T>` as the first argument of an Anchor instruction"))
+}
+
+/// Get the arguments declared by an `#[instruction(...)]` attribute — e.g. `#[instruction(amount: u64)]`.
+/// `#[derive(Accounts)]` structs always belong to Anchor crates.
+fn get_instruction_attribute_arguments(attributes: &Attributes) -> syn::Result<Vec<syn::Field>> {
+    let Some(attribute) = get_anchor_attribute(attributes, "instruction", true) else {
+        return Ok(vec![]);
+    };
+    let list = attribute.meta.require_list()?;
+    let fields = list.parse_args_with(|input: syn::parse::ParseStream| {
+        syn::punctuated::Punctuated::<syn::Field, syn::Token![,]>::parse_terminated_with(
+            input,
+            syn::Field::parse_named,
+        )
+    })?;
+    Ok(fields.into_iter().collect())
+}
+
+fn parse_account_field(
+    field: &FieldKorok,
+    account_names: &[String],
+    arguments: &[syn::Field],
+    get_type_node: &dyn Fn(&syn::Type) -> Option<TypeNode>,
+) -> syn::Result<AnchorAccountField> {
+    let Some(ident) = &field.ast.ident else {
+        return Err(field.ast.error("expected a named field"));
+    };
+
+    // Unwrap `Option<T>` and `Box<T>` wrappers.
+    let mut ty = &field.ast.ty;
+    let mut is_optional = false;
+    while let syn::Type::Path(syn::TypePath { path, .. }) = ty {
+        match (path.last_str().as_str(), path.generic_types().as_slice()) {
+            ("Option", [inner]) => {
+                is_optional = true;
+                ty = inner;
+            }
+            ("Box", [inner]) => ty = inner,
+            _ => break,
+        }
+    }
+    let (type_name, generic_type_name) = match ty {
+        syn::Type::Path(syn::TypePath { path, .. }) => (
+            path.last_str(),
+            match path.generic_types().as_slice() {
+                [syn::Type::Path(syn::TypePath { path, .. })] => Some(path.last_str()),
+                _ => None,
+            },
+        ),
+        _ => return Err(ty.error("expected an Anchor account type")),
+    };
+
+    let constraints = get_account_constraints(&field.attributes);
+    let has_constraint = |name: &str| constraints.iter().any(|meta| meta.path_str() == name);
+    let is_init = has_constraint("init") || has_constraint("init_if_needed");
+    let seeds = constraints
+        .iter()
+        .find(|meta| meta.path_str() == "seeds")
+        .and_then(|meta| meta.as_path_list().ok());
+    let is_associated_token = constraints
+        .iter()
+        .any(|meta| meta.path_str().starts_with("associated_token::"));
+
+    let is_writable = is_init || has_constraint("mut") || has_constraint("zero");
+    let is_signer = type_name == "Signer"
+        || has_constraint("signer")
+        || (is_init && seeds.is_none() && !is_associated_token);
+
+    let default_value = match (seeds, has_constraint("seeds::program")) {
+        (Some(seeds), false) => seeds
+            .as_expr_array()
+            .ok()
+            .and_then(|seeds| {
+                parse_pda_value_node(ident, &seeds, account_names, arguments, get_type_node)
+            })
+            .map(InstructionInputValueNode::from),
+        _ => get_known_address(&type_name, generic_type_name.as_deref())
+            .map(|address| PublicKeyValueNode::new(address).into()),
+    };
+
+    let is_account_type = matches!(
+        type_name.as_str(),
+        "Account"
+            | "AccountInfo"
+            | "AccountLoader"
+            | "Interface"
+            | "InterfaceAccount"
+            | "Program"
+            | "Signer"
+            | "SystemAccount"
+            | "Sysvar"
+            | "UncheckedAccount"
+    );
+
+    Ok(AnchorAccountField {
+        composite: (!is_account_type).then_some(type_name),
+        account: InstructionAccountNode {
+            is_optional,
+            docs: field.docs(),
+            default_value,
+            ..InstructionAccountNode::new(
+                ident.to_string(),
+                is_writable,
+                IsAccountSigner::from(is_signer),
+            )
+        },
+    })
+}
+
+/// Parse all constraints of the `#[account(...)]` attributes of a field.
+/// Constraints that cannot be parsed as a `Meta` — e.g. `has_one = authority @ MyError` — are ignored.
+fn get_account_constraints(attributes: &Attributes) -> Vec<Meta> {
+    attributes
+        .iter()
+        .filter_map(|attr| match attr {
+            Attribute::Unsupported(UnsupportedAttribute { ast })
+                if ast.path().is("anchor_lang::account") =>
+            {
+                ast.meta.require_list().ok()
+            }
+            _ => None,
+        })
+        .flat_map(|list| split_top_level_commas(list.tokens.clone()))
+        .filter_map(|tokens| syn::parse2::<Meta>(tokens).ok())
+        .collect()
+}
+
+fn split_top_level_commas(tokens: proc_macro2::TokenStream) -> Vec<proc_macro2::TokenStream> {
+    let mut chunks = vec![proc_macro2::TokenStream::new()];
+    for token in tokens {
+        match &token {
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                chunks.push(proc_macro2::TokenStream::new())
+            }
+            _ => chunks.last_mut().unwrap().extend([token]),
+        }
+    }
+    chunks
+        .into_iter()
+        .filter(|chunk| !chunk.is_empty())
+        .collect()
+}
+
+/// Parse the `seeds = [...]` constraint of an account into a `PdaValueNode`.
+/// Returns `None` if any of the seeds cannot be statically resolved.
+fn parse_pda_value_node(
+    ident: &syn::Ident,
+    seeds: &syn::ExprArray,
+    account_names: &[String],
+    arguments: &[syn::Field],
+    get_type_node: &dyn Fn(&syn::Type) -> Option<TypeNode>,
+) -> Option<PdaValueNode> {
+    let mut seed_nodes: Vec<PdaSeedNode> = vec![];
+    let mut seed_values = vec![];
+
+    for seed in &seeds.elems {
+        // Strip conversions such as `.key().as_ref()` or `.to_le_bytes()`.
+        let mut expr = seed;
+        loop {
+            match expr {
+                syn::Expr::MethodCall(call)
+                    if matches!(
+                        call.method.to_string().as_str(),
+                        "as_ref" | "as_bytes" | "key" | "to_bytes" | "to_le_bytes"
+                    ) && call.args.is_empty() =>
+                {
+                    expr = &call.receiver
+                }
+                syn::Expr::Reference(reference) => expr = &reference.expr,
+                syn::Expr::Paren(paren) => expr = &paren.expr,
+                _ => break,
+            }
+        }
+
+        match expr {
+            syn::Expr::Lit(syn::ExprLit { lit, .. }) => {
+                let value = match lit {
+                    syn::Lit::ByteStr(lit) => String::from_utf8(lit.value()).ok()?,
+                    syn::Lit::Str(lit) => lit.value(),
+                    _ => return None,
+                };
+                seed_nodes.push(
+                    ConstantPdaSeedNode::new(BytesTypeNode::new(), BytesValueNode::utf8(value))
+                        .into(),
+                );
+            }
+            syn::Expr::Path(syn::ExprPath { path, .. }) if path.get_ident().is_some() => {
+                let name = path.last_str();
+                if account_names.contains(&name) {
+                    seed_nodes.push(
+                        VariablePdaSeedNode::new(name.as_str(), PublicKeyTypeNode::new()).into(),
+                    );
+                    seed_values.push(PdaSeedValueNode::new(
+                        name.as_str(),
+                        AccountValueNode::new(name.as_str()),
+                    ));
+                } else {
+                    let argument = arguments
+                        .iter()
+                        .find(|argument| argument.ident.as_ref().is_some_and(|i| *i == name))?;
+                    let r#type = match get_type_node(&argument.ty)? {
+                        TypeNode::SizePrefix(node) => *node.r#type,
+                        node => node,
+                    };
+                    seed_nodes.push(VariablePdaSeedNode::new(name.as_str(), r#type).into());
+                    seed_values.push(PdaSeedValueNode::new(
+                        name.as_str(),
+                        ArgumentValueNode::new(name.as_str()),
+                    ));
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    Some(PdaValueNode::new(
+        PdaNode::new(CamelCaseString::from(ident.to_string()), seed_nodes),
+        seed_values,
+    ))
+}
+
+/// Get the address of well-known programs and sysvars — e.g. `Program<'info, System>`.
+fn get_known_address(type_name: &str, generic_type_name: Option<&str>) -> Option<&'static str> {
+    match (type_name, generic_type_name?) {
+        ("Program", "System") => Some("11111111111111111111111111111111"),
+        ("Program", "Token") => Some("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+        ("Program", "Token2022") => Some("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
+        ("Program", "AssociatedToken") => Some("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+        ("Sysvar", "Rent") => Some("SysvarRent111111111111111111111111111111111"),
+        ("Sysvar", "Clock") => Some("SysvarC1ock11111111111111111111111111111111"),
+        _ => None,
+    }
+}
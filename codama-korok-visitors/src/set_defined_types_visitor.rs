@@ -1,14 +1,17 @@
 use crate::{CombineTypesVisitor, KorokVisitor};
+use codama_attributes::Attributes;
 use codama_errors::CodamaResult;
 
 pub struct SetDefinedTypesVisitor {
     combine_types: CombineTypesVisitor,
+    shank: bool,
 }
 
 impl Default for SetDefinedTypesVisitor {
     fn default() -> Self {
         Self {
             combine_types: CombineTypesVisitor::strict(),
+            shank: false,
         }
     }
 }
@@ -17,12 +20,52 @@ impl SetDefinedTypesVisitor {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Use `#[derive(ShankType)]` items instead of Codama derives.
+    pub fn shank() -> Self {
+        Self {
+            shank: true,
+            ..Self::default()
+        }
+    }
+
+    fn is_defined_type(&self, attributes: &Attributes) -> bool {
+        match self.shank {
+            true => attributes.has_shank_derive("ShankType"),
+            false => attributes.has_codama_derive("CodamaType"),
+        }
+    }
 }
 
 impl KorokVisitor for SetDefinedTypesVisitor {
+    fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_crate_with(self, korok, |visitor| &mut visitor.combine_types)
+    }
+
+    fn visit_file_module(
+        &mut self,
+        korok: &mut codama_koroks::FileModuleKorok,
+    ) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_module_with(
+            self,
+            &korok.ast.ident,
+            &mut korok.items,
+            |visitor| &mut visitor.combine_types,
+        )
+    }
+
+    fn visit_module(&mut self, korok: &mut codama_koroks::ModuleKorok) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_module_with(
+            self,
+            &korok.ast.ident,
+            &mut korok.items,
+            |visitor| &mut visitor.combine_types,
+        )
+    }
+
     fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> CodamaResult<()> {
-        // Ensure the struct has the `CodamaType` attribute.
-        if !korok.attributes.has_codama_derive("CodamaType") {
+        // Ensure the struct has the `CodamaType` or `ShankType` attribute.
+        if !self.is_defined_type(&korok.attributes) {
             return Ok(());
         };
 
@@ -33,8 +76,8 @@ impl KorokVisitor for SetDefinedTypesVisitor {
     }
 
     fn visit_enum(&mut self, korok: &mut codama_koroks::EnumKorok) -> CodamaResult<()> {
-        // Ensure the enum has the `CodamaType` attribute.
-        if !korok.attributes.has_codama_derive("CodamaType") {
+        // Ensure the enum has the `CodamaType` or `ShankType` attribute.
+        if !self.is_defined_type(&korok.attributes) {
             return Ok(());
         };
 
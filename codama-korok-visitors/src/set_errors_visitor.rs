@@ -3,7 +3,7 @@ use codama_attributes::{
     Attribute, Attributes, ErrorDirective, TryFromFilter, UnsupportedAttribute,
 };
 use codama_errors::CodamaResult;
-use codama_nodes::{Docs, ErrorNode, Node, ProgramNode};
+use codama_nodes::{ErrorNode, Node, ProgramNode};
 use codama_syn_helpers::extensions::*;
 
 pub struct SetErrorsVisitor {
@@ -27,6 +27,10 @@ impl SetErrorsVisitor {
 }
 
 impl KorokVisitor for SetErrorsVisitor {
+    fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_crate_with(self, korok, |visitor| &mut visitor.combine_types)
+    }
+
     fn visit_enum(&mut self, korok: &mut codama_koroks::EnumKorok) -> CodamaResult<()> {
         // No overrides.
         if korok.node.is_some() {
@@ -73,7 +77,7 @@ impl KorokVisitor for SetErrorsVisitor {
     ) -> CodamaResult<()> {
         // Update current discriminator.
         let current_discriminator = match &korok.ast.discriminant {
-            Some((_, expr)) => expr.as_unsigned_integer()?,
+            Some((_, expr)) => expr.as_unsigned_integer_with(&self.combine_types.constants)?,
             _ => self.enum_current_discriminator,
         };
         self.enum_current_discriminator = current_discriminator + 1;
@@ -99,7 +103,7 @@ impl KorokVisitor for SetErrorsVisitor {
                 name: korok.name(),
                 code,
                 message,
-                docs: Docs::default(),
+                docs: korok.docs(),
             }
             .into(),
         );
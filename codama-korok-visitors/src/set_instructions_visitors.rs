@@ -17,6 +17,7 @@ pub struct SetInstructionsVisitor {
     enum_name: String,
     enum_discriminator: EnumDiscriminatorDirective,
     enum_current_discriminator: usize,
+    shank: bool,
 }
 
 impl Default for SetInstructionsVisitor {
@@ -35,6 +36,7 @@ impl Default for SetInstructionsVisitor {
             enum_name: "".to_string(),
             enum_discriminator: EnumDiscriminatorDirective::default(),
             enum_current_discriminator: 0,
+            shank: false,
         }
     }
 }
@@ -43,9 +45,42 @@ impl SetInstructionsVisitor {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Use `#[derive(ShankInstruction)]` enums instead of Codama derives.
+    pub fn shank() -> Self {
+        Self {
+            shank: true,
+            ..Self::default()
+        }
+    }
 }
 
 impl KorokVisitor for SetInstructionsVisitor {
+    fn visit_crate(&mut self, korok: &mut codama_koroks::CrateKorok) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_crate_with(self, korok, |visitor| &mut visitor.combine_types)
+    }
+
+    fn visit_file_module(
+        &mut self,
+        korok: &mut codama_koroks::FileModuleKorok,
+    ) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_module_with(
+            self,
+            &korok.ast.ident,
+            &mut korok.items,
+            |visitor| &mut visitor.combine_types,
+        )
+    }
+
+    fn visit_module(&mut self, korok: &mut codama_koroks::ModuleKorok) -> CodamaResult<()> {
+        CombineTypesVisitor::visit_module_with(
+            self,
+            &korok.ast.ident,
+            &mut korok.items,
+            |visitor| &mut visitor.combine_types,
+        )
+    }
+
     fn visit_struct(&mut self, korok: &mut codama_koroks::StructKorok) -> CodamaResult<()> {
         // No overrides.
         if korok.node.is_some() {
@@ -53,7 +88,7 @@ impl KorokVisitor for SetInstructionsVisitor {
         };
 
         // Ensure the struct has the `CodamaInstruction` attribute.
-        if !korok.attributes.has_codama_derive("CodamaInstruction") {
+        if self.shank || !korok.attributes.has_codama_derive("CodamaInstruction") {
             return Ok(());
         };
 
@@ -65,6 +100,7 @@ impl KorokVisitor for SetInstructionsVisitor {
         korok.node = Some(
             InstructionNode {
                 name,
+                docs: korok.docs(),
                 accounts: parse_accounts(&korok.attributes, &korok.fields),
                 arguments: parse_arguments(&korok.attributes, &korok.fields, data, None),
                 discriminators: DiscriminatorDirective::nodes(&korok.attributes),
@@ -82,8 +118,12 @@ impl KorokVisitor for SetInstructionsVisitor {
             return Ok(());
         };
 
-        // Ensure the struct has the `CodamaInstructions` attribute.
-        if !korok.attributes.has_codama_derive("CodamaInstructions") {
+        // Ensure the enum has the `CodamaInstructions` or `ShankInstruction` attribute.
+        let is_instructions = match self.shank {
+            true => korok.attributes.has_shank_derive("ShankInstruction"),
+            false => korok.attributes.has_codama_derive("CodamaInstructions"),
+        };
+        if !is_instructions {
             return Ok(());
         };
 
@@ -130,7 +170,7 @@ impl KorokVisitor for SetInstructionsVisitor {
     ) -> CodamaResult<()> {
         // Update current discriminator.
         let current_discriminator = match &korok.ast.discriminant {
-            Some((_, expr)) => expr.as_unsigned_integer()?,
+            Some((_, expr)) => expr.as_unsigned_integer_with(&self.combine_types.constants)?,
             _ => self.enum_current_discriminator,
         };
         self.enum_current_discriminator = current_discriminator + 1;
@@ -150,6 +190,7 @@ impl KorokVisitor for SetInstructionsVisitor {
         korok.node = Some(
             InstructionNode {
                 name,
+                docs: korok.docs(),
                 accounts: parse_accounts(&korok.attributes, &korok.fields),
                 arguments: parse_arguments(
                     &korok.attributes,
@@ -182,7 +223,14 @@ fn parse_accounts(attributes: &Attributes, fields: &[FieldKorok]) -> Vec<Instruc
             field
                 .attributes
                 .get_last(AccountDirective::filter)
-                .map(|attr| attr.account.clone())
+                .map(|attr| match attr.account.docs.is_empty() {
+                    // Fallback to the field's doc comments when no docs are explicitly provided.
+                    true => InstructionAccountNode {
+                        docs: field.docs(),
+                        ..attr.account.clone()
+                    },
+                    false => attr.account.clone(),
+                })
         })
         .collect::<Vec<_>>();
 
@@ -307,7 +355,12 @@ fn parse_enum_variant(
                                     .get(i)
                                     .and_then(|f| f.name())
                                     .unwrap_or_else(|| format!("arg{}", i).into());
-                                StructFieldTypeNode::new(name, item)
+                                let docs =
+                                    korok.fields.get(i).map(|f| f.docs()).unwrap_or_default();
+                                StructFieldTypeNode {
+                                    docs,
+                                    ..StructFieldTypeNode::new(name, item)
+                                }
                             })
                             .collect();
                         return Ok((node.name, StructTypeNode::new(fields)));
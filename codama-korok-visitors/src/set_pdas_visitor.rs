@@ -3,8 +3,7 @@ use codama_attributes::{Attributes, SeedDirective, SeedDirectiveType, TryFromFil
 use codama_errors::CodamaResult;
 use codama_koroks::FieldKorok;
 use codama_nodes::{
-    CamelCaseString, Docs, Node, PdaNode, PdaSeedNode, RegisteredTypeNode, TypeNode,
-    VariablePdaSeedNode,
+    CamelCaseString, Node, PdaNode, PdaSeedNode, RegisteredTypeNode, TypeNode, VariablePdaSeedNode,
 };
 
 #[derive(Default)]
@@ -46,7 +45,7 @@ pub fn parse_pda_node(
     PdaNode {
         name,
         seeds: parse_pda_seed_nodes(attributes, fields),
-        docs: Docs::default(),
+        docs: attributes.docs(),
         program_id: None,
     }
 }
@@ -61,18 +60,21 @@ pub fn parse_pda_seed_nodes(attributes: &Attributes, fields: &[FieldKorok]) -> V
                 if field.ast.ident.as_ref().is_none_or(|ident| ident != name) {
                     return None;
                 }
-                let (name, type_node) = match &field.node {
-                    Some(Node::Type(RegisteredTypeNode::StructField(struct_field))) => {
-                        (struct_field.name.clone(), struct_field.r#type.clone())
-                    }
+                let (name, type_node, docs) = match &field.node {
+                    Some(Node::Type(RegisteredTypeNode::StructField(struct_field))) => (
+                        struct_field.name.clone(),
+                        struct_field.r#type.clone(),
+                        struct_field.docs.clone(),
+                    ),
                     _ => match TypeNode::try_from(field.node.clone()) {
-                        Ok(type_node) => (name.clone().into(), type_node),
+                        Ok(type_node) => (name.clone().into(), type_node, field.docs()),
                         Err(_) => return None,
                     },
                 };
-                Some(PdaSeedNode::Variable(VariablePdaSeedNode::new(
-                    name, type_node,
-                )))
+                Some(PdaSeedNode::Variable(VariablePdaSeedNode {
+                    docs,
+                    ..VariablePdaSeedNode::new(name, type_node)
+                }))
             }),
         })
         .collect()
@@ -0,0 +1,218 @@
+use crate::TypeRegistry;
+use codama_koroks::ItemKorok;
+use codama_nodes::TypeNode;
+use codama_syn_helpers::{ConstEvaluator, Imports, PathResolver};
+use std::collections::{HashMap, HashSet};
+use syn::visit_mut::VisitMut;
+
+/// The maximum number of aliases followed when resolving a single type.
+const MAX_ALIAS_DEPTH: usize = 32;
+
+/// The type aliases, imports and constants declared in a crate, used to resolve field types.
+///
+/// Type aliases and definitions are keyed by their path from the crate root — e.g.
+/// `state::Lamports` — and imports are resolved from the module declaring them.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SymbolTable {
+    aliases: HashMap<String, Alias>,
+    definitions: HashSet<String>,
+    imports: Imports,
+    constants: ConstEvaluator,
+    scopes: Vec<Scope>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Alias {
+    ty: syn::Type,
+    /// The module declaring the alias, used to resolve the names it refers to.
+    module: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Scope {
+    module: Vec<String>,
+    constants: ConstEvaluator,
+}
+
+impl SymbolTable {
+    /// Collect all type aliases, definitions and imports declared in the given items
+    /// and their nested modules.
+    pub fn new(items: &[ItemKorok]) -> Self {
+        let mut table = Self::default();
+        table.collect_aliases(items, &mut vec![]);
+        table
+    }
+
+    /// Use the given constants of the crate, see `SymbolTable::constants`.
+    pub fn with_constants(mut self, constants: ConstEvaluator) -> Self {
+        self.constants = constants;
+        self
+    }
+
+    fn collect_aliases(&mut self, items: &[ItemKorok], module: &mut Vec<String>) {
+        for item in items {
+            match item {
+                ItemKorok::TypeAlias(korok) if korok.ast.generics.params.is_empty() => {
+                    let key = get_key(module, &[korok.ast.ident.to_string()]);
+                    self.aliases.entry(key).or_insert_with(|| Alias {
+                        ty: (*korok.ast.ty).clone(),
+                        module: module.clone(),
+                    });
+                }
+                ItemKorok::Struct(korok) => {
+                    self.definitions
+                        .insert(get_key(module, &[korok.ast.ident.to_string()]));
+                }
+                ItemKorok::Enum(korok) => {
+                    self.definitions
+                        .insert(get_key(module, &[korok.ast.ident.to_string()]));
+                }
+                ItemKorok::Use(korok) => self.imports.add(module, &korok.ast.tree),
+                ItemKorok::Module(korok) => {
+                    module.push(korok.ast.ident.to_string());
+                    self.collect_aliases(&korok.items, module);
+                    module.pop();
+                }
+                ItemKorok::FileModule(korok) => {
+                    module.push(korok.ast.ident.to_string());
+                    self.collect_aliases(&korok.items, module);
+                    module.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Enter a module whose imports and definitions take precedence.
+    /// The crate root is entered without a module name.
+    pub fn push_scope(&mut self, module: Option<&syn::Ident>) {
+        let (module, constants) = match (self.scopes.last(), module) {
+            (Some(parent), Some(module)) => (
+                [parent.module.clone(), vec![module.to_string()]].concat(),
+                parent.constants.in_module(module.to_string()),
+            ),
+            _ => (vec![], self.constants.clone()),
+        };
+        self.scopes.push(Scope { module, constants });
+    }
+
+    pub fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// The constants of the crate, evaluated from the current module.
+    pub fn constants(&self) -> &ConstEvaluator {
+        match self.scopes.last() {
+            Some(scope) => &scope.constants,
+            None => &self.constants,
+        }
+    }
+
+    /// Replace renamed imports with the name of their definition and inline type aliases.
+    /// E.g. `Option<Lamports>` becomes `Option<u64>` given `type Lamports = u64;`.
+    pub fn resolve(&self, ty: &syn::Type) -> syn::Type {
+        self.resolve_with(ty, None)
+    }
+
+    /// The type node of a Rust type from the current module, using the given registry.
+    /// Paths registered in the registry take precedence over the aliases they may refer to.
+    pub fn get_type_node(&self, ty: &syn::Type, registry: &TypeRegistry) -> Option<TypeNode> {
+        registry.get_type_node_with(&self.resolve_with(ty, Some(registry)), self.constants())
+    }
+
+    fn resolve_with(&self, ty: &syn::Type, registry: Option<&TypeRegistry>) -> syn::Type {
+        let mut ty = ty.clone();
+        TypeResolver {
+            table: self,
+            registry,
+            module: self.current_module().to_vec(),
+            depth: 0,
+        }
+        .visit_type_mut(&mut ty);
+        ty
+    }
+
+    fn current_module(&self) -> &[String] {
+        match self.scopes.last() {
+            Some(scope) => &scope.module,
+            None => &[],
+        }
+    }
+
+    /// The path imported as `ident` in the given module, if any.
+    fn get_import(&self, ident: &syn::Ident, module: &[String]) -> Option<&syn::Path> {
+        self.imports.get(module, &ident.to_string())
+    }
+
+    /// The alias a path refers to from the given module, see `PathResolver::resolve`.
+    /// Names of structs and enums defined in the given module are never aliases.
+    fn get_alias(&self, path: &syn::Path, module: &[String]) -> Option<&Alias> {
+        let segments = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>();
+        if let [name] = segments.as_slice() {
+            let key = get_key(module, &segments);
+            if !self.aliases.contains_key(&key)
+                && self.imports.get(module, name).is_none()
+                && self.definitions.contains(&key)
+            {
+                return None;
+            }
+        }
+        PathResolver::new(&self.aliases, &self.imports)
+            .resolve(&segments, module, None)
+            .ok()
+    }
+}
+
+struct TypeResolver<'a> {
+    table: &'a SymbolTable,
+    /// Registered paths are kept as they are instead of being inlined.
+    registry: Option<&'a TypeRegistry>,
+    /// The module names are resolved from, i.e. the module of the alias being inlined.
+    module: Vec<String>,
+    depth: usize,
+}
+
+impl VisitMut for TypeResolver<'_> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(syn::TypePath { qself: None, path }) = ty {
+            if path.leading_colon.is_none() {
+                // Renamed imports are replaced by the name of their definition.
+                let import = match path.segments.len() {
+                    1 => self.table.get_import(&path.segments[0].ident, &self.module),
+                    _ => None,
+                };
+                let registered = self
+                    .registry
+                    .is_some_and(|registry| registry.contains(import.unwrap_or(path)));
+                let alias = match path.segments.last().unwrap().arguments.is_none() {
+                    true if !registered => self.table.get_alias(path, &self.module),
+                    _ => None,
+                };
+                if let Some(import) = import {
+                    path.segments.last_mut().unwrap().ident =
+                        import.segments.last().unwrap().ident.clone();
+                }
+
+                if let (Some(alias), true) = (alias, self.depth < MAX_ALIAS_DEPTH) {
+                    *ty = alias.ty.clone();
+                    let module = std::mem::replace(&mut self.module, alias.module.clone());
+                    self.depth += 1;
+                    self.visit_type_mut(ty);
+                    self.depth -= 1;
+                    self.module = module;
+                    return;
+                }
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+/// The key of an item from its module and relative path, e.g. `state::Lamports`.
+fn get_key(module: &[String], segments: &[String]) -> String {
+    [module, segments].concat().join("::")
+}
@@ -0,0 +1,318 @@
+use crate::identify_field_types_visitor::get_builtin_type_node;
+use codama_attributes::FromMeta;
+use codama_errors::CodamaResult;
+use codama_nodes::{CamelCaseString, NodeTransformer, NodeVisitable, TypeNode};
+use codama_syn_helpers::{extensions::*, ConstEvaluator, Meta};
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use std::path::Path;
+
+/// Maps Rust type paths to type nodes, on top of the built-in mappings of `get_type_node`.
+///
+/// Entries can be parsed from tokens or loaded from a file using the `#[codama(type = ...)]` syntax.
+/// Generic parameters act as placeholders for the type nodes of their arguments.
+/// ```text
+/// my_crate::Amount -> amount(number(u64), decimals = 6),
+/// solana_program::clock::UnixTimestamp -> date_time(number(i64)),
+/// my_crate::Maybe<T> -> zeroable_option(T),
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TypeRegistry {
+    entries: Vec<TypeRegistryEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TypeRegistryEntry {
+    prefix: String,
+    name: String,
+    params: Vec<String>,
+    node: TypeNode,
+}
+
+impl TypeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a type node for the given path, e.g. `my_crate::Wrapper<T>`.
+    /// The node may use `DefinedTypeLinkNode`s named after generic parameters as placeholders.
+    /// Later entries take precedence over earlier ones.
+    pub fn with_type<T: Into<TypeNode>>(mut self, path: syn::Path, node: T) -> Self {
+        self.add_type(path, node);
+        self
+    }
+
+    pub fn add_type<T: Into<TypeNode>>(&mut self, path: syn::Path, node: T) {
+        self.entries.push(TypeRegistryEntry {
+            prefix: path.prefix(),
+            name: path.last_str(),
+            params: get_params(&path),
+            node: node.into(),
+        });
+    }
+
+    /// Add all entries of the given registry, which take precedence over the existing ones.
+    pub fn extend(&mut self, registry: TypeRegistry) {
+        self.entries.extend(registry.entries);
+    }
+
+    /// Parse entries such as `my_crate::Amount -> amount(number(u64), decimals = 6)`,
+    /// optionally separated by commas or semicolons.
+    pub fn parse(tt: TokenStream) -> CodamaResult<Self> {
+        Ok(syn::parse2(tt)?)
+    }
+
+    /// Load entries from a file using the same syntax as `TypeRegistry::parse`.
+    pub fn load<P: AsRef<Path>>(path: P) -> CodamaResult<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(syn::parse_str(&content)?)
+    }
+
+    /// The type node of a Rust type, using registered entries before built-in mappings.
+    pub fn get_type_node(&self, ty: &syn::Type) -> Option<TypeNode> {
+        self.get_type_node_with(ty, &ConstEvaluator::default())
+    }
+
+    /// Same as `get_type_node` but evaluates array lengths using the given constants.
+    pub fn get_type_node_with(
+        &self,
+        ty: &syn::Type,
+        constants: &ConstEvaluator,
+    ) -> Option<TypeNode> {
+        self.get_registered_type_node(ty, constants)
+            .or_else(|| get_builtin_type_node(ty, self, constants))
+    }
+
+    fn get_registered_type_node(
+        &self,
+        ty: &syn::Type,
+        constants: &ConstEvaluator,
+    ) -> Option<TypeNode> {
+        let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+            return None;
+        };
+        let entry = self.get_entry(path)?;
+        if entry.params.is_empty() {
+            return Some(entry.node.clone());
+        }
+
+        let args = path
+            .generic_types()
+            .iter()
+            .map(|arg| self.get_type_node_with(arg, constants))
+            .collect::<Option<Vec<_>>>()?;
+        let mut substitutions = PlaceholderSubstitutions(
+            entry
+                .params
+                .iter()
+                .map(|param| CamelCaseString::from(param.as_str()))
+                .zip(args)
+                .collect(),
+        );
+        entry.node.clone().transform(&mut substitutions).ok()?
+    }
+
+    /// Whether an entry is registered for the given path, e.g. `clock::UnixTimestamp`.
+    pub fn contains(&self, path: &syn::Path) -> bool {
+        self.get_entry(path).is_some()
+    }
+
+    /// The latest entry matching the name, prefix and number of generic arguments of a path.
+    fn get_entry(&self, path: &syn::Path) -> Option<&TypeRegistryEntry> {
+        let (prefix, name, args) = (path.prefix(), path.last_str(), path.generic_types());
+        self.entries.iter().rev().find(|entry| {
+            entry.name == name && entry.params.len() == args.len() && entry.matches(&prefix)
+        })
+    }
+}
+
+impl TypeRegistryEntry {
+    /// Whether a path prefix refers to this entry, e.g. `""` or `clock` for `solana_program::clock`.
+    /// Entries without prefix match any path with the same name.
+    fn matches(&self, prefix: &str) -> bool {
+        self.prefix.is_empty()
+            || prefix.is_empty()
+            || self.prefix == prefix
+            || self.prefix.ends_with(&format!("::{prefix}"))
+    }
+}
+
+impl syn::parse::Parse for TypeRegistry {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut registry = Self::default();
+        while !input.is_empty() {
+            let path = input.parse::<syn::Path>()?;
+            input.parse::<syn::Token![->]>()?;
+            let params = get_params(&path);
+            let tokens = with_placeholders(input.parse::<Meta>()?.to_token_stream(), &params);
+            let meta = syn::parse2::<Meta>(tokens)?;
+            registry.add_type(path, TypeNode::from_meta(&meta)?);
+            if input.peek(syn::Token![,]) {
+                input.parse::<syn::Token![,]>()?;
+            } else if input.peek(syn::Token![;]) {
+                input.parse::<syn::Token![;]>()?;
+            }
+        }
+        Ok(registry)
+    }
+}
+
+/// Replace generic parameters with `defined_type_link` placeholders, e.g. `option(T)`
+/// becomes `option(defined_type_link("T"))`.
+fn with_placeholders(tokens: TokenStream, params: &[String]) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(ident) if params.contains(&ident.to_string()) => {
+                let name = ident.to_string();
+                quote! { defined_type_link(#name) }
+            }
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), with_placeholders(group.stream(), params));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group).into()
+            }
+            token => token.into(),
+        })
+        .collect()
+}
+
+/// The names of the generic parameters of a path, e.g. `[T]` for `Wrapper<T>`.
+fn get_params(path: &syn::Path) -> Vec<String> {
+    path.generic_types()
+        .iter()
+        .map(|ty| ty.to_token_stream().to_string())
+        .collect()
+}
+
+/// Replace the placeholders of a generic entry with the type nodes of its arguments.
+struct PlaceholderSubstitutions(Vec<(CamelCaseString, TypeNode)>);
+
+impl NodeTransformer for PlaceholderSubstitutions {
+    fn transform_type(&mut self, node: TypeNode) -> CodamaResult<Option<TypeNode>> {
+        let TypeNode::Link(link) = &node else {
+            return Ok(Some(node));
+        };
+        let substitution = self
+            .0
+            .iter()
+            .find(|(param, _)| link.program.is_none() && *param == link.name);
+        Ok(Some(match substitution {
+            Some((_, argument)) => argument.clone(),
+            None => node,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        ArrayTypeNode, DefinedTypeLinkNode, FixedCountNode, NumberTypeNode, OptionTypeNode, U64, U8,
+    };
+
+    fn get_type_node(registry: &TypeRegistry, ty: syn::Type) -> Option<TypeNode> {
+        registry.get_type_node(&ty)
+    }
+
+    #[test]
+    fn entries_match_prefixes() {
+        let entry = TypeRegistryEntry {
+            prefix: "solana_program::clock".into(),
+            name: "UnixTimestamp".into(),
+            params: vec![],
+            node: NumberTypeNode::le(U64).into(),
+        };
+        assert!(entry.matches(""));
+        assert!(entry.matches("clock"));
+        assert!(entry.matches("solana_program::clock"));
+        assert!(!entry.matches("lock"));
+        assert!(!entry.matches("other::clock::inner"));
+    }
+
+    #[test]
+    fn entries_without_prefix_match_any_prefix() {
+        let registry =
+            TypeRegistry::new().with_type(syn::parse_quote! { Amount }, NumberTypeNode::le(U64));
+        assert_eq!(
+            get_type_node(&registry, syn::parse_quote! { any::crate::Amount }),
+            Some(NumberTypeNode::le(U64).into())
+        );
+    }
+
+    #[test]
+    fn params() {
+        assert_eq!(
+            get_params(&syn::parse_quote! { Amount }),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            get_params(&syn::parse_quote! { a::Pair<A, B> }),
+            vec!["A", "B"]
+        );
+    }
+
+    #[test]
+    fn placeholders() {
+        let tokens =
+            with_placeholders(quote! { tuple(T, option(U), V) }, &["T".into(), "U".into()]);
+        assert_eq!(
+            tokens.to_string(),
+            quote! { tuple(defined_type_link("T"), option(defined_type_link("U")), V) }.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_with_separators() {
+        let registry = TypeRegistry::parse(quote! {
+            A -> number(u8),
+            B -> number(u64);
+            C -> public_key
+        })
+        .unwrap();
+        assert_eq!(registry.entries.len(), 3);
+        assert_eq!(registry.entries[1].name, "B");
+        assert_eq!(registry.entries[1].node, NumberTypeNode::le(U64).into());
+    }
+
+    #[test]
+    fn generic_entries_need_matching_arguments() {
+        let registry = TypeRegistry::parse(quote! { Maybe<T> -> option(T) }).unwrap();
+        assert_eq!(
+            get_type_node(&registry, syn::parse_quote! { Maybe<u8> }),
+            Some(OptionTypeNode::new(NumberTypeNode::le(U8)).into())
+        );
+        assert_eq!(
+            get_type_node(&registry, syn::parse_quote! { Maybe }),
+            Some(DefinedTypeLinkNode::new("maybe").into())
+        );
+    }
+
+    #[test]
+    fn extended_entries_take_precedence() {
+        let mut registry = TypeRegistry::parse(quote! { Amount -> number(u8) }).unwrap();
+        registry.extend(TypeRegistry::parse(quote! { Amount -> number(u64) }).unwrap());
+        assert_eq!(
+            get_type_node(&registry, syn::parse_quote! { Amount }),
+            Some(NumberTypeNode::le(U64).into())
+        );
+    }
+
+    #[test]
+    fn constants_in_registered_arguments() {
+        let mut constants = ConstEvaluator::new();
+        constants.add_constant("LEN", syn::parse_quote! { 4 });
+        let registry = TypeRegistry::parse(quote! { Maybe<T> -> option(T) }).unwrap();
+        assert_eq!(
+            registry.get_type_node_with(&syn::parse_quote! { Maybe<[u8; LEN]> }, &constants),
+            Some(
+                OptionTypeNode::new(ArrayTypeNode::new(
+                    NumberTypeNode::le(U8),
+                    FixedCountNode::new(4)
+                ))
+                .into()
+            )
+        );
+    }
+}
@@ -19,6 +19,8 @@ impl KorokVisitable for codama_koroks::KorokMut<'_, '_> {
             Self::Root(k) => k.accept(visitor),
             Self::Struct(k) => k.accept(visitor),
             Self::Const(k) => k.accept(visitor),
+            Self::TypeAlias(k) => k.accept(visitor),
+            Self::Use(k) => k.accept(visitor),
             Self::UnsupportedItem(k) => k.accept(visitor),
             Self::ImplItem(k) => k.accept(visitor),
             Self::UnsupportedImplItem(k) => k.accept(visitor),
@@ -37,6 +39,8 @@ impl KorokVisitable for codama_koroks::KorokMut<'_, '_> {
             Self::Root(k) => k.get_children(),
             Self::Struct(k) => k.get_children(),
             Self::Const(k) => k.get_children(),
+            Self::TypeAlias(k) => k.get_children(),
+            Self::Use(k) => k.get_children(),
             Self::UnsupportedItem(k) => k.get_children(),
             Self::ImplItem(k) => k.get_children(),
             Self::UnsupportedImplItem(k) => k.get_children(),
@@ -80,6 +84,8 @@ impl KorokVisitable for codama_koroks::ItemKorok<'_> {
             codama_koroks::ItemKorok::Enum(k) => vec![k as &mut dyn KorokVisitable],
             codama_koroks::ItemKorok::Impl(k) => vec![k as &mut dyn KorokVisitable],
             codama_koroks::ItemKorok::Const(k) => vec![k as &mut dyn KorokVisitable],
+            codama_koroks::ItemKorok::TypeAlias(k) => vec![k as &mut dyn KorokVisitable],
+            codama_koroks::ItemKorok::Use(k) => vec![k as &mut dyn KorokVisitable],
             codama_koroks::ItemKorok::Unsupported(k) => vec![k as &mut dyn KorokVisitable],
         }
     }
@@ -196,6 +202,24 @@ impl KorokVisitable for codama_koroks::ConstKorok<'_> {
     }
 }
 
+impl KorokVisitable for codama_koroks::TypeAliasKorok<'_> {
+    fn accept(&mut self, visitor: &mut dyn KorokVisitor) -> CodamaResult<()> {
+        visitor.visit_type_alias(self)
+    }
+    fn get_children(&mut self) -> Vec<&mut dyn KorokVisitable> {
+        Vec::new()
+    }
+}
+
+impl KorokVisitable for codama_koroks::UseKorok<'_> {
+    fn accept(&mut self, visitor: &mut dyn KorokVisitor) -> CodamaResult<()> {
+        visitor.visit_use(self)
+    }
+    fn get_children(&mut self) -> Vec<&mut dyn KorokVisitable> {
+        Vec::new()
+    }
+}
+
 impl KorokVisitable for codama_koroks::UnsupportedItemKorok<'_> {
     fn accept(&mut self, visitor: &mut dyn KorokVisitor) -> CodamaResult<()> {
         visitor.visit_unsupported_item(self)
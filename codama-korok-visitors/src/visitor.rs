@@ -40,6 +40,8 @@ pub trait KorokVisitor {
             codama_koroks::ItemKorok::Enum(korok) => self.visit_enum(korok),
             codama_koroks::ItemKorok::Impl(korok) => self.visit_impl(korok),
             codama_koroks::ItemKorok::Const(korok) => self.visit_const(korok),
+            codama_koroks::ItemKorok::TypeAlias(korok) => self.visit_type_alias(korok),
+            codama_koroks::ItemKorok::Use(korok) => self.visit_use(korok),
             codama_koroks::ItemKorok::Unsupported(korok) => self.visit_unsupported_item(korok),
         }
     }
@@ -128,6 +130,14 @@ pub trait KorokVisitor {
         Ok(())
     }
 
+    fn visit_type_alias(&mut self, _korok: &mut codama_koroks::TypeAliasKorok) -> CodamaResult<()> {
+        Ok(())
+    }
+
+    fn visit_use(&mut self, _korok: &mut codama_koroks::UseKorok) -> CodamaResult<()> {
+        Ok(())
+    }
+
     fn visit_unsupported_impl_item(
         &mut self,
         _korok: &mut codama_koroks::UnsupportedImplItemKorok,
@@ -102,3 +102,29 @@ fn it_keeps_the_type_wrapped_in_a_struct_field_type_node() -> CodamaResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn it_evaluates_constant_sizes() -> CodamaResult<()> {
+    let store = codama_stores::CrateStore::hydrate(quote::quote! {
+        const NAME_LEN: usize = 32;
+        pub struct Person {
+            #[codama(fixed_size = NAME_LEN + 2)]
+            name: u32,
+        }
+    })?;
+    let mut korok = codama_koroks::CrateKorok::parse(&store)?;
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut ApplyTypeModifiersVisitor::new())?;
+
+    let codama_koroks::ItemKorok::Struct(person) = &korok.items[1] else {
+        panic!("Expected a struct");
+    };
+    assert_eq!(
+        person.fields[0].node,
+        Some(
+            StructFieldTypeNode::new("name", FixedSizeTypeNode::new(NumberTypeNode::le(U32), 34))
+                .into()
+        )
+    );
+    Ok(())
+}
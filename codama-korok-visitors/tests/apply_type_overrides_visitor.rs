@@ -1,7 +1,7 @@
 use codama_errors::CodamaResult;
 use codama_korok_visitors::{ApplyTypeOverridesVisitor, KorokVisitable};
 use codama_koroks::{FieldKorok, StructKorok};
-use codama_nodes::{BooleanTypeNode, StructFieldTypeNode};
+use codama_nodes::{BooleanTypeNode, DefinedTypeLinkNode, StructFieldTypeNode};
 
 #[test]
 fn it_set_the_node_on_the_korok() -> CodamaResult<()> {
@@ -68,3 +68,20 @@ fn it_uses_the_name_directive() -> CodamaResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn it_accepts_defined_type_links_for_named_field_koroks() -> CodamaResult<()> {
+    let item: syn::Field = syn::parse_quote! {
+        #[codama(type = defined_type_link("membership"))]
+        pub membership: u8
+    };
+    let mut korok = FieldKorok::parse(&item)?;
+
+    assert_eq!(korok.node, None);
+    korok.accept(&mut ApplyTypeOverridesVisitor::new())?;
+    assert_eq!(
+        korok.node,
+        Some(StructFieldTypeNode::new("membership", DefinedTypeLinkNode::new("membership")).into())
+    );
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use super::utils::{combine_modules, get_type, CombineModulesInput};
+use super::utils::{combine_crates, combine_modules, get_type, CombineModulesInput};
 use codama_nodes::{NumberFormat::U32, NumberTypeNode, ProgramNode, RootNode, StringValueNode};
 
 #[test]
@@ -86,6 +86,52 @@ fn roots_with_different_pubkey_programs() {
     );
 }
 
+#[test]
+fn roots_with_named_programs_without_pubkeys() {
+    assert_eq!(
+        combine_modules(
+            CombineModulesInput::new()
+                .add_node(RootNode::new(
+                    ProgramNode::new("program_a", "").add_defined_type(get_type("type_1"))
+                ))
+                .add_node(RootNode::new(
+                    ProgramNode::new("program_b", "").add_defined_type(get_type("type_2"))
+                ))
+        ),
+        Some(
+            RootNode::new(
+                ProgramNode::new("program_a", "")
+                    .add_defined_type(get_type("type_1"))
+                    .add_defined_type(get_type("type_2"))
+            )
+            .into()
+        )
+    );
+}
+
+#[test]
+fn crates_with_different_named_programs_without_pubkeys() {
+    assert_eq!(
+        combine_crates(vec![
+            RootNode::new(ProgramNode::new("program_a", "").add_defined_type(get_type("type_1")))
+                .into(),
+            RootNode::new(ProgramNode::new("program_b", "").add_defined_type(get_type("type_2")))
+                .into(),
+            RootNode::new(ProgramNode::new("program_a", "").add_defined_type(get_type("type_3")))
+                .into(),
+        ]),
+        Some(
+            RootNode::new(
+                ProgramNode::new("program_a", "")
+                    .add_defined_type(get_type("type_1"))
+                    .add_defined_type(get_type("type_3"))
+            )
+            .add_program(ProgramNode::new("program_b", "").add_defined_type(get_type("type_2")))
+            .into()
+        )
+    );
+}
+
 #[test]
 fn roots_with_additional_programs() {
     assert_eq!(
@@ -307,6 +353,29 @@ fn exisiting_root_with_pubkey_less_child_root() {
     );
 }
 
+#[test]
+fn exisiting_named_root_with_named_pubkey_less_child_root() {
+    assert_eq!(
+        combine_modules(
+            CombineModulesInput::new()
+                .set_initial_node(RootNode::new(
+                    ProgramNode::new("my_program", "").add_defined_type(get_type("type_1"))
+                ))
+                .add_node(RootNode::new(
+                    ProgramNode::new("my_child", "").add_defined_type(get_type("type_2"))
+                ))
+        ),
+        Some(
+            RootNode::new(
+                ProgramNode::new("my_program", "")
+                    .add_defined_type(get_type("type_1"))
+                    .add_defined_type(get_type("type_2"))
+            )
+            .into()
+        )
+    );
+}
+
 #[test]
 fn existing_defined_root_with_scraps() {
     assert_eq!(
@@ -1,8 +1,8 @@
 use codama_attributes::Attributes;
 use codama_korok_visitors::{CombineModulesVisitor, KorokVisitable};
-use codama_koroks::{ItemKorok, ModuleKorok, UnsupportedItemKorok};
+use codama_koroks::{ItemKorok, ModuleKorok, RootKorok, UnsupportedItemKorok};
 use codama_nodes::{DefinedTypeNode, Node, PublicKeyTypeNode};
-use codama_stores::CrateStore;
+use codama_stores::{CrateStore, RootStore};
 use quote::{format_ident, quote};
 use std::default;
 
@@ -83,6 +83,23 @@ pub fn combine_modules(input: CombineModulesInput) -> Option<Node> {
     module_korok.node
 }
 
+pub fn combine_crates(nodes: Vec<Node>) -> Option<Node> {
+    let root_store = RootStore {
+        crates: nodes
+            .iter()
+            .map(|_| CrateStore::hydrate(quote! {}).unwrap())
+            .collect(),
+    };
+    let mut root_korok = RootKorok::parse(&root_store).unwrap();
+    for (crate_korok, node) in root_korok.crates.iter_mut().zip(nodes) {
+        crate_korok.node = Some(node);
+    }
+    root_korok
+        .accept(&mut CombineModulesVisitor::new())
+        .unwrap();
+    root_korok.node
+}
+
 pub fn get_type(name: &str) -> DefinedTypeNode {
     DefinedTypeNode::new(name, PublicKeyTypeNode::new())
 }
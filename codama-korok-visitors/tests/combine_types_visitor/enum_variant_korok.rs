@@ -91,6 +91,30 @@ fn it_keeps_track_of_the_variant_discriminant() -> CodamaResult<()> {
     Ok(())
 }
 
+#[test]
+fn it_evaluates_constant_variant_discriminants() -> CodamaResult<()> {
+    let ast: syn::Variant = syn::parse_quote! { Foo = BASE + (1 << 2) };
+    let mut korok = EnumVariantKorok::parse(&ast)?;
+    let mut constants = codama_syn_helpers::ConstEvaluator::new();
+    constants.add_constant("BASE", syn::parse_quote! { 100 });
+
+    korok.accept(&mut CombineTypesVisitor {
+        constants,
+        ..CombineTypesVisitor::new()
+    })?;
+    assert_eq!(
+        korok.node,
+        Some(
+            EnumEmptyVariantTypeNode {
+                name: "foo".into(),
+                discriminator: Some(104)
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
+
 #[test]
 fn it_does_not_override_existing_nodes_by_default() -> CodamaResult<()> {
     let ast: syn::Variant = syn::parse_quote! { Foo };
@@ -283,3 +283,34 @@ fn it_prepends_and_appends_attribute_fields_to_structs() -> CodamaResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn it_uses_doc_comments_as_docs() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        /// A person with an age.
+        struct Person {
+            age: u8,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+    korok.fields[0].node = Some(StructFieldTypeNode::new("age", NumberTypeNode::le(U8)).into());
+
+    korok.accept(&mut CombineTypesVisitor::new())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            DefinedTypeNode {
+                docs: vec!["A person with an age.".to_string()].into(),
+                ..DefinedTypeNode::new(
+                    "person",
+                    StructTypeNode::new(vec![StructFieldTypeNode::new(
+                        "age",
+                        NumberTypeNode::le(U8)
+                    )])
+                )
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
@@ -15,7 +15,7 @@ fn it_only_starts_the_child_visitor_on_filtered_items() -> CodamaResult<()> {
             }
         }
     };
-    let mut korok = ItemKorok::parse(&ast, &[], &mut 0)?;
+    let mut korok = ItemKorok::parse(&ast, &[], &mut 0, &Default::default())?;
 
     korok.accept(&mut FilterItemsVisitor::new(
         |item| match item {
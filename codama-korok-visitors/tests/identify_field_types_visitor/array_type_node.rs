@@ -1,7 +1,7 @@
-use crate::identify_field_types_visitor::utils::get_node_from_type;
+use crate::identify_field_types_visitor::utils::{get_node, get_node_from_type};
 use codama_nodes::{
-    ArrayTypeNode, BooleanTypeNode, DefinedTypeLinkNode, FixedCountNode, Node, NumberTypeNode,
-    PrefixedCountNode, U32, U8,
+    ArrayTypeNode, BooleanTypeNode, BytesTypeNode, DefinedTypeLinkNode, FixedCountNode, Node,
+    NumberTypeNode, PrefixedCountNode, SizePrefixTypeNode, U32, U64, U8,
 };
 use quote::quote;
 
@@ -9,9 +9,9 @@ use quote::quote;
 fn it_identifies_vec_types() {
     let u32_prefix = PrefixedCountNode::new(NumberTypeNode::le(U32));
     assert_eq!(
-        get_node_from_type(quote! { Vec<u8> }),
+        get_node_from_type(quote! { Vec<u64> }),
         Some(Node::Type(
-            ArrayTypeNode::new(NumberTypeNode::le(U8), u32_prefix.clone()).into()
+            ArrayTypeNode::new(NumberTypeNode::le(U64), u32_prefix.clone()).into()
         ))
     );
     assert_eq!(
@@ -23,6 +23,33 @@ fn it_identifies_vec_types() {
     assert_eq!(get_node_from_type(quote! { some::wrong::Vec<bool> }), None);
 }
 
+#[test]
+fn it_identifies_vecs_of_u8_as_bytes() {
+    let expected: Option<Node> =
+        Some(SizePrefixTypeNode::new(BytesTypeNode::new(), NumberTypeNode::le(U32)).into());
+    assert_eq!(get_node_from_type(quote! { Vec<u8> }), expected);
+    assert_eq!(get_node_from_type(quote! { &'a [u8] }), expected);
+    assert_eq!(get_node_from_type(quote! { Box<[u8]> }), expected);
+    assert_eq!(get_node_from_type(quote! { Cow<'a, [u8]> }), expected);
+}
+
+#[test]
+fn it_identifies_slices_and_other_lists() {
+    let u32_prefix = PrefixedCountNode::new(NumberTypeNode::le(U32));
+    let expected: Option<Node> =
+        Some(ArrayTypeNode::new(BooleanTypeNode::default(), u32_prefix).into());
+    assert_eq!(get_node_from_type(quote! { &'a [bool] }), expected);
+    assert_eq!(get_node_from_type(quote! { VecDeque<bool> }), expected);
+    assert_eq!(
+        get_node_from_type(quote! { std::collections::LinkedList<bool> }),
+        expected
+    );
+    assert_eq!(
+        get_node_from_type(quote! { some::wrong::VecDeque<bool> }),
+        None
+    );
+}
+
 #[test]
 fn it_identifies_vecs_of_custom_types() {
     assert_eq!(
@@ -51,7 +78,13 @@ fn it_identifies_fixed_array_types() {
             ArrayTypeNode::new(BooleanTypeNode::default(), FixedCountNode::new(42)).into()
         ))
     );
-    assert_eq!(get_node_from_type(quote! { [bool; 1 + 2 * 4] }), None);
+    assert_eq!(
+        get_node_from_type(quote! { [bool; 1 + 2 * 4] }),
+        Some(Node::Type(
+            ArrayTypeNode::new(BooleanTypeNode::default(), FixedCountNode::new(9)).into()
+        ))
+    );
+    assert_eq!(get_node_from_type(quote! { [bool; UNKNOWN_LEN] }), None);
 }
 
 #[test]
@@ -67,3 +100,51 @@ fn it_identifies_fixed_arrays_of_custom_types() {
         ))
     );
 }
+
+#[test]
+fn it_evaluates_constant_array_lengths() {
+    let get_field_type = |korok: codama_koroks::RootKorok| match &korok.crates[0].items[2] {
+        codama_koroks::ItemKorok::Struct(korok) => korok.fields[0].node.clone(),
+        _ => None,
+    };
+    assert_eq!(
+        get_node(
+            quote! {
+                const MAX_NAME_LEN: usize = 16;
+                mod sizes { pub const SEED_LEN: usize = core::mem::size_of::<u64>(); }
+                pub struct Foo([u8; 2 * crate::MAX_NAME_LEN + sizes::SEED_LEN as usize]);
+            },
+            get_field_type
+        ),
+        Some(Node::Type(
+            ArrayTypeNode::new(NumberTypeNode::le(U8), FixedCountNode::new(40)).into()
+        ))
+    );
+}
+
+#[test]
+fn it_evaluates_array_lengths_from_the_module_of_the_field() {
+    let get_field_type = |korok: codama_koroks::RootKorok| match &korok.crates[0].items[1] {
+        codama_koroks::ItemKorok::Module(korok) => match &korok.items[1] {
+            codama_koroks::ItemKorok::Struct(korok) => korok.fields[0].node.clone(),
+            _ => None,
+        },
+        _ => None,
+    };
+    assert_eq!(
+        get_node(
+            quote! {
+                const LEN: usize = 16;
+                mod state {
+                    const LEN: usize = Vault::SEED_LEN + 1;
+                    pub struct Vault([u8; LEN]);
+                    impl Vault { pub const SEED_LEN: usize = 2 * super::LEN; }
+                }
+            },
+            get_field_type
+        ),
+        Some(Node::Type(
+            ArrayTypeNode::new(NumberTypeNode::le(U8), FixedCountNode::new(33)).into()
+        ))
+    );
+}
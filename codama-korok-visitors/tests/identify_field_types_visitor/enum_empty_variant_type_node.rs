@@ -25,9 +25,23 @@ fn it_identifies_literal_discriminators() {
 }
 
 #[test]
-fn it_does_not_identify_discriminators_from_complex_expressions() {
+fn it_identifies_discriminators_from_constant_expressions() {
     assert_eq!(
         get_node_from_enum_variant(quote! { Banana = 1 + 3 * 42 }),
+        Some(Node::Type(
+            EnumEmptyVariantTypeNode {
+                name: "banana".into(),
+                discriminator: Some(127),
+            }
+            .into()
+        ))
+    );
+}
+
+#[test]
+fn it_does_not_identify_discriminators_from_unknown_constants() {
+    assert_eq!(
+        get_node_from_enum_variant(quote! { Banana = UNKNOWN + 1 }),
         Some(Node::Type(EnumEmptyVariantTypeNode::new("banana").into()))
     );
 }
@@ -40,11 +40,26 @@ fn it_identifies_literal_discriminators() {
 }
 
 #[test]
-fn it_does_not_identify_discriminators_from_complex_expressions() {
+fn it_identifies_discriminators_from_constant_expressions() {
     assert!(matches!(
         get_node_from_enum_variant(quote! {
             Move { x: i32, y: i32 } = 1 + 3 * 42
         }),
+        Some(Node::Type(RegisteredTypeNode::EnumStructVariant(
+            EnumStructVariantTypeNode {
+                discriminator: Some(127),
+                ..
+            }
+        )))
+    ));
+}
+
+#[test]
+fn it_does_not_identify_discriminators_from_unknown_constants() {
+    assert!(matches!(
+        get_node_from_enum_variant(quote! {
+            Move { x: i32, y: i32 } = UNKNOWN + 1
+        }),
         Some(Node::Type(RegisteredTypeNode::EnumStructVariant(
             EnumStructVariantTypeNode {
                 discriminator: None,
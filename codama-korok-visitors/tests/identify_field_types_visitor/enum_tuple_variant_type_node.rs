@@ -40,11 +40,26 @@ fn it_identifies_literal_discriminators() {
 }
 
 #[test]
-fn it_does_not_identify_discriminators_from_complex_expressions() {
+fn it_identifies_discriminators_from_constant_expressions() {
     assert!(matches!(
         get_node_from_enum_variant(quote! {
             Push(u8, bool) = 1 + 3 * 42
         }),
+        Some(Node::Type(RegisteredTypeNode::EnumTupleVariant(
+            EnumTupleVariantTypeNode {
+                discriminator: Some(127),
+                ..
+            }
+        )))
+    ));
+}
+
+#[test]
+fn it_does_not_identify_discriminators_from_unknown_constants() {
+    assert!(matches!(
+        get_node_from_enum_variant(quote! {
+            Push(u8, bool) = UNKNOWN + 1
+        }),
         Some(Node::Type(RegisteredTypeNode::EnumTupleVariant(
             EnumTupleVariantTypeNode {
                 discriminator: None,
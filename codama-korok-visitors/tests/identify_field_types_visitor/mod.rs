@@ -11,7 +11,11 @@ mod number_type_node;
 mod option_type_node;
 mod public_key_type_node;
 mod set_type_node;
+mod smart_pointer_types;
 mod string_type_node;
 mod struct_type_node;
+mod symbol_table;
 mod tuple_type_node;
+mod type_registry;
 mod utils;
+mod zero_copy;
@@ -0,0 +1,28 @@
+use crate::identify_field_types_visitor::utils::get_node_from_type;
+use codama_nodes::{BooleanTypeNode, DefinedTypeLinkNode, NumberTypeNode, PublicKeyTypeNode, U64};
+use quote::quote;
+
+#[test]
+fn it_uses_the_inner_type_of_smart_pointers() {
+    assert_eq!(
+        get_node_from_type(quote! { Box<u64> }),
+        Some(NumberTypeNode::le(U64).into())
+    );
+    assert_eq!(
+        get_node_from_type(quote! { std::rc::Rc<Pubkey> }),
+        Some(PublicKeyTypeNode::new().into())
+    );
+    assert_eq!(
+        get_node_from_type(quote! { std::sync::Arc<bool> }),
+        Some(BooleanTypeNode::default().into())
+    );
+    assert_eq!(
+        get_node_from_type(quote! { Cow<'a, MyCustomType> }),
+        Some(DefinedTypeLinkNode::new("myCustomType").into())
+    );
+    assert_eq!(
+        get_node_from_type(quote! { &'a u64 }),
+        Some(NumberTypeNode::le(U64).into())
+    );
+    assert_eq!(get_node_from_type(quote! { some::wrong::Box<u64> }), None);
+}
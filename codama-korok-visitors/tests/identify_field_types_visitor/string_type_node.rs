@@ -10,3 +10,13 @@ fn it_identifies_string_types() {
     assert_eq!(get_node_from_type(quote! { std::string::String }), expected);
     assert_eq!(get_node_from_type(quote! { String<T> }), None);
 }
+
+#[test]
+fn it_identifies_borrowed_and_shared_strings() {
+    let expected: Option<Node> =
+        Some(SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U32)).into());
+    assert_eq!(get_node_from_type(quote! { &'static str }), expected);
+    assert_eq!(get_node_from_type(quote! { Box<str> }), expected);
+    assert_eq!(get_node_from_type(quote! { Cow<'a, str> }), expected);
+    assert_eq!(get_node_from_type(quote! { std::rc::Rc<String> }), expected);
+}
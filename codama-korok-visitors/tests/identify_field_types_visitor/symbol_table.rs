@@ -0,0 +1,232 @@
+use crate::identify_field_types_visitor::utils::get_node;
+use codama_koroks::{ItemKorok, RootKorok};
+use codama_nodes::{
+    DefinedTypeLinkNode, Node, NumberTypeNode, OptionTypeNode, PublicKeyTypeNode,
+    StructFieldTypeNode, StructTypeNode, U64, U8,
+};
+use quote::quote;
+
+/// Get the type of the last struct of the given items.
+fn get_struct_type(items: &[ItemKorok]) -> Option<Node> {
+    match items.iter().rev().find_map(|item| match item {
+        ItemKorok::Struct(korok) => korok.node.clone(),
+        _ => None,
+    }) {
+        Some(Node::DefinedType(node)) => Some(node.r#type.into()),
+        _ => None,
+    }
+}
+
+fn get_struct_node(korok: RootKorok) -> Option<Node> {
+    get_struct_type(&korok.crates[0].items)
+}
+
+fn get_module_struct_node(korok: RootKorok) -> Option<Node> {
+    match &korok.crates[0].items[0] {
+        ItemKorok::Module(module) => get_struct_type(&module.items),
+        _ => None,
+    }
+}
+
+#[test]
+fn it_inlines_type_aliases() {
+    assert_eq!(
+        get_node(
+            quote! {
+                type Lamports = u64;
+                type MaybeLamports = Option<Lamports>;
+                pub struct Vault { amount: Lamports, fee: MaybeLamports }
+            },
+            get_struct_node
+        ),
+        Some(
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("amount", NumberTypeNode::le(U64)),
+                StructFieldTypeNode::new("fee", OptionTypeNode::new(NumberTypeNode::le(U64))),
+            ])
+            .into()
+        )
+    );
+}
+
+#[test]
+fn it_inlines_type_aliases_from_other_modules() {
+    assert_eq!(
+        get_node(
+            quote! {
+                mod vault {
+                    use crate::types::Lamports;
+                    pub struct Vault { amount: Lamports, other: crate::types::Lamports }
+                }
+                mod types {
+                    pub type Lamports = u64;
+                }
+            },
+            get_module_struct_node
+        ),
+        Some(
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("amount", NumberTypeNode::le(U64)),
+                StructFieldTypeNode::new("other", NumberTypeNode::le(U64)),
+            ])
+            .into()
+        )
+    );
+}
+
+#[test]
+fn it_follows_renamed_imports() {
+    assert_eq!(
+        get_node(
+            quote! {
+                use spl_token::state::Account as TokenAccount;
+                use solana_program::pubkey::Pubkey as Key;
+                pub struct Vault { token: TokenAccount, owner: Key }
+            },
+            get_struct_node
+        ),
+        Some(
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("token", DefinedTypeLinkNode::new("account")),
+                StructFieldTypeNode::new("owner", PublicKeyTypeNode::new()),
+            ])
+            .into()
+        )
+    );
+}
+
+#[test]
+fn it_does_not_inline_shadowed_or_external_aliases() {
+    assert_eq!(
+        get_node(
+            quote! {
+                mod types {
+                    pub type Account = u64;
+                    pub type Config = u64;
+                }
+                use spl_token::state::Account;
+                pub struct Config { owner: u8 }
+                pub struct Vault { token: Account, config: Config }
+            },
+            get_struct_node
+        ),
+        Some(
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("token", DefinedTypeLinkNode::new("account")),
+                StructFieldTypeNode::new("config", DefinedTypeLinkNode::new("config")),
+            ])
+            .into()
+        )
+    );
+}
+
+#[test]
+fn it_stops_on_recursive_aliases() {
+    assert_eq!(
+        get_node(
+            quote! {
+                type Foo = Bar;
+                type Bar = Foo;
+                pub struct Vault { foo: Foo }
+            },
+            get_struct_node
+        ),
+        Some(
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "foo",
+                DefinedTypeLinkNode::new("foo")
+            )])
+            .into()
+        )
+    );
+}
+
+#[test]
+fn it_keys_type_aliases_by_module() {
+    assert_eq!(
+        get_node(
+            quote! {
+                mod vault {
+                    use super::b::Amount as Fee;
+                    pub type Amount = u64;
+                    pub struct Vault { amount: Amount, fee: Fee, other: crate::a::Amount }
+                }
+                mod a {
+                    pub type Amount = u8;
+                }
+                mod b {
+                    pub type Amount = Option<Inner>;
+                    type Inner = u64;
+                }
+            },
+            get_module_struct_node
+        ),
+        Some(
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("amount", NumberTypeNode::le(U64)),
+                StructFieldTypeNode::new("fee", OptionTypeNode::new(NumberTypeNode::le(U64))),
+                StructFieldTypeNode::new("other", NumberTypeNode::le(U8)),
+            ])
+            .into()
+        )
+    );
+}
+
+#[test]
+fn it_does_not_inline_ambiguous_type_aliases() {
+    assert_eq!(
+        get_node(
+            quote! {
+                mod a {
+                    pub type Amount = u8;
+                }
+                mod b {
+                    pub type Amount = u64;
+                }
+                pub struct Vault { amount: Amount }
+            },
+            get_struct_node
+        ),
+        Some(
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "amount",
+                DefinedTypeLinkNode::new("amount")
+            )])
+            .into()
+        )
+    );
+}
+
+#[test]
+fn it_resolves_relative_paths_through_imports_and_child_modules() {
+    assert_eq!(
+        get_node(
+            quote! {
+                mod vault {
+                    use crate::state::types;
+                    pub struct Vault { amount: types::Lamports, fee: state::Fee }
+                    mod state {
+                        pub type Fee = u8;
+                    }
+                }
+                mod state {
+                    pub mod types {
+                        use super::super::other::Inner as Amount;
+                        pub type Lamports = Option<Amount>;
+                    }
+                }
+                mod other {
+                    pub type Inner = u64;
+                }
+            },
+            get_module_struct_node
+        ),
+        Some(
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("amount", OptionTypeNode::new(NumberTypeNode::le(U64))),
+                StructFieldTypeNode::new("fee", NumberTypeNode::le(U8)),
+            ])
+            .into()
+        )
+    );
+}
@@ -1,7 +1,7 @@
-use crate::identify_field_types_visitor::utils::get_node_from_item;
+use crate::identify_field_types_visitor::utils::{get_node_from_item, get_node_from_type};
 use codama_nodes::{
-    BooleanTypeNode, DefinedTypeNode, Node, NumberFormat::U64, NumberTypeNode, SizePrefixTypeNode,
-    StringTypeNode, TupleTypeNode, U32, U8,
+    BooleanTypeNode, DefinedTypeLinkNode, DefinedTypeNode, Node, NumberFormat::U64, NumberTypeNode,
+    PublicKeyTypeNode, SizePrefixTypeNode, StringTypeNode, TupleTypeNode, U32, U8,
 };
 use quote::quote;
 
@@ -29,3 +29,33 @@ fn it_uses_the_inner_type_directly_on_single_unnamed_fields() {
         Some(DefinedTypeNode::new("slot", NumberTypeNode::le(U64)).into())
     );
 }
+
+#[test]
+fn it_identifies_tuple_types() {
+    assert_eq!(
+        get_node_from_type(quote! { (u8, Pubkey) }),
+        Some(
+            TupleTypeNode::new(vec![
+                NumberTypeNode::le(U8).into(),
+                PublicKeyTypeNode::new().into(),
+            ])
+            .into()
+        )
+    );
+    assert_eq!(
+        get_node_from_type(quote! { (bool, (u64, MyCustomType)) }),
+        Some(
+            TupleTypeNode::new(vec![
+                BooleanTypeNode::default().into(),
+                TupleTypeNode::new(vec![
+                    NumberTypeNode::le(U64).into(),
+                    DefinedTypeLinkNode::new("myCustomType").into(),
+                ])
+                .into(),
+            ])
+            .into()
+        )
+    );
+    assert_eq!(get_node_from_type(quote! { () }), None);
+    assert_eq!(get_node_from_type(quote! { (u8, HashMap<u8>) }), None);
+}
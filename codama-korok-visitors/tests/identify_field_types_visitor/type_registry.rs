@@ -0,0 +1,139 @@
+use codama_korok_visitors::{
+    CombineTypesVisitor, IdentifyFieldTypesVisitor, KorokVisitable, TypeRegistry,
+};
+use codama_koroks::{ItemKorok, RootKorok};
+use codama_nodes::{
+    AmountTypeNode, DateTimeTypeNode, DefinedTypeLinkNode, Node, NumberTypeNode, OptionTypeNode,
+    PublicKeyTypeNode, TupleTypeNode, ZeroableOptionTypeNode, I64, U32, U64,
+};
+use codama_stores::RootStore;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+fn get_node_from_type(registry: &TypeRegistry, tt: TokenStream) -> Option<Node> {
+    get_node_from_items(registry, quote! { pub struct Foo(#tt); })
+}
+
+/// Get the type of the first field of the first struct of the given items.
+fn get_node_from_items(registry: &TypeRegistry, tt: TokenStream) -> Option<Node> {
+    let store = RootStore::hydrate(tt).unwrap();
+    let mut korok = RootKorok::parse(&store).unwrap();
+    korok
+        .accept(&mut IdentifyFieldTypesVisitor::new().with_registry(registry.clone()))
+        .unwrap();
+    korok.accept(&mut CombineTypesVisitor::new()).unwrap();
+    korok.crates[0].items.iter().find_map(|item| match item {
+        ItemKorok::Struct(k) => Some(k.fields[0].node.clone()),
+        _ => None,
+    })?
+}
+
+fn get_registry() -> TypeRegistry {
+    TypeRegistry::parse(quote! {
+        my_crate::Amount -> amount(number(u64), decimals = 6),
+        solana_program::clock::UnixTimestamp -> date_time(number(i64));
+        my_crate::Maybe<T> -> zeroable_option(T)
+        my_crate::Pair<A, B> -> tuple(B, A)
+    })
+    .unwrap()
+}
+
+#[test]
+fn it_uses_registered_types() {
+    let registry = get_registry();
+    let amount = AmountTypeNode::new(NumberTypeNode::le(U64), 6, None);
+    assert_eq!(
+        get_node_from_type(&registry, quote! { my_crate::Amount }),
+        Some(amount.clone().into())
+    );
+    assert_eq!(
+        get_node_from_type(&registry, quote! { Amount }),
+        Some(amount.clone().into())
+    );
+    assert_eq!(
+        get_node_from_type(&registry, quote! { clock::UnixTimestamp }),
+        Some(DateTimeTypeNode::new(NumberTypeNode::le(I64)).into())
+    );
+    assert_eq!(
+        get_node_from_type(&registry, quote! { Option<Amount> }),
+        Some(OptionTypeNode::new(amount).into())
+    );
+    assert_eq!(
+        get_node_from_type(&registry, quote! { other_crate::Amount }),
+        Some(DefinedTypeLinkNode::new("amount").into())
+    );
+}
+
+#[test]
+fn it_uses_registered_generic_templates() {
+    let registry = get_registry();
+    assert_eq!(
+        get_node_from_type(&registry, quote! { Maybe<Pubkey> }),
+        Some(ZeroableOptionTypeNode::new(PublicKeyTypeNode::new()).into())
+    );
+    assert_eq!(
+        get_node_from_type(&registry, quote! { Pair<u32, Maybe<UnixTimestamp>> }),
+        Some(
+            TupleTypeNode::new(vec![
+                ZeroableOptionTypeNode::new(DateTimeTypeNode::new(NumberTypeNode::le(I64))).into(),
+                NumberTypeNode::le(U32).into(),
+            ])
+            .into()
+        )
+    );
+    assert_eq!(
+        get_node_from_type(&registry, quote! { Maybe<HashMap<u8>> }),
+        None
+    );
+}
+
+#[test]
+fn it_uses_registered_types_before_type_aliases() {
+    let registry = get_registry();
+    let date_time = DateTimeTypeNode::new(NumberTypeNode::le(I64));
+    assert_eq!(
+        get_node_from_items(
+            &registry,
+            quote! {
+                pub type UnixTimestamp = i64;
+                pub struct Foo(UnixTimestamp);
+            }
+        ),
+        Some(date_time.clone().into())
+    );
+    assert_eq!(
+        get_node_from_items(
+            &registry,
+            quote! {
+                pub type UnixTimestamp = i64;
+                pub struct Foo(Option<UnixTimestamp>);
+            }
+        ),
+        Some(OptionTypeNode::new(date_time).into())
+    );
+    assert_eq!(
+        get_node_from_items(
+            &registry,
+            quote! {
+                pub type Timestamp = i64;
+                pub struct Foo(Timestamp);
+            }
+        ),
+        Some(NumberTypeNode::le(I64).into())
+    );
+}
+
+#[test]
+fn it_gives_precedence_to_later_entries() {
+    let registry = get_registry().with_type(syn::parse_quote! { Amount }, NumberTypeNode::le(U64));
+    assert_eq!(
+        get_node_from_type(&registry, quote! { my_crate::Amount }),
+        Some(NumberTypeNode::le(U64).into())
+    );
+}
+
+#[test]
+fn it_fails_to_parse_invalid_entries() {
+    let error = TypeRegistry::parse(quote! { Amount -> not_a_type(u64) }).unwrap_err();
+    assert_eq!(error.to_string(), "unrecognized type");
+}
@@ -0,0 +1,373 @@
+use crate::identify_field_types_visitor::utils::{get_node_from_item, get_node_from_type};
+use codama_korok_visitors::{CombineTypesVisitor, IdentifyFieldTypesVisitor, KorokVisitable};
+use codama_koroks::RootKorok;
+use codama_nodes::{
+    ArrayTypeNode, BooleanTypeNode, DefinedTypeLinkNode, DefinedTypeNode, FixedCountNode, Node,
+    NumberTypeNode, OptionTypeNode, PostOffsetTypeNode, PublicKeyTypeNode, StructFieldTypeNode,
+    StructTypeNode, TupleTypeNode, ZeroableOptionTypeNode, I64, U128, U16, U32, U64, U8,
+};
+use codama_stores::RootStore;
+use quote::quote;
+
+#[test]
+fn it_identifies_pod_wrappers() {
+    assert_eq!(
+        get_node_from_type(quote! { PodU64 }),
+        Some(NumberTypeNode::le(U64).into())
+    );
+    assert_eq!(
+        get_node_from_type(quote! { spl_pod::primitives::PodI64 }),
+        Some(NumberTypeNode::le(I64).into())
+    );
+    assert_eq!(
+        get_node_from_type(quote! { PodBool }),
+        Some(BooleanTypeNode::default().into())
+    );
+}
+
+#[test]
+fn it_identifies_zeroable_options_in_zero_copy_structs() {
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C)]
+            #[derive(Clone, Copy, Pod, Zeroable)]
+            pub struct Config {
+                pub authority: Option<Pubkey>,
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "config",
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "authority",
+                ZeroableOptionTypeNode::new(PublicKeyTypeNode::new())
+            )])
+        )))
+    );
+    assert_eq!(
+        get_node_from_item(quote! {
+            pub struct Config {
+                pub authority: Option<Pubkey>,
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "config",
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "authority",
+                OptionTypeNode::new(PublicKeyTypeNode::new())
+            )])
+        )))
+    );
+}
+
+#[test]
+fn it_identifies_arrays_of_zeroable_options_in_zero_copy_structs() {
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C)]
+            #[derive(Clone, Copy, Pod, Zeroable)]
+            pub struct Config {
+                pub delegates: [Option<Pubkey>; 2],
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "config",
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "delegates",
+                ArrayTypeNode::new(
+                    ZeroableOptionTypeNode::new(PublicKeyTypeNode::new()),
+                    FixedCountNode::new(2)
+                )
+            )])
+        )))
+    );
+}
+
+#[test]
+fn it_pads_fields_of_repr_c_structs() {
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C)]
+            pub struct Counter {
+                pub bump: u8,
+                pub count: u64,
+                pub flag: u16,
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "counter",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new(
+                    "bump",
+                    PostOffsetTypeNode::padded(NumberTypeNode::le(U8), 7)
+                ),
+                StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+                StructFieldTypeNode::new(
+                    "flag",
+                    PostOffsetTypeNode::padded(NumberTypeNode::le(U16), 6)
+                ),
+            ])
+        )))
+    );
+}
+
+#[test]
+fn it_does_not_pad_unaligned_fields() {
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C)]
+            pub struct Vault {
+                pub bump: u8,
+                pub amount: PodU64,
+                pub owner: Pubkey,
+                pub seeds: [u8; 3],
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "vault",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("bump", NumberTypeNode::le(U8)),
+                StructFieldTypeNode::new("amount", NumberTypeNode::le(U64)),
+                StructFieldTypeNode::new("owner", PublicKeyTypeNode::new()),
+                StructFieldTypeNode::new(
+                    "seeds",
+                    ArrayTypeNode::new(NumberTypeNode::le(U8), FixedCountNode::new(3))
+                ),
+            ])
+        )))
+    );
+}
+
+#[test]
+fn it_pads_repr_c_structs_to_their_alignment() {
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C, align(8))]
+            pub struct Flags(u32, u8);
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "flags",
+            TupleTypeNode::new(vec![
+                NumberTypeNode::le(U32).into(),
+                PostOffsetTypeNode::padded(NumberTypeNode::le(U8), 3).into(),
+            ])
+        )))
+    );
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C)]
+            pub struct Wide {
+                pub value: u128,
+                pub bump: u8,
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "wide",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("value", NumberTypeNode::le(U128)),
+                // 128-bit integers are 8-byte aligned on SBF.
+                StructFieldTypeNode::new(
+                    "bump",
+                    PostOffsetTypeNode::padded(NumberTypeNode::le(U8), 7)
+                ),
+            ])
+        )))
+    );
+}
+
+#[test]
+fn it_does_not_pad_packed_or_borsh_structs() {
+    let expected = Some(Node::DefinedType(DefinedTypeNode::new(
+        "counter",
+        StructTypeNode::new(vec![
+            StructFieldTypeNode::new("bump", NumberTypeNode::le(U8)),
+            StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+        ]),
+    )));
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C, packed)]
+            pub struct Counter {
+                pub bump: u8,
+                pub count: u64,
+            }
+        }),
+        expected
+    );
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[derive(BorshSerialize)]
+            #[repr(C)]
+            pub struct Counter {
+                pub bump: u8,
+                pub count: u64,
+            }
+        }),
+        expected
+    );
+}
+
+#[test]
+fn it_pads_fields_linking_to_zero_copy_structs_of_the_crate() {
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C)]
+            #[derive(Clone, Copy, Pod, Zeroable)]
+            pub struct Vault {
+                pub bump: u8,
+                pub position: Position,
+            }
+
+            #[repr(C)]
+            #[derive(Clone, Copy, Pod, Zeroable)]
+            pub struct Position {
+                pub amount: u64,
+                pub flag: u8,
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "vault",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new(
+                    "bump",
+                    PostOffsetTypeNode::padded(NumberTypeNode::le(U8), 7)
+                ),
+                StructFieldTypeNode::new("position", DefinedTypeLinkNode::new("position")),
+            ])
+        )))
+    );
+}
+
+#[test]
+fn it_resolves_zero_copy_structs_of_the_crate_by_module() {
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C)]
+            #[derive(Clone, Copy, Pod, Zeroable)]
+            pub struct Vault {
+                pub bump: u8,
+                pub flag: other::Position,
+                pub position: Position,
+            }
+
+            use state::Position;
+
+            mod state {
+                #[repr(C)]
+                #[derive(Clone, Copy, Pod, Zeroable)]
+                pub struct Position {
+                    pub amount: u64,
+                }
+            }
+
+            mod other {
+                #[repr(C)]
+                #[derive(Clone, Copy, Pod, Zeroable)]
+                pub struct Position {
+                    pub flag: u8,
+                }
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "vault",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("bump", NumberTypeNode::le(U8)),
+                StructFieldTypeNode::new(
+                    "flag",
+                    PostOffsetTypeNode::padded(DefinedTypeLinkNode::new("position"), 6)
+                ),
+                StructFieldTypeNode::new("position", DefinedTypeLinkNode::new("position")),
+            ])
+        )))
+    );
+}
+
+#[test]
+fn it_fails_to_pad_fields_linking_to_unknown_layouts() {
+    let store = RootStore::hydrate(quote! {
+        #[repr(C)]
+        #[derive(Clone, Copy, Pod, Zeroable)]
+        pub struct Vault {
+            pub bump: u8,
+            pub position: external::Position,
+        }
+    })
+    .unwrap();
+    let mut korok = RootKorok::parse(&store).unwrap();
+    korok.accept(&mut IdentifyFieldTypesVisitor::new()).unwrap();
+    let error = korok.accept(&mut CombineTypesVisitor::new()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot compute the padding of zero-copy struct `Vault` because the layout of `position` is unknown"
+    );
+}
+
+#[test]
+fn it_fails_to_pad_fields_with_unknown_alignments() {
+    let store = RootStore::hydrate(quote! {
+        #[repr(C)]
+        #[derive(Clone, Copy, Pod, Zeroable)]
+        pub struct Vault {
+            pub bump: u8,
+            pub amounts: (u8, Vec<u64>),
+        }
+    })
+    .unwrap();
+    let mut korok = RootKorok::parse(&store).unwrap();
+    korok.accept(&mut IdentifyFieldTypesVisitor::new()).unwrap();
+    let error = korok.accept(&mut CombineTypesVisitor::new()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot compute the padding of zero-copy struct `Vault` because the layout of `(u8 , Vec < u64 >)` is unknown"
+    );
+}
+
+#[test]
+fn it_uses_the_alignment_of_crate_structs_prefixed_with_pod() {
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[repr(C)]
+            #[derive(Clone, Copy, Pod, Zeroable)]
+            pub struct Vault {
+                pub bump: u8,
+                pub config: PodConfig,
+            }
+
+            #[repr(C)]
+            #[derive(Clone, Copy, Pod, Zeroable)]
+            pub struct PodConfig {
+                pub amount: u64,
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "vault",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new(
+                    "bump",
+                    PostOffsetTypeNode::padded(NumberTypeNode::le(U8), 7)
+                ),
+                StructFieldTypeNode::new("config", DefinedTypeLinkNode::new("podConfig")),
+            ])
+        )))
+    );
+}
+
+#[test]
+fn it_does_not_pad_anchor_accounts() {
+    assert_eq!(
+        get_node_from_item(quote! {
+            #[account]
+            #[repr(C)]
+            pub struct Counter {
+                pub bump: u8,
+                pub count: u64,
+            }
+        }),
+        Some(Node::DefinedType(DefinedTypeNode::new(
+            "counter",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("bump", NumberTypeNode::le(U8)),
+                StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+            ])
+        )))
+    );
+}
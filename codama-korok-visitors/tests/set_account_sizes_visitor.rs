@@ -0,0 +1,135 @@
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{KorokVisitable, SetAccountSizesVisitor};
+use codama_koroks::RootKorok;
+use codama_nodes::{
+    AccountNode, DefinedTypeLinkNode, DefinedTypeNode, FieldDiscriminatorNode, Node,
+    NumberFormat::{U32, U64, U8},
+    NumberTypeNode, ProgramNode, PublicKeyTypeNode, RootNode, SizeDiscriminatorNode,
+    StringTypeNode, StructFieldTypeNode, StructTypeNode,
+};
+use codama_stores::RootStore;
+use quote::quote;
+
+fn set_account_sizes(node: Node, visitor: &mut SetAccountSizesVisitor) -> CodamaResult<Node> {
+    let store = RootStore::hydrate(quote! {})?;
+    let mut korok = RootKorok::parse(&store)?;
+    korok.node = Some(node);
+    korok.accept(visitor)?;
+    Ok(korok.node.unwrap())
+}
+
+fn get_account(node: Node, name: &str) -> AccountNode {
+    let Node::Root(root) = node else {
+        panic!("Expected root node");
+    };
+    root.program
+        .accounts
+        .into_iter()
+        .find(|account| account.name.as_str() == name)
+        .unwrap()
+}
+
+fn program() -> ProgramNode {
+    ProgramNode::default()
+        .add_account(AccountNode::new(
+            "counter",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("authority", PublicKeyTypeNode::new()),
+                StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+            ]),
+        ))
+        .add_account(AccountNode::new(
+            "profile",
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "name",
+                StringTypeNode::utf8(),
+            )]),
+        ))
+        .add_account(AccountNode::new(
+            "config",
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "settings",
+                DefinedTypeLinkNode::new("settings"),
+            )]),
+        ))
+        .add_defined_type(DefinedTypeNode::new(
+            "settings",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("fee", NumberTypeNode::le(U32)),
+                StructFieldTypeNode::new("bump", NumberTypeNode::le(U8)),
+            ]),
+        ))
+}
+
+#[test]
+fn it_sets_the_size_of_fixed_size_accounts() -> CodamaResult<()> {
+    let node = set_account_sizes(
+        RootNode::new(program()).into(),
+        &mut SetAccountSizesVisitor::new(),
+    )?;
+    assert_eq!(get_account(node.clone(), "counter").size, Some(40));
+    assert_eq!(get_account(node.clone(), "profile").size, None);
+    assert_eq!(get_account(node.clone(), "config").size, Some(5));
+    assert_eq!(get_account(node, "counter").discriminators, vec![]);
+    Ok(())
+}
+
+#[test]
+fn it_ignores_accounts_linking_to_unknown_types() -> CodamaResult<()> {
+    let program = program().add_account(AccountNode::new(
+        "vault",
+        StructTypeNode::new(vec![StructFieldTypeNode::new(
+            "state",
+            DefinedTypeLinkNode::new("unknown"),
+        )]),
+    ));
+    let node = set_account_sizes(
+        RootNode::new(program).into(),
+        &mut SetAccountSizesVisitor::new(),
+    )?;
+    assert_eq!(get_account(node.clone(), "counter").size, Some(40));
+    assert_eq!(get_account(node, "vault").size, None);
+    Ok(())
+}
+
+#[test]
+fn it_keeps_existing_sizes() -> CodamaResult<()> {
+    let program = ProgramNode::default().add_account(AccountNode {
+        size: Some(100),
+        ..AccountNode::new("counter", StructTypeNode::new(vec![]))
+    });
+    let node = set_account_sizes(
+        RootNode::new(program).into(),
+        &mut SetAccountSizesVisitor::new(),
+    )?;
+    assert_eq!(get_account(node, "counter").size, Some(100));
+    Ok(())
+}
+
+#[test]
+fn it_can_add_size_discriminators() -> CodamaResult<()> {
+    let program = program().add_account(AccountNode {
+        discriminators: vec![FieldDiscriminatorNode::new("authority", 0).into()],
+        ..AccountNode::new(
+            "vault",
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "authority",
+                PublicKeyTypeNode::new(),
+            )]),
+        )
+    });
+    let node = set_account_sizes(
+        RootNode::new(program).into(),
+        &mut SetAccountSizesVisitor::with_size_discriminators(),
+    )?;
+    assert_eq!(
+        get_account(node.clone(), "counter").discriminators,
+        vec![SizeDiscriminatorNode::new(40).into()]
+    );
+    assert_eq!(get_account(node.clone(), "profile").discriminators, vec![]);
+    assert_eq!(
+        get_account(node, "vault").discriminators,
+        vec![FieldDiscriminatorNode::new("authority", 0).into()]
+    );
+    Ok(())
+}
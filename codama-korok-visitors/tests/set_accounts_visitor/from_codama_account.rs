@@ -251,3 +251,45 @@ fn with_pda_and_seed_directives() -> CodamaResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn from_struct_with_doc_comments() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        /// A token account.
+        /// Holds an amount of a given mint.
+        #[derive(CodamaAccount)]
+        struct Token {
+            /// The mint of the token.
+            mint: Pubkey,
+            amount: u64,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetAccountsVisitor::new())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            AccountNode {
+                docs: vec![
+                    "A token account.".to_string(),
+                    "Holds an amount of a given mint.".to_string()
+                ]
+                .into(),
+                ..AccountNode::new(
+                    "token",
+                    StructTypeNode::new(vec![
+                        StructFieldTypeNode {
+                            docs: vec!["The mint of the token.".to_string()].into(),
+                            ..StructFieldTypeNode::new("mint", PublicKeyTypeNode::new())
+                        },
+                        StructFieldTypeNode::new("amount", NumberTypeNode::le(U64)),
+                    ])
+                )
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{
+    ApplyTypeOverridesVisitor, IdentifyFieldTypesVisitor, KorokVisitable, SetAccountsVisitor,
+    SetDefaultValuesVisitor,
+};
+use codama_koroks::StructKorok;
+use codama_nodes::{
+    AccountNode, BytesTypeNode, BytesValueNode, ConstantPdaSeedNode, DefaultValueStrategy,
+    FixedSizeTypeNode, NumberTypeNode, PdaLinkNode, PdaNode, ProgramNode, PublicKeyTypeNode,
+    StringTypeNode, StringValueNode, StructFieldTypeNode, StructTypeNode, VariablePdaSeedNode, U64,
+};
+
+#[test]
+fn from_shank_account_struct() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[derive(ShankAccount)]
+        struct Counter {
+            count: u64,
+            #[padding]
+            padding: [u8; 2],
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut ApplyTypeOverridesVisitor::new())?;
+    korok.accept(&mut SetDefaultValuesVisitor::new())?;
+    korok.accept(&mut SetAccountsVisitor::shank())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            AccountNode::new(
+                "counter",
+                StructTypeNode::new(vec![
+                    StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+                    StructFieldTypeNode {
+                        default_value_strategy: Some(DefaultValueStrategy::Omitted),
+                        default_value: Some(BytesValueNode::base16("0000").into()),
+                        ..StructFieldTypeNode::new(
+                            "padding",
+                            FixedSizeTypeNode::new(BytesTypeNode::new(), 2)
+                        )
+                    },
+                ])
+            )
+            .into()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn from_shank_account_struct_with_seeds() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[derive(ShankAccount)]
+        #[seeds("counter", authority("The counter authority"))]
+        struct Counter {
+            count: u64,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetAccountsVisitor::shank())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            ProgramNode {
+                accounts: vec![AccountNode {
+                    pda: Some(PdaLinkNode::new("counter")),
+                    ..AccountNode::new(
+                        "counter",
+                        StructTypeNode::new(vec![StructFieldTypeNode::new(
+                            "count",
+                            NumberTypeNode::le(U64)
+                        )])
+                    )
+                }],
+                pdas: vec![PdaNode::new(
+                    "counter",
+                    vec![
+                        ConstantPdaSeedNode::new(
+                            StringTypeNode::utf8(),
+                            StringValueNode::new("counter")
+                        )
+                        .into(),
+                        VariablePdaSeedNode {
+                            docs: vec!["The counter authority".to_string()].into(),
+                            ..VariablePdaSeedNode::new("authority", PublicKeyTypeNode::new())
+                        }
+                        .into(),
+                    ]
+                )],
+                ..ProgramNode::default()
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
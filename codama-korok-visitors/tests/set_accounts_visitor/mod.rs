@@ -1,2 +1,3 @@
 mod from_codama_account;
 mod from_codama_accounts;
+mod from_shank_account;
@@ -0,0 +1,148 @@
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{
+    get_anchor_discriminator, IdentifyFieldTypesVisitor, KorokVisitable, SetAnchorAccountsVisitor,
+};
+use codama_koroks::{ItemKorok, KorokTrait, RootKorok, StructKorok};
+use codama_nodes::{
+    AccountNode, BytesTypeNode, BytesValueNode, DefaultValueStrategy, FieldDiscriminatorNode,
+    FixedSizeTypeNode, Node, NumberTypeNode, PublicKeyTypeNode, StructFieldTypeNode,
+    StructTypeNode, U64,
+};
+use codama_stores::RootStore;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Whether the `Counter` struct of the given crate is identified as an account.
+fn is_crate_account(tt: TokenStream) -> CodamaResult<bool> {
+    let store = RootStore::hydrate(tt)?;
+    let mut korok = RootKorok::parse(&store)?;
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetAnchorAccountsVisitor::new())?;
+    Ok(korok.crates[0].items.iter().any(|item| match item {
+        ItemKorok::Struct(korok) if korok.ast.ident == "Counter" => {
+            matches!(korok.node(), Some(Node::Account(_)))
+        }
+        _ => false,
+    }))
+}
+
+#[test]
+fn it_computes_anchor_discriminators() {
+    // Known discriminator of Anchor's `global:initialize` instruction.
+    assert_eq!(
+        get_anchor_discriminator("global:initialize"),
+        [175, 175, 109, 31, 13, 152, 155, 237]
+    );
+}
+
+#[test]
+fn it_creates_accounts_from_anchor_account_structs() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        /// A simple counter.
+        #[account]
+        pub struct Counter {
+            pub authority: Pubkey,
+            pub count: u64,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetAnchorAccountsVisitor::new())?;
+
+    let discriminator = get_anchor_discriminator("account:Counter")
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    assert_eq!(
+        korok.node,
+        Some(
+            AccountNode {
+                docs: vec!["A simple counter.".to_string()].into(),
+                discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+                ..AccountNode::new(
+                    "counter",
+                    StructTypeNode::new(vec![
+                        StructFieldTypeNode {
+                            default_value_strategy: Some(DefaultValueStrategy::Omitted),
+                            default_value: Some(BytesValueNode::base16(discriminator).into()),
+                            ..StructFieldTypeNode::new(
+                                "discriminator",
+                                FixedSizeTypeNode::new(BytesTypeNode::new(), 8)
+                            )
+                        },
+                        StructFieldTypeNode::new("authority", PublicKeyTypeNode::new()),
+                        StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+                    ])
+                )
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn it_supports_zero_copy_and_prefixed_anchor_account_attributes() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[anchor_lang::account(zero_copy)]
+        pub struct Counter {
+            pub count: u64,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetAnchorAccountsVisitor::new())?;
+    assert!(matches!(korok.node, Some(codama_nodes::Node::Account(_))));
+    Ok(())
+}
+
+#[test]
+fn it_ignores_structs_without_the_anchor_account_attribute() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        pub struct Counter {
+            pub count: u64,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetAnchorAccountsVisitor::new())?;
+    assert_eq!(korok.node, None);
+    Ok(())
+}
+
+#[test]
+fn it_only_trusts_bare_account_attributes_in_anchor_crates() -> CodamaResult<()> {
+    let counter = quote! {
+        #[account]
+        pub struct Counter {
+            pub count: u64,
+        }
+    };
+    assert!(!is_crate_account(counter.clone())?);
+    assert!(is_crate_account(quote! {
+        declare_id!("Counter111111111111111111111111111111111111");
+        #counter
+    })?);
+    assert!(is_crate_account(quote! {
+        #[program]
+        pub mod counter {}
+        #counter
+    })?);
+    assert!(is_crate_account(quote! {
+        #[anchor_lang::prelude::account]
+        pub struct Counter {
+            pub count: u64,
+        }
+    })?);
+    assert!(!is_crate_account(quote! {
+        declare_id!("Counter111111111111111111111111111111111111");
+        #[other::account]
+        pub struct Counter {
+            pub count: u64,
+        }
+    })?);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{
+    IdentifyFieldTypesVisitor, KorokVisitable, SetAnchorDefinedTypesVisitor,
+};
+use codama_koroks::StructKorok;
+use codama_nodes::{DefinedTypeNode, NumberTypeNode, StructFieldTypeNode, StructTypeNode, U8};
+
+#[test]
+fn it_creates_defined_types_from_anchor_serializable_structs() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+        pub struct Config {
+            pub fee: u8,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetAnchorDefinedTypesVisitor::new())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            DefinedTypeNode::new(
+                "config",
+                StructTypeNode::new(vec![StructFieldTypeNode::new(
+                    "fee",
+                    NumberTypeNode::le(U8)
+                )])
+            )
+            .into()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn it_ignores_anchor_accounts() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[account]
+        #[derive(AnchorSerialize)]
+        pub struct Config {
+            pub fee: u8,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetAnchorDefinedTypesVisitor::new())?;
+    assert_eq!(korok.node, None);
+    Ok(())
+}
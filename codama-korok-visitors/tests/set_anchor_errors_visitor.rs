@@ -0,0 +1,65 @@
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{KorokVisitable, SetAnchorErrorsVisitor};
+use codama_koroks::EnumKorok;
+use codama_nodes::{ErrorNode, ProgramNode};
+
+#[test]
+fn it_creates_errors_from_anchor_error_code_enums() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[error_code]
+        pub enum MyError {
+            /// The amount is too large.
+            #[msg("Amount too large")]
+            AmountTooLarge,
+            #[msg("Unauthorized")]
+            Unauthorized = 5,
+            Unknown,
+        }
+    };
+    let mut korok = EnumKorok::parse(&item)?;
+
+    korok.accept(&mut SetAnchorErrorsVisitor::new())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            ProgramNode {
+                errors: vec![
+                    ErrorNode {
+                        docs: vec!["The amount is too large.".to_string()].into(),
+                        ..ErrorNode::new("amountTooLarge", 6000, "Amount too large")
+                    },
+                    ErrorNode::new("unauthorized", 6005, "Unauthorized"),
+                    ErrorNode::new("unknown", 6006, ""),
+                ],
+                ..ProgramNode::default()
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn it_uses_custom_error_code_offsets() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[error_code(offset = 100)]
+        pub enum MyError {
+            #[msg("First")]
+            First,
+        }
+    };
+    let mut korok = EnumKorok::parse(&item)?;
+
+    korok.accept(&mut SetAnchorErrorsVisitor::new())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            ProgramNode {
+                errors: vec![ErrorNode::new("first", 100, "First")],
+                ..ProgramNode::default()
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
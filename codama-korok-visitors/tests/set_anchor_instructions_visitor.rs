@@ -0,0 +1,268 @@
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{
+    get_anchor_discriminator_field, KorokVisitable, SetAnchorInstructionsVisitor, TypeRegistry,
+};
+use codama_koroks::{CrateKorok, ItemKorok, KorokTrait};
+use codama_nodes::{
+    AccountValueNode, ArgumentValueNode, ArrayTypeNode, BytesTypeNode, BytesValueNode,
+    ConstantPdaSeedNode, FieldDiscriminatorNode, FixedCountNode, InstructionAccountNode,
+    InstructionArgumentNode, InstructionNode, Node, NumberTypeNode, PdaNode, PdaSeedValueNode,
+    PdaValueNode, PublicKeyTypeNode, PublicKeyValueNode, VariablePdaSeedNode, U32, U64, U8,
+};
+use codama_stores::CrateStore;
+use quote::quote;
+
+fn get_instructions(korok: &CrateKorok) -> Vec<InstructionNode> {
+    let mut instructions = vec![];
+    for item in &korok.items {
+        if let ItemKorok::Module(module) = item {
+            for item in &module.items {
+                if let Some(Node::Instruction(instruction)) = item.node() {
+                    instructions.push(instruction.clone());
+                }
+            }
+        }
+    }
+    instructions
+}
+
+#[test]
+fn it_creates_instructions_from_anchor_programs() -> CodamaResult<()> {
+    let store = CrateStore::hydrate(quote! {
+        #[program]
+        pub mod counter {
+            use super::*;
+
+            /// Creates a new counter.
+            pub fn initialize(ctx: Context<Initialize>, start: u64) -> Result<()> {
+                Ok(())
+            }
+
+            fn helper() {}
+        }
+
+        #[derive(Accounts)]
+        pub struct Initialize<'info> {
+            /// The new counter account.
+            #[account(init, payer = payer, space = 8 + 8)]
+            pub counter: Account<'info, Counter>,
+            #[account(mut)]
+            pub payer: Signer<'info>,
+            pub system_program: Program<'info, System>,
+        }
+    })?;
+    let mut korok = CrateKorok::parse(&store)?;
+    korok.accept(&mut SetAnchorInstructionsVisitor::new())?;
+
+    assert_eq!(
+        get_instructions(&korok),
+        vec![InstructionNode {
+            name: "initialize".into(),
+            docs: vec!["Creates a new counter.".to_string()].into(),
+            accounts: vec![
+                InstructionAccountNode {
+                    docs: vec!["The new counter account.".to_string()].into(),
+                    ..InstructionAccountNode::new("counter", true, true)
+                },
+                InstructionAccountNode::new("payer", true, true),
+                InstructionAccountNode {
+                    default_value: Some(
+                        PublicKeyValueNode::new("11111111111111111111111111111111").into()
+                    ),
+                    ..InstructionAccountNode::new("system_program", false, false)
+                },
+            ],
+            arguments: vec![
+                get_anchor_discriminator_field("global:initialize").into(),
+                InstructionArgumentNode::new("start", NumberTypeNode::le(U64)),
+            ],
+            discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+            ..InstructionNode::default()
+        }]
+    );
+    Ok(())
+}
+
+#[test]
+fn it_uses_seeds_as_pda_default_values() -> CodamaResult<()> {
+    let store = CrateStore::hydrate(quote! {
+        #[program]
+        pub mod vault {
+            pub fn deposit(ctx: Context<Deposit>, index: u64) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        #[derive(Accounts)]
+        #[instruction(index: u64)]
+        pub struct Deposit<'info> {
+            #[account(mut, seeds = [b"vault", user.key().as_ref(), index.to_le_bytes().as_ref()], bump)]
+            pub vault: Account<'info, Vault>,
+            #[account(seeds = [SEED_PREFIX], bump)]
+            pub unresolved: Account<'info, Vault>,
+            pub user: Signer<'info>,
+            pub rent: Option<Sysvar<'info, Rent>>,
+        }
+    })?;
+    let mut korok = CrateKorok::parse(&store)?;
+    korok.accept(&mut SetAnchorInstructionsVisitor::new())?;
+
+    let instructions = get_instructions(&korok);
+    assert_eq!(
+        instructions[0].accounts,
+        vec![
+            InstructionAccountNode {
+                default_value: Some(
+                    PdaValueNode::new(
+                        PdaNode::new(
+                            "vault",
+                            vec![
+                                ConstantPdaSeedNode::new(
+                                    BytesTypeNode::new(),
+                                    BytesValueNode::utf8("vault")
+                                )
+                                .into(),
+                                VariablePdaSeedNode::new("user", PublicKeyTypeNode::new()).into(),
+                                VariablePdaSeedNode::new("index", NumberTypeNode::le(U64)).into(),
+                            ]
+                        ),
+                        vec![
+                            PdaSeedValueNode::new("user", AccountValueNode::new("user")),
+                            PdaSeedValueNode::new("index", ArgumentValueNode::new("index")),
+                        ]
+                    )
+                    .into()
+                ),
+                ..InstructionAccountNode::new("vault", true, false)
+            },
+            InstructionAccountNode::new("unresolved", false, false),
+            InstructionAccountNode::new("user", false, true),
+            InstructionAccountNode {
+                is_optional: true,
+                default_value: Some(
+                    PublicKeyValueNode::new("SysvarRent111111111111111111111111111111111").into()
+                ),
+                ..InstructionAccountNode::new("rent", false, false)
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn it_resolves_types_like_fields() -> CodamaResult<()> {
+    let store = CrateStore::hydrate(quote! {
+        type Index = u64;
+        const TAG_SIZE: usize = 4;
+
+        #[program]
+        pub mod vault {
+            use super::*;
+            use super::Amount as Lamports;
+
+            pub fn deposit(
+                ctx: Context<Deposit>,
+                index: Index,
+                tag: [u8; TAG_SIZE],
+                amount: Lamports,
+            ) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        #[derive(Accounts)]
+        #[instruction(index: Index)]
+        pub struct Deposit<'info> {
+            #[account(mut, seeds = [index.to_le_bytes().as_ref()], bump)]
+            pub vault: Account<'info, Vault>,
+        }
+    })?;
+    let registry =
+        TypeRegistry::new().with_type(syn::parse_quote! { Amount }, NumberTypeNode::le(U32));
+    let mut korok = CrateKorok::parse(&store)?;
+    korok.accept(&mut SetAnchorInstructionsVisitor::new().with_registry(registry))?;
+
+    let instructions = get_instructions(&korok);
+    assert_eq!(
+        instructions[0].arguments[1..],
+        [
+            InstructionArgumentNode::new("index", NumberTypeNode::le(U64)),
+            InstructionArgumentNode::new(
+                "tag",
+                ArrayTypeNode::new(NumberTypeNode::le(U8), FixedCountNode::new(4))
+            ),
+            InstructionArgumentNode::new("amount", NumberTypeNode::le(U32)),
+        ]
+    );
+    assert_eq!(
+        instructions[0].accounts[0].default_value,
+        Some(
+            PdaValueNode::new(
+                PdaNode::new(
+                    "vault",
+                    vec![VariablePdaSeedNode::new("index", NumberTypeNode::le(U64)).into()]
+                ),
+                vec![PdaSeedValueNode::new(
+                    "index",
+                    ArgumentValueNode::new("index")
+                )]
+            )
+            .into()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn it_flattens_nested_accounts_structs() -> CodamaResult<()> {
+    let store = CrateStore::hydrate(quote! {
+        #[program]
+        pub mod my_program {
+            pub fn transfer(ctx: Context<Transfer>) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        #[derive(Accounts)]
+        pub struct Transfer<'info> {
+            pub common: Common<'info>,
+            #[account(mut)]
+            pub destination: UncheckedAccount<'info>,
+        }
+
+        #[derive(Accounts)]
+        pub struct Common<'info> {
+            pub authority: Signer<'info>,
+        }
+    })?;
+    let mut korok = CrateKorok::parse(&store)?;
+    korok.accept(&mut SetAnchorInstructionsVisitor::new())?;
+
+    let instructions = get_instructions(&korok);
+    assert_eq!(
+        instructions[0].accounts,
+        vec![
+            InstructionAccountNode::new("authority", false, true),
+            InstructionAccountNode::new("destination", true, false),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn it_fails_when_the_accounts_struct_cannot_be_found() -> CodamaResult<()> {
+    let store = CrateStore::hydrate(quote! {
+        #[program]
+        pub mod my_program {
+            pub fn transfer(ctx: Context<Missing>) -> Result<()> {
+                Ok(())
+            }
+        }
+    })?;
+    let mut korok = CrateKorok::parse(&store)?;
+    let error = korok
+        .accept(&mut SetAnchorInstructionsVisitor::new())
+        .unwrap_err();
+    assert!(error.to_string().contains("Missing"));
+    Ok(())
+}
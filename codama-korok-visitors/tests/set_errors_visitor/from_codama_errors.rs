@@ -357,3 +357,42 @@ fn with_name_directives() -> CodamaResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn from_enum_with_doc_comments() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[derive(CodamaErrors)]
+        enum MyProgramErrors {
+            /// The account does not hold enough lamports
+            /// to be exempt from rent.
+            #[error("Lamports below rent-exempt threshold")]
+            NotRentExempt,
+            #[error("Insufficient funds")]
+            InsufficientFunds,
+        }
+    };
+    let mut korok = EnumKorok::parse(&item)?;
+
+    korok.accept(&mut SetErrorsVisitor::new())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            ProgramNode {
+                errors: vec![
+                    ErrorNode {
+                        docs: vec![
+                            "The account does not hold enough lamports".to_string(),
+                            "to be exempt from rent.".to_string()
+                        ]
+                        .into(),
+                        ..ErrorNode::new("notRentExempt", 0, "Lamports below rent-exempt threshold")
+                    },
+                    ErrorNode::new("insufficientFunds", 1, "Insufficient funds"),
+                ],
+                ..ProgramNode::default()
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
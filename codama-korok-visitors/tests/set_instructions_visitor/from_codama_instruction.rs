@@ -575,3 +575,50 @@ fn with_prepended_and_appended_argument_attributes() -> CodamaResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn from_struct_with_doc_comments() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        /// Initializes a new counter.
+        #[derive(CodamaInstruction)]
+        struct Initialize {
+            /// The authority of the counter.
+            #[codama(account(signer))]
+            authority: AccountMeta,
+            #[codama(account(writable, docs = "The payer of the rent."))]
+            payer: AccountMeta,
+            /// The initial value.
+            amount: u64,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetInstructionsVisitor::new())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            InstructionNode {
+                name: "initialize".into(),
+                docs: vec!["Initializes a new counter.".to_string()].into(),
+                accounts: vec![
+                    InstructionAccountNode {
+                        docs: vec!["The authority of the counter.".to_string()].into(),
+                        ..InstructionAccountNode::new("authority", false, true)
+                    },
+                    InstructionAccountNode {
+                        docs: vec!["The payer of the rent.".to_string()].into(),
+                        ..InstructionAccountNode::new("payer", true, false)
+                    },
+                ],
+                arguments: vec![InstructionArgumentNode {
+                    docs: vec!["The initial value.".to_string()].into(),
+                    ..InstructionArgumentNode::new("amount", NumberTypeNode::le(U64))
+                }],
+                ..InstructionNode::default()
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use codama_errors::CodamaResult;
+use codama_korok_visitors::{IdentifyFieldTypesVisitor, KorokVisitable, SetInstructionsVisitor};
+use codama_koroks::EnumKorok;
+use codama_nodes::{
+    DefaultValueStrategy, DefinedTypeLinkNode, FieldDiscriminatorNode, InstructionAccountNode,
+    InstructionArgumentNode, InstructionNode, NumberFormat::U8, NumberTypeNode, NumberValueNode,
+    ProgramNode,
+};
+
+#[test]
+fn from_shank_instruction_enum() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[derive(ShankInstruction)]
+        enum MyProgramInstruction {
+            #[account(0, writable, signer, name = "payer", desc = "The payer")]
+            #[account(1, optional, name = "authority")]
+            Create(CreateArgs),
+            #[account(0, optional_signer, name = "authority")]
+            Close,
+        }
+    };
+    let mut korok = EnumKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetInstructionsVisitor::shank())?;
+
+    let discriminator = |value: u8| InstructionArgumentNode {
+        default_value_strategy: Some(DefaultValueStrategy::Omitted),
+        default_value: Some(NumberValueNode::new(value).into()),
+        ..InstructionArgumentNode::new("discriminator", NumberTypeNode::le(U8))
+    };
+    assert_eq!(
+        korok.node,
+        Some(
+            ProgramNode {
+                instructions: vec![
+                    InstructionNode {
+                        name: "create".into(),
+                        accounts: vec![
+                            InstructionAccountNode {
+                                docs: vec!["The payer".to_string()].into(),
+                                ..InstructionAccountNode::new("payer", true, true)
+                            },
+                            InstructionAccountNode {
+                                is_optional: true,
+                                ..InstructionAccountNode::new("authority", false, false)
+                            },
+                        ],
+                        arguments: vec![
+                            discriminator(0),
+                            InstructionArgumentNode::new(
+                                "arg0",
+                                DefinedTypeLinkNode::new("createArgs")
+                            ),
+                        ],
+                        discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+                        ..InstructionNode::default()
+                    },
+                    InstructionNode {
+                        name: "close".into(),
+                        accounts: vec![InstructionAccountNode::new(
+                            "authority",
+                            false,
+                            codama_nodes::IsAccountSigner::Either
+                        )],
+                        arguments: vec![discriminator(1)],
+                        discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+                        ..InstructionNode::default()
+                    },
+                ],
+                ..ProgramNode::default()
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn it_ignores_shank_instructions_by_default() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        #[derive(ShankInstruction)]
+        enum MyProgramInstruction {
+            #[account(0, writable, signer, name = "payer")]
+            Create,
+        }
+    };
+    let mut korok = EnumKorok::parse(&item)?;
+
+    korok.accept(&mut SetInstructionsVisitor::new())?;
+    assert_eq!(korok.node, None);
+    Ok(())
+}
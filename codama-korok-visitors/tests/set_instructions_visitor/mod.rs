@@ -1,2 +1,3 @@
 mod from_codama_instruction;
 mod from_codama_instructions;
+mod from_shank_instruction;
@@ -192,3 +192,46 @@ fn it_defines_pdas_from_enums() -> CodamaResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn it_uses_doc_comments_as_docs() -> CodamaResult<()> {
+    let item: syn::Item = syn::parse_quote! {
+        /// The counter PDA of a given authority.
+        #[derive(CodamaPda)]
+        #[codama(seed(type = string(utf8), value = "counter_pda"))]
+        #[codama(seed(name = "authority"))]
+        struct Counter {
+            /// The authority of the counter.
+            authority: Pubkey,
+        }
+    };
+    let mut korok = StructKorok::parse(&item)?;
+
+    korok.accept(&mut IdentifyFieldTypesVisitor::new())?;
+    korok.accept(&mut SetPdasVisitor::new())?;
+    assert_eq!(
+        korok.node,
+        Some(
+            PdaNode {
+                docs: vec!["The counter PDA of a given authority.".to_string()].into(),
+                ..PdaNode::new(
+                    "counter",
+                    vec![
+                        ConstantPdaSeedNode::new(
+                            StringTypeNode::utf8(),
+                            StringValueNode::new("counter_pda")
+                        )
+                        .into(),
+                        VariablePdaSeedNode {
+                            docs: vec!["The authority of the counter.".to_string()].into(),
+                            ..VariablePdaSeedNode::new("authority", PublicKeyTypeNode::new())
+                        }
+                        .into(),
+                    ]
+                )
+            }
+            .into()
+        )
+    );
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::KorokTrait;
 use codama_attributes::Attributes;
 use codama_errors::CodamaResult;
 use codama_nodes::Node;
-use codama_syn_helpers::extensions::*;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 
 #[derive(Debug, PartialEq)]
 pub struct ConstKorok<'a> {
@@ -18,11 +18,11 @@ pub enum ConstAst<'a> {
 }
 
 impl<'a> ConstKorok<'a> {
-    pub fn parse(item: &'a syn::Item) -> CodamaResult<Self> {
+    pub fn parse(item: &'a syn::Item, constants: &ConstEvaluator) -> CodamaResult<Self> {
         let syn::Item::Const(ast) = item else {
             return Err(item.error("Expected a const item").into());
         };
-        let attributes = Attributes::parse(&ast.attrs, item.into())?;
+        let attributes = Attributes::parse_with(&ast.attrs, item.into(), constants, false)?;
         Ok(Self {
             ast: ConstAst::Item(ast),
             attributes,
@@ -30,11 +30,14 @@ impl<'a> ConstKorok<'a> {
         })
     }
 
-    pub fn parse_impl_item(item: &'a syn::ImplItem) -> CodamaResult<Self> {
+    pub fn parse_impl_item(
+        item: &'a syn::ImplItem,
+        constants: &ConstEvaluator,
+    ) -> CodamaResult<Self> {
         let syn::ImplItem::Const(ast) = item else {
             return Err(syn::Error::new_spanned(item, "Expected a const impl item").into());
         };
-        let attributes = Attributes::parse(&ast.attrs, item.into())?;
+        let attributes = Attributes::parse_with(&ast.attrs, item.into(), constants, false)?;
         Ok(Self {
             ast: ConstAst::ImplItem(ast),
             attributes,
@@ -2,11 +2,14 @@ use crate::{ItemKorok, KorokTrait};
 use codama_attributes::Attributes;
 use codama_errors::{combine_errors, CodamaError, CodamaResult};
 use codama_nodes::Node;
-use codama_stores::CrateStore;
+use codama_stores::{CrateStore, FileModuleStore};
+use codama_syn_helpers::ConstEvaluator;
 
 #[derive(Debug, PartialEq)]
 pub struct CrateKorok<'a> {
     pub attributes: Attributes<'a>,
+    /// The `const` items declared anywhere in the crate.
+    pub constants: ConstEvaluator,
     pub items: Vec<ItemKorok<'a>>,
     pub node: Option<Node>,
     pub store: &'a CrateStore,
@@ -14,12 +17,19 @@ pub struct CrateKorok<'a> {
 
 impl<'a> CrateKorok<'a> {
     pub fn parse(store: &'a CrateStore) -> CodamaResult<Self> {
+        // Constants of the crate can be used in attributes, e.g. `#[codama(fixed_size = SIZE)]`.
+        let mut constants = ConstEvaluator::new();
+        constants.add_items(&store.file.items);
+        add_file_module_constants(&mut constants, &store.file_modules);
+
         let (attributes, items) = combine_errors!(
-            Attributes::parse(&store.file.attrs, (&store.file).into()).map_err(CodamaError::from),
-            ItemKorok::parse_all(&store.file.items, &store.file_modules, &mut 0,),
+            Attributes::parse_with(&store.file.attrs, (&store.file).into(), &constants, false)
+                .map_err(CodamaError::from),
+            ItemKorok::parse_all(&store.file.items, &store.file_modules, &mut 0, &constants),
         )?;
         Ok(Self {
             attributes,
+            constants,
             items,
             node: None,
             store,
@@ -27,6 +37,15 @@ impl<'a> CrateKorok<'a> {
     }
 }
 
+fn add_file_module_constants(constants: &mut ConstEvaluator, modules: &[FileModuleStore]) {
+    for module in modules {
+        constants.with_module(module.item.ident.to_string(), |constants| {
+            constants.add_items(&module.file.items);
+            add_file_module_constants(constants, &module.file_modules);
+        });
+    }
+}
+
 impl KorokTrait for CrateKorok<'_> {
     fn node(&self) -> &Option<Node> {
         &self.node
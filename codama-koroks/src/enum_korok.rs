@@ -1,8 +1,8 @@
 use crate::{EnumVariantKorok, KorokTrait};
 use codama_attributes::{Attributes, NameDirective, TryFromFilter};
-use codama_errors::{combine_errors, CodamaError, CodamaResult};
-use codama_nodes::{CamelCaseString, Node};
-use codama_syn_helpers::extensions::*;
+use codama_errors::{combine_errors, CodamaError, CodamaResult, IteratorCombineErrors};
+use codama_nodes::{CamelCaseString, Docs, Node};
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 
 #[derive(Debug, PartialEq)]
 pub struct EnumKorok<'a> {
@@ -14,13 +14,30 @@ pub struct EnumKorok<'a> {
 
 impl<'a> EnumKorok<'a> {
     pub fn parse(item: &'a syn::Item) -> CodamaResult<Self> {
+        Self::parse_with(item, &ConstEvaluator::default())
+    }
+
+    /// Parse the item, resolving constant expressions using the constants of its crate.
+    pub fn parse_with(item: &'a syn::Item, constants: &ConstEvaluator) -> CodamaResult<Self> {
         let syn::Item::Enum(ast) = item else {
             return Err(item.error("Expected an enum").into());
         };
-        let (attributes, variants) = combine_errors!(
-            Attributes::parse(&ast.attrs, item.into()).map_err(CodamaError::from),
-            EnumVariantKorok::parse_all(&ast.variants),
+        let (mut attributes, mut variants) = combine_errors!(
+            Attributes::parse_with(&ast.attrs, item.into(), constants, false)
+                .map_err(CodamaError::from),
+            EnumVariantKorok::parse_all(&ast.variants, constants),
         )?;
+        // Shank attributes are only converted on items that derive Shank macros.
+        if attributes.has_any_shank_derive() {
+            (attributes, _) = combine_errors!(
+                Attributes::parse_with(&ast.attrs, item.into(), constants, true)
+                    .map_err(CodamaError::from),
+                variants
+                    .iter_mut()
+                    .map(|korok| korok.parse_shank_attributes(constants))
+                    .collect_and_combine_errors(),
+            )?;
+        }
         Ok(Self {
             ast,
             attributes,
@@ -35,6 +52,10 @@ impl<'a> EnumKorok<'a> {
             .map(|n| n.name.clone())
             .unwrap_or(self.ast.ident.to_string().into())
     }
+
+    pub fn docs(&self) -> Docs {
+        self.attributes.docs()
+    }
 }
 
 impl KorokTrait for EnumKorok<'_> {
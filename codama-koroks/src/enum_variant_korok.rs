@@ -1,7 +1,8 @@
 use crate::{FieldKorok, KorokTrait};
 use codama_attributes::{Attributes, NameDirective, TryFromFilter};
 use codama_errors::{combine_errors, CodamaError, CodamaResult, IteratorCombineErrors};
-use codama_nodes::{CamelCaseString, Node};
+use codama_nodes::{CamelCaseString, Docs, Node};
+use codama_syn_helpers::ConstEvaluator;
 
 #[derive(Debug, PartialEq)]
 pub struct EnumVariantKorok<'a> {
@@ -13,9 +14,15 @@ pub struct EnumVariantKorok<'a> {
 
 impl<'a> EnumVariantKorok<'a> {
     pub fn parse(ast: &'a syn::Variant) -> CodamaResult<Self> {
+        Self::parse_with(ast, &ConstEvaluator::default())
+    }
+
+    /// Parse the variant, resolving constant expressions using the constants of its crate.
+    pub fn parse_with(ast: &'a syn::Variant, constants: &ConstEvaluator) -> CodamaResult<Self> {
         let (attributes, fields) = combine_errors!(
-            Attributes::parse(&ast.attrs, ast.into()).map_err(CodamaError::from),
-            FieldKorok::parse_all(&ast.fields),
+            Attributes::parse_with(&ast.attrs, ast.into(), constants, false)
+                .map_err(CodamaError::from),
+            FieldKorok::parse_all(&ast.fields, constants),
         )?;
         Ok(Self {
             ast,
@@ -25,12 +32,28 @@ impl<'a> EnumVariantKorok<'a> {
         })
     }
 
+    /// Parse the attributes of the variant and its fields again,
+    /// converting Shank attributes into Codama directives.
+    pub fn parse_shank_attributes(&mut self, constants: &ConstEvaluator) -> CodamaResult<()> {
+        let (attributes, _) = combine_errors!(
+            Attributes::parse_with(&self.ast.attrs, self.ast.into(), constants, true)
+                .map_err(CodamaError::from),
+            self.fields
+                .iter_mut()
+                .map(|field| field.parse_shank_attributes(constants))
+                .collect_and_combine_errors(),
+        )?;
+        self.attributes = attributes;
+        Ok(())
+    }
+
     pub fn parse_all(
         variants: &'a syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
+        constants: &ConstEvaluator,
     ) -> CodamaResult<Vec<Self>> {
         variants
             .iter()
-            .map(Self::parse)
+            .map(|variant| Self::parse_with(variant, constants))
             .collect_and_combine_errors()
     }
 
@@ -40,6 +63,10 @@ impl<'a> EnumVariantKorok<'a> {
             .map(|n| n.name.clone())
             .unwrap_or(self.ast.ident.to_string().into())
     }
+
+    pub fn docs(&self) -> Docs {
+        self.attributes.docs()
+    }
 }
 
 impl KorokTrait for EnumVariantKorok<'_> {
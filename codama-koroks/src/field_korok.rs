@@ -1,7 +1,10 @@
 use crate::KorokTrait;
 use codama_attributes::{Attributes, NameDirective, TryFromFilter};
 use codama_errors::{CodamaResult, IteratorCombineErrors};
-use codama_nodes::{CamelCaseString, Node, RegisteredTypeNode, StructFieldTypeNode, TypeNode};
+use codama_nodes::{
+    CamelCaseString, Docs, Node, RegisteredTypeNode, StructFieldTypeNode, TypeNode,
+};
+use codama_syn_helpers::ConstEvaluator;
 
 #[derive(Debug, PartialEq)]
 pub struct FieldKorok<'a> {
@@ -12,7 +15,12 @@ pub struct FieldKorok<'a> {
 
 impl<'a> FieldKorok<'a> {
     pub fn parse(ast: &'a syn::Field) -> CodamaResult<Self> {
-        let attributes = Attributes::parse(&ast.attrs, ast.into())?;
+        Self::parse_with(ast, &ConstEvaluator::default())
+    }
+
+    /// Parse the field, resolving constant expressions using the constants of its crate.
+    pub fn parse_with(ast: &'a syn::Field, constants: &ConstEvaluator) -> CodamaResult<Self> {
+        let attributes = Attributes::parse_with(&ast.attrs, ast.into(), constants, false)?;
         Ok(Self {
             ast,
             attributes,
@@ -20,14 +28,18 @@ impl<'a> FieldKorok<'a> {
         })
     }
 
-    pub fn parse_all(ast: &'a syn::Fields) -> CodamaResult<Vec<Self>> {
+    /// Parse the attributes of the field again, converting Shank attributes into Codama directives.
+    pub fn parse_shank_attributes(&mut self, constants: &ConstEvaluator) -> CodamaResult<()> {
+        self.attributes =
+            Attributes::parse_with(&self.ast.attrs, self.ast.into(), constants, true)?;
+        Ok(())
+    }
+
+    pub fn parse_all(ast: &'a syn::Fields, constants: &ConstEvaluator) -> CodamaResult<Vec<Self>> {
+        let parse = |field| Self::parse_with(field, constants);
         match ast {
-            syn::Fields::Named(f) => f.named.iter().map(Self::parse).collect_and_combine_errors(),
-            syn::Fields::Unnamed(f) => f
-                .unnamed
-                .iter()
-                .map(Self::parse)
-                .collect_and_combine_errors(),
+            syn::Fields::Named(f) => f.named.iter().map(parse).collect_and_combine_errors(),
+            syn::Fields::Unnamed(f) => f.unnamed.iter().map(parse).collect_and_combine_errors(),
             syn::Fields::Unit => Ok(vec![]),
         }
     }
@@ -39,6 +51,10 @@ impl<'a> FieldKorok<'a> {
             .or_else(|| self.ast.ident.as_ref().map(|i| i.to_string().into()))
     }
 
+    pub fn docs(&self) -> Docs {
+        self.attributes.docs()
+    }
+
     pub fn get_updated_type_node(&self, node: TypeNode) -> Option<Node> {
         match &self.node {
             Some(Node::Type(RegisteredTypeNode::StructField(field))) => Some(
@@ -52,7 +68,13 @@ impl<'a> FieldKorok<'a> {
             // Tuple fields store the raw TypeNode; callers use field.name() to get custom
             // names from #[codama(name = "...")] when needed.
             _ => match self.ast.ident.as_ref() {
-                Some(_) => Some(StructFieldTypeNode::new(self.name().unwrap(), node).into()),
+                Some(_) => Some(
+                    StructFieldTypeNode {
+                        docs: self.docs(),
+                        ..StructFieldTypeNode::new(self.name().unwrap(), node)
+                    }
+                    .into(),
+                ),
                 None => Some(node.into()),
             },
         }
@@ -98,6 +120,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_updated_type_node_with_named_none_and_docs() {
+        let ast: syn::Field = syn::parse_quote! {
+            /// The age of the person.
+            pub age: u32
+        };
+        let korok = FieldKorok::parse(&ast).unwrap();
+        assert_eq!(
+            korok.get_updated_type_node(NumberTypeNode::le(U32).into()),
+            Some(
+                StructFieldTypeNode {
+                    docs: vec!["The age of the person.".to_string()].into(),
+                    ..StructFieldTypeNode::new("age", NumberTypeNode::le(U32))
+                }
+                .into()
+            )
+        );
+    }
+
     #[test]
     fn get_updated_type_node_with_unnamed_none() {
         let korok = FieldKorok {
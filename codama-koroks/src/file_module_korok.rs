@@ -3,7 +3,7 @@ use codama_attributes::Attributes;
 use codama_errors::{combine_errors, CodamaError, CodamaResult};
 use codama_nodes::Node;
 use codama_stores::FileModuleStore;
-use codama_syn_helpers::extensions::*;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 
 #[derive(Debug, PartialEq)]
 pub struct FileModuleKorok<'a> {
@@ -16,7 +16,11 @@ pub struct FileModuleKorok<'a> {
 }
 
 impl<'a> FileModuleKorok<'a> {
-    pub fn parse(item: &'a syn::Item, store: &'a FileModuleStore) -> CodamaResult<Self> {
+    pub fn parse(
+        item: &'a syn::Item,
+        store: &'a FileModuleStore,
+        constants: &ConstEvaluator,
+    ) -> CodamaResult<Self> {
         let syn::Item::Mod(ast) = item else {
             return Err(item.error("Expected an module").into());
         };
@@ -28,10 +32,23 @@ impl<'a> FileModuleKorok<'a> {
             .into());
         }
 
+        let inner_constants = constants.in_module(ast.ident.to_string());
         let (attributes, file_attributes, items) = combine_errors!(
-            Attributes::parse(&ast.attrs, item.into()).map_err(CodamaError::from),
-            Attributes::parse(&store.file.attrs, (&store.file).into()).map_err(CodamaError::from),
-            ItemKorok::parse_all(&store.file.items, &store.file_modules, &mut 0),
+            Attributes::parse_with(&ast.attrs, item.into(), constants, false)
+                .map_err(CodamaError::from),
+            Attributes::parse_with(
+                &store.file.attrs,
+                (&store.file).into(),
+                &inner_constants,
+                false
+            )
+            .map_err(CodamaError::from),
+            ItemKorok::parse_all(
+                &store.file.items,
+                &store.file_modules,
+                &mut 0,
+                &inner_constants
+            ),
         )?;
         Ok(Self {
             ast,
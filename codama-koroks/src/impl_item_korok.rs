@@ -2,6 +2,7 @@ use crate::{ConstKorok, KorokTrait, UnsupportedImplItemKorok};
 use codama_attributes::Attributes;
 use codama_errors::{CodamaResult, IteratorCombineErrors};
 use codama_nodes::Node;
+use codama_syn_helpers::ConstEvaluator;
 
 #[derive(Debug, PartialEq)]
 pub enum ImplItemKorok<'a> {
@@ -10,17 +11,25 @@ pub enum ImplItemKorok<'a> {
 }
 
 impl<'a> ImplItemKorok<'a> {
-    pub fn parse(item: &'a syn::ImplItem) -> CodamaResult<Self> {
+    pub fn parse(item: &'a syn::ImplItem, constants: &ConstEvaluator) -> CodamaResult<Self> {
         match item {
-            syn::ImplItem::Const(_) => Ok(ImplItemKorok::Const(ConstKorok::parse_impl_item(item)?)),
+            syn::ImplItem::Const(_) => Ok(ImplItemKorok::Const(ConstKorok::parse_impl_item(
+                item, constants,
+            )?)),
             _ => Ok(ImplItemKorok::Unsupported(UnsupportedImplItemKorok::parse(
-                item,
+                item, constants,
             )?)),
         }
     }
 
-    pub fn parse_all(items: &'a [syn::ImplItem]) -> CodamaResult<Vec<Self>> {
-        items.iter().map(Self::parse).collect_and_combine_errors()
+    pub fn parse_all(
+        items: &'a [syn::ImplItem],
+        constants: &ConstEvaluator,
+    ) -> CodamaResult<Vec<Self>> {
+        items
+            .iter()
+            .map(|item| Self::parse(item, constants))
+            .collect_and_combine_errors()
     }
 }
 
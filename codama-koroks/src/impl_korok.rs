@@ -2,7 +2,7 @@ use crate::{ImplItemKorok, KorokTrait};
 use codama_attributes::Attributes;
 use codama_errors::{combine_errors, CodamaError, CodamaResult};
 use codama_nodes::Node;
-use codama_syn_helpers::extensions::*;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 
 #[derive(Debug, PartialEq)]
 pub struct ImplKorok<'a> {
@@ -13,13 +13,14 @@ pub struct ImplKorok<'a> {
 }
 
 impl<'a> ImplKorok<'a> {
-    pub fn parse(item: &'a syn::Item) -> CodamaResult<Self> {
+    pub fn parse(item: &'a syn::Item, constants: &ConstEvaluator) -> CodamaResult<Self> {
         let syn::Item::Impl(ast) = item else {
             return Err(item.error("Expected an impl block").into());
         };
         let (attributes, items) = combine_errors!(
-            Attributes::parse(&ast.attrs, item.into()).map_err(CodamaError::from),
-            ImplItemKorok::parse_all(&ast.items),
+            Attributes::parse_with(&ast.attrs, item.into(), constants, false)
+                .map_err(CodamaError::from),
+            ImplItemKorok::parse_all(&ast.items, constants),
         )?;
         Ok(Self {
             ast,
@@ -1,11 +1,12 @@
 use crate::{
     ConstKorok, EnumKorok, FileModuleKorok, ImplKorok, KorokTrait, ModuleKorok, StructKorok,
-    UnsupportedItemKorok,
+    TypeAliasKorok, UnsupportedItemKorok, UseKorok,
 };
 use codama_attributes::Attributes;
 use codama_errors::{CodamaResult, IteratorCombineErrors};
 use codama_nodes::Node;
 use codama_stores::FileModuleStore;
+use codama_syn_helpers::ConstEvaluator;
 use std::ops::AddAssign;
 
 #[allow(clippy::large_enum_variant)]
@@ -17,6 +18,8 @@ pub enum ItemKorok<'a> {
     Enum(EnumKorok<'a>),
     Impl(ImplKorok<'a>),
     Const(ConstKorok<'a>),
+    TypeAlias(TypeAliasKorok<'a>),
+    Use(UseKorok<'a>),
     Unsupported(UnsupportedItemKorok<'a>),
 }
 
@@ -25,27 +28,39 @@ impl<'a> ItemKorok<'a> {
         item: &'a syn::Item,
         file_modules: &'a [FileModuleStore],
         file_module_index: &mut usize,
+        constants: &ConstEvaluator,
     ) -> CodamaResult<Self> {
         match item {
             syn::Item::Mod(ast) if ast.content.is_none() => {
                 match file_modules.get(*file_module_index) {
-                    Some(module) => {
+                    Some(module) if module.item == *ast => {
                         file_module_index.add_assign(1);
-                        Ok(ItemKorok::FileModule(FileModuleKorok::parse(item, module)?))
-                    }
-                    None => {
-                        Err(syn::Error::new_spanned(ast, "Associated ModuleStore not found").into())
+                        Ok(ItemKorok::FileModule(FileModuleKorok::parse(
+                            item, module, constants,
+                        )?))
                     }
+                    // Modules disabled by `#[cfg(...)]` attributes have no associated store.
+                    _ => Ok(ItemKorok::Unsupported(UnsupportedItemKorok::parse(
+                        item, constants,
+                    )?)),
                 }
             }
             syn::Item::Mod(ast) if ast.content.is_some() => Ok(ItemKorok::Module(
-                ModuleKorok::parse(item, file_modules, file_module_index)?,
+                ModuleKorok::parse(item, file_modules, file_module_index, constants)?,
             )),
-            syn::Item::Struct(_) => Ok(ItemKorok::Struct(StructKorok::parse(item)?)),
-            syn::Item::Enum(_) => Ok(ItemKorok::Enum(EnumKorok::parse(item)?)),
-            syn::Item::Impl(_) => Ok(ItemKorok::Impl(ImplKorok::parse(item)?)),
-            syn::Item::Const(_) => Ok(ItemKorok::Const(ConstKorok::parse(item)?)),
-            _ => Ok(ItemKorok::Unsupported(UnsupportedItemKorok::parse(item)?)),
+            syn::Item::Struct(_) => {
+                Ok(ItemKorok::Struct(StructKorok::parse_with(item, constants)?))
+            }
+            syn::Item::Enum(_) => Ok(ItemKorok::Enum(EnumKorok::parse_with(item, constants)?)),
+            syn::Item::Impl(_) => Ok(ItemKorok::Impl(ImplKorok::parse(item, constants)?)),
+            syn::Item::Const(_) => Ok(ItemKorok::Const(ConstKorok::parse(item, constants)?)),
+            syn::Item::Type(_) => Ok(ItemKorok::TypeAlias(TypeAliasKorok::parse(
+                item, constants,
+            )?)),
+            syn::Item::Use(_) => Ok(ItemKorok::Use(UseKorok::parse(item, constants)?)),
+            _ => Ok(ItemKorok::Unsupported(UnsupportedItemKorok::parse(
+                item, constants,
+            )?)),
         }
     }
 
@@ -53,10 +68,11 @@ impl<'a> ItemKorok<'a> {
         items: &'a [syn::Item],
         file_modules: &'a [FileModuleStore],
         file_module_index: &mut usize,
+        constants: &ConstEvaluator,
     ) -> CodamaResult<Vec<Self>> {
         items
             .iter()
-            .map(|item| Self::parse(item, file_modules, file_module_index))
+            .map(|item| Self::parse(item, file_modules, file_module_index, constants))
             .collect_and_combine_errors()
     }
 }
@@ -70,6 +86,8 @@ impl KorokTrait for ItemKorok<'_> {
             ItemKorok::Module(k) => k.node(),
             ItemKorok::Impl(k) => k.node(),
             ItemKorok::Const(k) => k.node(),
+            ItemKorok::TypeAlias(k) => k.node(),
+            ItemKorok::Use(k) => k.node(),
             ItemKorok::Unsupported(k) => k.node(),
         }
     }
@@ -82,6 +100,8 @@ impl KorokTrait for ItemKorok<'_> {
             ItemKorok::Module(k) => k.set_node(node),
             ItemKorok::Impl(k) => k.set_node(node),
             ItemKorok::Const(k) => k.set_node(node),
+            ItemKorok::TypeAlias(k) => k.set_node(node),
+            ItemKorok::Use(k) => k.set_node(node),
             ItemKorok::Unsupported(k) => k.set_node(node),
         }
     }
@@ -94,6 +114,8 @@ impl KorokTrait for ItemKorok<'_> {
             ItemKorok::Module(k) => k.attributes(),
             ItemKorok::Impl(k) => k.attributes(),
             ItemKorok::Const(k) => k.attributes(),
+            ItemKorok::TypeAlias(k) => k.attributes(),
+            ItemKorok::Use(k) => k.attributes(),
             ItemKorok::Unsupported(k) => k.attributes(),
         }
     }
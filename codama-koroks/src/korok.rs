@@ -37,6 +37,8 @@ pub enum KorokMut<'a, 'b> {
     Root(&'b mut crate::RootKorok<'a>),
     Struct(&'b mut crate::StructKorok<'a>),
     Const(&'b mut crate::ConstKorok<'a>),
+    TypeAlias(&'b mut crate::TypeAliasKorok<'a>),
+    Use(&'b mut crate::UseKorok<'a>),
     UnsupportedItem(&'b mut crate::UnsupportedItemKorok<'a>),
     ImplItem(&'b mut crate::ImplItemKorok<'a>),
     UnsupportedImplItem(&'b mut crate::UnsupportedImplItemKorok<'a>),
@@ -55,6 +57,8 @@ impl KorokTrait for KorokMut<'_, '_> {
             Self::Root(k) => k.node(),
             Self::Struct(k) => k.node(),
             Self::Const(k) => k.node(),
+            Self::TypeAlias(k) => k.node(),
+            Self::Use(k) => k.node(),
             Self::UnsupportedItem(k) => k.node(),
             Self::ImplItem(k) => k.node(),
             Self::UnsupportedImplItem(k) => k.node(),
@@ -73,6 +77,8 @@ impl KorokTrait for KorokMut<'_, '_> {
             Self::Root(k) => k.set_node(node),
             Self::Struct(k) => k.set_node(node),
             Self::Const(k) => k.set_node(node),
+            Self::TypeAlias(k) => k.set_node(node),
+            Self::Use(k) => k.set_node(node),
             Self::UnsupportedItem(k) => k.set_node(node),
             Self::ImplItem(k) => k.set_node(node),
             Self::UnsupportedImplItem(k) => k.set_node(node),
@@ -91,6 +97,8 @@ impl KorokTrait for KorokMut<'_, '_> {
             Self::Root(k) => k.attributes(),
             Self::Struct(k) => k.attributes(),
             Self::Const(k) => k.attributes(),
+            Self::TypeAlias(k) => k.attributes(),
+            Self::Use(k) => k.attributes(),
             Self::UnsupportedItem(k) => k.attributes(),
             Self::ImplItem(k) => k.attributes(),
             Self::UnsupportedImplItem(k) => k.attributes(),
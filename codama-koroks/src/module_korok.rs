@@ -3,7 +3,7 @@ use codama_attributes::Attributes;
 use codama_errors::{combine_errors, CodamaError, CodamaResult};
 use codama_nodes::Node;
 use codama_stores::FileModuleStore;
-use codama_syn_helpers::extensions::*;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 
 #[derive(Debug, PartialEq)]
 pub struct ModuleKorok<'a> {
@@ -18,6 +18,7 @@ impl<'a> ModuleKorok<'a> {
         item: &'a syn::Item,
         file_modules: &'a [FileModuleStore],
         file_module_index: &mut usize,
+        constants: &ConstEvaluator,
     ) -> CodamaResult<Self> {
         let syn::Item::Mod(ast) = item else {
             return Err(item.error("Expected an module").into());
@@ -29,8 +30,14 @@ impl<'a> ModuleKorok<'a> {
         };
 
         let (attributes, items) = combine_errors!(
-            Attributes::parse(&ast.attrs, item.into()).map_err(CodamaError::from),
-            ItemKorok::parse_all(&content.1, file_modules, file_module_index),
+            Attributes::parse_with(&ast.attrs, item.into(), constants, false)
+                .map_err(CodamaError::from),
+            ItemKorok::parse_all(
+                &content.1,
+                file_modules,
+                file_module_index,
+                &constants.in_module(ast.ident.to_string()),
+            ),
         )?;
         Ok(Self {
             ast,
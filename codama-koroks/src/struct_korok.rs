@@ -1,8 +1,8 @@
 use crate::{FieldKorok, KorokTrait};
 use codama_attributes::{Attributes, NameDirective, TryFromFilter};
-use codama_errors::{combine_errors, CodamaError, CodamaResult};
-use codama_nodes::{CamelCaseString, Node};
-use codama_syn_helpers::extensions::*;
+use codama_errors::{combine_errors, CodamaError, CodamaResult, IteratorCombineErrors};
+use codama_nodes::{CamelCaseString, Docs, Node};
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 
 #[derive(Debug, PartialEq)]
 pub struct StructKorok<'a> {
@@ -14,13 +14,30 @@ pub struct StructKorok<'a> {
 
 impl<'a> StructKorok<'a> {
     pub fn parse(item: &'a syn::Item) -> CodamaResult<Self> {
+        Self::parse_with(item, &ConstEvaluator::default())
+    }
+
+    /// Parse the item, resolving constant expressions using the constants of its crate.
+    pub fn parse_with(item: &'a syn::Item, constants: &ConstEvaluator) -> CodamaResult<Self> {
         let syn::Item::Struct(ast) = item else {
             return Err(item.error("Expected a struct").into());
         };
-        let (attributes, fields) = combine_errors!(
-            Attributes::parse(&ast.attrs, item.into()).map_err(CodamaError::from),
-            FieldKorok::parse_all(&ast.fields),
+        let (mut attributes, mut fields) = combine_errors!(
+            Attributes::parse_with(&ast.attrs, item.into(), constants, false)
+                .map_err(CodamaError::from),
+            FieldKorok::parse_all(&ast.fields, constants),
         )?;
+        // Shank attributes are only converted on items that derive Shank macros.
+        if attributes.has_any_shank_derive() {
+            (attributes, _) = combine_errors!(
+                Attributes::parse_with(&ast.attrs, item.into(), constants, true)
+                    .map_err(CodamaError::from),
+                fields
+                    .iter_mut()
+                    .map(|korok| korok.parse_shank_attributes(constants))
+                    .collect_and_combine_errors(),
+            )?;
+        }
         Ok(Self {
             ast,
             attributes,
@@ -35,6 +52,10 @@ impl<'a> StructKorok<'a> {
             .map(|n| n.name.clone())
             .unwrap_or(self.ast.ident.to_string().into())
     }
+
+    pub fn docs(&self) -> Docs {
+        self.attributes.docs()
+    }
 }
 
 impl KorokTrait for StructKorok<'_> {
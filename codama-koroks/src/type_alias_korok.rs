@@ -0,0 +1,41 @@
+use crate::KorokTrait;
+use codama_attributes::Attributes;
+use codama_errors::CodamaResult;
+use codama_nodes::Node;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
+
+/// A `type` alias item, e.g. `type Lamports = u64;`.
+#[derive(Debug, PartialEq)]
+pub struct TypeAliasKorok<'a> {
+    pub ast: &'a syn::ItemType,
+    pub attributes: Attributes<'a>,
+    pub node: Option<Node>,
+}
+
+impl<'a> TypeAliasKorok<'a> {
+    pub fn parse(item: &'a syn::Item, constants: &ConstEvaluator) -> CodamaResult<Self> {
+        let syn::Item::Type(ast) = item else {
+            return Err(item.error("Expected a type item").into());
+        };
+        let attributes = Attributes::parse_with(&ast.attrs, item.into(), constants, false)?;
+        Ok(Self {
+            ast,
+            attributes,
+            node: None,
+        })
+    }
+}
+
+impl KorokTrait for TypeAliasKorok<'_> {
+    fn node(&self) -> &Option<Node> {
+        &self.node
+    }
+
+    fn set_node(&mut self, node: Option<Node>) {
+        self.node = node;
+    }
+
+    fn attributes(&self) -> Option<&Attributes<'_>> {
+        Some(&self.attributes)
+    }
+}
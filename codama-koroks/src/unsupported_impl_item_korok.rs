@@ -2,6 +2,7 @@ use crate::KorokTrait;
 use codama_attributes::Attributes;
 use codama_errors::CodamaResult;
 use codama_nodes::Node;
+use codama_syn_helpers::ConstEvaluator;
 
 #[derive(Debug, PartialEq)]
 pub struct UnsupportedImplItemKorok<'a> {
@@ -11,12 +12,20 @@ pub struct UnsupportedImplItemKorok<'a> {
 }
 
 impl<'a> UnsupportedImplItemKorok<'a> {
-    pub fn parse(ast: &'a syn::ImplItem) -> CodamaResult<Self> {
+    pub fn parse(ast: &'a syn::ImplItem, constants: &ConstEvaluator) -> CodamaResult<Self> {
         let attributes = match ast {
-            syn::ImplItem::Const(item) => Attributes::parse(&item.attrs, ast.into())?,
-            syn::ImplItem::Fn(item) => Attributes::parse(&item.attrs, ast.into())?,
-            syn::ImplItem::Type(item) => Attributes::parse(&item.attrs, ast.into())?,
-            syn::ImplItem::Macro(item) => Attributes::parse(&item.attrs, ast.into())?,
+            syn::ImplItem::Const(item) => {
+                Attributes::parse_with(&item.attrs, ast.into(), constants, false)?
+            }
+            syn::ImplItem::Fn(item) => {
+                Attributes::parse_with(&item.attrs, ast.into(), constants, false)?
+            }
+            syn::ImplItem::Type(item) => {
+                Attributes::parse_with(&item.attrs, ast.into(), constants, false)?
+            }
+            syn::ImplItem::Macro(item) => {
+                Attributes::parse_with(&item.attrs, ast.into(), constants, false)?
+            }
             _ => Attributes(Vec::new()),
         };
         Ok(Self {
@@ -2,7 +2,7 @@ use crate::KorokTrait;
 use codama_attributes::Attributes;
 use codama_errors::CodamaResult;
 use codama_nodes::Node;
-use codama_syn_helpers::extensions::*;
+use codama_syn_helpers::{extensions::*, ConstEvaluator};
 
 #[derive(Debug, PartialEq)]
 pub struct UnsupportedItemKorok<'a> {
@@ -12,9 +12,9 @@ pub struct UnsupportedItemKorok<'a> {
 }
 
 impl<'a> UnsupportedItemKorok<'a> {
-    pub fn parse(ast: &'a syn::Item) -> CodamaResult<Self> {
+    pub fn parse(ast: &'a syn::Item, constants: &ConstEvaluator) -> CodamaResult<Self> {
         let attributes = match ast.attributes() {
-            Some(attrs) => Attributes::parse(attrs, ast.into())?,
+            Some(attrs) => Attributes::parse_with(attrs, ast.into(), constants, false)?,
             None => Attributes(Vec::new()),
         };
         Ok(Self {
@@ -0,0 +1,48 @@
+use crate::KorokTrait;
+use codama_attributes::Attributes;
+use codama_errors::CodamaResult;
+use codama_nodes::Node;
+use codama_syn_helpers::{extensions::*, use_tree_imports, ConstEvaluator};
+
+/// A `use` item, e.g. `use spl_token::state::Account as TokenAccount;`.
+#[derive(Debug, PartialEq)]
+pub struct UseKorok<'a> {
+    pub ast: &'a syn::ItemUse,
+    pub attributes: Attributes<'a>,
+    pub node: Option<Node>,
+}
+
+impl<'a> UseKorok<'a> {
+    pub fn parse(item: &'a syn::Item, constants: &ConstEvaluator) -> CodamaResult<Self> {
+        let syn::Item::Use(ast) = item else {
+            return Err(item.error("Expected a use item").into());
+        };
+        let attributes = Attributes::parse_with(&ast.attrs, item.into(), constants, false)?;
+        Ok(Self {
+            ast,
+            attributes,
+            node: None,
+        })
+    }
+
+    /// The names imported by this item with the full path they refer to.
+    /// E.g. `use a::{b::C, D as E};` imports `C -> a::b::C` and `E -> a::D`.
+    /// Glob imports and `self` imports are ignored.
+    pub fn imports(&self) -> Vec<(syn::Ident, syn::Path)> {
+        use_tree_imports(&self.ast.tree)
+    }
+}
+
+impl KorokTrait for UseKorok<'_> {
+    fn node(&self) -> &Option<Node> {
+        &self.node
+    }
+
+    fn set_node(&mut self, node: Option<Node>) {
+        self.node = node;
+    }
+
+    fn attributes(&self) -> Option<&Attributes<'_>> {
+        Some(&self.attributes)
+    }
+}
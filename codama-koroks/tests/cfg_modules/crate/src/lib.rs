@@ -0,0 +1,6 @@
+#[path = "custom/accounts.rs"]
+pub mod accounts;
+
+// This file does not exist.
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,27 @@
+use crate::get_path;
+use codama_koroks::{ItemKorok, RootKorok};
+use codama_stores::RootStore;
+
+#[test]
+fn load_cfg_modules() {
+    let path = get_path("cfg_modules/crate");
+    let root_store = RootStore::load_all(&[&path]).unwrap();
+    let root_korok = RootKorok::parse(&root_store).unwrap();
+
+    // Disabled modules are removed when loading the crate.
+    let [crate_korok] = &root_korok.crates.as_slice() else {
+        panic!("Unexpected number of crates");
+    };
+    let [ItemKorok::FileModule(accounts)] = &crate_korok.items.as_slice() else {
+        panic!("Unexpected items in lib.rs");
+    };
+
+    // Path attributes are resolved relative to the declaring file.
+    assert_eq!(
+        accounts.store.path,
+        get_path("cfg_modules/crate/src/custom/accounts.rs")
+    );
+    let [ItemKorok::Struct(_)] = &accounts.items.as_slice() else {
+        panic!("Unexpected items in accounts.rs");
+    };
+}
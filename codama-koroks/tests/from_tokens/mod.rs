@@ -1,3 +1,4 @@
+use codama_attributes::Attribute;
 use codama_koroks::{CrateKorok, ItemKorok, RootKorok};
 use codama_stores::{CrateStore, RootStore};
 use quote::quote;
@@ -39,3 +40,61 @@ fn crate_from_tokens() {
         [ItemKorok::Enum(_), ItemKorok::Struct(_),]
     ));
 }
+
+#[test]
+fn type_aliases_and_imports_from_tokens() {
+    let tt = quote! {
+        use spl_token::state::{Account as TokenAccount, Mint, self};
+        type Lamports = u64;
+    };
+
+    let store = CrateStore::hydrate(tt).unwrap();
+    let korok = CrateKorok::parse(&store).unwrap();
+    let [ItemKorok::Use(imports), ItemKorok::TypeAlias(alias)] = korok.items.as_slice() else {
+        panic!("Unexpected items");
+    };
+    assert_eq!(alias.ast.ident, "Lamports");
+    assert_eq!(
+        imports
+            .imports()
+            .iter()
+            .map(|(ident, path)| (ident.to_string(), quote! { #path }.to_string()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "TokenAccount".to_string(),
+                "spl_token :: state :: Account".to_string()
+            ),
+            ("Mint".to_string(), "spl_token :: state :: Mint".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn shank_attributes_are_only_converted_on_shank_items() {
+    let tt = quote! {
+        struct Padded {
+            #[padding]
+            padding: u64,
+        }
+        #[derive(ShankAccount)]
+        struct Counter {
+            #[padding]
+            padding: [u8; 4],
+        }
+    };
+
+    let store = CrateStore::hydrate(tt).unwrap();
+    let korok = CrateKorok::parse(&store).unwrap();
+    let [ItemKorok::Struct(padded), ItemKorok::Struct(counter)] = korok.items.as_slice() else {
+        panic!("expected two structs");
+    };
+    assert!(matches!(
+        padded.fields[0].attributes.as_slice(),
+        [Attribute::Unsupported(_)]
+    ));
+    assert!(matches!(
+        counter.fields[0].attributes.as_slice(),
+        [Attribute::Codama(_), Attribute::Codama(_)]
+    ));
+}
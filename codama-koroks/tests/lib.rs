@@ -1,3 +1,4 @@
+mod cfg_modules;
 mod from_tokens;
 mod nested_modules;
 mod single_crate;
@@ -18,7 +18,7 @@ fn load_single_crate() {
         crate_korok.store.path,
         get_path("single_crate/crate/src/lib.rs")
     );
-    let [ItemKorok::FileModule(membership), ItemKorok::FileModule(person), ItemKorok::Unsupported(_), ItemKorok::Unsupported(_)] =
+    let [ItemKorok::FileModule(membership), ItemKorok::FileModule(person), ItemKorok::Use(_), ItemKorok::Use(_)] =
         &crate_korok.items.as_slice()
     else {
         panic!("Unexpected items in lib.rs");
@@ -38,7 +38,7 @@ fn load_single_crate() {
         person.store.path,
         get_path("single_crate/crate/src/person.rs")
     );
-    let [ItemKorok::Unsupported(_), ItemKorok::Struct(_)] = &person.items.as_slice() else {
+    let [ItemKorok::Use(_), ItemKorok::Struct(_)] = &person.items.as_slice() else {
         panic!("Unexpected items in person.rs");
     };
 }
@@ -0,0 +1,389 @@
+use codama_errors::CodamaResult;
+use serde::{Deserialize, Serialize};
+
+/// An Anchor IDL, as found in the `target/idl` folder of Anchor programs.
+/// Both the 0.30+ specification and the legacy format are supported when parsing,
+/// legacy fields being accepted as aliases of their newer counterparts.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AnchorIdl {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<AnchorMetadata>,
+    /// Only used by legacy IDLs — see `metadata.name` otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Only used by legacy IDLs — see `metadata.version` otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(default)]
+    pub instructions: Vec<AnchorInstruction>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<AnchorAccount>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<AnchorEvent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<AnchorError>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<AnchorTypeDef>,
+}
+
+impl AnchorIdl {
+    pub fn from_json(json: &str) -> CodamaResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> CodamaResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn to_json_pretty(&self) -> CodamaResult<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Whether this IDL uses the legacy format, i.e. it predates Anchor 0.30.
+    pub fn is_legacy(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .is_none_or(|metadata| metadata.spec.is_none())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AnchorMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Only used by legacy IDLs — see `address` otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorInstruction {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<Vec<u8>>,
+    #[serde(default)]
+    pub accounts: Vec<AnchorInstructionAccountItem>,
+    #[serde(default)]
+    pub args: Vec<AnchorField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AnchorInstructionAccountItem {
+    Composite(AnchorCompositeAccount),
+    Single(AnchorInstructionAccount),
+}
+
+/// A group of accounts defined by a nested `#[derive(Accounts)]` struct.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorCompositeAccount {
+    pub name: String,
+    pub accounts: Vec<AnchorInstructionAccountItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AnchorInstructionAccount {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(default, alias = "isMut", skip_serializing_if = "crate::is_default")]
+    pub writable: bool,
+    #[serde(default, alias = "isSigner", skip_serializing_if = "crate::is_default")]
+    pub signer: bool,
+    #[serde(
+        default,
+        alias = "isOptional",
+        skip_serializing_if = "crate::is_default"
+    )]
+    pub optional: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pda: Option<AnchorPda>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorPda {
+    pub seeds: Vec<AnchorSeed>,
+    #[serde(default, alias = "programId", skip_serializing_if = "Option::is_none")]
+    pub program: Option<AnchorSeed>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum AnchorSeed {
+    /// Either an array of bytes or, for legacy IDLs, a value of the given type.
+    Const {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        r#type: Option<AnchorType>,
+        value: serde_json::Value,
+    },
+    Arg {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        r#type: Option<AnchorType>,
+        path: String,
+    },
+    Account {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        r#type: Option<AnchorType>,
+        path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        account: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorAccount {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<Vec<u8>>,
+    /// Only used by legacy IDLs — the type is defined in `types` otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AnchorTypeDefType>,
+}
+
+/// An event emitted by the program. Its type is defined in `types` under the same name,
+/// except for legacy IDLs which list its fields directly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorEvent {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorError {
+    pub code: usize,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub msg: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorTypeDef {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "crate::is_default")]
+    pub serialization: AnchorSerialization,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repr: Option<AnchorRepr>,
+    pub r#type: AnchorTypeDefType,
+}
+
+/// How a defined type is stored. Zero-copy types use `bytemuck`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnchorSerialization {
+    #[default]
+    Borsh,
+    Bytemuck,
+    BytemuckUnsafe,
+    Custom(serde_json::Value),
+}
+
+/// The `#[repr(...)]` attribute of a defined type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum AnchorRepr {
+    Rust(AnchorReprModifier),
+    C(AnchorReprModifier),
+    Transparent,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AnchorReprModifier {
+    #[serde(default, skip_serializing_if = "crate::is_default")]
+    pub packed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub align: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum AnchorTypeDefType {
+    Struct {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fields: Option<AnchorFields>,
+    },
+    Enum {
+        variants: Vec<AnchorEnumVariant>,
+    },
+    Type {
+        alias: AnchorType,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AnchorFields {
+    Named(Vec<AnchorField>),
+    Tuple(Vec<AnchorType>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorEnumVariant {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<AnchorFields>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorField {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    pub r#type: AnchorType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AnchorType {
+    /// E.g. `"u64"`, `"string"` or `"pubkey"` — `"publicKey"` for legacy IDLs.
+    Primitive(String),
+    Vec {
+        vec: Box<AnchorType>,
+    },
+    Option {
+        option: Box<AnchorType>,
+    },
+    COption {
+        coption: Box<AnchorType>,
+    },
+    Array {
+        array: (Box<AnchorType>, AnchorArrayLength),
+    },
+    Defined {
+        defined: AnchorDefined,
+    },
+    Generic {
+        generic: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AnchorArrayLength {
+    Value(usize),
+    Generic { generic: String },
+}
+
+/// A link to a defined type — a plain name for legacy IDLs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AnchorDefined {
+    Name(String),
+    Link {
+        name: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        generics: Vec<serde_json::Value>,
+    },
+}
+
+impl AnchorDefined {
+    pub fn name(&self) -> &str {
+        match self {
+            AnchorDefined::Name(name) => name,
+            AnchorDefined::Link { name, .. } => name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_types() {
+        let json = r#"[
+            "u64",
+            {"vec": "publicKey"},
+            {"array": ["u8", 32]},
+            {"option": {"defined": "Config"}},
+            {"defined": {"name": "Config"}}
+        ]"#;
+        let types: Vec<AnchorType> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            types,
+            vec![
+                AnchorType::Primitive("u64".to_string()),
+                AnchorType::Vec {
+                    vec: Box::new(AnchorType::Primitive("publicKey".to_string()))
+                },
+                AnchorType::Array {
+                    array: (
+                        Box::new(AnchorType::Primitive("u8".to_string())),
+                        AnchorArrayLength::Value(32)
+                    )
+                },
+                AnchorType::Option {
+                    option: Box::new(AnchorType::Defined {
+                        defined: AnchorDefined::Name("Config".to_string())
+                    })
+                },
+                AnchorType::Defined {
+                    defined: AnchorDefined::Link {
+                        name: "Config".to_string(),
+                        generics: vec![]
+                    }
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_legacy_accounts() {
+        let json = r#"[
+            {"name": "payer", "isMut": true, "isSigner": true},
+            {"name": "nested", "accounts": [{"name": "mint", "isMut": false, "isSigner": false}]}
+        ]"#;
+        let accounts: Vec<AnchorInstructionAccountItem> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            accounts,
+            vec![
+                AnchorInstructionAccountItem::Single(AnchorInstructionAccount {
+                    name: "payer".to_string(),
+                    writable: true,
+                    signer: true,
+                    ..AnchorInstructionAccount::default()
+                }),
+                AnchorInstructionAccountItem::Composite(AnchorCompositeAccount {
+                    name: "nested".to_string(),
+                    accounts: vec![AnchorInstructionAccountItem::Single(
+                        AnchorInstructionAccount {
+                            name: "mint".to_string(),
+                            ..AnchorInstructionAccount::default()
+                        }
+                    )],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn is_legacy() {
+        let idl =
+            AnchorIdl::from_json(r#"{"name": "counter", "version": "0.1.0", "instructions": []}"#)
+                .unwrap();
+        assert!(idl.is_legacy());
+        let idl = AnchorIdl::from_json(
+            r#"{"address": "1111", "metadata": {"name": "counter", "version": "0.1.0", "spec": "0.1.0"}, "instructions": []}"#,
+        )
+        .unwrap();
+        assert!(!idl.is_legacy());
+    }
+}
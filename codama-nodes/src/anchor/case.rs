@@ -0,0 +1,41 @@
+/// Anchor IDLs use `snake_case` for instructions, accounts and fields.
+pub(super) fn to_snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (i, char) in name.chars().enumerate() {
+        if char.is_uppercase() && i > 0 {
+            result.push('_');
+        }
+        result.extend(char.to_lowercase());
+    }
+    result
+}
+
+/// Anchor IDLs use `PascalCase` for account, type, variant and error names.
+pub(super) fn to_pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snake_case() {
+        assert_eq!(to_snake_case("initialize"), "initialize");
+        assert_eq!(
+            to_snake_case("initializeCounterV2"),
+            "initialize_counter_v2"
+        );
+    }
+
+    #[test]
+    fn pascal_case() {
+        assert_eq!(to_pascal_case("counter"), "Counter");
+        assert_eq!(to_pascal_case("tokenAccount"), "TokenAccount");
+        assert_eq!(to_pascal_case(""), "");
+    }
+}
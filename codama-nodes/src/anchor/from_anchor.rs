@@ -0,0 +1,1149 @@
+use super::case::to_snake_case;
+use crate::{
+    AccountNode, AccountValueNode, AnchorAccount, AnchorArrayLength, AnchorEnumVariant,
+    AnchorError, AnchorField, AnchorFields, AnchorIdl, AnchorInstruction, AnchorInstructionAccount,
+    AnchorInstructionAccountItem, AnchorPda, AnchorRepr, AnchorSeed, AnchorSerialization,
+    AnchorType, AnchorTypeDef, AnchorTypeDefType, ArgumentValueNode, ArrayTypeNode, Base16,
+    BooleanTypeNode, BytesTypeNode, BytesValueNode, CamelCaseString, ConstantDiscriminatorNode,
+    ConstantPdaSeedNode, ConstantValueNode, DefaultValueStrategy, DefinedTypeLinkNode,
+    DefinedTypeNode, EnumEmptyVariantTypeNode, EnumStructVariantTypeNode, EnumTupleVariantTypeNode,
+    EnumTypeNode, EnumVariantTypeNode, ErrorNode, FieldDiscriminatorNode, FixedSizeTypeNode,
+    InstructionAccountNode, InstructionArgumentNode, InstructionNode, NumberFormat, NumberTypeNode,
+    OptionTypeNode, PdaLinkNode, PdaNode, PdaSeedNode, PdaSeedValueNode, PdaValueNode,
+    PostOffsetTypeNode, ProgramNode, PublicKeyTypeNode, PublicKeyValueNode, RootNode,
+    SizePrefixTypeNode, StringTypeNode, StringValueNode, StructFieldTypeNode, StructTypeNode,
+    TupleTypeNode, TypeNode, VariablePdaSeedNode, ZeroableOptionTypeNode, U32,
+};
+use codama_errors::{CodamaError, CodamaResult};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+
+/// The name of the field or argument Anchor uses to store discriminators.
+const DISCRIMINATOR: &str = "discriminator";
+
+impl TryFrom<AnchorIdl> for ProgramNode {
+    type Error = CodamaError;
+
+    fn try_from(idl: AnchorIdl) -> CodamaResult<Self> {
+        AnchorConverter::new(&idl).program()
+    }
+}
+
+impl TryFrom<AnchorIdl> for RootNode {
+    type Error = CodamaError;
+
+    fn try_from(idl: AnchorIdl) -> CodamaResult<Self> {
+        Ok(RootNode::new(ProgramNode::try_from(idl)?))
+    }
+}
+
+impl ProgramNode {
+    /// Parses the given Anchor IDL — legacy or 0.30+ — into a program node.
+    pub fn from_anchor_json(json: &str) -> CodamaResult<Self> {
+        AnchorIdl::from_json(json)?.try_into()
+    }
+}
+
+impl RootNode {
+    /// Parses the given Anchor IDL — legacy or 0.30+ — into a root node.
+    pub fn from_anchor_json(json: &str) -> CodamaResult<Self> {
+        AnchorIdl::from_json(json)?.try_into()
+    }
+}
+
+struct AnchorConverter<'a> {
+    idl: &'a AnchorIdl,
+    pdas: Vec<PdaNode>,
+}
+
+impl<'a> AnchorConverter<'a> {
+    fn new(idl: &'a AnchorIdl) -> Self {
+        Self { idl, pdas: vec![] }
+    }
+
+    fn program(mut self) -> CodamaResult<ProgramNode> {
+        let idl = self.idl;
+        let metadata = idl.metadata.clone().unwrap_or_default();
+        let name = metadata.name.or(idl.name.clone()).unwrap_or_default();
+        let public_key = idl.address.clone().or(metadata.address).unwrap_or_default();
+        let mut program = ProgramNode::new(name, public_key);
+        program.version = metadata.version.or(idl.version.clone()).unwrap_or_default();
+        program.origin = Some("anchor".to_string());
+        program.docs = match metadata.description {
+            Some(description) if idl.docs.is_empty() => vec![description].into(),
+            _ => idl.docs.clone().into(),
+        };
+
+        program.accounts = idl
+            .accounts
+            .iter()
+            .map(|account| self.account(account))
+            .collect::<CodamaResult<_>>()?;
+        program.instructions = idl
+            .instructions
+            .iter()
+            .map(|instruction| self.instruction(instruction))
+            .collect::<CodamaResult<_>>()?;
+        // Account types are inlined in their account unless something else links to them.
+        let referenced = referenced_types(idl);
+        program.defined_types = idl
+            .types
+            .iter()
+            .filter(|def| {
+                referenced.contains(def.name.as_str())
+                    || !idl.accounts.iter().any(|account| account.name == def.name)
+            })
+            .map(|def| self.defined_type(def))
+            .collect::<CodamaResult<_>>()?;
+        program.errors = idl.errors.iter().map(error).collect();
+        program.pdas = self.pdas;
+        Ok(program)
+    }
+
+    fn account(&self, account: &AnchorAccount) -> CodamaResult<AccountNode> {
+        let type_def = self.find_type(&account.name);
+        let r#type = match (&account.r#type, type_def) {
+            (Some(r#type), _) => type_def_type(r#type)?,
+            (None, Some(def)) => self.type_def_node(def)?,
+            (None, None) => {
+                return Err(CodamaError::AnchorIdl(format!(
+                    "missing type for account `{}`",
+                    account.name
+                )))
+            }
+        };
+        let mut fields = match r#type {
+            TypeNode::Struct(node) => node.fields,
+            _ => {
+                return Err(CodamaError::AnchorIdl(format!(
+                    "account `{}` must be a struct",
+                    account.name
+                )))
+            }
+        };
+
+        let discriminator = match &account.discriminator {
+            Some(discriminator) => discriminator.clone(),
+            None => sighash("account", &account.name),
+        };
+        let hex = Base16.bytes_to_string(&discriminator)?;
+        fields.insert(0, discriminator_field(&discriminator)?);
+
+        let mut docs = account.docs.clone();
+        if let Some(def) = type_def.filter(|_| docs.is_empty()) {
+            docs = def.docs.clone();
+        }
+        Ok(AccountNode {
+            docs: docs.into(),
+            discriminators: vec![ConstantDiscriminatorNode::new(
+                ConstantValueNode::bytes(Base16, hex),
+                0,
+            )
+            .into()],
+            ..AccountNode::new(account.name.as_str(), StructTypeNode::new(fields))
+        })
+    }
+
+    fn instruction(&mut self, instruction: &AnchorInstruction) -> CodamaResult<InstructionNode> {
+        let discriminator = match &instruction.discriminator {
+            Some(discriminator) => discriminator.clone(),
+            None => sighash("global", &to_snake_case(&instruction.name)),
+        };
+        let mut arguments = vec![discriminator_field(&discriminator)?.into()];
+        for arg in &instruction.args {
+            arguments.push(InstructionArgumentNode {
+                docs: arg.docs.clone().into(),
+                ..InstructionArgumentNode::new(arg.name.as_str(), type_node(&arg.r#type)?)
+            });
+        }
+
+        let mut accounts = vec![];
+        for account in flatten_accounts(&instruction.accounts) {
+            accounts.push(self.instruction_account(instruction, account, &arguments)?);
+        }
+
+        Ok(InstructionNode {
+            name: instruction.name.as_str().into(),
+            docs: instruction.docs.clone().into(),
+            accounts,
+            arguments,
+            discriminators: vec![FieldDiscriminatorNode::new(DISCRIMINATOR, 0).into()],
+            ..InstructionNode::default()
+        })
+    }
+
+    fn instruction_account(
+        &mut self,
+        instruction: &AnchorInstruction,
+        account: &AnchorInstructionAccount,
+        arguments: &[InstructionArgumentNode],
+    ) -> CodamaResult<InstructionAccountNode> {
+        let default_value = match (&account.address, &account.pda) {
+            (Some(address), _) => Some(PublicKeyValueNode::new(address.as_str()).into()),
+            (None, Some(pda)) => self
+                .pda_value(instruction, account, pda, arguments)?
+                .map(Into::into),
+            (None, None) => None,
+        };
+        Ok(InstructionAccountNode {
+            is_optional: account.optional,
+            docs: account.docs.clone().into(),
+            default_value,
+            ..InstructionAccountNode::new(account.name.as_str(), account.writable, account.signer)
+        })
+    }
+
+    /// Registers a `PdaNode` for the given account and returns a value linking to it.
+    /// Returns `None` when the seeds cannot be expressed using Codama nodes —
+    /// e.g. seeds reading nested fields or PDAs owned by a non-constant program.
+    fn pda_value(
+        &mut self,
+        instruction: &AnchorInstruction,
+        account: &AnchorInstructionAccount,
+        pda: &AnchorPda,
+        arguments: &[InstructionArgumentNode],
+    ) -> CodamaResult<Option<PdaValueNode>> {
+        let program_id = match &pda.program {
+            None => None,
+            Some(AnchorSeed::Const { value, .. }) => match const_seed_bytes(value) {
+                Some(bytes) if bytes.len() == 32 => Some(bs58::encode(bytes).into_string()),
+                _ => return Ok(None),
+            },
+            Some(_) => return Ok(None),
+        };
+
+        let mut seeds = vec![];
+        let mut values = vec![];
+        for seed in &pda.seeds {
+            match seed {
+                AnchorSeed::Const { value, .. } => match const_seed(value) {
+                    Some(seed) => seeds.push(seed.into()),
+                    None => return Ok(None),
+                },
+                AnchorSeed::Arg { path, r#type } => {
+                    if path.contains('.') {
+                        return Ok(None);
+                    }
+                    let name = CamelCaseString::new(path.as_str());
+                    let r#type = match arguments.iter().find(|arg| arg.name == name) {
+                        Some(arg) => arg.r#type.clone(),
+                        None => match r#type {
+                            Some(r#type) => type_node(r#type)?,
+                            None => return Ok(None),
+                        },
+                    };
+                    seeds.push(VariablePdaSeedNode::new(name.clone(), unprefixed(r#type)).into());
+                    values.push(PdaSeedValueNode::new(
+                        name.clone(),
+                        ArgumentValueNode::new(name),
+                    ));
+                }
+                AnchorSeed::Account { path, .. } => {
+                    if path.contains('.') {
+                        return Ok(None);
+                    }
+                    let name = CamelCaseString::new(path.as_str());
+                    seeds.push(
+                        VariablePdaSeedNode::new(name.clone(), PublicKeyTypeNode::new()).into(),
+                    );
+                    values.push(PdaSeedValueNode::new(
+                        name.clone(),
+                        AccountValueNode::new(name),
+                    ));
+                }
+            }
+        }
+
+        let name = self.register_pda(instruction, account, program_id, seeds);
+        Ok(Some(PdaValueNode::new(PdaLinkNode::new(name), values)))
+    }
+
+    /// Adds the PDA to the program unless an identical one already exists, prefixing
+    /// its name with the instruction name when another PDA already uses it.
+    fn register_pda(
+        &mut self,
+        instruction: &AnchorInstruction,
+        account: &AnchorInstructionAccount,
+        program_id: Option<String>,
+        seeds: Vec<PdaSeedNode>,
+    ) -> CamelCaseString {
+        let pda = PdaNode {
+            program_id,
+            ..PdaNode::new(account.name.as_str(), seeds)
+        };
+        let prefixed_name = CamelCaseString::new(format!("{}_{}", instruction.name, account.name));
+        let name = match self.pdas.iter().find(|existing| existing.name == pda.name) {
+            Some(existing) if *existing == pda => return pda.name,
+            Some(_) => prefixed_name,
+            None => pda.name.clone(),
+        };
+        if !self.pdas.iter().any(|existing| existing.name == name) {
+            self.pdas.push(PdaNode {
+                name: name.clone(),
+                ..pda
+            });
+        }
+        name
+    }
+
+    fn find_type(&self, name: &str) -> Option<&'a AnchorTypeDef> {
+        self.idl.types.iter().find(|def| def.name == name)
+    }
+
+    fn defined_type(&self, def: &AnchorTypeDef) -> CodamaResult<DefinedTypeNode> {
+        Ok(DefinedTypeNode {
+            docs: def.docs.clone().into(),
+            ..DefinedTypeNode::new(def.name.as_str(), self.type_def_node(def)?)
+        })
+    }
+
+    /// The type of the given definition. Options of zero-copy types are zeroable
+    /// and the fields of `#[repr(C)]` zero-copy structs are padded to their alignment.
+    fn type_def_node(&self, def: &AnchorTypeDef) -> CodamaResult<TypeNode> {
+        let r#type = type_def_type(&def.r#type)?;
+        if !is_zero_copy(def) {
+            return Ok(r#type);
+        }
+        let paddings = match (&def.r#type, is_padded(def)) {
+            (
+                AnchorTypeDefType::Struct {
+                    fields: Some(fields),
+                },
+                true,
+            ) => self
+                .struct_layout(def, fields, &mut vec![])
+                .map(|(paddings, _)| paddings)
+                .ok_or_else(|| {
+                    CodamaError::AnchorIdl(format!(
+                        "cannot compute the padding of zero-copy type `{}`",
+                        def.name
+                    ))
+                })?,
+            _ => vec![],
+        };
+        let pad = |index: usize, r#type: TypeNode| {
+            let r#type = zeroable(r#type);
+            match paddings.get(index).copied().unwrap_or(0) {
+                0 => r#type,
+                padding => PostOffsetTypeNode::padded(r#type, padding as i32).into(),
+            }
+        };
+        Ok(match r#type {
+            TypeNode::Struct(node) => StructTypeNode::new(
+                node.fields
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, field)| StructFieldTypeNode {
+                        r#type: pad(index, field.r#type),
+                        ..field
+                    })
+                    .collect(),
+            )
+            .into(),
+            TypeNode::Tuple(node) => TupleTypeNode::new(
+                node.items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, item)| pad(index, item))
+                    .collect(),
+            )
+            .into(),
+            r#type => zeroable(r#type),
+        })
+    }
+
+    /// The size and alignment of the given type in memory on SBF, if it is a zero-copy type.
+    /// The names of the defined types being visited guard against recursive types.
+    fn layout(&self, r#type: &AnchorType, visiting: &mut Vec<String>) -> Option<(usize, usize)> {
+        match r#type {
+            AnchorType::Primitive(primitive) => match primitive.as_str() {
+                "bool" | "u8" | "i8" => Some((1, 1)),
+                "u16" | "i16" => Some((2, 2)),
+                "u32" | "i32" | "f32" => Some((4, 4)),
+                "u64" | "i64" | "f64" => Some((8, 8)),
+                "u128" | "i128" => Some((16, 8)),
+                "pubkey" | "publicKey" => Some((32, 1)),
+                _ => None,
+            },
+            AnchorType::Option { option } => self.layout(option, visiting),
+            AnchorType::Array {
+                array: (item, AnchorArrayLength::Value(count)),
+            } => {
+                let (size, align) = self.layout(item, visiting)?;
+                Some((size * count, align))
+            }
+            AnchorType::Defined { defined } => {
+                let def = self.find_type(defined.name())?;
+                if visiting.contains(&def.name) {
+                    return None;
+                }
+                visiting.push(def.name.clone());
+                let layout = self.type_def_layout(def, visiting);
+                visiting.pop();
+                layout
+            }
+            _ => None,
+        }
+    }
+
+    fn type_def_layout(
+        &self,
+        def: &AnchorTypeDef,
+        visiting: &mut Vec<String>,
+    ) -> Option<(usize, usize)> {
+        match &def.r#type {
+            AnchorTypeDefType::Type { alias } => self.layout(alias, visiting),
+            _ if !is_zero_copy(def) => None,
+            AnchorTypeDefType::Struct { fields: None } => Some((0, 1)),
+            AnchorTypeDefType::Struct {
+                fields: Some(fields),
+            } => self
+                .struct_layout(def, fields, visiting)
+                .map(|(_, layout)| layout),
+            AnchorTypeDefType::Enum { .. } => None,
+        }
+    }
+
+    /// The trailing padding of each field of a zero-copy struct, along with its own layout.
+    /// Fields are only padded for `#[repr(C)]` structs, other layouts being packed or unknown.
+    fn struct_layout(
+        &self,
+        def: &AnchorTypeDef,
+        fields: &AnchorFields,
+        visiting: &mut Vec<String>,
+    ) -> Option<(Vec<usize>, (usize, usize))> {
+        let types = match fields {
+            AnchorFields::Named(fields) => fields.iter().map(|field| &field.r#type).collect(),
+            AnchorFields::Tuple(items) => items.iter().collect::<Vec<_>>(),
+        };
+        let field_layouts = types
+            .into_iter()
+            .map(|r#type| self.layout(r#type, visiting))
+            .collect::<Option<Vec<_>>>()?;
+        let mut paddings = vec![0; field_layouts.len()];
+        let modifier = match &def.repr {
+            Some(AnchorRepr::C(modifier)) => modifier,
+            Some(AnchorRepr::Rust(modifier)) if modifier.packed => modifier,
+            Some(AnchorRepr::Transparent) if field_layouts.len() == 1 => {
+                return Some((paddings, field_layouts[0]));
+            }
+            _ => return None,
+        };
+        if modifier.packed {
+            let size = field_layouts.iter().map(|(size, _)| size).sum();
+            return Some((paddings, (size, 1)));
+        }
+        let mut offset = 0usize;
+        let mut alignment = modifier.align.unwrap_or(1);
+        for (index, (size, align)) in field_layouts.into_iter().enumerate() {
+            let padding = offset.next_multiple_of(align) - offset;
+            if let Some(previous) = index.checked_sub(1) {
+                paddings[previous] += padding;
+            }
+            offset += padding + size;
+            alignment = alignment.max(align);
+        }
+        let trailing = offset.next_multiple_of(alignment) - offset;
+        if let Some(last) = paddings.last_mut() {
+            *last += trailing;
+        }
+        Some((paddings, (offset + trailing, alignment)))
+    }
+}
+
+/// The names of the defined types used by instructions, other defined types or events.
+fn referenced_types(idl: &AnchorIdl) -> BTreeSet<&str> {
+    fn collect<'t>(r#type: &'t AnchorType, names: &mut BTreeSet<&'t str>) {
+        match r#type {
+            AnchorType::Defined { defined } => {
+                names.insert(defined.name());
+            }
+            AnchorType::Vec { vec: item }
+            | AnchorType::Option { option: item }
+            | AnchorType::COption { coption: item }
+            | AnchorType::Array { array: (item, _) } => collect(item, names),
+            AnchorType::Primitive(_) | AnchorType::Generic { .. } => {}
+        }
+    }
+    fn collect_fields<'t>(fields: &'t Option<AnchorFields>, names: &mut BTreeSet<&'t str>) {
+        match fields {
+            Some(AnchorFields::Named(fields)) => fields
+                .iter()
+                .for_each(|field| collect(&field.r#type, names)),
+            Some(AnchorFields::Tuple(items)) => items.iter().for_each(|item| collect(item, names)),
+            None => {}
+        }
+    }
+
+    let mut names = idl
+        .events
+        .iter()
+        .map(|event| event.name.as_str())
+        .collect::<BTreeSet<_>>();
+    for instruction in &idl.instructions {
+        instruction
+            .args
+            .iter()
+            .for_each(|arg| collect(&arg.r#type, &mut names));
+    }
+    for def in &idl.types {
+        match &def.r#type {
+            AnchorTypeDefType::Struct { fields } => collect_fields(fields, &mut names),
+            AnchorTypeDefType::Enum { variants } => variants
+                .iter()
+                .for_each(|variant| collect_fields(&variant.fields, &mut names)),
+            AnchorTypeDefType::Type { alias } => collect(alias, &mut names),
+        }
+    }
+    names
+}
+
+fn is_zero_copy(def: &AnchorTypeDef) -> bool {
+    matches!(
+        def.serialization,
+        AnchorSerialization::Bytemuck | AnchorSerialization::BytemuckUnsafe
+    )
+}
+
+/// Whether the fields of the given type are laid out in memory with C alignment rules.
+fn is_padded(def: &AnchorTypeDef) -> bool {
+    is_zero_copy(def) && matches!(&def.repr, Some(AnchorRepr::C(modifier)) if !modifier.packed)
+}
+
+/// Zero-copy types store missing options as zeroes instead of using a prefix.
+fn zeroable(r#type: TypeNode) -> TypeNode {
+    match r#type {
+        TypeNode::Option(node) => ZeroableOptionTypeNode::new(zeroable(*node.item)).into(),
+        TypeNode::Array(mut node) => {
+            node.item = Box::new(zeroable(*node.item));
+            node.into()
+        }
+        r#type => r#type,
+    }
+}
+
+fn error(error: &AnchorError) -> ErrorNode {
+    ErrorNode::new(
+        error.name.as_str(),
+        error.code,
+        error.msg.clone().unwrap_or_default(),
+    )
+}
+
+fn type_def_type(r#type: &AnchorTypeDefType) -> CodamaResult<TypeNode> {
+    Ok(match r#type {
+        AnchorTypeDefType::Struct { fields: None } => StructTypeNode::new(vec![]).into(),
+        AnchorTypeDefType::Struct {
+            fields: Some(AnchorFields::Named(fields)),
+        } => StructTypeNode::new(struct_fields(fields)?).into(),
+        AnchorTypeDefType::Struct {
+            fields: Some(AnchorFields::Tuple(items)),
+        } => TupleTypeNode::new(tuple_items(items)?).into(),
+        AnchorTypeDefType::Enum { variants } => EnumTypeNode::new(
+            variants
+                .iter()
+                .map(enum_variant)
+                .collect::<CodamaResult<_>>()?,
+        )
+        .into(),
+        AnchorTypeDefType::Type { alias } => type_node(alias)?,
+    })
+}
+
+fn enum_variant(variant: &AnchorEnumVariant) -> CodamaResult<EnumVariantTypeNode> {
+    let name = variant.name.as_str();
+    Ok(match &variant.fields {
+        None => EnumEmptyVariantTypeNode::new(name).into(),
+        Some(AnchorFields::Named(fields)) => {
+            EnumStructVariantTypeNode::new(name, StructTypeNode::new(struct_fields(fields)?)).into()
+        }
+        Some(AnchorFields::Tuple(items)) => {
+            EnumTupleVariantTypeNode::new(name, TupleTypeNode::new(tuple_items(items)?)).into()
+        }
+    })
+}
+
+fn struct_fields(fields: &[AnchorField]) -> CodamaResult<Vec<StructFieldTypeNode>> {
+    fields
+        .iter()
+        .map(|field| {
+            Ok(StructFieldTypeNode {
+                docs: field.docs.clone().into(),
+                ..StructFieldTypeNode::new(field.name.as_str(), type_node(&field.r#type)?)
+            })
+        })
+        .collect()
+}
+
+fn tuple_items(items: &[AnchorType]) -> CodamaResult<Vec<TypeNode>> {
+    items.iter().map(type_node).collect()
+}
+
+fn type_node(r#type: &AnchorType) -> CodamaResult<TypeNode> {
+    Ok(match r#type {
+        AnchorType::Primitive(primitive) => match primitive.as_str() {
+            "bool" => BooleanTypeNode::default().into(),
+            "string" => {
+                SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U32)).into()
+            }
+            "bytes" => {
+                SizePrefixTypeNode::new(BytesTypeNode::new(), NumberTypeNode::le(U32)).into()
+            }
+            "pubkey" | "publicKey" => PublicKeyTypeNode::new().into(),
+            format => NumberTypeNode::le(
+                NumberFormat::try_from(format)
+                    .map_err(|_| CodamaError::AnchorIdl(format!("unsupported type `{format}`")))?,
+            )
+            .into(),
+        },
+        AnchorType::Vec { vec } => {
+            ArrayTypeNode::prefixed(type_node(vec)?, NumberTypeNode::le(U32)).into()
+        }
+        AnchorType::Option { option } => OptionTypeNode::new(type_node(option)?).into(),
+        AnchorType::COption { coption } => OptionTypeNode {
+            prefix: NumberTypeNode::le(U32).into(),
+            ..OptionTypeNode::fixed(type_node(coption)?)
+        }
+        .into(),
+        AnchorType::Array {
+            array: (item, AnchorArrayLength::Value(size)),
+        } => ArrayTypeNode::fixed(type_node(item)?, *size).into(),
+        AnchorType::Defined { defined } => DefinedTypeLinkNode::new(defined.name()).into(),
+        AnchorType::Array {
+            array: (_, AnchorArrayLength::Generic { generic }),
+        }
+        | AnchorType::Generic { generic } => {
+            return Err(CodamaError::AnchorIdl(format!(
+                "unsupported generic `{generic}`"
+            )))
+        }
+    })
+}
+
+/// The fixed-size `discriminator` field prepended to account data and instruction arguments.
+fn discriminator_field(discriminator: &[u8]) -> CodamaResult<StructFieldTypeNode> {
+    let hex = Base16.bytes_to_string(discriminator)?;
+    Ok(StructFieldTypeNode {
+        default_value_strategy: Some(DefaultValueStrategy::Omitted),
+        default_value: Some(BytesValueNode::base16(hex).into()),
+        ..StructFieldTypeNode::new(
+            DISCRIMINATOR,
+            FixedSizeTypeNode::new(BytesTypeNode::new(), discriminator.len()),
+        )
+    })
+}
+
+/// The discriminator legacy IDLs omit: the first 8 bytes of `sha256("{namespace}:{name}")`.
+fn sighash(namespace: &str, name: &str) -> Vec<u8> {
+    Sha256::digest(format!("{namespace}:{name}").as_bytes())[..8].to_vec()
+}
+
+fn flatten_accounts(items: &[AnchorInstructionAccountItem]) -> Vec<&AnchorInstructionAccount> {
+    items
+        .iter()
+        .flat_map(|item| match item {
+            AnchorInstructionAccountItem::Single(account) => vec![account],
+            AnchorInstructionAccountItem::Composite(composite) => {
+                flatten_accounts(&composite.accounts)
+            }
+        })
+        .collect()
+}
+
+/// Constant seeds are byte arrays, or plain strings for legacy IDLs.
+fn const_seed(value: &serde_json::Value) -> Option<ConstantPdaSeedNode> {
+    if let serde_json::Value::String(string) = value {
+        return Some(string_seed(string));
+    }
+    let bytes = const_seed_bytes(value)?;
+    match String::from_utf8(bytes.clone()) {
+        Ok(string) if !string.chars().any(char::is_control) => Some(string_seed(&string)),
+        _ => Some(ConstantPdaSeedNode::new(
+            BytesTypeNode::new(),
+            BytesValueNode::base16(Base16.bytes_to_string(&bytes).ok()?),
+        )),
+    }
+}
+
+fn const_seed_bytes(value: &serde_json::Value) -> Option<Vec<u8>> {
+    serde_json::from_value(value.clone()).ok()
+}
+
+fn string_seed(string: &str) -> ConstantPdaSeedNode {
+    ConstantPdaSeedNode::new(StringTypeNode::utf8(), StringValueNode::new(string))
+}
+
+/// Seeds use the raw bytes of strings and byte arrays, without their size prefix.
+fn unprefixed(r#type: TypeNode) -> TypeNode {
+    match r#type {
+        TypeNode::SizePrefix(node) => *node.r#type,
+        r#type => r#type,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InstructionInputValueNode, NestedTypeNodeTrait, PdaValue, U16, U64, U8};
+    use pretty_assertions::assert_eq;
+
+    const COUNTER_IDL: &str = r#"{
+        "address": "Counter111111111111111111111111111111111111",
+        "metadata": { "name": "counter", "version": "0.1.0", "spec": "0.1.0" },
+        "instructions": [
+            {
+                "name": "increment",
+                "discriminator": [11, 18, 104, 9, 104, 174, 59, 33],
+                "accounts": [
+                    { "name": "authority", "signer": true },
+                    {
+                        "name": "counter",
+                        "writable": true,
+                        "pda": {
+                            "seeds": [
+                                { "kind": "const", "value": [99, 111, 117, 110, 116, 101, 114] },
+                                { "kind": "account", "path": "authority" },
+                                { "kind": "arg", "path": "label" }
+                            ]
+                        }
+                    },
+                    { "name": "system_program", "address": "11111111111111111111111111111111" }
+                ],
+                "args": [
+                    { "name": "amount", "type": "u64" },
+                    { "name": "label", "type": "string" }
+                ]
+            }
+        ],
+        "accounts": [
+            { "name": "Counter", "discriminator": [255, 176, 4, 245, 188, 253, 124, 25] }
+        ],
+        "errors": [
+            { "code": 6000, "name": "Overflow", "msg": "Counter overflowed" }
+        ],
+        "types": [
+            {
+                "name": "Counter",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "count", "type": "u64" },
+                        { "name": "mode", "type": { "defined": { "name": "Mode" } } }
+                    ]
+                }
+            },
+            {
+                "name": "Mode",
+                "type": {
+                    "kind": "enum",
+                    "variants": [{ "name": "Up" }, { "name": "Down", "fields": ["u8"] }]
+                }
+            }
+        ]
+    }"#;
+
+    const LEGACY_IDL: &str = r#"{
+        "version": "0.1.0",
+        "name": "counter",
+        "instructions": [
+            {
+                "name": "initializeCounter",
+                "accounts": [
+                    { "name": "payer", "isMut": true, "isSigner": true },
+                    {
+                        "name": "counter",
+                        "isMut": true,
+                        "isSigner": false,
+                        "pda": {
+                            "seeds": [
+                                { "kind": "const", "type": "string", "value": "counter" },
+                                { "kind": "account", "type": "publicKey", "path": "payer" }
+                            ]
+                        }
+                    }
+                ],
+                "args": [{ "name": "start", "type": { "option": "u64" } }]
+            }
+        ],
+        "accounts": [
+            {
+                "name": "Counter",
+                "type": { "kind": "struct", "fields": [{ "name": "count", "type": "u64" }] }
+            }
+        ],
+        "metadata": { "address": "Counter111111111111111111111111111111111111" }
+    }"#;
+
+    #[test]
+    fn program() {
+        let program = ProgramNode::from_anchor_json(COUNTER_IDL).unwrap();
+        assert_eq!(program.name, CamelCaseString::new("counter"));
+        assert_eq!(
+            program.public_key,
+            "Counter111111111111111111111111111111111111"
+        );
+        assert_eq!(program.version, "0.1.0");
+        assert_eq!(program.origin, Some("anchor".to_string()));
+        assert_eq!(
+            program.errors,
+            vec![ErrorNode::new("overflow", 6000, "Counter overflowed")]
+        );
+    }
+
+    #[test]
+    fn accounts() {
+        let program = ProgramNode::from_anchor_json(COUNTER_IDL).unwrap();
+        assert_eq!(
+            program.accounts,
+            vec![AccountNode {
+                discriminators: vec![ConstantDiscriminatorNode::new(
+                    ConstantValueNode::bytes(Base16, "ffb004f5bcfd7c19"),
+                    0
+                )
+                .into()],
+                ..AccountNode::new(
+                    "counter",
+                    StructTypeNode::new(vec![
+                        StructFieldTypeNode {
+                            default_value_strategy: Some(DefaultValueStrategy::Omitted),
+                            default_value: Some(BytesValueNode::base16("ffb004f5bcfd7c19").into()),
+                            ..StructFieldTypeNode::new(
+                                "discriminator",
+                                FixedSizeTypeNode::new(BytesTypeNode::new(), 8)
+                            )
+                        },
+                        StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+                        StructFieldTypeNode::new("mode", DefinedTypeLinkNode::new("mode")),
+                    ])
+                )
+            }]
+        );
+    }
+
+    #[test]
+    fn defined_types() {
+        let program = ProgramNode::from_anchor_json(COUNTER_IDL).unwrap();
+        assert_eq!(
+            program.defined_types,
+            vec![DefinedTypeNode::new(
+                "mode",
+                EnumTypeNode::new(vec![
+                    EnumEmptyVariantTypeNode::new("up").into(),
+                    EnumTupleVariantTypeNode::new(
+                        "down",
+                        TupleTypeNode::new(vec![NumberTypeNode::le(U8).into()])
+                    )
+                    .into(),
+                ])
+            )]
+        );
+    }
+
+    #[test]
+    fn instructions() {
+        let program = ProgramNode::from_anchor_json(COUNTER_IDL).unwrap();
+        let instruction = &program.instructions[0];
+        assert_eq!(instruction.name, CamelCaseString::new("increment"));
+        assert_eq!(
+            instruction.discriminators,
+            vec![FieldDiscriminatorNode::new("discriminator", 0).into()]
+        );
+        assert_eq!(
+            instruction
+                .arguments
+                .iter()
+                .map(|arg| arg.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["discriminator", "amount", "label"]
+        );
+        assert_eq!(
+            instruction.arguments[0].default_value,
+            Some(BytesValueNode::base16("0b12680968ae3b21").into())
+        );
+        assert_eq!(
+            instruction.accounts[0],
+            InstructionAccountNode::new("authority", false, true)
+        );
+        assert_eq!(
+            instruction.accounts[2].default_value,
+            Some(PublicKeyValueNode::new("11111111111111111111111111111111").into())
+        );
+    }
+
+    #[test]
+    fn pdas() {
+        let program = ProgramNode::from_anchor_json(COUNTER_IDL).unwrap();
+        assert_eq!(
+            program.pdas,
+            vec![PdaNode::new(
+                "counter",
+                vec![
+                    ConstantPdaSeedNode::new(
+                        StringTypeNode::utf8(),
+                        StringValueNode::new("counter")
+                    )
+                    .into(),
+                    VariablePdaSeedNode::new("authority", PublicKeyTypeNode::new()).into(),
+                    VariablePdaSeedNode::new("label", StringTypeNode::utf8()).into(),
+                ]
+            )]
+        );
+        assert_eq!(
+            program.instructions[0].accounts[1].default_value,
+            Some(InstructionInputValueNode::Pda(PdaValueNode::new(
+                PdaValue::Linked(PdaLinkNode::new("counter")),
+                vec![
+                    PdaSeedValueNode::new("authority", AccountValueNode::new("authority")),
+                    PdaSeedValueNode::new("label", ArgumentValueNode::new("label")),
+                ]
+            )))
+        );
+    }
+
+    #[test]
+    fn legacy() {
+        let root = RootNode::from_anchor_json(LEGACY_IDL).unwrap();
+        let program = root.program;
+        assert_eq!(
+            program.public_key,
+            "Counter111111111111111111111111111111111111"
+        );
+
+        // Discriminators are derived from the account and instruction names.
+        assert_eq!(
+            program.accounts[0].discriminators,
+            vec![ConstantDiscriminatorNode::new(
+                ConstantValueNode::bytes(Base16, "ffb004f5bcfd7c19"),
+                0
+            )
+            .into()]
+        );
+        let instruction = &program.instructions[0];
+        assert_eq!(
+            instruction.arguments[0].default_value,
+            Some(
+                BytesValueNode::base16(
+                    Base16
+                        .bytes_to_string(&sighash("global", "initialize_counter"))
+                        .unwrap()
+                )
+                .into()
+            )
+        );
+        assert_eq!(
+            instruction.arguments[1].r#type,
+            OptionTypeNode::new(NumberTypeNode::le(U64)).into()
+        );
+        assert_eq!(
+            instruction.accounts[0],
+            InstructionAccountNode::new("payer", true, true)
+        );
+        assert_eq!(
+            program.pdas,
+            vec![PdaNode::new(
+                "counter",
+                vec![
+                    ConstantPdaSeedNode::new(
+                        StringTypeNode::utf8(),
+                        StringValueNode::new("counter")
+                    )
+                    .into(),
+                    VariablePdaSeedNode::new("payer", PublicKeyTypeNode::new()).into(),
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn pda_name_conflicts() {
+        let idl = AnchorIdl::from_json(
+            r#"{
+                "address": "Counter111111111111111111111111111111111111",
+                "metadata": { "name": "counter", "version": "0.1.0", "spec": "0.1.0" },
+                "instructions": [
+                    {
+                        "name": "create",
+                        "discriminator": [0],
+                        "accounts": [{ "name": "vault", "pda": { "seeds": [{ "kind": "const", "value": [1] }] } }],
+                        "args": []
+                    },
+                    {
+                        "name": "close",
+                        "discriminator": [1],
+                        "accounts": [{ "name": "vault", "pda": { "seeds": [{ "kind": "const", "value": [2] }] } }],
+                        "args": []
+                    },
+                    {
+                        "name": "use_vault",
+                        "discriminator": [2],
+                        "accounts": [{ "name": "vault", "pda": { "seeds": [{ "kind": "const", "value": [1] }] } }],
+                        "args": []
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let program = ProgramNode::try_from(idl).unwrap();
+        assert_eq!(
+            program
+                .pdas
+                .iter()
+                .map(|pda| pda.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["vault", "closeVault"]
+        );
+    }
+
+    #[test]
+    fn referenced_account_types() {
+        let program = ProgramNode::from_anchor_json(
+            r#"{
+                "metadata": { "name": "counter", "version": "0.1.0", "spec": "0.1.0" },
+                "instructions": [
+                    {
+                        "name": "reset",
+                        "discriminator": [0],
+                        "accounts": [],
+                        "args": [{ "name": "state", "type": { "defined": { "name": "Counter" } } }]
+                    }
+                ],
+                "accounts": [
+                    { "name": "Counter", "discriminator": [1] },
+                    { "name": "Settings", "discriminator": [2] },
+                    { "name": "Ledger", "discriminator": [3] }
+                ],
+                "events": [{ "name": "Settings", "discriminator": [4] }],
+                "types": [
+                    { "name": "Counter", "type": { "kind": "struct", "fields": [{ "name": "count", "type": "u64" }] } },
+                    { "name": "Settings", "type": { "kind": "struct", "fields": [] } },
+                    { "name": "Ledger", "type": { "kind": "struct", "fields": [] } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            program
+                .defined_types
+                .iter()
+                .map(|def| def.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["counter", "settings"]
+        );
+        assert_eq!(program.accounts.len(), 3);
+    }
+
+    #[test]
+    fn zero_copy_types() {
+        let program = ProgramNode::from_anchor_json(
+            r#"{
+                "metadata": { "name": "oracle", "version": "0.1.0", "spec": "0.1.0" },
+                "instructions": [],
+                "accounts": [{ "name": "Oracle", "discriminator": [1, 2, 3, 4, 5, 6, 7, 8] }],
+                "types": [
+                    {
+                        "name": "Oracle",
+                        "serialization": "bytemuck",
+                        "repr": { "kind": "c" },
+                        "type": {
+                            "kind": "struct",
+                            "fields": [
+                                { "name": "decimals", "type": "u8" },
+                                { "name": "price", "type": { "defined": { "name": "Price" } } },
+                                { "name": "authority", "type": { "option": "pubkey" } },
+                                { "name": "flags", "type": "u16" }
+                            ]
+                        }
+                    },
+                    {
+                        "name": "Price",
+                        "serialization": "bytemuck",
+                        "repr": { "kind": "c" },
+                        "type": { "kind": "struct", "fields": [{ "name": "value", "type": "u64" }] }
+                    },
+                    {
+                        "name": "Packed",
+                        "serialization": "bytemuckunsafe",
+                        "repr": { "kind": "c", "packed": true },
+                        "type": { "kind": "struct", "fields": [{ "name": "flag", "type": "u8" }, { "name": "value", "type": "u64" }] }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            program.accounts[0].data.get_nested_type_node().fields[1..],
+            vec![
+                StructFieldTypeNode::new(
+                    "decimals",
+                    PostOffsetTypeNode::padded(NumberTypeNode::le(U8), 7)
+                ),
+                StructFieldTypeNode::new("price", DefinedTypeLinkNode::new("price")),
+                StructFieldTypeNode::new(
+                    "authority",
+                    ZeroableOptionTypeNode::new(PublicKeyTypeNode::new())
+                ),
+                StructFieldTypeNode::new(
+                    "flags",
+                    PostOffsetTypeNode::padded(NumberTypeNode::le(U16), 6)
+                ),
+            ]
+        );
+        assert_eq!(
+            program.defined_types[1].r#type,
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("flag", NumberTypeNode::le(U8)),
+                StructFieldTypeNode::new("value", NumberTypeNode::le(U64)),
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn zero_copy_types_with_unknown_layouts() {
+        let error = ProgramNode::from_anchor_json(
+            r#"{
+                "metadata": { "name": "oracle", "version": "0.1.0", "spec": "0.1.0" },
+                "instructions": [],
+                "types": [
+                    {
+                        "name": "Oracle",
+                        "serialization": "bytemuck",
+                        "repr": { "kind": "c" },
+                        "type": { "kind": "struct", "fields": [{ "name": "name", "type": "string" }] }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid Anchor IDL: cannot compute the padding of zero-copy type `Oracle`"
+        );
+    }
+
+    #[test]
+    fn unsupported_generics() {
+        let error = ProgramNode::from_anchor_json(
+            r#"{
+                "metadata": { "name": "counter", "version": "0.1.0", "spec": "0.1.0" },
+                "instructions": [],
+                "types": [{ "name": "Wrapper", "type": { "kind": "type", "alias": { "generic": "T" } } }]
+            }"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid Anchor IDL: unsupported generic `T`"
+        );
+    }
+
+    #[test]
+    fn merge_as_additional_program() {
+        let root =
+            RootNode::default().add_program(ProgramNode::from_anchor_json(COUNTER_IDL).unwrap());
+        assert_eq!(
+            root.additional_programs[0].name,
+            CamelCaseString::new("counter")
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod anchor_idl;
+mod case;
+mod from_anchor;
+mod to_anchor;
+
+pub use anchor_idl::*;
+pub use to_anchor::*;
@@ -0,0 +1,797 @@
+use super::case::{to_pascal_case, to_snake_case};
+use crate::{
+    AccountNode, AnchorAccount, AnchorArrayLength, AnchorDefined, AnchorEnumVariant, AnchorError,
+    AnchorField, AnchorFields, AnchorIdl, AnchorInstruction, AnchorInstructionAccount,
+    AnchorInstructionAccountItem, AnchorMetadata, AnchorPda, AnchorSeed, AnchorSerialization,
+    AnchorType, AnchorTypeDef, AnchorTypeDefType, ConstantPdaSeedValueNode, CountNode,
+    DefinedTypeNode, Diagnostic, DiscriminatorNode, Endian, EnumVariantTypeNode, HasKind, HasName,
+    InstructionAccountNode, InstructionInputValueNode, InstructionNode, IsAccountSigner,
+    NestedTypeNode, NestedTypeNodeTrait, NodePath, NumberFormat, NumberTypeNode,
+    PdaProgramIdValueNode, PdaSeedNode, PdaSeedValueValueNode, PdaValue, PdaValueNode, ProgramNode,
+    PublicKeyValueNode, RootNode, StructFieldTypeNode, TypeCodec, TypeNode, ValueNode, U32, U8,
+};
+
+/// The version of the Anchor IDL specification produced by the exporter.
+pub const ANCHOR_IDL_SPEC: &str = "0.1.0";
+
+/// An Anchor IDL exported from a Codama program, along with warnings
+/// for the nodes that have no Anchor equivalent and were approximated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnchorExport {
+    pub idl: AnchorIdl,
+    pub warnings: Vec<Diagnostic>,
+}
+
+impl ProgramNode {
+    /// Converts this program into an Anchor 0.30+ IDL on a best-effort basis.
+    pub fn to_anchor_idl(&self) -> AnchorExport {
+        AnchorExporter::new(self, TypeCodec::from_program(self)).export(&NodePath::new())
+    }
+}
+
+impl RootNode {
+    /// Converts the main program of this root into an Anchor 0.30+ IDL on a best-effort basis.
+    pub fn to_anchor_idl(&self) -> AnchorExport {
+        AnchorExporter::new(&self.program, TypeCodec::from_root(self))
+            .export(&NodePath::new().child(self.kind(), None))
+    }
+}
+
+struct AnchorExporter<'a> {
+    program: &'a ProgramNode,
+    codec: TypeCodec<'a>,
+    warnings: Vec<Diagnostic>,
+}
+
+impl<'a> AnchorExporter<'a> {
+    fn new(program: &'a ProgramNode, codec: TypeCodec<'a>) -> Self {
+        Self {
+            program,
+            codec,
+            warnings: vec![],
+        }
+    }
+
+    fn warn<T: Into<String>>(&mut self, message: T, path: &NodePath) {
+        self.warnings.push(Diagnostic::warning(message, path));
+    }
+
+    fn unsupported<T: HasKind>(&mut self, node: &T, path: &NodePath) {
+        self.warn(format!("`{}` has no Anchor equivalent", node.kind()), path);
+    }
+
+    fn export(mut self, parent: &NodePath) -> AnchorExport {
+        let program = self.program;
+        let path = parent.child(program.kind(), Some(&program.name));
+        let mut idl = AnchorIdl {
+            address: Some(program.public_key.clone()),
+            metadata: Some(AnchorMetadata {
+                name: Some(to_snake_case(&program.name)),
+                version: Some(program.version.clone()),
+                spec: Some(ANCHOR_IDL_SPEC.to_string()),
+                ..AnchorMetadata::default()
+            }),
+            docs: program.docs.to_vec(),
+            ..AnchorIdl::default()
+        };
+
+        for account in &program.accounts {
+            let (account, type_def) = self.account(account, &path);
+            idl.accounts.push(account);
+            idl.types.push(type_def);
+        }
+        for instruction in &program.instructions {
+            let instruction = self.instruction(instruction, &path);
+            idl.instructions.push(instruction);
+        }
+        for defined_type in &program.defined_types {
+            let type_def = self.defined_type(defined_type, &path);
+            idl.types.push(type_def);
+        }
+        idl.errors = program
+            .errors
+            .iter()
+            .map(|error| AnchorError {
+                code: error.code,
+                name: to_pascal_case(&error.name),
+                msg: Some(error.message.clone()).filter(|message| !message.is_empty()),
+            })
+            .collect();
+
+        AnchorExport {
+            idl,
+            warnings: self.warnings,
+        }
+    }
+
+    fn account(
+        &mut self,
+        account: &AccountNode,
+        parent: &NodePath,
+    ) -> (AnchorAccount, AnchorTypeDef) {
+        let path = parent.child(account.kind(), Some(&account.name));
+        let fields = &account.data.get_nested_type_node().fields;
+        let (discriminator, fields) = self.discriminator(&account.discriminators, fields, &path);
+        let name = to_pascal_case(&account.name);
+        let fields = self.fields(&fields, &path);
+        (
+            AnchorAccount {
+                name: name.clone(),
+                docs: vec![],
+                discriminator: Some(discriminator),
+                r#type: None,
+            },
+            AnchorTypeDef {
+                name,
+                docs: account.docs.to_vec(),
+                serialization: AnchorSerialization::default(),
+                repr: None,
+                r#type: AnchorTypeDefType::Struct {
+                    fields: Some(AnchorFields::Named(fields)).filter(|f| !fields_is_empty(f)),
+                },
+            },
+        )
+    }
+
+    fn instruction(
+        &mut self,
+        instruction: &InstructionNode,
+        parent: &NodePath,
+    ) -> AnchorInstruction {
+        let path = parent.child(instruction.kind(), Some(&instruction.name));
+        let arguments = instruction
+            .arguments
+            .iter()
+            .map(|argument| StructFieldTypeNode {
+                docs: argument.docs.clone(),
+                default_value: argument
+                    .default_value
+                    .clone()
+                    .and_then(|value| ValueNode::try_from(value).ok()),
+                ..StructFieldTypeNode::new(argument.name.clone(), argument.r#type.clone())
+            })
+            .collect::<Vec<_>>();
+        let (discriminator, arguments) =
+            self.discriminator(&instruction.discriminators, &arguments, &path);
+
+        AnchorInstruction {
+            name: to_snake_case(&instruction.name),
+            docs: instruction.docs.to_vec(),
+            discriminator: Some(discriminator),
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|account| {
+                    AnchorInstructionAccountItem::Single(self.instruction_account(account, &path))
+                })
+                .collect(),
+            args: self.fields(&arguments, &path),
+        }
+    }
+
+    fn instruction_account(
+        &mut self,
+        account: &InstructionAccountNode,
+        parent: &NodePath,
+    ) -> AnchorInstructionAccount {
+        let path = parent.child(account.kind(), Some(&account.name));
+        if account.is_signer == IsAccountSigner::Either {
+            self.warn(
+                "optional signers have no Anchor equivalent and are exported as non-signers",
+                &path,
+            );
+        }
+        let (address, pda) = match &account.default_value {
+            Some(InstructionInputValueNode::PublicKey(value)) => {
+                (Some(value.public_key.clone()), None)
+            }
+            Some(InstructionInputValueNode::Pda(value)) => (None, self.pda(value, &path)),
+            _ => (None, None),
+        };
+        AnchorInstructionAccount {
+            name: to_snake_case(&account.name),
+            docs: account.docs.to_vec(),
+            writable: account.is_writable,
+            signer: account.is_signer == IsAccountSigner::True,
+            optional: account.is_optional,
+            address,
+            pda,
+        }
+    }
+
+    /// Anchor only supports seeds whose value is constant or read
+    /// from an account address or an instruction argument.
+    fn pda(&mut self, value: &PdaValueNode, path: &NodePath) -> Option<AnchorPda> {
+        let pda = match &value.pda {
+            PdaValue::Nested(pda) => pda,
+            PdaValue::Linked(link) => {
+                match self.program.pdas.iter().find(|pda| pda.name == link.name) {
+                    Some(pda) => pda,
+                    None => {
+                        self.warn(
+                            format!("could not resolve PDA `{}`", link.name.as_str()),
+                            path,
+                        );
+                        return None;
+                    }
+                }
+            }
+        };
+
+        let mut seeds = vec![];
+        for seed in &pda.seeds {
+            let seed = match seed {
+                PdaSeedNode::Constant(seed) => {
+                    let value = match seed.value.clone() {
+                        ConstantPdaSeedValueNode::ProgramId(_) => {
+                            PublicKeyValueNode::new(self.program.public_key.as_str()).into()
+                        }
+                        value => ValueNode::try_from(value).ok()?,
+                    };
+                    self.const_seed(&value, &seed.r#type, path)?
+                }
+                PdaSeedNode::Variable(seed) => {
+                    let seed_value = value.seeds.iter().find(|value| value.name == seed.name);
+                    match seed_value.map(|value| &value.value) {
+                        Some(PdaSeedValueValueNode::Account(value)) => AnchorSeed::Account {
+                            r#type: None,
+                            path: to_snake_case(&value.name),
+                            account: None,
+                        },
+                        Some(PdaSeedValueValueNode::Argument(value)) => AnchorSeed::Arg {
+                            r#type: None,
+                            path: to_snake_case(&value.name),
+                        },
+                        Some(value) => {
+                            let value = ValueNode::try_from(value.clone()).ok()?;
+                            self.const_seed(&value, &seed.r#type, path)?
+                        }
+                        None => {
+                            self.warn(
+                                format!("missing value for PDA seed `{}`", seed.name.as_str()),
+                                path,
+                            );
+                            return None;
+                        }
+                    }
+                }
+            };
+            seeds.push(seed);
+        }
+
+        let program = match (value.program_id.as_ref(), &pda.program_id) {
+            (Some(PdaProgramIdValueNode::Account(value)), _) => Some(AnchorSeed::Account {
+                r#type: None,
+                path: to_snake_case(&value.name),
+                account: None,
+            }),
+            (Some(PdaProgramIdValueNode::Argument(value)), _) => Some(AnchorSeed::Arg {
+                r#type: None,
+                path: to_snake_case(&value.name),
+            }),
+            (None, Some(program_id)) => match bs58::decode(program_id).into_vec() {
+                Ok(bytes) => Some(AnchorSeed::Const {
+                    r#type: None,
+                    value: bytes.into(),
+                }),
+                Err(_) => {
+                    self.warn(format!("invalid PDA program `{program_id}`"), path);
+                    return None;
+                }
+            },
+            (None, None) => None,
+        };
+
+        Some(AnchorPda { seeds, program })
+    }
+
+    fn const_seed(
+        &mut self,
+        value: &ValueNode,
+        r#type: &TypeNode,
+        path: &NodePath,
+    ) -> Option<AnchorSeed> {
+        match self.codec.encode(value, r#type) {
+            Ok(bytes) => Some(AnchorSeed::Const {
+                r#type: None,
+                value: bytes.into(),
+            }),
+            Err(error) => {
+                self.warn(format!("could not encode PDA seed: {error}"), path);
+                None
+            }
+        }
+    }
+
+    /// Anchor discriminators are constant bytes at the beginning of the data.
+    /// Returns these bytes along with the remaining fields.
+    fn discriminator(
+        &mut self,
+        discriminators: &[DiscriminatorNode],
+        fields: &[StructFieldTypeNode],
+        path: &NodePath,
+    ) -> (Vec<u8>, Vec<StructFieldTypeNode>) {
+        let bytes = match discriminators {
+            [DiscriminatorNode::Constant(discriminator)] if discriminator.offset == 0 => {
+                self.codec.encode(
+                    &discriminator.constant.value,
+                    &discriminator.constant.r#type,
+                )
+            }
+            [DiscriminatorNode::Field(discriminator)]
+                if discriminator.offset == 0
+                    && fields
+                        .first()
+                        .is_some_and(|field| field.name == discriminator.name) =>
+            {
+                match &fields[0].default_value {
+                    Some(value) => self.codec.encode(value, &fields[0].r#type),
+                    None => Err(codama_errors::CodamaError::Encoding(
+                        "discriminator field has no default value".to_string(),
+                    )),
+                }
+            }
+            [] => {
+                self.warn("missing discriminator", path);
+                return (vec![], fields.to_vec());
+            }
+            _ => {
+                self.warn(
+                    "only a single constant or field discriminator at offset 0 is supported by Anchor",
+                    path,
+                );
+                return (vec![], fields.to_vec());
+            }
+        };
+        match bytes {
+            Ok(bytes) => {
+                // Anchor discriminators are implicit so we drop the field storing them, if any.
+                let stores_discriminator = fields.first().is_some_and(|field| {
+                    field.default_value.as_ref().is_some_and(|value| {
+                        self.codec
+                            .encode(value, &field.r#type)
+                            .is_ok_and(|encoded| encoded == bytes)
+                    })
+                });
+                match stores_discriminator {
+                    true => (bytes, fields[1..].to_vec()),
+                    false => (bytes, fields.to_vec()),
+                }
+            }
+            Err(error) => {
+                self.warn(format!("could not encode discriminator: {error}"), path);
+                (vec![], fields.to_vec())
+            }
+        }
+    }
+
+    fn defined_type(&mut self, defined_type: &DefinedTypeNode, parent: &NodePath) -> AnchorTypeDef {
+        let path = parent.child(defined_type.kind(), Some(&defined_type.name));
+        AnchorTypeDef {
+            name: to_pascal_case(&defined_type.name),
+            docs: defined_type.docs.to_vec(),
+            serialization: AnchorSerialization::default(),
+            repr: None,
+            r#type: self.type_def_type(&defined_type.r#type, &path),
+        }
+    }
+
+    fn type_def_type(&mut self, r#type: &TypeNode, path: &NodePath) -> AnchorTypeDefType {
+        match r#type {
+            TypeNode::Struct(node) => {
+                let fields = self.fields(&node.fields, path);
+                AnchorTypeDefType::Struct {
+                    fields: Some(AnchorFields::Named(fields)).filter(|f| !fields_is_empty(f)),
+                }
+            }
+            TypeNode::Tuple(node) => AnchorTypeDefType::Struct {
+                fields: Some(AnchorFields::Tuple(self.items(&node.items, path))),
+            },
+            TypeNode::Enum(node) => {
+                if !is_number(&node.size, U8) {
+                    self.warn("enums with a non-`u8` size have no Anchor equivalent", path);
+                }
+                AnchorTypeDefType::Enum {
+                    variants: node
+                        .variants
+                        .iter()
+                        .map(|variant| self.enum_variant(variant, path))
+                        .collect(),
+                }
+            }
+            r#type => AnchorTypeDefType::Type {
+                alias: self.r#type(r#type, path),
+            },
+        }
+    }
+
+    fn enum_variant(
+        &mut self,
+        variant: &EnumVariantTypeNode,
+        parent: &NodePath,
+    ) -> AnchorEnumVariant {
+        let path = parent.child(variant.kind(), Some(variant.name()));
+        let (discriminator, fields) = match variant {
+            EnumVariantTypeNode::Empty(node) => (node.discriminator, None),
+            EnumVariantTypeNode::Struct(node) => (
+                node.discriminator,
+                Some(AnchorFields::Named(
+                    self.fields(&node.r#struct.get_nested_type_node().fields, &path),
+                )),
+            ),
+            EnumVariantTypeNode::Tuple(node) => (
+                node.discriminator,
+                Some(AnchorFields::Tuple(
+                    self.items(&node.tuple.get_nested_type_node().items, &path),
+                )),
+            ),
+        };
+        if discriminator.is_some() {
+            self.warn(
+                "explicit variant discriminators have no Anchor equivalent",
+                &path,
+            );
+        }
+        AnchorEnumVariant {
+            name: to_pascal_case(variant.name()),
+            fields,
+        }
+    }
+
+    fn fields(&mut self, fields: &[StructFieldTypeNode], parent: &NodePath) -> Vec<AnchorField> {
+        fields
+            .iter()
+            .map(|field| {
+                let path = parent.child(field.kind(), Some(&field.name));
+                AnchorField {
+                    name: to_snake_case(&field.name),
+                    docs: field.docs.to_vec(),
+                    r#type: self.r#type(&field.r#type, &path),
+                }
+            })
+            .collect()
+    }
+
+    fn items(&mut self, items: &[TypeNode], path: &NodePath) -> Vec<AnchorType> {
+        items.iter().map(|item| self.r#type(item, path)).collect()
+    }
+
+    fn r#type(&mut self, r#type: &TypeNode, path: &NodePath) -> AnchorType {
+        match r#type {
+            TypeNode::Boolean(node) => {
+                if !is_number(&node.size, U8) {
+                    self.warn(
+                        "booleans with a non-`u8` size have no Anchor equivalent",
+                        path,
+                    );
+                }
+                primitive("bool")
+            }
+            TypeNode::Number(node) => self.number(node, path),
+            TypeNode::Amount(node) => self.number(node.number.get_nested_type_node(), path),
+            TypeNode::SolAmount(node) => self.number(node.number.get_nested_type_node(), path),
+            TypeNode::DateTime(node) => self.number(node.number.get_nested_type_node(), path),
+            TypeNode::PublicKey(_) => primitive("pubkey"),
+            TypeNode::String(_) => {
+                self.warn(
+                    "strings without a `u32` size prefix have no Anchor equivalent",
+                    path,
+                );
+                primitive("string")
+            }
+            TypeNode::Bytes(_) => {
+                self.warn(
+                    "bytes without a `u32` size prefix have no Anchor equivalent",
+                    path,
+                );
+                primitive("bytes")
+            }
+            TypeNode::SizePrefix(node) => {
+                match (node.r#type.as_ref(), is_number(&node.prefix, U32)) {
+                    (TypeNode::String(string), true) if string.encoding == crate::Utf8 => {
+                        primitive("string")
+                    }
+                    (TypeNode::Bytes(_), true) => primitive("bytes"),
+                    (inner, _) => {
+                        self.unsupported(node, path);
+                        self.r#type(inner, path)
+                    }
+                }
+            }
+            TypeNode::FixedSize(node) => match node.r#type.as_ref() {
+                TypeNode::Bytes(_) => AnchorType::Array {
+                    array: (
+                        Box::new(primitive("u8")),
+                        AnchorArrayLength::Value(node.size),
+                    ),
+                },
+                inner => {
+                    self.unsupported(node, path);
+                    self.r#type(inner, path)
+                }
+            },
+            TypeNode::Array(node) => {
+                let item = Box::new(self.r#type(&node.item, path));
+                match &node.count {
+                    CountNode::Fixed(count) => AnchorType::Array {
+                        array: (item, AnchorArrayLength::Value(count.value)),
+                    },
+                    CountNode::Prefixed(count) if is_number(&count.prefix, U32) => {
+                        AnchorType::Vec { vec: item }
+                    }
+                    count => {
+                        self.warn(
+                            format!("arrays with a `{}` have no Anchor equivalent", count.kind()),
+                            path,
+                        );
+                        AnchorType::Vec { vec: item }
+                    }
+                }
+            }
+            TypeNode::Set(node) => {
+                self.unsupported(node, path);
+                AnchorType::Vec {
+                    vec: Box::new(self.r#type(&node.item, path)),
+                }
+            }
+            TypeNode::Option(node) => {
+                let option = Box::new(self.r#type(&node.item, path));
+                match (node.fixed, &node.prefix) {
+                    (false, prefix) if is_number(prefix, U8) => AnchorType::Option { option },
+                    (true, prefix) if is_number(prefix, U32) => {
+                        AnchorType::COption { coption: option }
+                    }
+                    _ => {
+                        self.warn(
+                            "options other than `Option` and `COption` have no Anchor equivalent",
+                            path,
+                        );
+                        AnchorType::Option { option }
+                    }
+                }
+            }
+            TypeNode::ZeroableOption(node) => {
+                self.unsupported(node, path);
+                AnchorType::Option {
+                    option: Box::new(self.r#type(&node.item, path)),
+                }
+            }
+            TypeNode::RemainderOption(node) => {
+                self.unsupported(node, path);
+                AnchorType::Option {
+                    option: Box::new(self.r#type(&node.item, path)),
+                }
+            }
+            TypeNode::Link(node) => AnchorType::Defined {
+                defined: AnchorDefined::Link {
+                    name: to_pascal_case(&node.name),
+                    generics: vec![],
+                },
+            },
+            TypeNode::HiddenPrefix(node) => {
+                self.unsupported(node, path);
+                self.r#type(&node.r#type, path)
+            }
+            TypeNode::HiddenSuffix(node) => {
+                self.unsupported(node, path);
+                self.r#type(&node.r#type, path)
+            }
+            TypeNode::PreOffset(node) => {
+                self.unsupported(node, path);
+                self.r#type(&node.r#type, path)
+            }
+            TypeNode::PostOffset(node) => {
+                self.unsupported(node, path);
+                self.r#type(&node.r#type, path)
+            }
+            TypeNode::Sentinel(node) => {
+                self.unsupported(node, path);
+                self.r#type(&node.r#type, path)
+            }
+            TypeNode::Map(_) | TypeNode::Struct(_) | TypeNode::Tuple(_) | TypeNode::Enum(_) => {
+                self.warn(
+                    format!(
+                        "inline `{}` has no Anchor equivalent and is exported as bytes — consider extracting it into a defined type",
+                        r#type.kind()
+                    ),
+                    path,
+                );
+                primitive("bytes")
+            }
+        }
+    }
+
+    fn number(&mut self, node: &NumberTypeNode, path: &NodePath) -> AnchorType {
+        if node.endian == Endian::Big {
+            self.warn("big-endian numbers have no Anchor equivalent", path);
+        }
+        primitive(match node.format {
+            NumberFormat::U8 => "u8",
+            NumberFormat::U16 => "u16",
+            NumberFormat::U32 => "u32",
+            NumberFormat::U64 => "u64",
+            NumberFormat::U128 => "u128",
+            NumberFormat::I8 => "i8",
+            NumberFormat::I16 => "i16",
+            NumberFormat::I32 => "i32",
+            NumberFormat::I64 => "i64",
+            NumberFormat::I128 => "i128",
+            NumberFormat::F32 => "f32",
+            NumberFormat::F64 => "f64",
+            NumberFormat::ShortU16 => {
+                self.warn("`shortU16` numbers have no Anchor equivalent", path);
+                "u16"
+            }
+        })
+    }
+}
+
+fn primitive(name: &str) -> AnchorType {
+    AnchorType::Primitive(name.to_string())
+}
+
+fn is_number(node: &NestedTypeNode<NumberTypeNode>, format: NumberFormat) -> bool {
+    matches!(node, NestedTypeNode::Value(number) if number.format == format && number.endian == Endian::Little)
+}
+
+fn fields_is_empty(fields: &AnchorFields) -> bool {
+    matches!(fields, AnchorFields::Named(fields) if fields.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountNode, BytesTypeNode, DefinedTypeLinkNode, HiddenPrefixTypeNode, NumberValueNode,
+        SizeDiscriminatorNode, StructTypeNode, ZeroableOptionTypeNode,
+    };
+    use pretty_assertions::assert_eq;
+
+    const VAULT_IDL: &str = r#"{
+        "address": "Vau1t11111111111111111111111111111111111111",
+        "metadata": { "name": "vault", "version": "1.2.0", "spec": "0.1.0" },
+        "instructions": [
+            {
+                "name": "deposit",
+                "discriminator": [242, 35, 198, 137, 82, 225, 242, 182],
+                "accounts": [
+                    { "name": "owner", "writable": true, "signer": true },
+                    {
+                        "name": "vault",
+                        "writable": true,
+                        "pda": {
+                            "seeds": [
+                                { "kind": "const", "value": [118, 97, 117, 108, 116] },
+                                { "kind": "account", "path": "owner" },
+                                { "kind": "arg", "path": "index" }
+                            ]
+                        }
+                    },
+                    { "name": "system_program", "address": "11111111111111111111111111111111" }
+                ],
+                "args": [
+                    { "name": "index", "type": "u8" },
+                    { "name": "amount", "type": { "option": "u64" } }
+                ]
+            }
+        ],
+        "accounts": [{ "name": "Vault", "discriminator": [211, 8, 232, 43, 2, 152, 117, 119] }],
+        "errors": [{ "code": 6000, "name": "Empty", "msg": "The vault is empty" }],
+        "types": [
+            {
+                "name": "Vault",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "owner", "type": "pubkey" },
+                        { "name": "state", "type": { "defined": { "name": "State" } } },
+                        { "name": "seed", "type": { "array": ["u8", 32] } },
+                        { "name": "history", "type": { "vec": "u64" } },
+                        { "name": "label", "type": "string" }
+                    ]
+                }
+            },
+            {
+                "name": "State",
+                "type": {
+                    "kind": "enum",
+                    "variants": [
+                        { "name": "Open" },
+                        { "name": "Locked", "fields": [{ "name": "until", "type": "i64" }] }
+                    ]
+                }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn round_trip() {
+        let idl = AnchorIdl::from_json(VAULT_IDL).unwrap();
+        let program = ProgramNode::try_from(idl.clone()).unwrap();
+        let export = program.to_anchor_idl();
+        assert_eq!(export.warnings, vec![]);
+        assert_eq!(export.idl, idl);
+    }
+
+    #[test]
+    fn unsupported_types() {
+        let program = ProgramNode {
+            accounts: vec![AccountNode {
+                discriminators: vec![SizeDiscriminatorNode::new(8).into()],
+                ..AccountNode::new(
+                    "token",
+                    StructTypeNode::new(vec![
+                        StructFieldTypeNode::new(
+                            "amount",
+                            HiddenPrefixTypeNode::new(
+                                NumberTypeNode::le(crate::U64),
+                                vec![crate::ConstantValueNode::new(
+                                    NumberTypeNode::le(U8),
+                                    NumberValueNode::new(1u8),
+                                )],
+                            ),
+                        ),
+                        StructFieldTypeNode::new(
+                            "delegate",
+                            ZeroableOptionTypeNode::new(DefinedTypeLinkNode::new("delegate")),
+                        ),
+                        StructFieldTypeNode::new("data", BytesTypeNode::new()),
+                    ]),
+                )
+            }],
+            ..ProgramNode::new("token", "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+        };
+        let export = program.to_anchor_idl();
+        assert_eq!(
+            export
+                .warnings
+                .iter()
+                .map(|warning| warning.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "warning: only a single constant or field discriminator at offset 0 is supported by Anchor (at [programNode]token.[accountNode]token)",
+                "warning: `hiddenPrefixTypeNode` has no Anchor equivalent (at [programNode]token.[accountNode]token.[structFieldTypeNode]amount)",
+                "warning: `zeroableOptionTypeNode` has no Anchor equivalent (at [programNode]token.[accountNode]token.[structFieldTypeNode]delegate)",
+                "warning: bytes without a `u32` size prefix have no Anchor equivalent (at [programNode]token.[accountNode]token.[structFieldTypeNode]data)",
+            ]
+        );
+        assert_eq!(
+            export.idl.types[0].r#type,
+            AnchorTypeDefType::Struct {
+                fields: Some(AnchorFields::Named(vec![
+                    AnchorField {
+                        name: "amount".to_string(),
+                        docs: vec![],
+                        r#type: primitive("u64"),
+                    },
+                    AnchorField {
+                        name: "delegate".to_string(),
+                        docs: vec![],
+                        r#type: AnchorType::Option {
+                            option: Box::new(AnchorType::Defined {
+                                defined: AnchorDefined::Link {
+                                    name: "Delegate".to_string(),
+                                    generics: vec![],
+                                },
+                            }),
+                        },
+                    },
+                    AnchorField {
+                        name: "data".to_string(),
+                        docs: vec![],
+                        r#type: primitive("bytes"),
+                    },
+                ])),
+            }
+        );
+    }
+
+    #[test]
+    fn root_path() {
+        let root = RootNode::new(ProgramNode::new("token", "1111"));
+        assert_eq!(
+            root.to_anchor_idl().idl.metadata.unwrap().name,
+            Some("token".to_string())
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    AccountNode, DiscriminatorNode, NestedTypeNodeTrait, ProgramNode, StructTypeNode,
+    StructValueNode, TypeCodec, TypeNode, ValueNode,
+};
+use codama_errors::{CodamaError, CodamaResult};
+
+/// An account identified from raw data along with its decoded content.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DecodedAccount<'a> {
+    pub account: &'a AccountNode,
+    pub data: StructValueNode,
+}
+
+impl<'a> TypeCodec<'a> {
+    /// Whether the given data satisfies all discriminators of the given account.
+    /// Accounts without discriminators cannot be identified and never match.
+    pub fn matches_account(&self, account: &AccountNode, data: &[u8]) -> bool {
+        self.matches_discriminators(
+            &account.discriminators,
+            account.data.get_nested_type_node(),
+            data,
+        )
+    }
+
+    /// Returns the first account of the given list whose discriminators match the given data.
+    pub fn identify_account<I>(&self, accounts: I, data: &[u8]) -> Option<&'a AccountNode>
+    where
+        I: IntoIterator<Item = &'a AccountNode>,
+    {
+        accounts
+            .into_iter()
+            .find(|account| self.matches_account(account, data))
+    }
+
+    /// Decodes the given data as the given account, without checking its discriminators.
+    pub fn decode_account(
+        &self,
+        account: &AccountNode,
+        data: &[u8],
+    ) -> CodamaResult<StructValueNode> {
+        match self.decode(&TypeNode::from(account.data.clone()), data)? {
+            ValueNode::Struct(value) => Ok(value),
+            _ => unreachable!("struct types decode into struct values"),
+        }
+    }
+
+    /// Whether the given data satisfies all of the given discriminators, using the
+    /// provided struct to resolve the default values of field discriminators.
+    pub(super) fn matches_discriminators(
+        &self,
+        discriminators: &[DiscriminatorNode],
+        fields: &StructTypeNode,
+        data: &[u8],
+    ) -> bool {
+        !discriminators.is_empty()
+            && discriminators
+                .iter()
+                .all(|discriminator| self.matches_discriminator(discriminator, fields, data))
+    }
+
+    fn matches_discriminator(
+        &self,
+        discriminator: &DiscriminatorNode,
+        fields: &StructTypeNode,
+        data: &[u8],
+    ) -> bool {
+        let (offset, expected) = match discriminator {
+            DiscriminatorNode::Size(node) => return data.len() == node.size,
+            DiscriminatorNode::Constant(node) => (
+                node.offset,
+                self.encode(&node.constant.value, &node.constant.r#type),
+            ),
+            DiscriminatorNode::Field(node) => {
+                let Some(field) = fields.fields.iter().find(|field| field.name == node.name) else {
+                    return false;
+                };
+                let Some(default_value) = &field.default_value else {
+                    return false;
+                };
+                (node.offset, self.encode(default_value, &field.r#type))
+            }
+        };
+        match expected {
+            Ok(expected) => {
+                let bytes = offset
+                    .checked_add(expected.len())
+                    .and_then(|end| data.get(offset..end));
+                bytes == Some(expected.as_slice())
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl ProgramNode {
+    /// Returns the account of this program whose discriminators match the given data.
+    pub fn identify_account(&self, data: &[u8]) -> Option<&AccountNode> {
+        TypeCodec::from_program(self).identify_account(&self.accounts, data)
+    }
+
+    /// Identifies which account of this program the given data belongs to and decodes it.
+    pub fn decode_account(&self, data: &[u8]) -> CodamaResult<DecodedAccount<'_>> {
+        let codec = TypeCodec::from_program(self);
+        let account = codec
+            .identify_account(&self.accounts, data)
+            .ok_or_else(|| {
+                CodamaError::Decoding(format!(
+                    "no account of program \"{}\" matches the given data",
+                    self.name.as_str()
+                ))
+            })?;
+        Ok(DecodedAccount {
+            account,
+            data: codec.decode_account(account, data)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BooleanTypeNode, BooleanValueNode, BytesEncoding, ConstantDiscriminatorNode,
+        ConstantValueNode, FieldDiscriminatorNode, FixedSizeTypeNode, NumberTypeNode,
+        NumberValueNode, SizeDiscriminatorNode, StructFieldTypeNode, StructFieldValueNode,
+        StructTypeNode, U32, U64, U8,
+    };
+
+    fn program() -> ProgramNode {
+        let counter = AccountNode {
+            discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+            ..AccountNode::new(
+                "counter",
+                StructTypeNode::new(vec![
+                    StructFieldTypeNode {
+                        default_value: Some(NumberValueNode::new(1u8).into()),
+                        ..StructFieldTypeNode::new("discriminator", NumberTypeNode::le(U8))
+                    },
+                    StructFieldTypeNode::new("count", NumberTypeNode::le(U32)),
+                ]),
+            )
+        };
+        let config = AccountNode {
+            discriminators: vec![ConstantDiscriminatorNode::new(
+                ConstantValueNode::bytes(BytesEncoding::Base16, "ff00"),
+                0,
+            )
+            .into()],
+            ..AccountNode::new(
+                "config",
+                StructTypeNode::new(vec![
+                    StructFieldTypeNode::new(
+                        "discriminator",
+                        FixedSizeTypeNode::new(crate::BytesTypeNode::new(), 2),
+                    ),
+                    StructFieldTypeNode::new("paused", BooleanTypeNode::default()),
+                ]),
+            )
+        };
+        let vault = AccountNode {
+            discriminators: vec![SizeDiscriminatorNode::new(8).into()],
+            ..AccountNode::new(
+                "vault",
+                StructTypeNode::new(vec![StructFieldTypeNode::new(
+                    "lamports",
+                    NumberTypeNode::le(U64),
+                )]),
+            )
+        };
+        ProgramNode::new("myProgram", "1111")
+            .add_account(counter)
+            .add_account(config)
+            .add_account(vault)
+    }
+
+    #[test]
+    fn field_discriminator() {
+        let program = program();
+        let decoded = program.decode_account(&[1, 42, 0, 0, 0]).unwrap();
+        assert_eq!(decoded.account.name.as_str(), "counter");
+        assert_eq!(
+            decoded.data,
+            StructValueNode::new(vec![
+                StructFieldValueNode::new("discriminator", NumberValueNode::new(1u8)),
+                StructFieldValueNode::new("count", NumberValueNode::new(42u8)),
+            ])
+        );
+    }
+
+    #[test]
+    fn constant_discriminator() {
+        let program = program();
+        let decoded = program.decode_account(&[0xff, 0x00, 1]).unwrap();
+        assert_eq!(decoded.account.name.as_str(), "config");
+        assert_eq!(
+            decoded.data.fields[1],
+            StructFieldValueNode::new("paused", BooleanValueNode::new(true))
+        );
+    }
+
+    #[test]
+    fn size_discriminator() {
+        let program = program();
+        let account = program.identify_account(&[0; 8]).unwrap();
+        assert_eq!(account.name.as_str(), "vault");
+    }
+
+    #[test]
+    fn no_matching_account() {
+        let program = program();
+        assert_eq!(program.identify_account(&[2, 0, 0]), None);
+        assert!(matches!(
+            program.decode_account(&[2, 0, 0]),
+            Err(CodamaError::Decoding(_))
+        ));
+    }
+
+    #[test]
+    fn accounts_without_discriminators_never_match() {
+        let account = AccountNode::new("empty", StructTypeNode::new(vec![]));
+        assert!(!TypeCodec::new().matches_account(&account, &[]));
+    }
+
+    #[test]
+    fn overflowing_discriminator_offsets() {
+        let account = AccountNode {
+            discriminators: vec![ConstantDiscriminatorNode::new(
+                ConstantValueNode::bytes(BytesEncoding::Base16, "ff"),
+                usize::MAX,
+            )
+            .into()],
+            ..AccountNode::new("overflow", StructTypeNode::new(vec![]))
+        };
+        assert!(!TypeCodec::new().matches_account(&account, &[0xff]));
+    }
+}
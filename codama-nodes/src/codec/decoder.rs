@@ -0,0 +1,486 @@
+use super::encoder::{absolute_offset, relative_offset, variant_discriminator, variant_name};
+use crate::{
+    ArrayValueNode, BooleanValueNode, BytesValueNode, ConstantValueNode, CountNode,
+    DefinedTypeLinkNode, Endian, EnumValueNode, EnumVariantData, EnumVariantTypeNode,
+    MapEntryValueNode, MapValueNode, NoneValueNode, Number, NumberFormat, NumberTypeNode,
+    NumberValueNode, PostOffsetStrategy, PreOffsetStrategy, PublicKeyValueNode, SetValueNode,
+    SomeValueNode, StringValueNode, StructFieldValueNode, StructValueNode, TupleValueNode,
+    TypeCodec, TypeNode, ValueNode,
+};
+use codama_errors::{CodamaError, CodamaResult};
+
+/// A byte slice that reads from a movable offset.
+#[derive(Debug)]
+pub(super) struct Reader<'b> {
+    bytes: &'b [u8],
+    pub offset: usize,
+}
+
+impl<'b> Reader<'b> {
+    pub fn new(bytes: &'b [u8], offset: usize) -> Self {
+        Self { bytes, offset }
+    }
+
+    pub fn read(&mut self, len: usize) -> CodamaResult<&'b [u8]> {
+        let bytes = self.peek(len)?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    pub fn peek(&self, len: usize) -> CodamaResult<&'b [u8]> {
+        self.offset
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.offset..end))
+            .ok_or_else(|| {
+                error(format!(
+                    "expected {len} bytes at offset {}, found {}",
+                    self.offset,
+                    self.remaining().len()
+                ))
+            })
+    }
+
+    pub fn remaining(&self) -> &'b [u8] {
+        self.bytes.get(self.offset..).unwrap_or_default()
+    }
+}
+
+fn error<T: Into<String>>(message: T) -> CodamaError {
+    CodamaError::Decoding(message.into())
+}
+
+impl TypeCodec<'_> {
+    pub(super) fn read(
+        &self,
+        reader: &mut Reader,
+        r#type: &TypeNode,
+        link_name: Option<&str>,
+    ) -> CodamaResult<ValueNode> {
+        match r#type {
+            TypeNode::Amount(node) => self.read(reader, &node.number.clone().into(), None),
+            TypeNode::Array(node) => {
+                let items = self.read_items(reader, &node.count, &[&node.item], |reader| {
+                    self.read(reader, &node.item, None)
+                })?;
+                Ok(ArrayValueNode::new(items).into())
+            }
+            TypeNode::Boolean(node) => {
+                let value = self.read_usize(reader, &node.size.clone().into())?;
+                Ok(BooleanValueNode::new(value != 0).into())
+            }
+            TypeNode::Bytes(_) => {
+                let bytes = reader.read(reader.remaining().len())?;
+                Ok(BytesValueNode::base16(crate::Base16.bytes_to_string(bytes)?).into())
+            }
+            TypeNode::DateTime(node) => self.read(reader, &node.number.clone().into(), None),
+            TypeNode::Enum(node) => {
+                let discriminator = self.read_usize(reader, &node.size.clone().into())?;
+                let variant = node
+                    .variants
+                    .iter()
+                    .enumerate()
+                    .find(|(index, variant)| {
+                        variant_discriminator(variant).unwrap_or(*index) == discriminator
+                    })
+                    .map(|(_, variant)| variant)
+                    .ok_or_else(|| error(format!("unknown enum discriminator {discriminator}")))?;
+                let data = match variant {
+                    EnumVariantTypeNode::Empty(_) => None,
+                    EnumVariantTypeNode::Struct(variant) => {
+                        match self.read(reader, &variant.r#struct.clone().into(), None)? {
+                            ValueNode::Struct(data) => Some(EnumVariantData::Struct(data)),
+                            _ => unreachable!("struct types decode into struct values"),
+                        }
+                    }
+                    EnumVariantTypeNode::Tuple(variant) => {
+                        match self.read(reader, &variant.tuple.clone().into(), None)? {
+                            ValueNode::Tuple(data) => Some(EnumVariantData::Tuple(data)),
+                            _ => unreachable!("tuple types decode into tuple values"),
+                        }
+                    }
+                };
+                let r#enum = DefinedTypeLinkNode::new(link_name.unwrap_or_default());
+                Ok(EnumValueNode::new(r#enum, variant_name(variant), data).into())
+            }
+            TypeNode::FixedSize(node) => {
+                let bytes = reader.read(node.size)?;
+                self.read(&mut Reader::new(bytes, 0), &node.r#type, link_name)
+            }
+            TypeNode::HiddenPrefix(node) => {
+                self.read_constants(reader, &node.prefix)?;
+                self.read(reader, &node.r#type, link_name)
+            }
+            TypeNode::HiddenSuffix(node) => {
+                let value = self.read(reader, &node.r#type, link_name)?;
+                self.read_constants(reader, &node.suffix)?;
+                Ok(value)
+            }
+            TypeNode::Link(link) => {
+                let defined_type = self.resolve(link)?;
+                self.read(
+                    reader,
+                    &defined_type.r#type,
+                    Some(defined_type.name.as_str()),
+                )
+            }
+            TypeNode::Map(node) => {
+                let item_types = [node.key.as_ref(), node.value.as_ref()];
+                let entries = self.read_items(reader, &node.count, &item_types, |reader| {
+                    let key = self.read(reader, &node.key, None)?;
+                    let value = self.read(reader, &node.value, None)?;
+                    Ok(MapEntryValueNode::new(key, value))
+                })?;
+                Ok(MapValueNode::new(entries).into())
+            }
+            TypeNode::Number(node) => Ok(NumberValueNode::new(read_number(reader, node)?).into()),
+            TypeNode::Option(node) => {
+                let prefix = self.read_usize(reader, &node.prefix.clone().into())?;
+                match prefix {
+                    0 => {
+                        if node.fixed {
+                            let size = self.fixed_size(&node.item)?.ok_or_else(|| {
+                                error("fixed options must have a fixed-size item")
+                            })?;
+                            reader.read(size)?;
+                        }
+                        Ok(NoneValueNode::new().into())
+                    }
+                    1 => Ok(SomeValueNode::new(self.read(reader, &node.item, None)?).into()),
+                    prefix => Err(error(format!("invalid option prefix {prefix}"))),
+                }
+            }
+            TypeNode::PostOffset(node) => {
+                let pre_offset = reader.offset;
+                let value = self.read(reader, &node.r#type, link_name)?;
+                reader.offset = match node.strategy {
+                    PostOffsetStrategy::Absolute => {
+                        absolute_offset(node.offset, reader.bytes.len())
+                    }
+                    PostOffsetStrategy::Padded | PostOffsetStrategy::Relative => {
+                        relative_offset(reader.offset, node.offset)
+                    }
+                    PostOffsetStrategy::PreOffset => relative_offset(pre_offset, node.offset),
+                };
+                Ok(value)
+            }
+            TypeNode::PreOffset(node) => {
+                reader.offset = match node.strategy {
+                    PreOffsetStrategy::Absolute => absolute_offset(node.offset, reader.bytes.len()),
+                    PreOffsetStrategy::Padded | PreOffsetStrategy::Relative => {
+                        relative_offset(reader.offset, node.offset)
+                    }
+                };
+                self.read(reader, &node.r#type, link_name)
+            }
+            TypeNode::PublicKey(_) => {
+                let bytes = reader.read(32)?;
+                Ok(PublicKeyValueNode::new(crate::Base58.bytes_to_string(bytes)?).into())
+            }
+            TypeNode::RemainderOption(node) => match reader.remaining().is_empty() {
+                true => Ok(NoneValueNode::new().into()),
+                false => Ok(SomeValueNode::new(self.read(reader, &node.item, None)?).into()),
+            },
+            TypeNode::Sentinel(node) => {
+                let sentinel = self.encode(&node.sentinel.value, &node.sentinel.r#type)?;
+                let remaining = reader.remaining();
+                let position = (!sentinel.is_empty())
+                    .then(|| {
+                        remaining
+                            .windows(sentinel.len())
+                            .position(|window| window == sentinel)
+                    })
+                    .flatten()
+                    .ok_or_else(|| error("sentinel bytes not found"))?;
+                let bytes = reader.read(position)?;
+                reader.read(sentinel.len())?;
+                self.read(&mut Reader::new(bytes, 0), &node.r#type, link_name)
+            }
+            TypeNode::Set(node) => {
+                let items = self.read_items(reader, &node.count, &[&node.item], |reader| {
+                    self.read(reader, &node.item, None)
+                })?;
+                Ok(SetValueNode::new(items).into())
+            }
+            TypeNode::SizePrefix(node) => {
+                let size = self.read_usize(reader, &(*node.prefix).clone().into())?;
+                let bytes = reader.read(size)?;
+                self.read(&mut Reader::new(bytes, 0), &node.r#type, link_name)
+            }
+            TypeNode::SolAmount(node) => self.read(reader, &node.number.clone().into(), None),
+            TypeNode::String(node) => {
+                let bytes = reader.read(reader.remaining().len())?;
+                Ok(StringValueNode::new(node.encoding.bytes_to_string(bytes)?).into())
+            }
+            TypeNode::Struct(node) => {
+                let fields = node
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let value = self.read(reader, &field.r#type, None)?;
+                        Ok(StructFieldValueNode::new(field.name.clone(), value))
+                    })
+                    .collect::<CodamaResult<Vec<_>>>()?;
+                Ok(StructValueNode::new(fields).into())
+            }
+            TypeNode::Tuple(node) => {
+                let items = node
+                    .items
+                    .iter()
+                    .map(|item| self.read(reader, item, None))
+                    .collect::<CodamaResult<Vec<_>>>()?;
+                Ok(TupleValueNode::new(items).into())
+            }
+            TypeNode::ZeroableOption(node) => {
+                let zero_value = match &node.zero_value {
+                    Some(zero_value) => self.encode(&zero_value.value, &zero_value.r#type)?,
+                    None => {
+                        let size = self
+                            .fixed_size(&node.item)?
+                            .ok_or_else(|| error("zeroable options must have a fixed-size item"))?;
+                        vec![0; size]
+                    }
+                };
+                match reader.peek(zero_value.len())? == zero_value.as_slice() {
+                    true => {
+                        reader.read(zero_value.len())?;
+                        Ok(NoneValueNode::new().into())
+                    }
+                    false => Ok(SomeValueNode::new(self.read(reader, &node.item, None)?).into()),
+                }
+            }
+        }
+    }
+
+    /// Reads a number that is used as a length, a prefix or a discriminator.
+    fn read_usize(&self, reader: &mut Reader, r#type: &TypeNode) -> CodamaResult<usize> {
+        match self.read(reader, r#type, None)? {
+            ValueNode::Number(NumberValueNode {
+                number: Number::UnsignedInteger(number),
+            }) => usize::try_from(number).map_err(|_| error("number is too large")),
+            ValueNode::Number(NumberValueNode {
+                number: Number::SignedInteger(number),
+            }) => usize::try_from(number).map_err(|_| error("number must be positive")),
+            _ => Err(error("expected an integer")),
+        }
+    }
+
+    fn read_count(&self, reader: &mut Reader, count: &CountNode) -> CodamaResult<Option<usize>> {
+        match count {
+            CountNode::Fixed(count) => Ok(Some(count.value)),
+            CountNode::Prefixed(count) => self
+                .read_usize(reader, &count.prefix.clone().into())
+                .map(Some),
+            CountNode::Remainder(_) => Ok(None),
+        }
+    }
+
+    /// Reads the items of an array, a set or a map — whose items are made of the
+    /// given types — using the given item reader.
+    ///
+    /// Every item must consume at least one byte — or its fixed size when known — so
+    /// counts exceeding the remaining bytes are rejected before reading any item.
+    fn read_items<T>(
+        &self,
+        reader: &mut Reader,
+        count: &CountNode,
+        item_types: &[&TypeNode],
+        mut read_item: impl FnMut(&mut Reader) -> CodamaResult<T>,
+    ) -> CodamaResult<Vec<T>> {
+        let count = self.read_count(reader, count)?;
+        if let Some(count) = count {
+            let item_size = item_types
+                .iter()
+                .map(|r#type| self.fixed_size(r#type).ok().flatten().unwrap_or(0))
+                .sum::<usize>()
+                .max(1);
+            let remaining = reader.remaining().len();
+            if count.saturating_mul(item_size) > remaining {
+                return Err(error(format!(
+                    "expected {count} items of at least {item_size} bytes, found {remaining} bytes"
+                )));
+            }
+        }
+        let mut items = vec![];
+        while count.is_some_and(|count| items.len() < count)
+            || (count.is_none() && !reader.remaining().is_empty())
+        {
+            let offset = reader.offset;
+            items.push(read_item(reader)?);
+            ensure_progress(reader, offset)?;
+        }
+        Ok(items)
+    }
+
+    fn read_constants(
+        &self,
+        reader: &mut Reader,
+        constants: &[ConstantValueNode],
+    ) -> CodamaResult<()> {
+        for constant in constants {
+            let expected = self.encode(&constant.value, &constant.r#type)?;
+            if reader.read(expected.len())? != expected.as_slice() {
+                return Err(error("hidden constant bytes do not match"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Collections must consume bytes on each item to avoid looping forever
+/// or allocating items for a count that the data cannot hold.
+fn ensure_progress(reader: &Reader, offset: usize) -> CodamaResult<()> {
+    match reader.offset <= offset {
+        true => Err(error("collection items must not be empty")),
+        false => Ok(()),
+    }
+}
+
+fn read_number(reader: &mut Reader, node: &NumberTypeNode) -> CodamaResult<Number> {
+    macro_rules! read_int {
+        ($int:ty, $variant:ident, $cast:ty) => {{
+            let bytes = reader
+                .read(std::mem::size_of::<$int>())?
+                .try_into()
+                .unwrap();
+            let value = match node.endian {
+                Endian::Little => <$int>::from_le_bytes(bytes),
+                Endian::Big => <$int>::from_be_bytes(bytes),
+            };
+            Number::$variant(
+                <$cast>::try_from(value)
+                    .map_err(|_| error(format!("number {value} does not fit in a number value")))?,
+            )
+        }};
+    }
+    macro_rules! read_float {
+        ($float:ty) => {{
+            let bytes = reader
+                .read(std::mem::size_of::<$float>())?
+                .try_into()
+                .unwrap();
+            let value = match node.endian {
+                Endian::Little => <$float>::from_le_bytes(bytes),
+                Endian::Big => <$float>::from_be_bytes(bytes),
+            };
+            Number::Float(value as f64)
+        }};
+    }
+    Ok(match node.format {
+        NumberFormat::U8 => read_int!(u8, UnsignedInteger, u64),
+        NumberFormat::U16 => read_int!(u16, UnsignedInteger, u64),
+        NumberFormat::U32 => read_int!(u32, UnsignedInteger, u64),
+        NumberFormat::U64 => read_int!(u64, UnsignedInteger, u64),
+        NumberFormat::U128 => read_int!(u128, UnsignedInteger, u64),
+        NumberFormat::I8 => read_int!(i8, SignedInteger, i64),
+        NumberFormat::I16 => read_int!(i16, SignedInteger, i64),
+        NumberFormat::I32 => read_int!(i32, SignedInteger, i64),
+        NumberFormat::I64 => read_int!(i64, SignedInteger, i64),
+        NumberFormat::I128 => read_int!(i128, SignedInteger, i64),
+        NumberFormat::F32 => read_float!(f32),
+        NumberFormat::F64 => read_float!(f64),
+        NumberFormat::ShortU16 => {
+            let mut value = 0u32;
+            for index in 0..3 {
+                let byte = reader.read(1)?[0];
+                value |= ((byte & 0x7f) as u32) << (index * 7);
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+            Number::UnsignedInteger(
+                u16::try_from(value).map_err(|_| error("invalid shortU16 value"))? as u64,
+            )
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ArrayTypeNode, MapTypeNode, NumberFormat::*, OptionTypeNode, SetTypeNode, StructTypeNode,
+    };
+
+    #[test]
+    fn reader() {
+        let mut reader = Reader::new(&[1, 2, 3], 1);
+        assert_eq!(reader.peek(2).unwrap(), &[2, 3]);
+        assert_eq!(reader.read(1).unwrap(), &[2]);
+        assert_eq!(reader.remaining(), &[3]);
+        assert!(reader.read(2).is_err());
+        assert_eq!(reader.offset, 2);
+    }
+
+    #[test]
+    fn reader_out_of_bounds() {
+        let reader = Reader::new(&[1], 5);
+        assert_eq!(reader.remaining(), &[] as &[u8]);
+        assert!(reader.peek(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn option_prefixes() {
+        let r#type = OptionTypeNode::new(NumberTypeNode::le(U8)).into();
+        let codec = TypeCodec::new();
+        assert_eq!(
+            codec.decode(&r#type, &[0]).unwrap(),
+            NoneValueNode::new().into()
+        );
+        assert_eq!(
+            codec.decode(&r#type, &[1, 42]).unwrap(),
+            SomeValueNode::new(NumberValueNode::new(42u8)).into()
+        );
+        assert!(codec.decode(&r#type, &[2, 42]).is_err());
+    }
+
+    #[test]
+    fn fixed_options() {
+        let r#type = OptionTypeNode::fixed(NumberTypeNode::le(U16)).into();
+        assert_eq!(
+            TypeCodec::new()
+                .decode_at(&r#type, &[0, 0, 0, 7], 0)
+                .unwrap(),
+            (NoneValueNode::new().into(), 3)
+        );
+    }
+
+    #[test]
+    fn counts_larger_than_the_remaining_bytes() {
+        let codec = TypeCodec::new();
+        let array = ArrayTypeNode::prefixed(NumberTypeNode::le(U32), NumberTypeNode::le(U32));
+        assert!(codec
+            .decode(&array.into(), &[0xff, 0xff, 0xff, 0xff, 1, 0, 0, 0])
+            .is_err());
+
+        // Two u16 items need four bytes.
+        let set = SetTypeNode::prefixed(NumberTypeNode::le(U16), NumberTypeNode::le(U8));
+        assert!(codec.decode(&set.into(), &[2, 1, 0, 2]).is_err());
+
+        let map = MapTypeNode::prefixed(
+            NumberTypeNode::le(U8),
+            NumberTypeNode::le(U16),
+            NumberTypeNode::le(U8),
+        );
+        assert!(codec.decode(&map.clone().into(), &[2, 1, 1, 0, 2]).is_err());
+        assert_eq!(
+            codec.decode(&map.into(), &[1, 1, 1, 0]).unwrap(),
+            MapValueNode::new(vec![MapEntryValueNode::new(
+                NumberValueNode::new(1u8),
+                NumberValueNode::new(1u16)
+            )])
+            .into()
+        );
+    }
+
+    #[test]
+    fn empty_items() {
+        let codec = TypeCodec::new();
+        let empty = StructTypeNode::new(vec![]);
+        let prefixed = ArrayTypeNode::prefixed(empty.clone(), NumberTypeNode::le(U8));
+        assert!(codec.decode(&prefixed.into(), &[1, 0]).is_err());
+        let fixed = ArrayTypeNode::fixed(empty.clone(), 1);
+        assert!(codec.decode(&fixed.into(), &[0]).is_err());
+        let remainder = ArrayTypeNode::remainder(empty);
+        assert!(codec.decode(&remainder.into(), &[0]).is_err());
+    }
+}
@@ -0,0 +1,619 @@
+use crate::{
+    ConstantValueNode, CountNode, DefaultValueStrategy, Endian, EnumVariantData,
+    EnumVariantTypeNode, HasKind, NestedTypeNode, Number, NumberFormat, NumberTypeNode,
+    PostOffsetStrategy, PreOffsetStrategy, StructTypeNode, StructValueNode, TupleTypeNode,
+    TupleValueNode, TypeCodec, TypeNode, ValueNode,
+};
+use codama_errors::{CodamaError, CodamaResult};
+
+/// The maximum number of bytes a value can be encoded into, i.e. the maximum
+/// size of account data, so that offsets cannot cause huge allocations.
+const MAX_LEN: usize = 10 * 1024 * 1024;
+
+/// A byte buffer that writes at a movable offset.
+#[derive(Debug, Default)]
+pub(super) struct Writer {
+    bytes: Vec<u8>,
+    offset: usize,
+}
+
+impl Writer {
+    pub fn write(&mut self, bytes: &[u8]) -> CodamaResult<()> {
+        let end = self.end(bytes.len())?;
+        if self.bytes.len() < end {
+            self.bytes.resize(end, 0);
+        }
+        self.bytes[self.offset..end].copy_from_slice(bytes);
+        self.offset = end;
+        Ok(())
+    }
+
+    pub fn write_zeros(&mut self, len: usize) -> CodamaResult<()> {
+        self.end(len)?;
+        self.write(&vec![0; len])
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn seek(&mut self, offset: usize) -> CodamaResult<()> {
+        if offset > MAX_LEN {
+            return Err(error(format!(
+                "offset {offset} exceeds the maximum size of {MAX_LEN} bytes"
+            )));
+        }
+        self.offset = offset;
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.bytes.len() < self.offset {
+            self.bytes.resize(self.offset, 0);
+        }
+        self.bytes
+    }
+
+    /// The offset after writing `len` bytes, which must not exceed the maximum size.
+    fn end(&self, len: usize) -> CodamaResult<usize> {
+        self.offset
+            .checked_add(len)
+            .filter(|end| *end <= MAX_LEN)
+            .ok_or_else(|| error(format!("cannot encode more than {MAX_LEN} bytes")))
+    }
+}
+
+fn error<T: Into<String>>(message: T) -> CodamaError {
+    CodamaError::Encoding(message.into())
+}
+
+fn unexpected_value(expected: &str, value: &ValueNode) -> CodamaError {
+    error(format!("expected {expected}, found `{}`", value.kind()))
+}
+
+impl TypeCodec<'_> {
+    pub(super) fn write(
+        &self,
+        writer: &mut Writer,
+        value: &ValueNode,
+        r#type: &TypeNode,
+        link_name: Option<&str>,
+    ) -> CodamaResult<()> {
+        match r#type {
+            TypeNode::Amount(node) => self.write_nested_number(writer, value, &node.number),
+            TypeNode::Array(node) => match value {
+                ValueNode::Array(value) => {
+                    self.write_items(writer, &value.items, &node.item, &node.count)
+                }
+                _ => Err(unexpected_value("an ArrayValueNode", value)),
+            },
+            TypeNode::Boolean(node) => match value {
+                ValueNode::Boolean(value) => self.write(
+                    writer,
+                    &crate::NumberValueNode::new(value.boolean as u8).into(),
+                    &node.size.clone().into(),
+                    None,
+                ),
+                _ => Err(unexpected_value("a BooleanValueNode", value)),
+            },
+            TypeNode::Bytes(_) => match value {
+                ValueNode::Bytes(value) => {
+                    writer.write(&value.encoding.string_to_bytes(&value.data)?)?;
+                    Ok(())
+                }
+                _ => Err(unexpected_value("a BytesValueNode", value)),
+            },
+            TypeNode::DateTime(node) => self.write_nested_number(writer, value, &node.number),
+            TypeNode::Enum(node) => {
+                let ValueNode::Enum(value) = value else {
+                    return Err(unexpected_value("an EnumValueNode", value));
+                };
+                let (index, variant) = node
+                    .variants
+                    .iter()
+                    .enumerate()
+                    .find(|(_, variant)| variant_name(variant) == value.variant.as_str())
+                    .ok_or_else(|| {
+                        error(format!(
+                            "unknown variant `{}` for enum `{}`",
+                            value.variant.as_str(),
+                            link_name.unwrap_or(value.r#enum.name.as_str())
+                        ))
+                    })?;
+                let discriminator = variant_discriminator(variant).unwrap_or(index);
+                self.write(
+                    writer,
+                    &crate::NumberValueNode::new(discriminator as u64).into(),
+                    &node.size.clone().into(),
+                    None,
+                )?;
+                match (variant, &value.value) {
+                    (EnumVariantTypeNode::Empty(_), None) => Ok(()),
+                    (EnumVariantTypeNode::Struct(variant), Some(EnumVariantData::Struct(data))) => {
+                        self.write_struct(writer, data, &variant.r#struct)
+                    }
+                    (EnumVariantTypeNode::Tuple(variant), Some(EnumVariantData::Tuple(data))) => {
+                        self.write_tuple(writer, data, &variant.tuple)
+                    }
+                    _ => Err(error(format!(
+                        "invalid data for enum variant `{}`",
+                        value.variant.as_str()
+                    ))),
+                }
+            }
+            TypeNode::FixedSize(node) => {
+                let bytes = self.encode(value, &node.r#type)?;
+                if bytes.len() > node.size {
+                    return Err(error(format!(
+                        "expected at most {} bytes, found {}",
+                        node.size,
+                        bytes.len()
+                    )));
+                }
+                writer.write(&bytes)?;
+                writer.write_zeros(node.size - bytes.len())
+            }
+            TypeNode::HiddenPrefix(node) => {
+                self.write_constants(writer, &node.prefix)?;
+                self.write(writer, value, &node.r#type, link_name)
+            }
+            TypeNode::HiddenSuffix(node) => {
+                self.write(writer, value, &node.r#type, link_name)?;
+                self.write_constants(writer, &node.suffix)
+            }
+            TypeNode::Link(link) => {
+                let defined_type = self.resolve(link)?;
+                self.write(
+                    writer,
+                    value,
+                    &defined_type.r#type,
+                    Some(defined_type.name.as_str()),
+                )
+            }
+            TypeNode::Map(node) => {
+                let ValueNode::Map(value) = value else {
+                    return Err(unexpected_value("a MapValueNode", value));
+                };
+                self.write_count(writer, value.entries.len(), &node.count)?;
+                for entry in &value.entries {
+                    self.write(writer, &entry.key, &node.key, None)?;
+                    self.write(writer, &entry.value, &node.value, None)?;
+                }
+                Ok(())
+            }
+            TypeNode::Number(node) => match value {
+                ValueNode::Number(value) => {
+                    writer.write(&number_to_bytes(value.number, node)?)?;
+                    Ok(())
+                }
+                _ => Err(unexpected_value("a NumberValueNode", value)),
+            },
+            TypeNode::Option(node) => {
+                let prefix = node.prefix.clone().into();
+                match value {
+                    ValueNode::None(_) => {
+                        self.write(
+                            writer,
+                            &crate::NumberValueNode::new(0u8).into(),
+                            &prefix,
+                            None,
+                        )?;
+                        if node.fixed {
+                            let size = self.fixed_size(&node.item)?.ok_or_else(|| {
+                                error("fixed options must have a fixed-size item")
+                            })?;
+                            writer.write_zeros(size)?;
+                        }
+                        Ok(())
+                    }
+                    ValueNode::Some(value) => {
+                        self.write(
+                            writer,
+                            &crate::NumberValueNode::new(1u8).into(),
+                            &prefix,
+                            None,
+                        )?;
+                        self.write(writer, &value.value, &node.item, None)
+                    }
+                    _ => Err(unexpected_value(
+                        "a NoneValueNode or a SomeValueNode",
+                        value,
+                    )),
+                }
+            }
+            TypeNode::PostOffset(node) => {
+                let pre_offset = writer.offset();
+                self.write(writer, value, &node.r#type, link_name)?;
+                writer.seek(match node.strategy {
+                    PostOffsetStrategy::Absolute => absolute_offset(node.offset, writer.len()),
+                    PostOffsetStrategy::Padded | PostOffsetStrategy::Relative => {
+                        relative_offset(writer.offset(), node.offset)
+                    }
+                    PostOffsetStrategy::PreOffset => relative_offset(pre_offset, node.offset),
+                })
+            }
+            TypeNode::PreOffset(node) => {
+                writer.seek(match node.strategy {
+                    PreOffsetStrategy::Absolute => absolute_offset(node.offset, writer.len()),
+                    PreOffsetStrategy::Padded | PreOffsetStrategy::Relative => {
+                        relative_offset(writer.offset(), node.offset)
+                    }
+                })?;
+                self.write(writer, value, &node.r#type, link_name)
+            }
+            TypeNode::PublicKey(_) => match value {
+                ValueNode::PublicKey(value) => {
+                    let bytes = crate::Base58.string_to_bytes(&value.public_key)?;
+                    if bytes.len() != 32 {
+                        return Err(error(format!("invalid public key `{}`", value.public_key)));
+                    }
+                    writer.write(&bytes)?;
+                    Ok(())
+                }
+                _ => Err(unexpected_value("a PublicKeyValueNode", value)),
+            },
+            TypeNode::RemainderOption(node) => match value {
+                ValueNode::None(_) => Ok(()),
+                ValueNode::Some(value) => self.write(writer, &value.value, &node.item, None),
+                _ => Err(unexpected_value(
+                    "a NoneValueNode or a SomeValueNode",
+                    value,
+                )),
+            },
+            TypeNode::Sentinel(node) => {
+                let bytes = self.encode(value, &node.r#type)?;
+                let sentinel = self.encode(&node.sentinel.value, &node.sentinel.r#type)?;
+                if contains(&bytes, &sentinel) {
+                    return Err(error("encoded value contains the sentinel bytes"));
+                }
+                writer.write(&bytes)?;
+                writer.write(&sentinel)?;
+                Ok(())
+            }
+            TypeNode::Set(node) => match value {
+                ValueNode::Set(value) => {
+                    self.write_items(writer, &value.items, &node.item, &node.count)
+                }
+                _ => Err(unexpected_value("a SetValueNode", value)),
+            },
+            TypeNode::SizePrefix(node) => {
+                let bytes = self.encode(value, &node.r#type)?;
+                self.write(
+                    writer,
+                    &crate::NumberValueNode::new(bytes.len() as u64).into(),
+                    &(*node.prefix).clone().into(),
+                    None,
+                )?;
+                writer.write(&bytes)?;
+                Ok(())
+            }
+            TypeNode::SolAmount(node) => self.write_nested_number(writer, value, &node.number),
+            TypeNode::String(node) => match value {
+                ValueNode::String(value) => {
+                    writer.write(&node.encoding.string_to_bytes(&value.string)?)?;
+                    Ok(())
+                }
+                _ => Err(unexpected_value("a StringValueNode", value)),
+            },
+            TypeNode::Struct(node) => match value {
+                ValueNode::Struct(value) => self.write_struct_fields(writer, value, node),
+                _ => Err(unexpected_value("a StructValueNode", value)),
+            },
+            TypeNode::Tuple(node) => match value {
+                ValueNode::Tuple(value) => self.write_tuple_items(writer, value, node),
+                _ => Err(unexpected_value("a TupleValueNode", value)),
+            },
+            TypeNode::ZeroableOption(node) => match value {
+                ValueNode::None(_) => match &node.zero_value {
+                    Some(zero_value) => self.write_constants(writer, [zero_value]),
+                    None => {
+                        let size = self
+                            .fixed_size(&node.item)?
+                            .ok_or_else(|| error("zeroable options must have a fixed-size item"))?;
+                        writer.write_zeros(size)?;
+                        Ok(())
+                    }
+                },
+                ValueNode::Some(value) => self.write(writer, &value.value, &node.item, None),
+                _ => Err(unexpected_value(
+                    "a NoneValueNode or a SomeValueNode",
+                    value,
+                )),
+            },
+        }
+    }
+
+    fn write_nested_number(
+        &self,
+        writer: &mut Writer,
+        value: &ValueNode,
+        number: &NestedTypeNode<NumberTypeNode>,
+    ) -> CodamaResult<()> {
+        self.write(writer, value, &number.clone().into(), None)
+    }
+
+    fn write_count(&self, writer: &mut Writer, len: usize, count: &CountNode) -> CodamaResult<()> {
+        match count {
+            CountNode::Fixed(count) if count.value != len => Err(error(format!(
+                "expected {} items, found {len}",
+                count.value
+            ))),
+            CountNode::Fixed(_) | CountNode::Remainder(_) => Ok(()),
+            CountNode::Prefixed(count) => self.write(
+                writer,
+                &crate::NumberValueNode::new(len as u64).into(),
+                &count.prefix.clone().into(),
+                None,
+            ),
+        }
+    }
+
+    fn write_items(
+        &self,
+        writer: &mut Writer,
+        items: &[ValueNode],
+        item_type: &TypeNode,
+        count: &CountNode,
+    ) -> CodamaResult<()> {
+        self.write_count(writer, items.len(), count)?;
+        items
+            .iter()
+            .try_for_each(|item| self.write(writer, item, item_type, None))
+    }
+
+    fn write_struct(
+        &self,
+        writer: &mut Writer,
+        value: &StructValueNode,
+        r#type: &NestedTypeNode<StructTypeNode>,
+    ) -> CodamaResult<()> {
+        self.write(writer, &value.clone().into(), &r#type.clone().into(), None)
+    }
+
+    fn write_struct_fields(
+        &self,
+        writer: &mut Writer,
+        value: &StructValueNode,
+        r#type: &StructTypeNode,
+    ) -> CodamaResult<()> {
+        for field in &r#type.fields {
+            let provided = value.fields.iter().find(|f| f.name == field.name);
+            let field_value = match (&field.default_value, field.default_value_strategy) {
+                (Some(default), Some(DefaultValueStrategy::Omitted)) => default,
+                (_, _) if provided.is_some() => &provided.unwrap().value,
+                (Some(default), _) => default,
+                (None, _) => {
+                    return Err(error(format!(
+                        "missing struct field `{}`",
+                        field.name.as_str()
+                    )))
+                }
+            };
+            self.write(writer, field_value, &field.r#type, None)?;
+        }
+        Ok(())
+    }
+
+    fn write_tuple(
+        &self,
+        writer: &mut Writer,
+        value: &TupleValueNode,
+        r#type: &NestedTypeNode<TupleTypeNode>,
+    ) -> CodamaResult<()> {
+        self.write(writer, &value.clone().into(), &r#type.clone().into(), None)
+    }
+
+    fn write_tuple_items(
+        &self,
+        writer: &mut Writer,
+        value: &TupleValueNode,
+        r#type: &TupleTypeNode,
+    ) -> CodamaResult<()> {
+        if value.items.len() != r#type.items.len() {
+            return Err(error(format!(
+                "expected {} tuple items, found {}",
+                r#type.items.len(),
+                value.items.len()
+            )));
+        }
+        value
+            .items
+            .iter()
+            .zip(&r#type.items)
+            .try_for_each(|(item, item_type)| self.write(writer, item, item_type, None))
+    }
+
+    fn write_constants<'c, I>(&self, writer: &mut Writer, constants: I) -> CodamaResult<()>
+    where
+        I: IntoIterator<Item = &'c ConstantValueNode>,
+    {
+        constants
+            .into_iter()
+            .try_for_each(|constant| self.write(writer, &constant.value, &constant.r#type, None))
+    }
+}
+
+pub(super) fn variant_name(variant: &EnumVariantTypeNode) -> &str {
+    match variant {
+        EnumVariantTypeNode::Empty(variant) => variant.name.as_str(),
+        EnumVariantTypeNode::Struct(variant) => variant.name.as_str(),
+        EnumVariantTypeNode::Tuple(variant) => variant.name.as_str(),
+    }
+}
+
+pub(super) fn variant_discriminator(variant: &EnumVariantTypeNode) -> Option<usize> {
+    match variant {
+        EnumVariantTypeNode::Empty(variant) => variant.discriminator,
+        EnumVariantTypeNode::Struct(variant) => variant.discriminator,
+        EnumVariantTypeNode::Tuple(variant) => variant.discriminator,
+    }
+}
+
+pub(super) fn absolute_offset(offset: i32, len: usize) -> usize {
+    match offset >= 0 {
+        true => offset as usize,
+        false => len.saturating_sub(offset.unsigned_abs() as usize),
+    }
+}
+
+pub(super) fn relative_offset(current: usize, offset: i32) -> usize {
+    current.saturating_add_signed(offset as isize)
+}
+
+pub(super) fn contains(bytes: &[u8], pattern: &[u8]) -> bool {
+    !pattern.is_empty() && bytes.windows(pattern.len()).any(|window| window == pattern)
+}
+
+fn number_to_bytes(number: Number, node: &NumberTypeNode) -> CodamaResult<Vec<u8>> {
+    let out_of_range = || {
+        error(format!(
+            "number {number:?} is out of range for {:?}",
+            node.format
+        ))
+    };
+    macro_rules! int_bytes {
+        ($int:ty) => {{
+            let value: $int = match number {
+                Number::UnsignedInteger(n) => <$int>::try_from(n).map_err(|_| out_of_range())?,
+                Number::SignedInteger(n) => <$int>::try_from(n).map_err(|_| out_of_range())?,
+                Number::Float(_) => return Err(out_of_range()),
+            };
+            match node.endian {
+                Endian::Little => value.to_le_bytes().to_vec(),
+                Endian::Big => value.to_be_bytes().to_vec(),
+            }
+        }};
+    }
+    macro_rules! float_bytes {
+        ($float:ty) => {{
+            let value = match number {
+                Number::UnsignedInteger(n) => n as $float,
+                Number::SignedInteger(n) => n as $float,
+                Number::Float(n) => n as $float,
+            };
+            match node.endian {
+                Endian::Little => value.to_le_bytes().to_vec(),
+                Endian::Big => value.to_be_bytes().to_vec(),
+            }
+        }};
+    }
+    Ok(match node.format {
+        NumberFormat::U8 => int_bytes!(u8),
+        NumberFormat::U16 => int_bytes!(u16),
+        NumberFormat::U32 => int_bytes!(u32),
+        NumberFormat::U64 => int_bytes!(u64),
+        NumberFormat::U128 => int_bytes!(u128),
+        NumberFormat::I8 => int_bytes!(i8),
+        NumberFormat::I16 => int_bytes!(i16),
+        NumberFormat::I32 => int_bytes!(i32),
+        NumberFormat::I64 => int_bytes!(i64),
+        NumberFormat::I128 => int_bytes!(i128),
+        NumberFormat::F32 => float_bytes!(f32),
+        NumberFormat::F64 => float_bytes!(f64),
+        NumberFormat::ShortU16 => {
+            let mut value = match number {
+                Number::UnsignedInteger(n) => u16::try_from(n).map_err(|_| out_of_range())?,
+                Number::SignedInteger(n) => u16::try_from(n).map_err(|_| out_of_range())?,
+                Number::Float(_) => return Err(out_of_range()),
+            };
+            let mut bytes = vec![];
+            loop {
+                let byte = (value & 0x7f) as u8;
+                value >>= 7;
+                match value {
+                    0 => {
+                        bytes.push(byte);
+                        break bytes;
+                    }
+                    _ => bytes.push(byte | 0x80),
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        FixedSizeTypeNode, NoneValueNode, NumberFormat::*, NumberValueNode, OptionTypeNode,
+        PreOffsetTypeNode, StringTypeNode, StringValueNode,
+    };
+
+    #[test]
+    fn writer_pads_gaps_with_zeros() {
+        let mut writer = Writer::default();
+        writer.write(&[1, 2]).unwrap();
+        writer.seek(4).unwrap();
+        writer.write(&[3]).unwrap();
+        writer.seek(1).unwrap();
+        writer.write(&[4]).unwrap();
+        assert_eq!(writer.offset(), 2);
+        assert_eq!(writer.finish(), vec![1, 4, 0, 0, 3]);
+    }
+
+    #[test]
+    fn writer_is_bounded() {
+        let mut writer = Writer::default();
+        assert!(writer.seek(usize::MAX).is_err());
+        assert!(writer.write_zeros(usize::MAX).is_err());
+        writer.seek(MAX_LEN).unwrap();
+        assert!(writer.write(&[1]).is_err());
+        assert_eq!(writer.len(), 0);
+    }
+
+    #[test]
+    fn absolute_pre_offsets_are_bounded() {
+        let r#type = PreOffsetTypeNode::new(
+            NumberTypeNode::le(U8),
+            PreOffsetStrategy::Absolute,
+            i32::MAX,
+        );
+        let value = NumberValueNode::new(1u8).into();
+        assert!(TypeCodec::new().encode(&value, &r#type.into()).is_err());
+    }
+
+    #[test]
+    fn fixed_sizes() {
+        let r#type: TypeNode = FixedSizeTypeNode::new(StringTypeNode::utf8(), 4).into();
+        let codec = TypeCodec::new();
+        assert_eq!(
+            codec
+                .encode(&StringValueNode::new("ab").into(), &r#type)
+                .unwrap(),
+            vec![b'a', b'b', 0, 0]
+        );
+        assert!(codec
+            .encode(&StringValueNode::new("abcde").into(), &r#type)
+            .is_err());
+    }
+
+    #[test]
+    fn fixed_options() {
+        let r#type = OptionTypeNode::fixed(NumberTypeNode::le(U16)).into();
+        assert_eq!(
+            TypeCodec::new()
+                .encode(&NoneValueNode::new().into(), &r#type)
+                .unwrap(),
+            vec![0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn numbers_out_of_range() {
+        let value = NumberValueNode::new(256u16).into();
+        assert!(TypeCodec::new()
+            .encode(&value, &NumberTypeNode::le(U8).into())
+            .is_err());
+        assert_eq!(
+            TypeCodec::new()
+                .encode(&value, &NumberTypeNode::le(ShortU16).into())
+                .unwrap(),
+            vec![0x80, 0x02]
+        );
+    }
+}
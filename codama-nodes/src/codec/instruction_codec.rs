@@ -0,0 +1,511 @@
+use crate::{
+    CamelCaseString, InstructionAccountNode, InstructionNode, InstructionOptionalAccountStrategy,
+    InstructionRemainingAccountsNode, InstructionRemainingAccountsNodeValue, ProgramNode,
+    StructFieldTypeNode, StructTypeNode, StructValueNode, TypeCodec, ValueNode,
+};
+use codama_errors::{CodamaError, CodamaResult};
+
+/// An instruction identified from raw data along with its decoded arguments and accounts.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DecodedInstruction<'a> {
+    pub instruction: &'a InstructionNode,
+    pub arguments: StructValueNode,
+    pub accounts: Vec<DecodedInstructionAccount<'a>>,
+    /// The account keys provided after all the named accounts of the instruction.
+    pub remaining_accounts: Vec<DecodedRemainingAccount<'a>>,
+}
+
+/// An instruction account matched with the address it was given.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DecodedInstructionAccount<'a> {
+    pub account: &'a InstructionAccountNode,
+    /// The address of the account, or `None` if an optional account was not provided.
+    pub address: Option<String>,
+}
+
+/// A remaining account key matched with the remaining accounts it belongs to.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DecodedRemainingAccount<'a> {
+    /// The remaining accounts of the instruction this key belongs to, or `None`
+    /// if the instruction does not declare remaining accounts for it.
+    pub remaining_accounts: Option<&'a InstructionRemainingAccountsNode>,
+    pub address: String,
+}
+
+impl DecodedRemainingAccount<'_> {
+    /// The name of the argument or resolver providing this remaining account, if any.
+    pub fn name(&self) -> Option<&CamelCaseString> {
+        self.remaining_accounts.map(|node| match &node.value {
+            InstructionRemainingAccountsNodeValue::Argument(value) => &value.name,
+            InstructionRemainingAccountsNodeValue::Resolver(value) => &value.name,
+        })
+    }
+}
+
+impl<'a> TypeCodec<'a> {
+    /// Whether the given data satisfies all discriminators of the given instruction.
+    /// Instructions without discriminators cannot be identified and never match.
+    pub fn matches_instruction(&self, instruction: &InstructionNode, data: &[u8]) -> bool {
+        self.matches_discriminators(
+            &instruction.discriminators,
+            &arguments_type(instruction),
+            data,
+        )
+    }
+
+    /// Returns the first instruction of the given list whose discriminators match the given data.
+    pub fn identify_instruction<I>(
+        &self,
+        instructions: I,
+        data: &[u8],
+    ) -> Option<&'a InstructionNode>
+    where
+        I: IntoIterator<Item = &'a InstructionNode>,
+    {
+        instructions
+            .into_iter()
+            .find(|instruction| self.matches_instruction(instruction, data))
+    }
+
+    /// Decodes the given data as the arguments of the given instruction,
+    /// without checking its discriminators.
+    pub fn decode_instruction_arguments(
+        &self,
+        instruction: &InstructionNode,
+        data: &[u8],
+    ) -> CodamaResult<StructValueNode> {
+        match self.decode(&arguments_type(instruction).into(), data)? {
+            ValueNode::Struct(value) => Ok(value),
+            _ => unreachable!("struct types decode into struct values"),
+        }
+    }
+
+    /// Encodes the given argument values as the data of the given instruction.
+    /// Arguments that are not provided fall back to their default values,
+    /// and arguments using the `Omitted` strategy always use their default values.
+    pub fn encode_instruction_arguments(
+        &self,
+        instruction: &InstructionNode,
+        arguments: &StructValueNode,
+    ) -> CodamaResult<Vec<u8>> {
+        self.encode(
+            &arguments.clone().into(),
+            &arguments_type(instruction).into(),
+        )
+    }
+}
+
+impl ProgramNode {
+    /// Returns the instruction of this program whose discriminators match the given data.
+    pub fn identify_instruction(&self, data: &[u8]) -> Option<&InstructionNode> {
+        TypeCodec::from_program(self).identify_instruction(&self.instructions, data)
+    }
+
+    /// Identifies which instruction of this program the given data belongs to,
+    /// decodes its arguments and names the given account keys.
+    pub fn decode_instruction<S: AsRef<str>>(
+        &self,
+        data: &[u8],
+        account_keys: &[S],
+    ) -> CodamaResult<DecodedInstruction<'_>> {
+        let codec = TypeCodec::from_program(self);
+        let instruction = codec
+            .identify_instruction(&self.instructions, data)
+            .ok_or_else(|| {
+                CodamaError::Decoding(format!(
+                    "no instruction of program \"{}\" matches the given data",
+                    self.name.as_str()
+                ))
+            })?;
+        let arguments = codec.decode_instruction_arguments(instruction, data)?;
+        let (accounts, remaining_accounts) =
+            decode_instruction_accounts(instruction, &arguments, &self.public_key, account_keys)?;
+        Ok(DecodedInstruction {
+            instruction,
+            arguments,
+            accounts,
+            remaining_accounts,
+        })
+    }
+
+    /// Encodes the data of the instruction with the given name from the given argument values.
+    pub fn encode_instruction<T>(
+        &self,
+        name: T,
+        arguments: &StructValueNode,
+    ) -> CodamaResult<Vec<u8>>
+    where
+        T: Into<CamelCaseString>,
+    {
+        let name = name.into();
+        let instruction = self
+            .instructions
+            .iter()
+            .find(|instruction| instruction.name == name)
+            .ok_or_else(|| {
+                CodamaError::Encoding(format!(
+                    "unknown instruction \"{}\" in program \"{}\"",
+                    name.as_str(),
+                    self.name.as_str()
+                ))
+            })?;
+        TypeCodec::from_program(self).encode_instruction_arguments(instruction, arguments)
+    }
+}
+
+/// The struct type describing the data of the given instruction.
+fn arguments_type(instruction: &InstructionNode) -> StructTypeNode {
+    StructTypeNode::new(
+        instruction
+            .arguments
+            .iter()
+            .map(|argument| StructFieldTypeNode {
+                name: argument.name.clone(),
+                default_value_strategy: argument.default_value_strategy,
+                docs: argument.docs.clone(),
+                r#type: argument.r#type.clone(),
+                default_value: argument
+                    .default_value
+                    .clone()
+                    .and_then(|value| ValueNode::try_from(value).ok()),
+            })
+            .collect(),
+    )
+}
+
+/// Assigns the given account keys to the accounts of the given instruction.
+///
+/// With the `ProgramId` strategy, optional accounts are always provided and
+/// the program ID marks them as missing. With the `Omitted` strategy, optional
+/// accounts are filled in order for as long as there are spare keys.
+///
+/// Remaining accounts provided by an array argument have a known length, so their
+/// keys are reserved before filling optional accounts. The first remaining accounts
+/// of unknown length receive any other key left after the named accounts.
+fn decode_instruction_accounts<'a, S: AsRef<str>>(
+    instruction: &'a InstructionNode,
+    arguments: &StructValueNode,
+    program_id: &str,
+    account_keys: &[S],
+) -> CodamaResult<(
+    Vec<DecodedInstructionAccount<'a>>,
+    Vec<DecodedRemainingAccount<'a>>,
+)> {
+    let strategy = instruction.optional_account_strategy;
+    let required = match strategy {
+        InstructionOptionalAccountStrategy::ProgramId => instruction.accounts.len(),
+        InstructionOptionalAccountStrategy::Omitted => instruction
+            .accounts
+            .iter()
+            .filter(|account| !account.is_optional)
+            .count(),
+    };
+    let remaining_lengths = instruction
+        .remaining_accounts
+        .iter()
+        .map(|node| remaining_accounts_length(node, arguments))
+        .collect::<Vec<_>>();
+    let reserved = remaining_lengths.iter().flatten().sum::<usize>();
+    if account_keys.len() < required + reserved {
+        return Err(CodamaError::Decoding(format!(
+            "instruction \"{}\" expects at least {} accounts, found {}",
+            instruction.name.as_str(),
+            required + reserved,
+            account_keys.len()
+        )));
+    }
+
+    let mut spare_keys = account_keys.len() - required - reserved;
+    let mut keys = account_keys.iter().map(|key| key.as_ref().to_string());
+    let accounts = instruction
+        .accounts
+        .iter()
+        .map(|account| {
+            let address = match (account.is_optional, strategy) {
+                (false, _) => keys.next(),
+                (true, InstructionOptionalAccountStrategy::ProgramId) => {
+                    keys.next().filter(|key| key != program_id)
+                }
+                (true, InstructionOptionalAccountStrategy::Omitted) if spare_keys > 0 => {
+                    spare_keys -= 1;
+                    keys.next()
+                }
+                (true, InstructionOptionalAccountStrategy::Omitted) => None,
+            };
+            DecodedInstructionAccount { account, address }
+        })
+        .collect::<Vec<_>>();
+
+    let mut unknown_keys = keys.len() - reserved;
+    let mut remaining_accounts = Vec::with_capacity(keys.len());
+    for (node, length) in instruction.remaining_accounts.iter().zip(remaining_lengths) {
+        let length = length.unwrap_or_else(|| std::mem::take(&mut unknown_keys));
+        remaining_accounts.extend(keys.by_ref().take(length).map(|address| {
+            DecodedRemainingAccount {
+                remaining_accounts: Some(node),
+                address,
+            }
+        }));
+    }
+    remaining_accounts.extend(keys.map(|address| DecodedRemainingAccount {
+        remaining_accounts: None,
+        address,
+    }));
+    Ok((accounts, remaining_accounts))
+}
+
+/// The number of keys provided by the given remaining accounts, when
+/// they come from an array argument whose length is known.
+fn remaining_accounts_length(
+    node: &InstructionRemainingAccountsNode,
+    arguments: &StructValueNode,
+) -> Option<usize> {
+    let InstructionRemainingAccountsNodeValue::Argument(argument) = &node.value else {
+        return None;
+    };
+    let field = arguments
+        .fields
+        .iter()
+        .find(|field| field.name == argument.name)?;
+    match &field.value {
+        ValueNode::Array(value) => Some(value.items.len()),
+        ValueNode::Set(value) => Some(value.items.len()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ArgumentValueNode, ArrayTypeNode, DefaultValueStrategy, FieldDiscriminatorNode,
+        InstructionArgumentNode, NumberTypeNode, NumberValueNode, StructFieldValueNode, U32, U8,
+    };
+
+    fn discriminator(value: u8) -> InstructionArgumentNode {
+        InstructionArgumentNode {
+            default_value: Some(NumberValueNode::new(value).into()),
+            default_value_strategy: Some(DefaultValueStrategy::Omitted),
+            ..InstructionArgumentNode::new("discriminator", NumberTypeNode::le(U8))
+        }
+    }
+
+    fn program(strategy: InstructionOptionalAccountStrategy) -> ProgramNode {
+        ProgramNode::new("counter", "Counter111")
+            .add_instruction(InstructionNode {
+                name: "initialize".into(),
+                accounts: vec![InstructionAccountNode::new("counter", true, true)],
+                arguments: vec![discriminator(0)],
+                discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+                ..InstructionNode::default()
+            })
+            .add_instruction(InstructionNode {
+                name: "increment".into(),
+                optional_account_strategy: strategy,
+                accounts: vec![
+                    InstructionAccountNode::new("counter", true, false),
+                    InstructionAccountNode {
+                        is_optional: true,
+                        ..InstructionAccountNode::new("logger", false, false)
+                    },
+                    InstructionAccountNode::new("authority", false, true),
+                ],
+                arguments: vec![
+                    discriminator(1),
+                    InstructionArgumentNode::new("amount", NumberTypeNode::le(U32)),
+                ],
+                remaining_accounts: vec![crate::InstructionRemainingAccountsNode {
+                    is_optional: true,
+                    is_signer: Default::default(),
+                    is_writable: false,
+                    docs: Default::default(),
+                    value: ArgumentValueNode::new("extras").into(),
+                }],
+                discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+                ..InstructionNode::default()
+            })
+    }
+
+    fn addresses<'a>(decoded: &'a DecodedInstruction) -> Vec<Option<&'a str>> {
+        decoded
+            .accounts
+            .iter()
+            .map(|account| account.address.as_deref())
+            .collect()
+    }
+
+    fn remaining<'a>(decoded: &'a DecodedInstruction) -> Vec<(Option<&'a str>, &'a str)> {
+        decoded
+            .remaining_accounts
+            .iter()
+            .map(|account| {
+                (
+                    account.name().map(|name| name.as_str()),
+                    account.address.as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encode_and_decode() {
+        let program = program(InstructionOptionalAccountStrategy::ProgramId);
+        let arguments = StructValueNode::new(vec![StructFieldValueNode::new(
+            "amount",
+            NumberValueNode::new(5u8),
+        )]);
+        let data = program.encode_instruction("increment", &arguments).unwrap();
+        assert_eq!(data, vec![1, 5, 0, 0, 0]);
+
+        let decoded = program
+            .decode_instruction(&data, &["counter", "logger", "authority", "extra"])
+            .unwrap();
+        assert_eq!(decoded.instruction.name.as_str(), "increment");
+        assert_eq!(
+            decoded.arguments,
+            StructValueNode::new(vec![
+                StructFieldValueNode::new("discriminator", NumberValueNode::new(1u8)),
+                StructFieldValueNode::new("amount", NumberValueNode::new(5u8)),
+            ])
+        );
+        assert_eq!(
+            addresses(&decoded),
+            vec![Some("counter"), Some("logger"), Some("authority")]
+        );
+        assert_eq!(remaining(&decoded), vec![(Some("extras"), "extra")]);
+    }
+
+    #[test]
+    fn omitted_arguments_use_their_default_value() {
+        let program = program(InstructionOptionalAccountStrategy::ProgramId);
+        let arguments = StructValueNode::new(vec![
+            StructFieldValueNode::new("discriminator", NumberValueNode::new(9u8)),
+            StructFieldValueNode::new("amount", NumberValueNode::new(5u8)),
+        ]);
+        let data = program.encode_instruction("increment", &arguments).unwrap();
+        assert_eq!(data[0], 1);
+    }
+
+    #[test]
+    fn missing_argument() {
+        let program = program(InstructionOptionalAccountStrategy::ProgramId);
+        let arguments = StructValueNode::new(vec![]);
+        assert!(matches!(
+            program.encode_instruction("increment", &arguments),
+            Err(CodamaError::Encoding(_))
+        ));
+        assert!(matches!(
+            program.encode_instruction("unknown", &arguments),
+            Err(CodamaError::Encoding(_))
+        ));
+    }
+
+    #[test]
+    fn optional_accounts_as_program_id() {
+        let program = program(InstructionOptionalAccountStrategy::ProgramId);
+        let decoded = program
+            .decode_instruction(&[1, 0, 0, 0, 0], &["counter", "Counter111", "authority"])
+            .unwrap();
+        assert_eq!(
+            addresses(&decoded),
+            vec![Some("counter"), None, Some("authority")]
+        );
+    }
+
+    #[test]
+    fn omitted_optional_accounts() {
+        let program = program(InstructionOptionalAccountStrategy::Omitted);
+        let decoded = program
+            .decode_instruction(&[1, 0, 0, 0, 0], &["counter", "authority"])
+            .unwrap();
+        assert_eq!(
+            addresses(&decoded),
+            vec![Some("counter"), None, Some("authority")]
+        );
+        assert!(decoded.remaining_accounts.is_empty());
+    }
+
+    #[test]
+    fn omitted_optional_accounts_with_remaining_accounts() {
+        let program = program(InstructionOptionalAccountStrategy::Omitted);
+        let decoded = program
+            .decode_instruction(
+                &[1, 0, 0, 0, 0],
+                &["counter", "logger", "authority", "extra"],
+            )
+            .unwrap();
+        assert_eq!(
+            addresses(&decoded),
+            vec![Some("counter"), Some("logger"), Some("authority")]
+        );
+        assert_eq!(remaining(&decoded), vec![(Some("extras"), "extra")]);
+
+        let program = program.add_instruction(InstructionNode {
+            name: "distribute".into(),
+            optional_account_strategy: InstructionOptionalAccountStrategy::Omitted,
+            accounts: vec![
+                InstructionAccountNode {
+                    is_optional: true,
+                    ..InstructionAccountNode::new("logger", false, false)
+                },
+                InstructionAccountNode::new("authority", false, true),
+            ],
+            arguments: vec![
+                discriminator(2),
+                InstructionArgumentNode::new(
+                    "shares",
+                    ArrayTypeNode::prefixed(NumberTypeNode::le(U8), NumberTypeNode::le(U8)),
+                ),
+            ],
+            remaining_accounts: vec![crate::InstructionRemainingAccountsNode {
+                is_optional: false,
+                is_signer: Default::default(),
+                is_writable: true,
+                docs: Default::default(),
+                value: ArgumentValueNode::new("shares").into(),
+            }],
+            discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+            ..InstructionNode::default()
+        });
+
+        // The two shares reserve the last two keys, so the logger cannot be present.
+        let decoded = program
+            .decode_instruction(&[2, 2, 50, 50], &["authority", "alice", "bob"])
+            .unwrap();
+        assert_eq!(addresses(&decoded), vec![None, Some("authority")]);
+        assert_eq!(
+            remaining(&decoded),
+            vec![(Some("shares"), "alice"), (Some("shares"), "bob")]
+        );
+
+        let decoded = program
+            .decode_instruction(&[2, 1, 100], &["logger", "authority", "alice"])
+            .unwrap();
+        assert_eq!(addresses(&decoded), vec![Some("logger"), Some("authority")]);
+        assert_eq!(remaining(&decoded), vec![(Some("shares"), "alice")]);
+
+        assert!(matches!(
+            program.decode_instruction(&[2, 2, 50, 50], &["authority", "alice"]),
+            Err(CodamaError::Decoding(_))
+        ));
+    }
+
+    #[test]
+    fn not_enough_accounts() {
+        let program = program(InstructionOptionalAccountStrategy::ProgramId);
+        assert!(matches!(
+            program.decode_instruction(&[1, 0, 0, 0, 0], &["counter", "authority"]),
+            Err(CodamaError::Decoding(_))
+        ));
+    }
+
+    #[test]
+    fn identify_instruction() {
+        let program = program(InstructionOptionalAccountStrategy::ProgramId);
+        assert_eq!(
+            program.identify_instruction(&[0]).map(|i| i.name.as_str()),
+            Some("initialize")
+        );
+        assert_eq!(program.identify_instruction(&[2]), None);
+    }
+}
@@ -0,0 +1,11 @@
+mod account_decoder;
+mod decoder;
+mod encoder;
+mod instruction_codec;
+mod program_address;
+mod type_codec;
+
+pub use account_decoder::*;
+pub use instruction_codec::*;
+pub use program_address::*;
+pub use type_codec::*;
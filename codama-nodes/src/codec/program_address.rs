@@ -0,0 +1,240 @@
+use crate::{
+    ConstantPdaSeedValueNode, PdaNode, PdaSeedNode, PdaSeedValueNode, PublicKeyValueNode,
+    TypeCodec, ValueNode,
+};
+use codama_errors::{CodamaError, CodamaResult};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use sha2::{Digest, Sha256};
+
+const MAX_SEEDS: usize = 16;
+const MAX_SEED_LENGTH: usize = 32;
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+impl TypeCodec<'_> {
+    /// Serializes the seeds of the given PDA for the given program,
+    /// using the provided values for its variable seeds.
+    pub fn pda_seeds(
+        &self,
+        pda: &PdaNode,
+        program_id: &str,
+        seed_values: &[PdaSeedValueNode],
+    ) -> CodamaResult<Vec<Vec<u8>>> {
+        pda.seeds
+            .iter()
+            .map(|seed| match seed {
+                PdaSeedNode::Constant(seed) => {
+                    let value = match seed.value.clone() {
+                        ConstantPdaSeedValueNode::ProgramId(_) => {
+                            PublicKeyValueNode::new(program_id).into()
+                        }
+                        value => ValueNode::try_from(value)?,
+                    };
+                    self.encode(&value, &seed.r#type)
+                }
+                PdaSeedNode::Variable(seed) => {
+                    let seed_value = seed_values
+                        .iter()
+                        .find(|value| value.name == seed.name)
+                        .ok_or_else(|| {
+                            error(format!("missing value for seed `{}`", seed.name.as_str()))
+                        })?;
+                    let value = ValueNode::try_from(seed_value.value.clone())?;
+                    self.encode(&value, &seed.r#type)
+                }
+            })
+            .collect()
+    }
+
+    /// Derives the address and bump of the given PDA for the given program.
+    pub fn find_pda(
+        &self,
+        pda: &PdaNode,
+        program_id: &str,
+        seed_values: &[PdaSeedValueNode],
+    ) -> CodamaResult<(String, u8)> {
+        let seeds = self.pda_seeds(pda, program_id, seed_values)?;
+        let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        find_program_address(&seeds, program_id)
+    }
+}
+
+impl PdaNode {
+    /// Derives the address and bump of this PDA for the given program.
+    /// Seeds that link to defined types require [`TypeCodec::find_pda`] instead.
+    pub fn find_program_address(
+        &self,
+        program_id: &str,
+        seed_values: &[PdaSeedValueNode],
+    ) -> CodamaResult<(String, u8)> {
+        TypeCodec::new().find_pda(self, program_id, seed_values)
+    }
+}
+
+/// Finds the first off-curve address for the given seeds,
+/// trying bumps from 255 down to 0 like Solana's `find_program_address`.
+pub fn find_program_address(seeds: &[&[u8]], program_id: &str) -> CodamaResult<(String, u8)> {
+    let program_id = decode_address(program_id)?;
+    if seeds.len() >= MAX_SEEDS {
+        return Err(error(format!(
+            "expected at most {} seeds, found {}",
+            MAX_SEEDS - 1,
+            seeds.len()
+        )));
+    }
+    for bump in (0..=u8::MAX).rev() {
+        let bump_seed = [bump];
+        let mut seeds_with_bump = seeds.to_vec();
+        seeds_with_bump.push(&bump_seed);
+        if let Some(address) = hash_program_address(&seeds_with_bump, &program_id)? {
+            return Ok((bs58::encode(address).into_string(), bump));
+        }
+    }
+    Err(error("no viable bump found"))
+}
+
+/// Computes the program address for the given seeds,
+/// failing if the resulting address lies on the ed25519 curve.
+pub fn create_program_address(seeds: &[&[u8]], program_id: &str) -> CodamaResult<String> {
+    let program_id = decode_address(program_id)?;
+    hash_program_address(seeds, &program_id)?
+        .map(|address| bs58::encode(address).into_string())
+        .ok_or_else(|| error("address must fall off the ed25519 curve"))
+}
+
+fn hash_program_address(seeds: &[&[u8]], program_id: &[u8; 32]) -> CodamaResult<Option<[u8; 32]>> {
+    if seeds.len() > MAX_SEEDS {
+        return Err(error(format!(
+            "expected at most {MAX_SEEDS} seeds, found {}",
+            seeds.len()
+        )));
+    }
+    let mut hasher = Sha256::new();
+    for seed in seeds {
+        if seed.len() > MAX_SEED_LENGTH {
+            return Err(error(format!(
+                "seeds must be at most {MAX_SEED_LENGTH} bytes, found {}",
+                seed.len()
+            )));
+        }
+        hasher.update(seed);
+    }
+    hasher.update(program_id);
+    hasher.update(PDA_MARKER);
+    let address: [u8; 32] = hasher.finalize().into();
+    match CompressedEdwardsY(address).decompress() {
+        Some(_) => Ok(None),
+        None => Ok(Some(address)),
+    }
+}
+
+fn decode_address(address: &str) -> CodamaResult<[u8; 32]> {
+    bs58::decode(address)
+        .into_vec()
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| error(format!("invalid program address `{address}`")))
+}
+
+fn error<T: Into<String>>(message: T) -> CodamaError {
+    CodamaError::ProgramAddress(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ConstantPdaSeedNode, NumberTypeNode, NumberValueNode, ProgramIdValueNode,
+        PublicKeyTypeNode, StringTypeNode, StringValueNode, VariablePdaSeedNode, U8,
+    };
+
+    const PROGRAM_ID: &str = "BPFLoaderUpgradeab1e11111111111111111111111";
+
+    #[test]
+    fn create_program_address_vectors() {
+        assert_eq!(
+            create_program_address(&[b"", &[1]], PROGRAM_ID).unwrap(),
+            "BwqrghZA2htAcqq8dzP1WDAhTXYTYWj7CHxF5j7TDBAe"
+        );
+        assert_eq!(
+            create_program_address(&["☉".as_bytes(), &[0]], PROGRAM_ID).unwrap(),
+            "13yWmRpaTR4r5nAktwLqMpRNr28tnVUZw26rTvPSSB19"
+        );
+        assert_eq!(
+            create_program_address(&[b"Talking", b"Squirrels"], PROGRAM_ID).unwrap(),
+            "2fnQrngrQT4SeLcdToJAD96phoEjNL2man2kfRLCASVk"
+        );
+    }
+
+    #[test]
+    fn seed_limits() {
+        assert!(matches!(
+            create_program_address(&[&[0; 33]], PROGRAM_ID),
+            Err(CodamaError::ProgramAddress(_))
+        ));
+        assert!(matches!(
+            find_program_address(&[b"".as_slice(); 16], PROGRAM_ID),
+            Err(CodamaError::ProgramAddress(_))
+        ));
+    }
+
+    #[test]
+    fn find_program_address_is_off_curve() {
+        let (address, bump) = find_program_address(&[b"Lil'", b"Bits"], PROGRAM_ID).unwrap();
+        assert_eq!(
+            create_program_address(&[b"Lil'", b"Bits", &[bump]], PROGRAM_ID).unwrap(),
+            address
+        );
+    }
+
+    #[test]
+    fn pda_node() {
+        let owner = "SeedPubey1111111111111111111111111111111111";
+        let pda = PdaNode::new(
+            "counter",
+            vec![
+                ConstantPdaSeedNode::new(StringTypeNode::utf8(), StringValueNode::new("counter"))
+                    .into(),
+                VariablePdaSeedNode::new("owner", PublicKeyTypeNode::new()).into(),
+                VariablePdaSeedNode::new("index", NumberTypeNode::le(U8)).into(),
+            ],
+        );
+        let seed_values = vec![
+            PdaSeedValueNode::new("owner", ValueNode::from(PublicKeyValueNode::new(owner))),
+            PdaSeedValueNode::new("index", ValueNode::from(NumberValueNode::new(3u8))),
+        ];
+
+        let owner_bytes = bs58::decode(owner).into_vec().unwrap();
+        let expected = find_program_address(&[b"counter", &owner_bytes, &[3]], PROGRAM_ID).unwrap();
+        assert_eq!(
+            pda.find_program_address(PROGRAM_ID, &seed_values).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn program_id_seed() {
+        let pda = PdaNode::new(
+            "metadata",
+            vec![
+                ConstantPdaSeedNode::new(PublicKeyTypeNode::new(), ProgramIdValueNode::new())
+                    .into(),
+            ],
+        );
+
+        let program_bytes = bs58::decode(PROGRAM_ID).into_vec().unwrap();
+        let expected = find_program_address(&[&program_bytes], PROGRAM_ID).unwrap();
+        assert_eq!(pda.find_program_address(PROGRAM_ID, &[]).unwrap(), expected);
+    }
+
+    #[test]
+    fn missing_seed_value() {
+        let pda = PdaNode::new(
+            "counter",
+            vec![VariablePdaSeedNode::new("owner", PublicKeyTypeNode::new()).into()],
+        );
+        assert!(matches!(
+            pda.find_program_address(PROGRAM_ID, &[]),
+            Err(CodamaError::ProgramAddress(_))
+        ));
+    }
+}
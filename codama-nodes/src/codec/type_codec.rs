@@ -0,0 +1,658 @@
+use super::{decoder, encoder};
+use crate::{
+    CountNode, DefinedTypeLinkNode, DefinedTypeNode, EnumVariantTypeNode, NumberFormat,
+    NumberTypeNode, PostOffsetStrategy, PreOffsetStrategy, ProgramNode, RootNode, TypeNode,
+    ValueNode,
+};
+use codama_errors::{CodamaError, CodamaResult};
+use std::collections::HashMap;
+
+/// Encodes `ValueNode`s into bytes and decodes bytes into `ValueNode`s
+/// according to a `TypeNode`.
+///
+/// Defined type links are resolved using the defined types registered
+/// on the codec — e.g. via [`TypeCodec::from_root`].
+#[derive(Debug, Default, Clone)]
+pub struct TypeCodec<'a> {
+    defined_types: HashMap<&'a str, &'a DefinedTypeNode>,
+    program_defined_types: HashMap<(&'a str, &'a str), &'a DefinedTypeNode>,
+}
+
+impl<'a> TypeCodec<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves defined type links against all programs of the given root,
+    /// giving priority to the main program for links without a program.
+    pub fn from_root(root: &'a RootNode) -> Self {
+        root.additional_programs
+            .iter()
+            .rev()
+            .fold(Self::new(), |codec, program| codec.add_program(program))
+            .add_program(&root.program)
+    }
+
+    pub fn from_program(program: &'a ProgramNode) -> Self {
+        Self::new().add_program(program)
+    }
+
+    pub fn add_program(mut self, program: &'a ProgramNode) -> Self {
+        for defined_type in &program.defined_types {
+            self.defined_types
+                .insert(defined_type.name.as_str(), defined_type);
+            self.program_defined_types.insert(
+                (program.name.as_str(), defined_type.name.as_str()),
+                defined_type,
+            );
+        }
+        self
+    }
+
+    pub fn add_defined_type(mut self, defined_type: &'a DefinedTypeNode) -> Self {
+        self.defined_types
+            .insert(defined_type.name.as_str(), defined_type);
+        self
+    }
+
+    /// Encodes the given value according to the given type.
+    pub fn encode(&self, value: &ValueNode, r#type: &TypeNode) -> CodamaResult<Vec<u8>> {
+        let mut writer = encoder::Writer::default();
+        self.write(&mut writer, value, r#type, None)?;
+        Ok(writer.finish())
+    }
+
+    /// Decodes the given bytes according to the given type, ignoring any trailing bytes.
+    pub fn decode(&self, r#type: &TypeNode, bytes: &[u8]) -> CodamaResult<ValueNode> {
+        self.decode_at(r#type, bytes, 0).map(|(value, _)| value)
+    }
+
+    /// Decodes the given bytes from the given offset and returns the offset after the value.
+    pub fn decode_at(
+        &self,
+        r#type: &TypeNode,
+        bytes: &[u8],
+        offset: usize,
+    ) -> CodamaResult<(ValueNode, usize)> {
+        let mut reader = decoder::Reader::new(bytes, offset);
+        let value = self.read(&mut reader, r#type, None)?;
+        Ok((value, reader.offset))
+    }
+
+    /// The number of bytes used by all values of the given type,
+    /// or `None` if the size depends on the value.
+    /// Fails if the size does not fit in a `usize`.
+    pub fn fixed_size(&self, r#type: &TypeNode) -> CodamaResult<Option<usize>> {
+        self.fixed_size_with(r#type, &mut Vec::new())
+    }
+
+    /// Computes the fixed size of a type while tracking the defined types being resolved
+    /// so that recursive types are considered variable-sized instead of looping forever.
+    fn fixed_size_with(
+        &self,
+        r#type: &TypeNode,
+        resolving: &mut Vec<&'a DefinedTypeNode>,
+    ) -> CodamaResult<Option<usize>> {
+        // Returns early from this function when the given size is variable.
+        macro_rules! fixed {
+            ($size:expr) => {
+                match $size {
+                    Some(size) => size,
+                    None => return Ok(None),
+                }
+            };
+        }
+        let size = match r#type {
+            TypeNode::Amount(node) => {
+                fixed!(self.fixed_size_with(&node.number.clone().into(), resolving)?)
+            }
+            TypeNode::Array(node) => {
+                let count = fixed!(fixed_count(&node.count));
+                checked_mul(count, fixed!(self.fixed_size_with(&node.item, resolving)?))?
+            }
+            TypeNode::Boolean(node) => {
+                fixed!(self.fixed_size_with(&node.size.clone().into(), resolving)?)
+            }
+            TypeNode::Bytes(_) => return Ok(None),
+            TypeNode::DateTime(node) => {
+                fixed!(self.fixed_size_with(&node.number.clone().into(), resolving)?)
+            }
+            TypeNode::Enum(node) => {
+                let size = fixed!(self.fixed_size_with(&node.size.clone().into(), resolving)?);
+                let mut variants_size = None;
+                for variant in &node.variants {
+                    let variant_size = fixed!(match variant {
+                        EnumVariantTypeNode::Empty(_) => Some(0),
+                        EnumVariantTypeNode::Struct(node) => {
+                            self.fixed_size_with(&node.r#struct.clone().into(), resolving)?
+                        }
+                        EnumVariantTypeNode::Tuple(node) => {
+                            self.fixed_size_with(&node.tuple.clone().into(), resolving)?
+                        }
+                    });
+                    if *variants_size.get_or_insert(variant_size) != variant_size {
+                        return Ok(None);
+                    }
+                }
+                checked_add(size, variants_size.unwrap_or(0))?
+            }
+            TypeNode::FixedSize(node) => node.size,
+            TypeNode::HiddenPrefix(node) => checked_add(
+                fixed!(self.fixed_size_with(&node.r#type, resolving)?),
+                fixed!(self.constants_size(node.prefix.iter().map(|c| (&*c.r#type, &*c.value)))),
+            )?,
+            TypeNode::HiddenSuffix(node) => checked_add(
+                fixed!(self.fixed_size_with(&node.r#type, resolving)?),
+                fixed!(self.constants_size(node.suffix.iter().map(|c| (&*c.r#type, &*c.value)))),
+            )?,
+            TypeNode::Link(link) => {
+                let Ok(defined_type) = self.resolve(link) else {
+                    return Ok(None);
+                };
+                if resolving.iter().any(|t| std::ptr::eq(*t, defined_type)) {
+                    return Ok(None);
+                }
+                resolving.push(defined_type);
+                let size = self.fixed_size_with(&defined_type.r#type, resolving);
+                resolving.pop();
+                fixed!(size?)
+            }
+            TypeNode::Map(node) => {
+                let count = fixed!(fixed_count(&node.count));
+                let entry = checked_add(
+                    fixed!(self.fixed_size_with(&node.key, resolving)?),
+                    fixed!(self.fixed_size_with(&node.value, resolving)?),
+                )?;
+                checked_mul(count, entry)?
+            }
+            TypeNode::Number(node) => fixed!(number_size(node)),
+            TypeNode::Option(node) => match node.fixed {
+                true => checked_add(
+                    fixed!(self.fixed_size_with(&node.prefix.clone().into(), resolving)?),
+                    fixed!(self.fixed_size_with(&node.item, resolving)?),
+                )?,
+                false => return Ok(None),
+            },
+            TypeNode::PostOffset(node) => {
+                let size = fixed!(self.fixed_size_with(&node.r#type, resolving)?);
+                match node.strategy {
+                    PostOffsetStrategy::Padded => checked_add_signed(size, node.offset)?,
+                    _ => size,
+                }
+            }
+            TypeNode::PreOffset(node) => {
+                let size = fixed!(self.fixed_size_with(&node.r#type, resolving)?);
+                match node.strategy {
+                    PreOffsetStrategy::Padded => checked_add_signed(size, node.offset)?,
+                    _ => size,
+                }
+            }
+            TypeNode::PublicKey(_) => 32,
+            TypeNode::RemainderOption(_) => return Ok(None),
+            TypeNode::Sentinel(_) => return Ok(None),
+            TypeNode::Set(node) => {
+                let count = fixed!(fixed_count(&node.count));
+                checked_mul(count, fixed!(self.fixed_size_with(&node.item, resolving)?))?
+            }
+            TypeNode::SizePrefix(_) => return Ok(None),
+            TypeNode::SolAmount(node) => {
+                fixed!(self.fixed_size_with(&node.number.clone().into(), resolving)?)
+            }
+            TypeNode::String(_) => return Ok(None),
+            TypeNode::Struct(node) => {
+                let mut size = 0;
+                for field in &node.fields {
+                    size = checked_add(
+                        size,
+                        fixed!(self.fixed_size_with(&field.r#type, resolving)?),
+                    )?;
+                }
+                size
+            }
+            TypeNode::Tuple(node) => {
+                let mut size = 0;
+                for item in &node.items {
+                    size = checked_add(size, fixed!(self.fixed_size_with(item, resolving)?))?;
+                }
+                size
+            }
+            TypeNode::ZeroableOption(node) => {
+                fixed!(self.fixed_size_with(&node.item, resolving)?)
+            }
+        };
+        Ok(Some(size))
+    }
+
+    pub(crate) fn resolve(&self, link: &DefinedTypeLinkNode) -> CodamaResult<&'a DefinedTypeNode> {
+        let defined_type = match &link.program {
+            Some(program) => self
+                .program_defined_types
+                .get(&(program.name.as_str(), link.name.as_str())),
+            None => self.defined_types.get(link.name.as_str()),
+        };
+        defined_type.copied().ok_or_else(|| {
+            CodamaError::Encoding(format!(
+                "cannot resolve defined type link `{}`",
+                link.name.as_str()
+            ))
+        })
+    }
+
+    /// The total size of the given constants, if they can be encoded.
+    pub(crate) fn constants_size<'b, I>(&self, constants: I) -> Option<usize>
+    where
+        I: IntoIterator<Item = (&'b TypeNode, &'b ValueNode)>,
+    {
+        constants
+            .into_iter()
+            .map(|(r#type, value)| self.encode(value, r#type).ok().map(|bytes| bytes.len()))
+            .sum()
+    }
+}
+
+/// Encodes the given value according to the given type, without resolving defined type links.
+pub fn encode_value(value: &ValueNode, r#type: &TypeNode) -> CodamaResult<Vec<u8>> {
+    TypeCodec::new().encode(value, r#type)
+}
+
+/// Decodes the given bytes according to the given type, without resolving defined type links.
+pub fn decode_value(r#type: &TypeNode, bytes: &[u8]) -> CodamaResult<ValueNode> {
+    TypeCodec::new().decode(r#type, bytes)
+}
+
+fn size_overflow() -> CodamaError {
+    CodamaError::Encoding("fixed size overflows".to_string())
+}
+
+fn checked_add(left: usize, right: usize) -> CodamaResult<usize> {
+    left.checked_add(right).ok_or_else(size_overflow)
+}
+
+fn checked_mul(left: usize, right: usize) -> CodamaResult<usize> {
+    left.checked_mul(right).ok_or_else(size_overflow)
+}
+
+/// Adds the offset of a padded type, which may not make its size negative.
+fn checked_add_signed(size: usize, offset: i32) -> CodamaResult<usize> {
+    size.checked_add_signed(offset as isize)
+        .ok_or_else(|| CodamaError::Encoding(format!("invalid padding {offset} for size {size}")))
+}
+
+fn fixed_count(count: &CountNode) -> Option<usize> {
+    match count {
+        CountNode::Fixed(count) => Some(count.value),
+        _ => None,
+    }
+}
+
+pub(crate) fn number_size(node: &NumberTypeNode) -> Option<usize> {
+    match node.format {
+        NumberFormat::U8 | NumberFormat::I8 => Some(1),
+        NumberFormat::U16 | NumberFormat::I16 => Some(2),
+        NumberFormat::U32 | NumberFormat::I32 | NumberFormat::F32 => Some(4),
+        NumberFormat::U64 | NumberFormat::I64 | NumberFormat::F64 => Some(8),
+        NumberFormat::U128 | NumberFormat::I128 => Some(16),
+        NumberFormat::ShortU16 => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ArrayTypeNode, ArrayValueNode, BooleanTypeNode, BooleanValueNode, BytesValueNode,
+        ConstantValueNode, EnumEmptyVariantTypeNode, EnumTupleVariantTypeNode, EnumTypeNode,
+        EnumValueNode, EnumVariantData, FixedSizeTypeNode, HiddenPrefixTypeNode, MapEntryValueNode,
+        MapTypeNode, MapValueNode, NoneValueNode, Number, NumberFormat::*, NumberValueNode,
+        OptionTypeNode, PostOffsetTypeNode, PreOffsetTypeNode, PublicKeyTypeNode,
+        PublicKeyValueNode, SentinelTypeNode, SetTypeNode, SetValueNode, SizePrefixTypeNode,
+        SomeValueNode, StringTypeNode, StringValueNode, StructFieldTypeNode, StructFieldValueNode,
+        StructTypeNode, StructValueNode, TupleTypeNode, TupleValueNode, ZeroableOptionTypeNode,
+    };
+
+    fn assert_round_trip(value: ValueNode, r#type: TypeNode, bytes: &[u8]) {
+        assert_eq!(encode_value(&value, &r#type).unwrap(), bytes);
+        assert_eq!(decode_value(&r#type, bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn numbers() {
+        assert_round_trip(
+            NumberValueNode::new(42u8).into(),
+            NumberTypeNode::le(U8).into(),
+            &[42],
+        );
+        assert_round_trip(
+            NumberValueNode::new(258u32).into(),
+            NumberTypeNode::le(U32).into(),
+            &[2, 1, 0, 0],
+        );
+        assert_round_trip(
+            NumberValueNode::new(258u32).into(),
+            NumberTypeNode::be(U32).into(),
+            &[0, 0, 1, 2],
+        );
+        assert_round_trip(
+            NumberValueNode::new(-1i16).into(),
+            NumberTypeNode::le(I16).into(),
+            &[255, 255],
+        );
+        assert_round_trip(
+            NumberValueNode::new(1.5f32).into(),
+            NumberTypeNode::le(F32).into(),
+            &1.5f32.to_le_bytes(),
+        );
+    }
+
+    #[test]
+    fn short_u16() {
+        let r#type: TypeNode = NumberTypeNode::le(ShortU16).into();
+        assert_round_trip(NumberValueNode::new(127u8).into(), r#type.clone(), &[127]);
+        assert_round_trip(
+            NumberValueNode::new(128u8).into(),
+            r#type.clone(),
+            &[0x80, 0x01],
+        );
+        assert_round_trip(
+            NumberValueNode::new(u16::MAX).into(),
+            r#type,
+            &[0xff, 0xff, 0x03],
+        );
+    }
+
+    #[test]
+    fn number_out_of_range() {
+        let value = NumberValueNode::new(256u16).into();
+        assert!(matches!(
+            encode_value(&value, &NumberTypeNode::le(U8).into()),
+            Err(CodamaError::Encoding(_))
+        ));
+    }
+
+    #[test]
+    fn strings_and_public_keys() {
+        assert_round_trip(
+            StringValueNode::new("hi").into(),
+            SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U32)).into(),
+            &[2, 0, 0, 0, b'h', b'i'],
+        );
+        assert_round_trip(
+            PublicKeyValueNode::new("11111111111111111111111111111111").into(),
+            PublicKeyTypeNode::new().into(),
+            &[0; 32],
+        );
+    }
+
+    #[test]
+    fn bytes() {
+        assert_round_trip(
+            BytesValueNode::base16("deadbeef").into(),
+            crate::BytesTypeNode::new().into(),
+            &[0xde, 0xad, 0xbe, 0xef],
+        );
+    }
+
+    #[test]
+    fn options() {
+        let r#type: TypeNode = OptionTypeNode::new(NumberTypeNode::le(U16)).into();
+        assert_round_trip(NoneValueNode::new().into(), r#type.clone(), &[0]);
+        assert_round_trip(
+            SomeValueNode::new(NumberValueNode::new(1u8)).into(),
+            r#type,
+            &[1, 1, 0],
+        );
+
+        let r#type: TypeNode = OptionTypeNode::fixed(NumberTypeNode::le(U16)).into();
+        assert_round_trip(NoneValueNode::new().into(), r#type, &[0, 0, 0]);
+
+        let r#type: TypeNode = ZeroableOptionTypeNode::new(NumberTypeNode::le(U16)).into();
+        assert_round_trip(NoneValueNode::new().into(), r#type.clone(), &[0, 0]);
+        assert_round_trip(
+            SomeValueNode::new(NumberValueNode::new(2u8)).into(),
+            r#type,
+            &[2, 0],
+        );
+    }
+
+    #[test]
+    fn collections() {
+        assert_round_trip(
+            ArrayValueNode::new(vec![
+                BooleanValueNode::new(true).into(),
+                BooleanValueNode::new(false).into(),
+            ])
+            .into(),
+            ArrayTypeNode::prefixed(BooleanTypeNode::default(), NumberTypeNode::le(U8)).into(),
+            &[2, 1, 0],
+        );
+        assert_round_trip(
+            SetValueNode::new(vec![NumberValueNode::new(7u8).into()]).into(),
+            SetTypeNode::remainder(NumberTypeNode::le(U8)).into(),
+            &[7],
+        );
+        assert_round_trip(
+            MapValueNode::new(vec![MapEntryValueNode::new(
+                NumberValueNode::new(1u8),
+                BooleanValueNode::new(true),
+            )])
+            .into(),
+            MapTypeNode::fixed(NumberTypeNode::le(U8), BooleanTypeNode::default(), 1).into(),
+            &[1, 1],
+        );
+        assert_round_trip(
+            TupleValueNode::new(vec![
+                NumberValueNode::new(1u8).into(),
+                BooleanValueNode::new(true).into(),
+            ])
+            .into(),
+            TupleTypeNode::new(vec![
+                NumberTypeNode::le(U16).into(),
+                BooleanTypeNode::default().into(),
+            ])
+            .into(),
+            &[1, 0, 1],
+        );
+    }
+
+    #[test]
+    fn wrong_item_count() {
+        let value = ArrayValueNode::new(vec![]).into();
+        let r#type = ArrayTypeNode::fixed(BooleanTypeNode::default(), 2).into();
+        assert!(encode_value(&value, &r#type).is_err());
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode_value(&NumberTypeNode::le(U32).into(), &[1, 2]),
+            Err(CodamaError::Decoding(_))
+        ));
+    }
+
+    #[test]
+    fn sentinels_and_hidden_prefixes() {
+        let sentinel = ConstantValueNode::new(NumberTypeNode::le(U8), NumberValueNode::new(0u8));
+        assert_round_trip(
+            StringValueNode::new("ab").into(),
+            SentinelTypeNode::new(StringTypeNode::utf8(), sentinel).into(),
+            &[b'a', b'b', 0],
+        );
+        let prefix = ConstantValueNode::new(NumberTypeNode::le(U16), NumberValueNode::new(9u8));
+        assert_round_trip(
+            BooleanValueNode::new(true).into(),
+            HiddenPrefixTypeNode::new(BooleanTypeNode::default(), vec![prefix]).into(),
+            &[9, 0, 1],
+        );
+        assert!(decode_value(
+            &HiddenPrefixTypeNode::new(
+                BooleanTypeNode::default(),
+                vec![ConstantValueNode::new(
+                    NumberTypeNode::le(U8),
+                    NumberValueNode::new(9u8)
+                )]
+            )
+            .into(),
+            &[8, 1]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn offsets() {
+        let r#type: TypeNode = TupleTypeNode::new(vec![
+            PostOffsetTypeNode::padded(NumberTypeNode::le(U8), 2).into(),
+            NumberTypeNode::le(U8).into(),
+        ])
+        .into();
+        let value: ValueNode = TupleValueNode::new(vec![
+            NumberValueNode::new(1u8).into(),
+            NumberValueNode::new(2u8).into(),
+        ])
+        .into();
+        assert_round_trip(value, r#type, &[1, 0, 0, 2]);
+
+        let r#type: TypeNode = PreOffsetTypeNode::padded(NumberTypeNode::le(U8), 1).into();
+        assert_round_trip(NumberValueNode::new(5u8).into(), r#type, &[0, 5]);
+    }
+
+    #[test]
+    fn structs_and_enums_through_links() {
+        let program = ProgramNode::new("myProgram", "1111")
+            .add_defined_type(DefinedTypeNode::new(
+                "direction",
+                EnumTypeNode::new(vec![
+                    EnumEmptyVariantTypeNode::new("up").into(),
+                    EnumTupleVariantTypeNode::new(
+                        "custom",
+                        TupleTypeNode::new(vec![NumberTypeNode::le(U8).into()]),
+                    )
+                    .into(),
+                ]),
+            ))
+            .add_defined_type(DefinedTypeNode::new(
+                "movement",
+                StructTypeNode::new(vec![
+                    StructFieldTypeNode::new("direction", DefinedTypeLinkNode::new("direction")),
+                    StructFieldTypeNode::new("steps", NumberTypeNode::le(U16)),
+                ]),
+            ));
+        let codec = TypeCodec::from_program(&program);
+        let r#type: TypeNode = DefinedTypeLinkNode::new("movement").into();
+        let value: ValueNode = StructValueNode::new(vec![
+            StructFieldValueNode::new(
+                "direction",
+                EnumValueNode::new(
+                    "direction",
+                    "custom",
+                    Some(EnumVariantData::Tuple(TupleValueNode::new(vec![
+                        NumberValueNode::new(3u8).into(),
+                    ]))),
+                ),
+            ),
+            StructFieldValueNode::new("steps", NumberValueNode::new(Number::UnsignedInteger(4))),
+        ])
+        .into();
+
+        let bytes = codec.encode(&value, &r#type).unwrap();
+        assert_eq!(bytes, vec![1, 3, 4, 0]);
+        assert_eq!(codec.decode(&r#type, &bytes).unwrap(), value);
+        assert_eq!(codec.fixed_size(&r#type).unwrap(), None);
+        assert_eq!(
+            codec.decode_at(&r#type, &[0, 0, 1, 3, 4, 0], 2).unwrap().1,
+            6
+        );
+    }
+
+    #[test]
+    fn unresolved_links() {
+        let r#type: TypeNode = DefinedTypeLinkNode::new("missing").into();
+        assert!(decode_value(&r#type, &[0]).is_err());
+        assert_eq!(TypeCodec::new().fixed_size(&r#type).unwrap(), None);
+    }
+
+    #[test]
+    fn fixed_sizes() {
+        let codec = TypeCodec::new();
+        assert_eq!(
+            codec.fixed_size(&NumberTypeNode::le(U64).into()).unwrap(),
+            Some(8)
+        );
+        assert_eq!(
+            codec
+                .fixed_size(&NumberTypeNode::le(ShortU16).into())
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            codec.fixed_size(&StringTypeNode::utf8().into()).unwrap(),
+            None
+        );
+        assert_eq!(
+            codec
+                .fixed_size(&OptionTypeNode::fixed(PublicKeyTypeNode::new()).into())
+                .unwrap(),
+            Some(33)
+        );
+        assert_eq!(
+            codec
+                .fixed_size(&OptionTypeNode::new(PublicKeyTypeNode::new()).into())
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            codec
+                .fixed_size(&ArrayTypeNode::fixed(NumberTypeNode::le(U32), 3).into())
+                .unwrap(),
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn overflowing_fixed_sizes() {
+        let codec = TypeCodec::new();
+        let array = ArrayTypeNode::fixed(NumberTypeNode::le(U64), usize::MAX);
+        assert!(codec.fixed_size(&array.into()).is_err());
+
+        let r#type = TupleTypeNode::new(vec![
+            FixedSizeTypeNode::new(StringTypeNode::utf8(), usize::MAX).into(),
+            NumberTypeNode::le(U8).into(),
+        ]);
+        assert!(codec.fixed_size(&r#type.into()).is_err());
+    }
+
+    #[test]
+    fn recursive_links() {
+        let program = ProgramNode::new("myProgram", "1111").add_defined_type(DefinedTypeNode::new(
+            "node",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("value", NumberTypeNode::le(U8)),
+                StructFieldTypeNode::new(
+                    "next",
+                    OptionTypeNode::fixed(DefinedTypeLinkNode::new("node")),
+                ),
+                StructFieldTypeNode::new(
+                    "children",
+                    ArrayTypeNode::prefixed(
+                        DefinedTypeLinkNode::new("node"),
+                        NumberTypeNode::le(U32),
+                    ),
+                ),
+            ]),
+        ));
+        let codec = TypeCodec::from_program(&program);
+        assert_eq!(
+            codec
+                .fixed_size(&DefinedTypeLinkNode::new("node").into())
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn overflowing_offsets() {
+        let r#type: TypeNode = NumberTypeNode::le(U16).into();
+        assert!(TypeCodec::new()
+            .decode_at(&r#type, &[1, 2], usize::MAX)
+            .is_err());
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
     AccountValueNode, ArgumentValueNode, ArrayValueNode, BooleanValueNode, BytesValueNode,
-    CamelCaseString, ConstantValueNode, EnumValueNode, HasName, MapValueNode, NoneValueNode,
-    NumberValueNode, PublicKeyValueNode, SetValueNode, SomeValueNode, StringValueNode,
-    StructValueNode, TupleValueNode, ValueNode,
+    CamelCaseString, ConstantValueNode, EnumValueNode, HasKind, HasName, MapValueNode,
+    NoneValueNode, NumberValueNode, PublicKeyValueNode, SetValueNode, SomeValueNode,
+    StringValueNode, StructValueNode, TupleValueNode, ValueNode,
 };
+use codama_errors::CodamaError;
 use codama_nodes_derive::{node, node_union};
 
 #[node]
@@ -83,6 +84,33 @@ impl From<ValueNode> for PdaSeedValueValueNode {
     }
 }
 
+impl TryFrom<PdaSeedValueValueNode> for ValueNode {
+    type Error = CodamaError;
+
+    fn try_from(value: PdaSeedValueValueNode) -> Result<Self, Self::Error> {
+        match value {
+            PdaSeedValueValueNode::Array(value) => Ok(Self::Array(value)),
+            PdaSeedValueValueNode::Boolean(value) => Ok(Self::Boolean(value)),
+            PdaSeedValueValueNode::Bytes(value) => Ok(Self::Bytes(value)),
+            PdaSeedValueValueNode::Constant(value) => Ok(Self::Constant(value)),
+            PdaSeedValueValueNode::Enum(value) => Ok(Self::Enum(value)),
+            PdaSeedValueValueNode::Map(value) => Ok(Self::Map(value)),
+            PdaSeedValueValueNode::None(value) => Ok(Self::None(value)),
+            PdaSeedValueValueNode::Number(value) => Ok(Self::Number(value)),
+            PdaSeedValueValueNode::PublicKey(value) => Ok(Self::PublicKey(value)),
+            PdaSeedValueValueNode::Set(value) => Ok(Self::Set(value)),
+            PdaSeedValueValueNode::Some(value) => Ok(Self::Some(value)),
+            PdaSeedValueValueNode::String(value) => Ok(Self::String(value)),
+            PdaSeedValueValueNode::Struct(value) => Ok(Self::Struct(value)),
+            PdaSeedValueValueNode::Tuple(value) => Ok(Self::Tuple(value)),
+            _ => Err(CodamaError::InvalidNodeConversion {
+                from: value.kind().to_string(),
+                into: "ValueNode".to_string(),
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::NumberValueNode;
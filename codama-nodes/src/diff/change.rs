@@ -0,0 +1,79 @@
+use crate::NodePath;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangeKind::Added => write!(f, "added"),
+            ChangeKind::Removed => write!(f, "removed"),
+            ChangeKind::Changed => write!(f, "changed"),
+        }
+    }
+}
+
+/// A difference between two versions of an IDL.
+/// Breaking changes are the ones that affect the wire format or existing clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdlChange {
+    pub kind: ChangeKind,
+    pub breaking: bool,
+    pub message: String,
+    pub path: NodePath,
+}
+
+impl IdlChange {
+    pub fn breaking<T: Into<String>>(kind: ChangeKind, message: T, path: &NodePath) -> Self {
+        Self {
+            kind,
+            breaking: true,
+            message: message.into(),
+            path: path.clone(),
+        }
+    }
+
+    pub fn non_breaking<T: Into<String>>(kind: ChangeKind, message: T, path: &NodePath) -> Self {
+        Self {
+            kind,
+            breaking: false,
+            message: message.into(),
+            path: path.clone(),
+        }
+    }
+}
+
+impl Display for IdlChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self.breaking {
+            true => "breaking",
+            false => "non-breaking",
+        };
+        write!(f, "{label}: {} (at {})", self.message, self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let path = NodePath::new().child("programNode", Some(&"token".into()));
+        assert_eq!(
+            IdlChange::breaking(ChangeKind::Removed, "accountNode `mint` was removed", &path)
+                .to_string(),
+            "breaking: accountNode `mint` was removed (at [programNode]token)"
+        );
+        assert_eq!(
+            IdlChange::non_breaking(ChangeKind::Added, "errorNode `oops` was added", &path)
+                .to_string(),
+            "non-breaking: errorNode `oops` was added (at [programNode]token)"
+        );
+    }
+}
@@ -0,0 +1,810 @@
+use crate::{
+    AccountNode, ChangeKind, CountNode, DefaultValueStrategy, DefinedTypeLinkNode, DefinedTypeNode,
+    DiscriminatorNode, EnumVariantTypeNode, ErrorNode, HasKind, HasName, IdlChange,
+    InstructionAccountNode, InstructionArgumentNode, InstructionNode, NodePath, NumberFormat,
+    PdaNode, ProgramNode, RootNode, StructFieldTypeNode, TypeNode,
+};
+
+/// Lists the differences between two versions of an IDL, classifying each of
+/// them as breaking when it changes the wire format or removes something clients use.
+pub fn diff(old: &RootNode, new: &RootNode) -> Vec<IdlChange> {
+    let mut differ = Differ::default();
+    let path = NodePath::new().child(old.kind(), None);
+    differ.diff_program(
+        &old.program,
+        &new.program,
+        &path.child(old.program.kind(), Some(&old.program.name)),
+    );
+    differ.diff_named(
+        &old.additional_programs,
+        &new.additional_programs,
+        &path,
+        Differ::diff_program,
+    );
+    differ.changes
+}
+
+impl RootNode {
+    /// Lists the changes from this IDL to the given newer version of it.
+    pub fn diff(&self, new: &RootNode) -> Vec<IdlChange> {
+        diff(self, new)
+    }
+}
+
+#[derive(Default)]
+struct Differ {
+    changes: Vec<IdlChange>,
+}
+
+impl Differ {
+    fn breaking(&mut self, kind: ChangeKind, message: String, path: &NodePath) {
+        self.changes.push(IdlChange::breaking(kind, message, path));
+    }
+
+    fn non_breaking(&mut self, kind: ChangeKind, message: String, path: &NodePath) {
+        self.changes
+            .push(IdlChange::non_breaking(kind, message, path));
+    }
+
+    /// Matches items by name regardless of their order — e.g. accounts or errors.
+    fn diff_named<T: HasKind + HasName>(
+        &mut self,
+        old: &[T],
+        new: &[T],
+        path: &NodePath,
+        diff_item: fn(&mut Self, &T, &T, &NodePath),
+    ) {
+        for old_item in old {
+            match new.iter().find(|item| item.name() == old_item.name()) {
+                Some(new_item) => diff_item(self, old_item, new_item, &item_path(old_item, path)),
+                None => self.breaking(ChangeKind::Removed, removed(old_item), path),
+            }
+        }
+        for new_item in new.iter().filter(|item| !contains(old, item)) {
+            self.non_breaking(ChangeKind::Added, added(new_item), path);
+        }
+    }
+
+    /// Matches items by name where their order is part of the wire format — e.g. struct fields.
+    /// New items are only non-breaking when they are optional and appended after existing ones.
+    fn diff_ordered<T: HasKind + HasName>(
+        &mut self,
+        old: &[T],
+        new: &[T],
+        path: &NodePath,
+        is_optional: fn(&T) -> bool,
+        diff_item: fn(&mut Self, &T, &T, &NodePath),
+    ) {
+        for old_item in old {
+            match new.iter().find(|item| item.name() == old_item.name()) {
+                Some(new_item) => diff_item(self, old_item, new_item, &item_path(old_item, path)),
+                None => self.breaking(ChangeKind::Removed, removed(old_item), path),
+            }
+        }
+
+        let old_order = old.iter().filter(|item| contains(new, item));
+        let new_order = new.iter().filter(|item| contains(old, item));
+        if !old_order.map(T::name).eq(new_order.map(T::name)) {
+            let kind = old.first().map(T::kind).unwrap_or_default();
+            self.breaking(ChangeKind::Changed, format!("{kind}s were reordered"), path);
+        }
+
+        let last_existing = new.iter().rposition(|item| contains(old, item));
+        for (index, new_item) in new.iter().enumerate() {
+            if contains(old, new_item) {
+                continue;
+            }
+            let appended = last_existing.is_none_or(|last| index > last);
+            match (appended, is_optional(new_item)) {
+                (true, true) => self.non_breaking(ChangeKind::Added, added(new_item), path),
+                (true, false) => self.breaking(
+                    ChangeKind::Added,
+                    format!("{} without being optional", added(new_item)),
+                    path,
+                ),
+                (false, _) => self.breaking(
+                    ChangeKind::Added,
+                    format!("{} before existing items", added(new_item)),
+                    path,
+                ),
+            }
+        }
+    }
+
+    fn diff_program(&mut self, old: &ProgramNode, new: &ProgramNode, path: &NodePath) {
+        if old.public_key != new.public_key {
+            self.breaking(
+                ChangeKind::Changed,
+                format!(
+                    "public key changed from `{}` to `{}`",
+                    old.public_key, new.public_key
+                ),
+                path,
+            );
+        }
+        self.diff_named(&old.accounts, &new.accounts, path, Self::diff_account);
+        self.diff_named(
+            &old.instructions,
+            &new.instructions,
+            path,
+            Self::diff_instruction,
+        );
+        self.diff_named(
+            &old.defined_types,
+            &new.defined_types,
+            path,
+            Self::diff_defined_type,
+        );
+        self.diff_named(&old.pdas, &new.pdas, path, Self::diff_pda);
+        self.diff_named(&old.errors, &new.errors, path, Self::diff_error);
+    }
+
+    fn diff_account(&mut self, old: &AccountNode, new: &AccountNode, path: &NodePath) {
+        self.diff_discriminators(&old.discriminators, &new.discriminators, path);
+        if let (Some(old_size), Some(new_size)) = (old.size, new.size) {
+            if old_size != new_size {
+                self.breaking(
+                    ChangeKind::Changed,
+                    format!("size changed from {old_size} to {new_size} bytes"),
+                    path,
+                );
+            }
+        }
+        self.diff_type(&old.data.clone().into(), &new.data.clone().into(), path);
+    }
+
+    fn diff_instruction(&mut self, old: &InstructionNode, new: &InstructionNode, path: &NodePath) {
+        self.diff_discriminators(&old.discriminators, &new.discriminators, path);
+        if old.optional_account_strategy != new.optional_account_strategy {
+            self.breaking(
+                ChangeKind::Changed,
+                "optional account strategy changed".to_string(),
+                path,
+            );
+        }
+        self.diff_ordered(
+            &old.accounts,
+            &new.accounts,
+            path,
+            |account| account.is_optional,
+            Self::diff_instruction_account,
+        );
+        self.diff_ordered(
+            &old.arguments,
+            &new.arguments,
+            path,
+            |argument| argument.default_value.is_some() || is_optional_type(&argument.r#type),
+            Self::diff_instruction_argument,
+        );
+    }
+
+    fn diff_instruction_account(
+        &mut self,
+        old: &InstructionAccountNode,
+        new: &InstructionAccountNode,
+        path: &NodePath,
+    ) {
+        if old.is_writable != new.is_writable {
+            self.breaking(
+                ChangeKind::Changed,
+                format!(
+                    "writable changed from {} to {}",
+                    old.is_writable, new.is_writable
+                ),
+                path,
+            );
+        }
+        if old.is_signer != new.is_signer {
+            self.breaking(
+                ChangeKind::Changed,
+                format!(
+                    "signer changed from {:?} to {:?}",
+                    old.is_signer, new.is_signer
+                ),
+                path,
+            );
+        }
+        match (old.is_optional, new.is_optional) {
+            (true, false) => self.breaking(
+                ChangeKind::Changed,
+                "account is no longer optional".to_string(),
+                path,
+            ),
+            (false, true) => self.non_breaking(
+                ChangeKind::Changed,
+                "account became optional".to_string(),
+                path,
+            ),
+            _ => {}
+        }
+    }
+
+    fn diff_instruction_argument(
+        &mut self,
+        old: &InstructionArgumentNode,
+        new: &InstructionArgumentNode,
+        path: &NodePath,
+    ) {
+        self.diff_type(&old.r#type, &new.r#type, path);
+        let omitted = old.default_value_strategy == Some(DefaultValueStrategy::Omitted)
+            || new.default_value_strategy == Some(DefaultValueStrategy::Omitted);
+        self.diff_default_value(old.default_value != new.default_value, omitted, path);
+    }
+
+    fn diff_struct_field(
+        &mut self,
+        old: &StructFieldTypeNode,
+        new: &StructFieldTypeNode,
+        path: &NodePath,
+    ) {
+        self.diff_type(&old.r#type, &new.r#type, path);
+        let omitted = old.default_value_strategy == Some(DefaultValueStrategy::Omitted)
+            || new.default_value_strategy == Some(DefaultValueStrategy::Omitted);
+        self.diff_default_value(old.default_value != new.default_value, omitted, path);
+    }
+
+    /// Omitted default values are always written to the data — e.g. discriminators.
+    fn diff_default_value(&mut self, changed: bool, omitted: bool, path: &NodePath) {
+        match (changed, omitted) {
+            (true, true) => self.breaking(
+                ChangeKind::Changed,
+                "omitted default value changed".to_string(),
+                path,
+            ),
+            (true, false) => self.non_breaking(
+                ChangeKind::Changed,
+                "default value changed".to_string(),
+                path,
+            ),
+            _ => {}
+        }
+    }
+
+    fn diff_defined_type(&mut self, old: &DefinedTypeNode, new: &DefinedTypeNode, path: &NodePath) {
+        self.diff_type(&old.r#type, &new.r#type, path);
+    }
+
+    fn diff_type(&mut self, old: &TypeNode, new: &TypeNode, path: &NodePath) {
+        if old == new {
+            return;
+        }
+        match (old, new) {
+            (TypeNode::Struct(old), TypeNode::Struct(new)) => self.diff_ordered(
+                &old.fields,
+                &new.fields,
+                path,
+                |field| field.default_value.is_some() || is_optional_type(&field.r#type),
+                Self::diff_struct_field,
+            ),
+            (TypeNode::Number(old), TypeNode::Number(new)) => {
+                if old.format != new.format {
+                    self.breaking(
+                        ChangeKind::Changed,
+                        format!(
+                            "number format changed from {} to {}",
+                            format_name(old.format),
+                            format_name(new.format)
+                        ),
+                        path,
+                    );
+                }
+                if old.endian != new.endian {
+                    self.breaking(ChangeKind::Changed, "endianness changed".to_string(), path);
+                }
+            }
+            (TypeNode::Enum(old), TypeNode::Enum(new)) => {
+                self.diff_type(&old.size.clone().into(), &new.size.clone().into(), path);
+                self.diff_ordered(
+                    &old.variants,
+                    &new.variants,
+                    path,
+                    |_| true,
+                    Self::diff_enum_variant,
+                );
+            }
+            (TypeNode::Option(old), TypeNode::Option(new)) => {
+                if old.fixed != new.fixed {
+                    self.breaking(
+                        ChangeKind::Changed,
+                        format!("fixed option changed from {} to {}", old.fixed, new.fixed),
+                        path,
+                    );
+                }
+                self.diff_type(&old.prefix.clone().into(), &new.prefix.clone().into(), path);
+                self.diff_type(&old.item, &new.item, path);
+            }
+            (TypeNode::ZeroableOption(old), TypeNode::ZeroableOption(new)) => {
+                if old.zero_value != new.zero_value {
+                    self.breaking(ChangeKind::Changed, "zero value changed".to_string(), path);
+                }
+                self.diff_type(&old.item, &new.item, path);
+            }
+            (TypeNode::Array(old), TypeNode::Array(new)) => {
+                self.diff_count(&old.count, &new.count, path);
+                self.diff_type(&old.item, &new.item, path);
+            }
+            (TypeNode::Set(old), TypeNode::Set(new)) => {
+                self.diff_count(&old.count, &new.count, path);
+                self.diff_type(&old.item, &new.item, path);
+            }
+            (TypeNode::Map(old), TypeNode::Map(new)) => {
+                self.diff_count(&old.count, &new.count, path);
+                self.diff_type(&old.key, &new.key, path);
+                self.diff_type(&old.value, &new.value, path);
+            }
+            (TypeNode::Tuple(old), TypeNode::Tuple(new)) => {
+                if old.items.len() != new.items.len() {
+                    self.breaking(
+                        ChangeKind::Changed,
+                        format!(
+                            "tuple length changed from {} to {}",
+                            old.items.len(),
+                            new.items.len()
+                        ),
+                        path,
+                    );
+                }
+                for (old, new) in old.items.iter().zip(&new.items) {
+                    self.diff_type(old, new, path);
+                }
+            }
+            (TypeNode::SizePrefix(old), TypeNode::SizePrefix(new)) => {
+                self.diff_type(
+                    &(*old.prefix).clone().into(),
+                    &(*new.prefix).clone().into(),
+                    path,
+                );
+                self.diff_type(&old.r#type, &new.r#type, path);
+            }
+            (TypeNode::FixedSize(old), TypeNode::FixedSize(new)) => {
+                if old.size != new.size {
+                    self.breaking(
+                        ChangeKind::Changed,
+                        format!("size changed from {} to {} bytes", old.size, new.size),
+                        path,
+                    );
+                }
+                self.diff_type(&old.r#type, &new.r#type, path);
+            }
+            (TypeNode::Link(old), TypeNode::Link(new)) => self.breaking(
+                ChangeKind::Changed,
+                format!("type changed from {} to {}", link_name(old), link_name(new)),
+                path,
+            ),
+            _ if old.kind() == new.kind() => {
+                self.breaking(ChangeKind::Changed, format!("{} changed", old.kind()), path)
+            }
+            _ => self.breaking(
+                ChangeKind::Changed,
+                format!("type changed from {} to {}", old.kind(), new.kind()),
+                path,
+            ),
+        }
+    }
+
+    fn diff_count(&mut self, old: &CountNode, new: &CountNode, path: &NodePath) {
+        match (old, new) {
+            (CountNode::Fixed(old), CountNode::Fixed(new)) if old.value != new.value => self
+                .breaking(
+                    ChangeKind::Changed,
+                    format!("count changed from {} to {}", old.value, new.value),
+                    path,
+                ),
+            (CountNode::Prefixed(old), CountNode::Prefixed(new)) => {
+                self.diff_type(&old.prefix.clone().into(), &new.prefix.clone().into(), path)
+            }
+            _ if old.kind() != new.kind() => self.breaking(
+                ChangeKind::Changed,
+                format!("count changed from {} to {}", old.kind(), new.kind()),
+                path,
+            ),
+            _ => {}
+        }
+    }
+
+    fn diff_enum_variant(
+        &mut self,
+        old: &EnumVariantTypeNode,
+        new: &EnumVariantTypeNode,
+        path: &NodePath,
+    ) {
+        if variant_discriminator(old) != variant_discriminator(new) {
+            self.breaking(
+                ChangeKind::Changed,
+                "variant discriminator changed".to_string(),
+                path,
+            );
+        }
+        match (old, new) {
+            (EnumVariantTypeNode::Empty(_), EnumVariantTypeNode::Empty(_)) => {}
+            (EnumVariantTypeNode::Struct(old), EnumVariantTypeNode::Struct(new)) => self.diff_type(
+                &old.r#struct.clone().into(),
+                &new.r#struct.clone().into(),
+                path,
+            ),
+            (EnumVariantTypeNode::Tuple(old), EnumVariantTypeNode::Tuple(new)) => {
+                self.diff_type(&old.tuple.clone().into(), &new.tuple.clone().into(), path)
+            }
+            _ => self.breaking(
+                ChangeKind::Changed,
+                format!("variant changed from {} to {}", old.kind(), new.kind()),
+                path,
+            ),
+        }
+    }
+
+    fn diff_pda(&mut self, old: &PdaNode, new: &PdaNode, path: &NodePath) {
+        if old.program_id != new.program_id {
+            self.breaking(ChangeKind::Changed, "program ID changed".to_string(), path);
+        }
+        if old.seeds != new.seeds {
+            self.breaking(ChangeKind::Changed, "seeds changed".to_string(), path);
+        }
+    }
+
+    fn diff_error(&mut self, old: &ErrorNode, new: &ErrorNode, path: &NodePath) {
+        if old.code != new.code {
+            self.breaking(
+                ChangeKind::Changed,
+                format!("code changed from {} to {}", old.code, new.code),
+                path,
+            );
+        }
+        if old.message != new.message {
+            self.non_breaking(ChangeKind::Changed, "message changed".to_string(), path);
+        }
+    }
+
+    fn diff_discriminators(
+        &mut self,
+        old: &[DiscriminatorNode],
+        new: &[DiscriminatorNode],
+        path: &NodePath,
+    ) {
+        if old != new {
+            self.breaking(
+                ChangeKind::Changed,
+                "discriminators changed".to_string(),
+                path,
+            );
+        }
+    }
+}
+
+fn contains<T: HasName>(items: &[T], item: &T) -> bool {
+    items.iter().any(|other| other.name() == item.name())
+}
+
+fn item_path<T: HasKind + HasName>(item: &T, parent: &NodePath) -> NodePath {
+    parent.child(item.kind(), Some(item.name()))
+}
+
+fn added<T: HasKind + HasName>(item: &T) -> String {
+    format!("{} `{}` was added", item.kind(), item.name().as_str())
+}
+
+fn removed<T: HasKind + HasName>(item: &T) -> String {
+    format!("{} `{}` was removed", item.kind(), item.name().as_str())
+}
+
+fn is_optional_type(r#type: &TypeNode) -> bool {
+    matches!(
+        r#type,
+        TypeNode::Option(_) | TypeNode::RemainderOption(_) | TypeNode::ZeroableOption(_)
+    )
+}
+
+/// The name of a linked type, including its program when it comes from another program.
+fn link_name(link: &DefinedTypeLinkNode) -> String {
+    match &link.program {
+        Some(program) => format!(
+            "`{}` from program `{}`",
+            link.name.as_str(),
+            program.name.as_str()
+        ),
+        None => format!("`{}`", link.name.as_str()),
+    }
+}
+
+fn format_name(format: NumberFormat) -> String {
+    serde_json::to_value(format)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_else(|| format!("{format:?}"))
+}
+
+fn variant_discriminator(variant: &EnumVariantTypeNode) -> Option<usize> {
+    match variant {
+        EnumVariantTypeNode::Empty(variant) => variant.discriminator,
+        EnumVariantTypeNode::Struct(variant) => variant.discriminator,
+        EnumVariantTypeNode::Tuple(variant) => variant.discriminator,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ArrayTypeNode, EnumEmptyVariantTypeNode, EnumTypeNode, FieldDiscriminatorNode,
+        FixedSizeTypeNode, MapTypeNode, NumberTypeNode, OptionTypeNode, ProgramLinkNode,
+        PublicKeyTypeNode, SizePrefixTypeNode, StringTypeNode, StructTypeNode, TupleTypeNode,
+        ZeroableOptionTypeNode, U32, U64, U8,
+    };
+
+    fn mint(supply: NumberFormat) -> AccountNode {
+        AccountNode::new(
+            "mint",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("authority", PublicKeyTypeNode::new()),
+                StructFieldTypeNode::new("supply", NumberTypeNode::le(supply)),
+            ]),
+        )
+    }
+
+    fn transfer(arguments: Vec<InstructionArgumentNode>) -> InstructionNode {
+        InstructionNode {
+            name: "transfer".into(),
+            arguments,
+            ..InstructionNode::default()
+        }
+    }
+
+    fn root(program: ProgramNode) -> RootNode {
+        RootNode::new(program)
+    }
+
+    fn summary(changes: Vec<IdlChange>) -> Vec<(bool, String)> {
+        changes
+            .into_iter()
+            .map(|change| (change.breaking, change.message))
+            .collect()
+    }
+
+    #[test]
+    fn no_changes() {
+        let idl = root(ProgramNode::new("token", "1111").add_account(mint(U64)));
+        assert_eq!(idl.diff(&idl), vec![]);
+    }
+
+    #[test]
+    fn added_and_removed_accounts() {
+        let old = root(ProgramNode::new("token", "1111").add_account(mint(U64)));
+        let new = root(
+            ProgramNode::new("token", "1111")
+                .add_account(AccountNode::new("vault", StructTypeNode::new(vec![]))),
+        );
+        assert_eq!(
+            summary(old.diff(&new)),
+            vec![
+                (true, "accountNode `mint` was removed".to_string()),
+                (false, "accountNode `vault` was added".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_number_format() {
+        let old = root(ProgramNode::new("token", "1111").add_account(mint(U32)));
+        let new = root(ProgramNode::new("token", "1111").add_account(mint(U64)));
+        let changes = old.diff(&new);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].to_string(),
+            "breaking: number format changed from u32 to u64 (at [rootNode].[programNode]token.[accountNode]mint.[structFieldTypeNode]supply)"
+        );
+    }
+
+    #[test]
+    fn reordered_fields() {
+        let old = root(ProgramNode::new("token", "1111").add_account(mint(U64)));
+        let mut reordered = mint(U64);
+        reordered.data = StructTypeNode::new(vec![
+            StructFieldTypeNode::new("supply", NumberTypeNode::le(U64)),
+            StructFieldTypeNode::new("authority", PublicKeyTypeNode::new()),
+        ])
+        .into();
+        let new = root(ProgramNode::new("token", "1111").add_account(reordered));
+        assert_eq!(
+            summary(old.diff(&new)),
+            vec![(true, "structFieldTypeNodes were reordered".to_string())]
+        );
+    }
+
+    #[test]
+    fn added_arguments() {
+        let amount = InstructionArgumentNode::new("amount", NumberTypeNode::le(U64));
+        let memo =
+            InstructionArgumentNode::new("memo", OptionTypeNode::new(NumberTypeNode::le(U8)));
+        let decimals = InstructionArgumentNode::new("decimals", NumberTypeNode::le(U8));
+        let old =
+            root(ProgramNode::new("token", "1111").add_instruction(transfer(vec![amount.clone()])));
+
+        let new = root(
+            ProgramNode::new("token", "1111")
+                .add_instruction(transfer(vec![amount.clone(), memo.clone()])),
+        );
+        assert_eq!(
+            summary(old.diff(&new)),
+            vec![(
+                false,
+                "instructionArgumentNode `memo` was added".to_string()
+            )]
+        );
+
+        let new = root(
+            ProgramNode::new("token", "1111")
+                .add_instruction(transfer(vec![amount.clone(), decimals])),
+        );
+        assert_eq!(
+            summary(old.diff(&new)),
+            vec![(
+                true,
+                "instructionArgumentNode `decimals` was added without being optional".to_string()
+            )]
+        );
+
+        let new =
+            root(ProgramNode::new("token", "1111").add_instruction(transfer(vec![memo, amount])));
+        assert_eq!(
+            summary(old.diff(&new)),
+            vec![(
+                true,
+                "instructionArgumentNode `memo` was added before existing items".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn changed_discriminators() {
+        let old = root(ProgramNode::new("token", "1111").add_account(mint(U64)));
+        let new = root(ProgramNode::new("token", "1111").add_account(AccountNode {
+            discriminators: vec![FieldDiscriminatorNode::new("authority", 0).into()],
+            ..mint(U64)
+        }));
+        assert_eq!(
+            summary(old.diff(&new)),
+            vec![(true, "discriminators changed".to_string())]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let error = ErrorNode::new("insufficientFunds", 1, "Insufficient funds");
+        let old = root(ProgramNode::new("token", "1111").add_error(error.clone()));
+        let new = root(
+            ProgramNode::new("token", "1111")
+                .add_error(ErrorNode {
+                    code: 2,
+                    ..error.clone()
+                })
+                .add_error(ErrorNode {
+                    name: "invalidMint".into(),
+                    ..error
+                }),
+        );
+        assert_eq!(
+            summary(old.diff(&new)),
+            vec![
+                (true, "code changed from 1 to 2".to_string()),
+                (false, "errorNode `invalidMint` was added".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn appended_enum_variants() {
+        let direction = |variants: Vec<&str>| {
+            DefinedTypeNode::new(
+                "direction",
+                EnumTypeNode::new(
+                    variants
+                        .into_iter()
+                        .map(|name| EnumEmptyVariantTypeNode::new(name).into())
+                        .collect(),
+                ),
+            )
+        };
+        let old = root(ProgramNode::new("token", "1111").add_defined_type(direction(vec!["up"])));
+        let new =
+            root(ProgramNode::new("token", "1111").add_defined_type(direction(vec!["up", "down"])));
+        assert_eq!(
+            summary(old.diff(&new)),
+            vec![(
+                false,
+                "enumEmptyVariantTypeNode `down` was added".to_string()
+            )]
+        );
+        assert_eq!(
+            summary(new.diff(&old)),
+            vec![(
+                true,
+                "enumEmptyVariantTypeNode `down` was removed".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn nested_types() {
+        let wrapper = |r#type: TypeNode| {
+            root(
+                ProgramNode::new("token", "1111")
+                    .add_defined_type(DefinedTypeNode::new("wrapper", r#type)),
+            )
+        };
+        let changes = |old: TypeNode, new: TypeNode| summary(wrapper(old).diff(&wrapper(new)));
+
+        assert_eq!(
+            changes(
+                OptionTypeNode::new(NumberTypeNode::le(U32)).into(),
+                OptionTypeNode::new(NumberTypeNode::le(U64)).into()
+            ),
+            vec![(true, "number format changed from u32 to u64".to_string())]
+        );
+        assert_eq!(
+            changes(
+                ArrayTypeNode::fixed(NumberTypeNode::le(U8), 3).into(),
+                ArrayTypeNode::prefixed(NumberTypeNode::le(U8), NumberTypeNode::le(U32)).into()
+            ),
+            vec![(
+                true,
+                "count changed from fixedCountNode to prefixedCountNode".to_string()
+            )]
+        );
+        assert_eq!(
+            changes(
+                MapTypeNode::fixed(NumberTypeNode::le(U8), PublicKeyTypeNode::new(), 2).into(),
+                MapTypeNode::fixed(NumberTypeNode::le(U32), PublicKeyTypeNode::new(), 4).into()
+            ),
+            vec![
+                (true, "count changed from 2 to 4".to_string()),
+                (true, "number format changed from u8 to u32".to_string()),
+            ]
+        );
+        assert_eq!(
+            changes(
+                TupleTypeNode::new(vec![NumberTypeNode::le(U8).into()]).into(),
+                TupleTypeNode::new(vec![
+                    NumberTypeNode::le(U32).into(),
+                    PublicKeyTypeNode::new().into()
+                ])
+                .into()
+            ),
+            vec![
+                (true, "tuple length changed from 1 to 2".to_string()),
+                (true, "number format changed from u8 to u32".to_string()),
+            ]
+        );
+        assert_eq!(
+            changes(
+                SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U32)).into(),
+                SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U64)).into()
+            ),
+            vec![(true, "number format changed from u32 to u64".to_string())]
+        );
+        assert_eq!(
+            changes(
+                FixedSizeTypeNode::new(StringTypeNode::utf8(), 32).into(),
+                FixedSizeTypeNode::new(StringTypeNode::utf8(), 64).into()
+            ),
+            vec![(true, "size changed from 32 to 64 bytes".to_string())]
+        );
+        assert_eq!(
+            changes(
+                ZeroableOptionTypeNode::new(NumberTypeNode::le(U32)).into(),
+                ZeroableOptionTypeNode::new(NumberTypeNode::le(U64)).into()
+            ),
+            vec![(true, "number format changed from u32 to u64".to_string())]
+        );
+        assert_eq!(
+            changes(
+                DefinedTypeLinkNode::new("amount").into(),
+                DefinedTypeLinkNode::new_from_program("amount", ProgramLinkNode::new("system"))
+                    .into()
+            ),
+            vec![(
+                true,
+                "type changed from `amount` to `amount` from program `system`".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod change;
+mod compare;
+
+pub use change::*;
+pub use compare::*;
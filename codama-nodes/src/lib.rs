@@ -1,7 +1,10 @@
 mod account_node;
+mod anchor;
+mod codec;
 mod contextual_value_nodes;
 mod count_nodes;
 mod defined_type_node;
+mod diff;
 mod discriminator_nodes;
 mod error_node;
 mod instruction_account_node;
@@ -19,12 +22,17 @@ mod root_node;
 mod shared;
 mod traits;
 mod type_nodes;
+mod validation;
 mod value_nodes;
+mod visitors;
 
 pub use account_node::*;
+pub use anchor::*;
+pub use codec::*;
 pub use contextual_value_nodes::*;
 pub use count_nodes::*;
 pub use defined_type_node::*;
+pub use diff::*;
 pub use discriminator_nodes::*;
 pub use error_node::*;
 pub use instruction_account_node::*;
@@ -42,7 +50,9 @@ pub use root_node::*;
 pub use shared::*;
 pub use traits::*;
 pub use type_nodes::*;
+pub use validation::*;
 pub use value_nodes::*;
+pub use visitors::*;
 
 // Serde helper function to use with `#[serde(some_thing = "crate::is_default")]`.
 fn is_default<T: Default + PartialEq>(t: &T) -> bool {
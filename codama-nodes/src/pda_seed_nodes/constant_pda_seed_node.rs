@@ -1,11 +1,93 @@
-use crate::{TypeNode, ValueNode};
-use codama_nodes_derive::node;
+use crate::{
+    ArrayValueNode, BooleanValueNode, BytesValueNode, ConstantValueNode, EnumValueNode, HasKind,
+    MapValueNode, NoneValueNode, NumberValueNode, ProgramIdValueNode, PublicKeyValueNode,
+    SetValueNode, SomeValueNode, StringValueNode, StructValueNode, TupleValueNode, TypeNode,
+    ValueNode,
+};
+use codama_errors::CodamaError;
+use codama_nodes_derive::{node, node_union};
 
 #[node]
 pub struct ConstantPdaSeedNode {
     // Children.
     pub r#type: TypeNode,
-    pub value: ValueNode,
+    pub value: ConstantPdaSeedValueNode,
+}
+
+#[node_union]
+pub enum ConstantPdaSeedValueNode {
+    ProgramId(ProgramIdValueNode),
+
+    // ValueNodes.
+    Array(ArrayValueNode),
+    Boolean(BooleanValueNode),
+    Bytes(BytesValueNode),
+    Constant(ConstantValueNode),
+    Enum(EnumValueNode),
+    Map(MapValueNode),
+    None(NoneValueNode),
+    Number(NumberValueNode),
+    PublicKey(PublicKeyValueNode),
+    Set(SetValueNode),
+    Some(SomeValueNode),
+    String(StringValueNode),
+    Struct(StructValueNode),
+    Tuple(TupleValueNode),
+}
+
+impl ConstantPdaSeedValueNode {
+    /// Whether the seed is the address of the program deriving the PDA.
+    pub fn is_program_id(&self) -> bool {
+        matches!(self, Self::ProgramId(_))
+    }
+}
+
+impl From<ValueNode> for ConstantPdaSeedValueNode {
+    fn from(value: ValueNode) -> Self {
+        match value {
+            ValueNode::Array(value) => Self::Array(value),
+            ValueNode::Boolean(value) => Self::Boolean(value),
+            ValueNode::Bytes(value) => Self::Bytes(value),
+            ValueNode::Constant(value) => Self::Constant(value),
+            ValueNode::Enum(value) => Self::Enum(value),
+            ValueNode::Map(value) => Self::Map(value),
+            ValueNode::None(value) => Self::None(value),
+            ValueNode::Number(value) => Self::Number(value),
+            ValueNode::PublicKey(value) => Self::PublicKey(value),
+            ValueNode::Set(value) => Self::Set(value),
+            ValueNode::Some(value) => Self::Some(value),
+            ValueNode::String(value) => Self::String(value),
+            ValueNode::Struct(value) => Self::Struct(value),
+            ValueNode::Tuple(value) => Self::Tuple(value),
+        }
+    }
+}
+
+impl TryFrom<ConstantPdaSeedValueNode> for ValueNode {
+    type Error = CodamaError;
+
+    fn try_from(value: ConstantPdaSeedValueNode) -> Result<Self, Self::Error> {
+        match value {
+            ConstantPdaSeedValueNode::Array(value) => Ok(Self::Array(value)),
+            ConstantPdaSeedValueNode::Boolean(value) => Ok(Self::Boolean(value)),
+            ConstantPdaSeedValueNode::Bytes(value) => Ok(Self::Bytes(value)),
+            ConstantPdaSeedValueNode::Constant(value) => Ok(Self::Constant(value)),
+            ConstantPdaSeedValueNode::Enum(value) => Ok(Self::Enum(value)),
+            ConstantPdaSeedValueNode::Map(value) => Ok(Self::Map(value)),
+            ConstantPdaSeedValueNode::None(value) => Ok(Self::None(value)),
+            ConstantPdaSeedValueNode::Number(value) => Ok(Self::Number(value)),
+            ConstantPdaSeedValueNode::PublicKey(value) => Ok(Self::PublicKey(value)),
+            ConstantPdaSeedValueNode::Set(value) => Ok(Self::Set(value)),
+            ConstantPdaSeedValueNode::Some(value) => Ok(Self::Some(value)),
+            ConstantPdaSeedValueNode::String(value) => Ok(Self::String(value)),
+            ConstantPdaSeedValueNode::Struct(value) => Ok(Self::Struct(value)),
+            ConstantPdaSeedValueNode::Tuple(value) => Ok(Self::Tuple(value)),
+            _ => Err(CodamaError::InvalidNodeConversion {
+                from: value.kind().to_string(),
+                into: "ValueNode".to_string(),
+            }),
+        }
+    }
 }
 
 impl From<ConstantPdaSeedNode> for crate::Node {
@@ -18,7 +100,7 @@ impl ConstantPdaSeedNode {
     pub fn new<T, U>(r#type: T, value: U) -> Self
     where
         T: Into<TypeNode>,
-        U: Into<ValueNode>,
+        U: Into<ConstantPdaSeedValueNode>,
     {
         Self {
             r#type: r#type.into(),
@@ -30,13 +112,16 @@ impl ConstantPdaSeedNode {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{NumberTypeNode, NumberValueNode, U64};
+    use crate::{NumberTypeNode, PublicKeyTypeNode, U64};
 
     #[test]
     fn new() {
         let node = ConstantPdaSeedNode::new(NumberTypeNode::le(U64), NumberValueNode::new(42u64));
         assert_eq!(node.r#type, TypeNode::Number(NumberTypeNode::le(U64)));
-        assert_eq!(node.value, ValueNode::Number(NumberValueNode::new(42u64)));
+        assert_eq!(
+            node.value,
+            ConstantPdaSeedValueNode::Number(NumberValueNode::new(42u64))
+        );
     }
 
     #[test]
@@ -58,4 +143,14 @@ mod tests {
             ConstantPdaSeedNode::new(NumberTypeNode::le(U64), NumberValueNode::new(42u64))
         );
     }
+
+    #[test]
+    fn program_id_to_json() {
+        let node = ConstantPdaSeedNode::new(PublicKeyTypeNode::new(), ProgramIdValueNode::new());
+        let json = serde_json::to_string(&node).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"constantPdaSeedNode","type":{"kind":"publicKeyTypeNode"},"value":{"kind":"programIdValueNode"}}"#
+        );
+    }
 }
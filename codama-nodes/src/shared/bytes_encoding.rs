@@ -1,3 +1,4 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use codama_errors::{CodamaError, CodamaResult};
 use serde::{Deserialize, Serialize};
 pub use BytesEncoding::*;
@@ -32,3 +33,63 @@ impl TryFrom<&str> for BytesEncoding {
         }
     }
 }
+
+impl BytesEncoding {
+    /// Converts a string using this encoding into bytes.
+    pub fn string_to_bytes(&self, data: &str) -> CodamaResult<Vec<u8>> {
+        let invalid = || CodamaError::Encoding(format!("invalid {self:?} string `{data}`"));
+        match self {
+            Base16 => {
+                if !data.len().is_multiple_of(2) {
+                    return Err(invalid());
+                }
+                (0..data.len())
+                    .step_by(2)
+                    .map(|i| {
+                        let byte = data.get(i..i + 2).ok_or_else(invalid)?;
+                        u8::from_str_radix(byte, 16).map_err(|_| invalid())
+                    })
+                    .collect()
+            }
+            Base58 => bs58::decode(data).into_vec().map_err(|_| invalid()),
+            Base64 => BASE64.decode(data).map_err(|_| invalid()),
+            Utf8 => Ok(data.as_bytes().to_vec()),
+        }
+    }
+
+    /// Converts bytes into a string using this encoding.
+    pub fn bytes_to_string(&self, bytes: &[u8]) -> CodamaResult<String> {
+        match self {
+            Base16 => Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect()),
+            Base58 => Ok(bs58::encode(bytes).into_string()),
+            Base64 => Ok(BASE64.encode(bytes)),
+            Utf8 => String::from_utf8(bytes.to_vec())
+                .map_err(|_| CodamaError::Decoding("invalid utf8 bytes".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_to_bytes() {
+        assert_eq!(Base16.string_to_bytes("ff01").unwrap(), vec![255, 1]);
+        assert_eq!(Base58.string_to_bytes("5R").unwrap(), vec![1, 0]);
+        assert_eq!(Base64.string_to_bytes("/wE=").unwrap(), vec![255, 1]);
+        assert_eq!(Utf8.string_to_bytes("Hi").unwrap(), vec![72, 105]);
+        assert!(Base16.string_to_bytes("f").is_err());
+        assert!(Base16.string_to_bytes("zz").is_err());
+        assert!(Base58.string_to_bytes("0OIl").is_err());
+    }
+
+    #[test]
+    fn bytes_to_string() {
+        assert_eq!(Base16.bytes_to_string(&[255, 1]).unwrap(), "ff01");
+        assert_eq!(Base58.bytes_to_string(&[1, 0]).unwrap(), "5R");
+        assert_eq!(Base64.bytes_to_string(&[255, 1]).unwrap(), "/wE=");
+        assert_eq!(Utf8.bytes_to_string(&[72, 105]).unwrap(), "Hi");
+        assert!(Utf8.bytes_to_string(&[255]).is_err());
+    }
+}
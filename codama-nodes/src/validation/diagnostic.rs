@@ -0,0 +1,107 @@
+use crate::CamelCaseString;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// The location of a node within a `RootNode`, e.g. `[programNode]token.[accountNode]mint`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodePath(Vec<(&'static str, Option<CamelCaseString>)>);
+
+impl NodePath {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn child(&self, kind: &'static str, name: Option<&CamelCaseString>) -> Self {
+        let mut path = self.clone();
+        path.0.push((kind, name.cloned()));
+        path
+    }
+
+    pub fn segments(&self) -> &[(&'static str, Option<CamelCaseString>)] {
+        &self.0
+    }
+}
+
+impl Display for NodePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let segments = self
+            .0
+            .iter()
+            .map(|(kind, name)| match name {
+                Some(name) => format!("[{kind}]{}", name.as_str()),
+                None => format!("[{kind}]"),
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", segments.join("."))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub path: NodePath,
+}
+
+impl Diagnostic {
+    pub fn error<T: Into<String>>(message: T, path: &NodePath) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+            path: path.clone(),
+        }
+    }
+
+    pub fn warning<T: Into<String>>(message: T, path: &NodePath) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+            path: path.clone(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} (at {})", self.severity, self.message, self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let path = NodePath::new()
+            .child("programNode", Some(&"token".into()))
+            .child("accountNode", Some(&"mint".into()))
+            .child("pdaLinkNode", None);
+        assert_eq!(
+            path.to_string(),
+            "[programNode]token.[accountNode]mint.[pdaLinkNode]"
+        );
+        assert_eq!(
+            Diagnostic::error("Something went wrong", &path).to_string(),
+            "error: Something went wrong (at [programNode]token.[accountNode]mint.[pdaLinkNode])"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod diagnostic;
+mod validate;
+
+pub use diagnostic::*;
+pub use validate::*;
@@ -0,0 +1,698 @@
+use crate::{
+    AccountBumpValueNode, AccountLinkNode, AccountValueNode, ArgumentValueNode, CamelCaseString,
+    DefinedTypeLinkNode, Diagnostic, DiscriminatorNode, HasKind, InstructionAccountLinkNode,
+    InstructionArgumentLinkNode, InstructionLinkNode, InstructionNode, NestedTypeNodeTrait,
+    NodePath, NodeVisitable, NodeVisitor, PdaLinkNode, PdaNode, PdaSeedNode, PdaValue,
+    PdaValueNode, ProgramLinkNode, ProgramNode, RootNode,
+};
+use codama_errors::CodamaResult;
+use std::collections::HashMap;
+
+/// Checks that the given IDL is consistent — e.g. that all links can be
+/// resolved and that names, error codes and discriminators are unique.
+pub fn validate(root: &RootNode) -> Vec<Diagnostic> {
+    let mut validator = Validator {
+        root,
+        diagnostics: vec![],
+    };
+    validator.validate_root();
+    validator.diagnostics
+}
+
+struct Validator<'a> {
+    root: &'a RootNode,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Validator<'a> {
+    fn programs(&self) -> impl Iterator<Item = &'a ProgramNode> {
+        std::iter::once(&self.root.program).chain(self.root.additional_programs.iter())
+    }
+
+    fn find_program(&self, name: &CamelCaseString) -> Option<&'a ProgramNode> {
+        self.programs().find(|program| &program.name == name)
+    }
+
+    fn validate_root(&mut self) {
+        let path = NodePath::new().child(self.root.kind(), None);
+        let programs = self.programs().collect::<Vec<_>>();
+        self.check_duplicate_names(programs.iter().map(|p| (p.kind(), &p.name)), &path);
+        for program in programs {
+            self.validate_program(program, &path);
+        }
+    }
+
+    fn validate_program(&mut self, program: &'a ProgramNode, parent: &NodePath) {
+        let path = parent.child(program.kind(), Some(&program.name));
+
+        // Ensure names and error codes are unique within the program.
+        self.check_duplicate_names(program.accounts.iter().map(|n| (n.kind(), &n.name)), &path);
+        self.check_duplicate_names(
+            program.instructions.iter().map(|n| (n.kind(), &n.name)),
+            &path,
+        );
+        self.check_duplicate_names(
+            program.defined_types.iter().map(|n| (n.kind(), &n.name)),
+            &path,
+        );
+        self.check_duplicate_names(program.pdas.iter().map(|n| (n.kind(), &n.name)), &path);
+        self.check_duplicate_names(program.errors.iter().map(|n| (n.kind(), &n.name)), &path);
+        self.check_duplicate_error_codes(program, &path);
+
+        // Ensure discriminators can tell accounts and instructions apart.
+        let accounts = program
+            .accounts
+            .iter()
+            .map(|account| {
+                let fields = &account.data.get_nested_type_node().fields;
+                let signature = discriminator_signature(&account.discriminators, |name| {
+                    fields
+                        .iter()
+                        .find(|field| &field.name == name)
+                        .and_then(|field| serde_json::to_string(field.default_value.as_ref()?).ok())
+                });
+                (account.kind(), &account.name, signature)
+            })
+            .collect::<Vec<_>>();
+        self.check_conflicting_discriminators(accounts, &path);
+        let instructions = program
+            .instructions
+            .iter()
+            .map(|instruction| {
+                let signature = discriminator_signature(&instruction.discriminators, |name| {
+                    instruction
+                        .arguments
+                        .iter()
+                        .find(|argument| &argument.name == name)
+                        .and_then(|argument| {
+                            serde_json::to_string(argument.default_value.as_ref()?).ok()
+                        })
+                });
+                (instruction.kind(), &instruction.name, signature)
+            })
+            .collect::<Vec<_>>();
+        self.check_conflicting_discriminators(instructions, &path);
+
+        // Ensure all links and references can be resolved.
+        for account in &program.accounts {
+            let path = path.child(account.kind(), Some(&account.name));
+            self.check_references(account, program, None, &path);
+        }
+        for defined_type in &program.defined_types {
+            let path = path.child(defined_type.kind(), Some(&defined_type.name));
+            self.check_references(defined_type, program, None, &path);
+        }
+        for pda in &program.pdas {
+            let path = path.child(pda.kind(), Some(&pda.name));
+            self.check_references(pda, program, None, &path);
+        }
+        for instruction in &program.instructions {
+            self.validate_instruction(instruction, program, &path);
+        }
+    }
+
+    fn validate_instruction(
+        &mut self,
+        instruction: &'a InstructionNode,
+        program: &'a ProgramNode,
+        parent: &NodePath,
+    ) {
+        let path = parent.child(instruction.kind(), Some(&instruction.name));
+        let context = Some(instruction);
+
+        // Ensure instruction accounts and arguments are unique.
+        self.check_duplicate_names(
+            instruction.accounts.iter().map(|n| (n.kind(), &n.name)),
+            &path,
+        );
+        self.check_duplicate_names(
+            instruction
+                .arguments
+                .iter()
+                .chain(instruction.extra_arguments.iter())
+                .map(|n| (n.kind(), &n.name)),
+            &path,
+        );
+
+        // Ensure all links and references can be resolved.
+        for account in &instruction.accounts {
+            let path = path.child(account.kind(), Some(&account.name));
+            self.check_references(account, program, context, &path);
+        }
+        for argument in instruction
+            .arguments
+            .iter()
+            .chain(instruction.extra_arguments.iter())
+        {
+            let path = path.child(argument.kind(), Some(&argument.name));
+            self.check_references(argument, program, context, &path);
+        }
+        for remaining_accounts in &instruction.remaining_accounts {
+            self.check_references(remaining_accounts, program, context, &path);
+        }
+        for byte_delta in &instruction.byte_deltas {
+            self.check_references(byte_delta, program, context, &path);
+        }
+
+        // Sub-instructions resolve their references against themselves.
+        for sub_instruction in &instruction.sub_instructions {
+            self.validate_instruction(sub_instruction, program, &path);
+        }
+    }
+
+    fn check_duplicate_names<I>(&mut self, nodes: I, path: &NodePath)
+    where
+        I: IntoIterator<Item = (&'static str, &'a CamelCaseString)>,
+    {
+        let mut seen = HashMap::<&str, usize>::new();
+        for (kind, name) in nodes {
+            let count = seen.entry(name.as_str()).or_default();
+            *count += 1;
+            if *count == 2 {
+                self.diagnostics.push(Diagnostic::error(
+                    format!("Duplicate {} name `{}`", kind_label(kind), name.as_str()),
+                    &path.child(kind, Some(name)),
+                ));
+            }
+        }
+    }
+
+    fn check_duplicate_error_codes(&mut self, program: &ProgramNode, path: &NodePath) {
+        let mut seen = HashMap::<usize, &CamelCaseString>::new();
+        for error in &program.errors {
+            match seen.get(&error.code) {
+                Some(other) => self.diagnostics.push(Diagnostic::error(
+                    format!(
+                        "Duplicate error code {} (also used by `{}`)",
+                        error.code,
+                        other.as_str()
+                    ),
+                    &path.child(error.kind(), Some(&error.name)),
+                )),
+                None => {
+                    seen.insert(error.code, &error.name);
+                }
+            }
+        }
+    }
+
+    fn check_conflicting_discriminators(
+        &mut self,
+        nodes: Vec<(&'static str, &CamelCaseString, Option<Vec<String>>)>,
+        path: &NodePath,
+    ) {
+        for (index, (kind, name, signature)) in nodes.iter().enumerate() {
+            let Some(signature) = signature else {
+                continue;
+            };
+            let conflict = nodes[..index]
+                .iter()
+                .find(|(_, _, other)| other.as_ref() == Some(signature));
+            if let Some((_, other, _)) = conflict {
+                self.diagnostics.push(Diagnostic::error(
+                    format!(
+                        "Conflicting discriminators: {} `{}` cannot be distinguished from `{}`",
+                        kind_label(kind),
+                        name.as_str(),
+                        other.as_str()
+                    ),
+                    &path.child(kind, Some(name)),
+                ));
+            }
+        }
+    }
+
+    fn check_references<N: NodeVisitable>(
+        &mut self,
+        node: &N,
+        program: &'a ProgramNode,
+        instruction: Option<&'a InstructionNode>,
+        path: &NodePath,
+    ) {
+        let mut references = ReferenceCollector::default();
+        // The reference collector never fails.
+        let _ = node.accept(&mut references);
+
+        for link in references.programs {
+            if self.find_program(&link.name).is_none() {
+                self.unresolved(&link, path);
+            }
+        }
+        for link in references.defined_types {
+            if let Some(program) = self.link_program(link.program.as_ref(), program) {
+                if !program.defined_types.iter().any(|n| n.name == link.name) {
+                    self.unresolved(&link, path);
+                }
+            }
+        }
+        for link in references.accounts {
+            if let Some(program) = self.link_program(link.program.as_ref(), program) {
+                if !program.accounts.iter().any(|n| n.name == link.name) {
+                    self.unresolved(&link, path);
+                }
+            }
+        }
+        for link in references.pdas {
+            if let Some(program) = self.link_program(link.program.as_ref(), program) {
+                if !program.pdas.iter().any(|n| n.name == link.name) {
+                    self.unresolved(&link, path);
+                }
+            }
+        }
+        for link in references.instructions {
+            if let Some(program) = self.link_program(link.program.as_ref(), program) {
+                if find_instruction(&program.instructions, &link.name).is_none() {
+                    self.unresolved(&link, path);
+                }
+            }
+        }
+        for link in references.instruction_accounts {
+            if let Some(instruction) =
+                self.link_instruction(link.instruction.as_ref(), program, instruction)
+            {
+                if !instruction.accounts.iter().any(|n| n.name == link.name) {
+                    self.unresolved(&link, path);
+                }
+            }
+        }
+        for link in references.instruction_arguments {
+            if let Some(instruction) =
+                self.link_instruction(link.instruction.as_ref(), program, instruction)
+            {
+                if !has_argument(instruction, &link.name) {
+                    self.unresolved(&link, path);
+                }
+            }
+        }
+
+        // Contextual values only make sense within an instruction.
+        if let Some(instruction) = instruction {
+            for name in references.account_values {
+                if !instruction.accounts.iter().any(|n| n.name == name) {
+                    self.diagnostics.push(Diagnostic::error(
+                        format!(
+                            "Reference to missing instruction account `{}`",
+                            name.as_str()
+                        ),
+                        path,
+                    ));
+                }
+            }
+            for name in references.argument_values {
+                if !has_argument(instruction, &name) {
+                    self.diagnostics.push(Diagnostic::error(
+                        format!(
+                            "Reference to missing instruction argument `{}`",
+                            name.as_str()
+                        ),
+                        path,
+                    ));
+                }
+            }
+        }
+
+        for pda_value in references.pda_values {
+            self.check_pda_value_seeds(&pda_value, program, path);
+        }
+    }
+
+    fn check_pda_value_seeds(
+        &mut self,
+        pda_value: &PdaValueNode,
+        program: &'a ProgramNode,
+        path: &NodePath,
+    ) {
+        let pda = match &pda_value.pda {
+            PdaValue::Nested(pda) => pda,
+            PdaValue::Linked(link) => {
+                let pda = self
+                    .link_program(link.program.as_ref(), program)
+                    .and_then(|program| program.pdas.iter().find(|n| n.name == link.name));
+                match pda {
+                    Some(pda) => pda,
+                    // Unresolved PDA links are reported separately.
+                    None => return,
+                }
+            }
+        };
+
+        let variable_seeds = variable_seed_names(pda);
+        for seed in &pda_value.seeds {
+            if !variable_seeds.contains(&&seed.name) {
+                self.diagnostics.push(Diagnostic::error(
+                    format!(
+                        "PDA seed `{}` does not exist on PDA `{}`",
+                        seed.name.as_str(),
+                        pda.name.as_str()
+                    ),
+                    path,
+                ));
+            }
+        }
+        for name in variable_seeds {
+            if !pda_value.seeds.iter().any(|seed| &seed.name == name) {
+                self.diagnostics.push(Diagnostic::warning(
+                    format!(
+                        "PDA seed `{}` of PDA `{}` is not provided",
+                        name.as_str(),
+                        pda.name.as_str()
+                    ),
+                    path,
+                ));
+            }
+        }
+    }
+
+    /// The program a link points to, or `None` if its program link cannot be resolved.
+    fn link_program(
+        &self,
+        link: Option<&ProgramLinkNode>,
+        program: &'a ProgramNode,
+    ) -> Option<&'a ProgramNode> {
+        match link {
+            Some(link) => self.find_program(&link.name),
+            None => Some(program),
+        }
+    }
+
+    /// The instruction an instruction account or argument link points to.
+    fn link_instruction(
+        &mut self,
+        link: Option<&InstructionLinkNode>,
+        program: &'a ProgramNode,
+        instruction: Option<&'a InstructionNode>,
+    ) -> Option<&'a InstructionNode> {
+        match link {
+            Some(link) => {
+                let program = self.link_program(link.program.as_ref(), program)?;
+                find_instruction(&program.instructions, &link.name)
+            }
+            None => instruction,
+        }
+    }
+
+    fn unresolved<N: HasKind + NodeVisitable>(&mut self, link: &N, path: &NodePath) {
+        let name = link.node_name().map(|name| name.as_str()).unwrap_or("");
+        self.diagnostics.push(Diagnostic::error(
+            format!("Unresolved {} `{name}`", kind_label(link.kind())),
+            path,
+        ));
+    }
+}
+
+/// Collects all links and contextual references found within a node.
+#[derive(Default)]
+struct ReferenceCollector {
+    programs: Vec<ProgramLinkNode>,
+    defined_types: Vec<DefinedTypeLinkNode>,
+    accounts: Vec<AccountLinkNode>,
+    pdas: Vec<PdaLinkNode>,
+    instructions: Vec<InstructionLinkNode>,
+    instruction_accounts: Vec<InstructionAccountLinkNode>,
+    instruction_arguments: Vec<InstructionArgumentLinkNode>,
+    account_values: Vec<CamelCaseString>,
+    argument_values: Vec<CamelCaseString>,
+    pda_values: Vec<PdaValueNode>,
+}
+
+impl NodeVisitor for ReferenceCollector {
+    fn visit_program_link(&mut self, node: &ProgramLinkNode) -> CodamaResult<()> {
+        self.programs.push(node.clone());
+        Ok(())
+    }
+
+    fn visit_defined_type_link(&mut self, node: &DefinedTypeLinkNode) -> CodamaResult<()> {
+        self.defined_types.push(node.clone());
+        Ok(())
+    }
+
+    fn visit_account_link(&mut self, node: &AccountLinkNode) -> CodamaResult<()> {
+        self.accounts.push(node.clone());
+        Ok(())
+    }
+
+    fn visit_pda_link(&mut self, node: &PdaLinkNode) -> CodamaResult<()> {
+        self.pdas.push(node.clone());
+        Ok(())
+    }
+
+    fn visit_instruction_link(&mut self, node: &InstructionLinkNode) -> CodamaResult<()> {
+        self.instructions.push(node.clone());
+        Ok(())
+    }
+
+    fn visit_instruction_account_link(
+        &mut self,
+        node: &InstructionAccountLinkNode,
+    ) -> CodamaResult<()> {
+        self.instruction_accounts.push(node.clone());
+        Ok(())
+    }
+
+    fn visit_instruction_argument_link(
+        &mut self,
+        node: &InstructionArgumentLinkNode,
+    ) -> CodamaResult<()> {
+        self.instruction_arguments.push(node.clone());
+        Ok(())
+    }
+
+    fn visit_account_value(&mut self, node: &AccountValueNode) -> CodamaResult<()> {
+        self.account_values.push(node.name.clone());
+        Ok(())
+    }
+
+    fn visit_account_bump_value(&mut self, node: &AccountBumpValueNode) -> CodamaResult<()> {
+        self.account_values.push(node.name.clone());
+        Ok(())
+    }
+
+    fn visit_argument_value(&mut self, node: &ArgumentValueNode) -> CodamaResult<()> {
+        self.argument_values.push(node.name.clone());
+        Ok(())
+    }
+
+    fn visit_pda_value(&mut self, node: &PdaValueNode) -> CodamaResult<()> {
+        self.pda_values.push(node.clone());
+        Ok(())
+    }
+}
+
+fn find_instruction<'a>(
+    instructions: &'a [InstructionNode],
+    name: &CamelCaseString,
+) -> Option<&'a InstructionNode> {
+    instructions.iter().find_map(|instruction| {
+        if &instruction.name == name {
+            return Some(instruction);
+        }
+        find_instruction(&instruction.sub_instructions, name)
+    })
+}
+
+fn has_argument(instruction: &InstructionNode, name: &CamelCaseString) -> bool {
+    instruction
+        .arguments
+        .iter()
+        .chain(instruction.extra_arguments.iter())
+        .any(|argument| &argument.name == name)
+}
+
+fn variable_seed_names(pda: &PdaNode) -> Vec<&CamelCaseString> {
+    pda.seeds
+        .iter()
+        .filter_map(|seed| match seed {
+            PdaSeedNode::Variable(seed) => Some(&seed.name),
+            PdaSeedNode::Constant(_) => None,
+        })
+        .collect()
+}
+
+/// A comparable representation of a set of discriminators, or `None` if
+/// the discriminators are empty or depend on a field without a default value.
+fn discriminator_signature<F>(
+    discriminators: &[DiscriminatorNode],
+    field_value: F,
+) -> Option<Vec<String>>
+where
+    F: Fn(&CamelCaseString) -> Option<String>,
+{
+    if discriminators.is_empty() {
+        return None;
+    }
+    let mut signature = discriminators
+        .iter()
+        .map(|discriminator| match discriminator {
+            DiscriminatorNode::Constant(node) => Some(format!(
+                "constant:{}:{}",
+                node.offset,
+                serde_json::to_string(&node.constant).ok()?
+            )),
+            DiscriminatorNode::Field(node) => Some(format!(
+                "field:{}:{}",
+                node.offset,
+                field_value(&node.name)?
+            )),
+            DiscriminatorNode::Size(node) => Some(format!("size:{}", node.size)),
+        })
+        .collect::<Option<Vec<_>>>()?;
+    signature.sort();
+    Some(signature)
+}
+
+/// A human-readable label for a node kind — e.g. `"defined type link"` for `"definedTypeLinkNode"`.
+fn kind_label(kind: &str) -> String {
+    let kind = kind.strip_suffix("Node").unwrap_or(kind);
+    let mut label = String::new();
+    for char in kind.chars() {
+        if char.is_uppercase() {
+            label.push(' ');
+        }
+        label.push(char.to_ascii_lowercase());
+    }
+    label.replace("pda", "PDA")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountNode, ArgumentValueNode, BytesEncoding, ConstantDiscriminatorNode,
+        ConstantValueNode, DefinedTypeNode, ErrorNode, InstructionAccountNode,
+        InstructionArgumentNode, NumberTypeNode, PdaSeedValueNode, PublicKeyTypeNode, Severity,
+        StructFieldTypeNode, StructTypeNode, VariablePdaSeedNode, U64,
+    };
+
+    fn program() -> ProgramNode {
+        ProgramNode::new("token", "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+    }
+
+    fn messages(root: &RootNode) -> Vec<String> {
+        validate(root).iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn valid_root() {
+        let root = RootNode::new(
+            program()
+                .add_defined_type(DefinedTypeNode::new("amount", NumberTypeNode::le(U64)))
+                .add_account(AccountNode::new(
+                    "token",
+                    StructTypeNode::new(vec![StructFieldTypeNode::new(
+                        "amount",
+                        DefinedTypeLinkNode::new("amount"),
+                    )]),
+                )),
+        );
+        assert_eq!(validate(&root), vec![]);
+    }
+
+    #[test]
+    fn unresolved_defined_type_link() {
+        let root = RootNode::new(program().add_account(AccountNode::new(
+            "token",
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "amount",
+                DefinedTypeLinkNode::new("amount"),
+            )]),
+        )));
+        assert_eq!(
+            messages(&root),
+            vec!["error: Unresolved defined type link `amount` (at [rootNode].[programNode]token.[accountNode]token)"]
+        );
+    }
+
+    #[test]
+    fn unresolved_program_link() {
+        let root = RootNode::new(program().add_defined_type(DefinedTypeNode::new(
+            "wrapper",
+            DefinedTypeLinkNode::new_from_program("amount", ProgramLinkNode::new("missing")),
+        )));
+        assert_eq!(
+            messages(&root),
+            vec!["error: Unresolved program link `missing` (at [rootNode].[programNode]token.[definedTypeNode]wrapper)"]
+        );
+    }
+
+    #[test]
+    fn duplicate_names_and_error_codes() {
+        let root = RootNode::new(
+            program()
+                .add_account(AccountNode::new("token", StructTypeNode::new(vec![])))
+                .add_account(AccountNode::new("token", StructTypeNode::new(vec![])))
+                .add_error(ErrorNode::new("invalidMint", 1, "Invalid mint"))
+                .add_error(ErrorNode::new("invalidOwner", 1, "Invalid owner")),
+        );
+        assert_eq!(
+            messages(&root),
+            vec![
+                "error: Duplicate account name `token` (at [rootNode].[programNode]token.[accountNode]token)",
+                "error: Duplicate error code 1 (also used by `invalidMint`) (at [rootNode].[programNode]token.[errorNode]invalidOwner)",
+            ]
+        );
+    }
+
+    #[test]
+    fn conflicting_discriminators() {
+        let discriminator = ConstantDiscriminatorNode::new(
+            ConstantValueNode::bytes(BytesEncoding::Base16, "01"),
+            0,
+        );
+        let account = |name: &str| AccountNode {
+            discriminators: vec![discriminator.clone().into()],
+            ..AccountNode::new(name, StructTypeNode::new(vec![]))
+        };
+        let root = RootNode::new(
+            program()
+                .add_account(account("mint"))
+                .add_account(account("token")),
+        );
+        assert_eq!(
+            messages(&root),
+            vec!["error: Conflicting discriminators: account `token` cannot be distinguished from `mint` (at [rootNode].[programNode]token.[accountNode]token)"]
+        );
+    }
+
+    #[test]
+    fn pda_seeds_referencing_missing_arguments() {
+        let instruction = InstructionNode {
+            name: "create".into(),
+            accounts: vec![InstructionAccountNode {
+                default_value: Some(
+                    PdaValueNode::new(
+                        PdaLinkNode::new("vault"),
+                        vec![
+                            PdaSeedValueNode::new("owner", ArgumentValueNode::new("owner")),
+                            PdaSeedValueNode::new("mint", ArgumentValueNode::new("mint")),
+                        ],
+                    )
+                    .into(),
+                ),
+                ..InstructionAccountNode::new("vault", true, false)
+            }],
+            arguments: vec![InstructionArgumentNode::new(
+                "mint",
+                PublicKeyTypeNode::new(),
+            )],
+            ..InstructionNode::default()
+        };
+        let root = RootNode::new(
+            program()
+                .add_pda(PdaNode::new(
+                    "vault",
+                    vec![VariablePdaSeedNode::new("owner", PublicKeyTypeNode::new()).into()],
+                ))
+                .add_instruction(instruction),
+        );
+
+        let diagnostics = validate(&root);
+        assert_eq!(
+            diagnostics.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "error: Reference to missing instruction argument `owner` (at [rootNode].[programNode]token.[instructionNode]create.[instructionAccountNode]vault)",
+                "error: PDA seed `mint` does not exist on PDA `vault` (at [rootNode].[programNode]token.[instructionNode]create.[instructionAccountNode]vault)",
+            ]
+        );
+        assert!(diagnostics.iter().all(|d| d.severity == Severity::Error));
+    }
+}
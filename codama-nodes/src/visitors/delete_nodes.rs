@@ -0,0 +1,146 @@
+use crate::{
+    HasKind, InstructionNode, NodeScope, NodeSelector, NodeTransformer, NodeVisitable, ProgramNode,
+    TraversalMode,
+};
+use codama_errors::CodamaResult;
+
+/// Deletes all nodes matching any of the given selectors.
+///
+/// Deleting a required child also deletes its parent, which is why
+/// the transformed node itself may end up being deleted.
+pub fn delete_nodes<N, I, S>(node: N, selectors: I) -> CodamaResult<Option<N>>
+where
+    N: NodeVisitable,
+    I: IntoIterator<Item = S>,
+    S: Into<NodeSelector>,
+{
+    node.transform(&mut DeleteNodesTransformer {
+        selectors: selectors.into_iter().map(Into::into).collect(),
+        scope: NodeScope::default(),
+    })
+}
+
+struct DeleteNodesTransformer {
+    selectors: Vec<NodeSelector>,
+    scope: NodeScope,
+}
+
+impl NodeTransformer for DeleteNodesTransformer {
+    fn mode(&self) -> TraversalMode {
+        // Deleted nodes don't need their children to be transformed.
+        TraversalMode::TopDown
+    }
+
+    fn transform_node<N: NodeVisitable + HasKind>(&mut self, node: N) -> CodamaResult<Option<N>> {
+        match self
+            .selectors
+            .iter()
+            .any(|selector| selector.matches_in(&node, &self.scope))
+        {
+            true => Ok(None),
+            false => Ok(Some(node)),
+        }
+    }
+
+    fn transform_program(&mut self, node: ProgramNode) -> CodamaResult<Option<ProgramNode>> {
+        let node = self.transform_node(node)?;
+        if let Some(node) = &node {
+            self.scope.enter_program(node);
+        }
+        Ok(node)
+    }
+
+    fn transform_instruction(
+        &mut self,
+        node: InstructionNode,
+    ) -> CodamaResult<Option<InstructionNode>> {
+        let node = self.transform_node(node)?;
+        if let Some(node) = &node {
+            self.scope.enter_instruction(node);
+        }
+        Ok(node)
+    }
+
+    fn leave_node(&mut self, kind: &'static str) -> CodamaResult<()> {
+        self.scope.leave(kind);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountNode, ErrorNode, NumberTypeNode, ProgramNode, RootNode, StringTypeNode,
+        StructFieldTypeNode, StructTypeNode, U64,
+    };
+
+    fn root() -> RootNode {
+        RootNode::new(
+            ProgramNode::new("token", "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+                .add_account(AccountNode::new(
+                    "mint",
+                    StructTypeNode::new(vec![
+                        StructFieldTypeNode::new("supply", NumberTypeNode::le(U64)),
+                        StructFieldTypeNode::new("name", StringTypeNode::utf8()),
+                    ]),
+                ))
+                .add_account(AccountNode::new("token", StructTypeNode::new(vec![])))
+                .add_error(ErrorNode::new("invalidMint", 1, "Invalid mint")),
+        )
+    }
+
+    #[test]
+    fn delete_by_kind_and_name() {
+        let root = delete_nodes(root(), ["[accountNode]mint"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            root.program.accounts,
+            vec![AccountNode::new("token", StructTypeNode::new(vec![]))]
+        );
+        assert_eq!(root.program.errors.len(), 1);
+    }
+
+    #[test]
+    fn delete_by_path() {
+        let root = delete_nodes(
+            root(),
+            ["[programNode]system.mint", "token.[accountNode]token"],
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(root.program.accounts.len(), 1);
+        assert_eq!(root.program.accounts[0].name, "mint".into());
+    }
+
+    #[test]
+    fn delete_by_kind() {
+        let root = delete_nodes(root(), ["[accountNode]", "[errorNode]"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(root.program.accounts, vec![]);
+        assert_eq!(root.program.errors, vec![]);
+    }
+
+    #[test]
+    fn delete_required_child() {
+        // Deleting the string type deletes the struct field that requires it.
+        let root = delete_nodes(root(), ["[stringTypeNode]"]).unwrap().unwrap();
+        assert_eq!(
+            root.program.accounts[0],
+            AccountNode::new(
+                "mint",
+                StructTypeNode::new(vec![StructFieldTypeNode::new(
+                    "supply",
+                    NumberTypeNode::le(U64)
+                )]),
+            )
+        );
+    }
+
+    #[test]
+    fn delete_root() {
+        assert_eq!(delete_nodes(root(), ["[programNode]"]).unwrap(), None);
+    }
+}
@@ -0,0 +1,17 @@
+mod delete_nodes;
+mod node_selector;
+mod node_transformer;
+mod node_visitable;
+mod node_visitor;
+mod rename;
+mod update_accounts;
+mod update_instructions;
+
+pub use delete_nodes::*;
+pub use node_selector::*;
+pub use node_transformer::*;
+pub use node_visitable::*;
+pub use node_visitor::*;
+pub use rename::*;
+pub use update_accounts::*;
+pub use update_instructions::*;
@@ -0,0 +1,215 @@
+use crate::{CamelCaseString, HasKind, InstructionNode, NodeVisitable, ProgramNode};
+
+/// Selects nodes by kind and/or name.
+///
+/// Selectors can be parsed from strings such as `"[accountNode]mint"` (an
+/// account named `mint`), `"[accountNode]"` (any account) or `"mint"` (any
+/// named node called `mint`). Dot-separated paths select nodes within a given
+/// program or instruction, e.g. `"[instructionNode]transfer.[instructionAccountNode]source"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeSelector {
+    pub kind: Option<String>,
+    pub name: Option<CamelCaseString>,
+    /// Selectors of the programs and instructions containing the node, outermost first.
+    pub parents: Vec<NodeSelector>,
+}
+
+impl NodeSelector {
+    pub fn new(selector: &str) -> Self {
+        let mut parents = selector
+            .split('.')
+            .map(Self::new_single)
+            .collect::<Vec<_>>();
+        let selector = parents.pop().unwrap_or_default();
+        Self {
+            parents,
+            ..selector
+        }
+    }
+
+    fn new_single(selector: &str) -> Self {
+        let selector = selector.trim();
+        let (kind, name) = match selector
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once(']'))
+        {
+            Some((kind, name)) => (Some(kind.trim().to_string()), name.trim()),
+            None => (None, selector),
+        };
+        Self {
+            kind: kind.filter(|kind| !kind.is_empty()),
+            name: match name {
+                "" => None,
+                name => Some(CamelCaseString::new(name)),
+            },
+            parents: vec![],
+        }
+    }
+
+    pub fn kind(kind: &str) -> Self {
+        Self {
+            kind: Some(kind.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Whether the selector matches the given node, regardless of its parents.
+    /// Selectors with parents never match here; use `matches_in` instead.
+    pub fn matches<N: NodeVisitable + HasKind>(&self, node: &N) -> bool {
+        self.parents.is_empty() && self.matches_kind_and_name(node.kind(), node.node_name())
+    }
+
+    /// Whether the selector matches the given node within the given scope.
+    pub fn matches_in<N: NodeVisitable + HasKind>(&self, node: &N, scope: &NodeScope) -> bool {
+        if !self.matches_kind_and_name(node.kind(), node.node_name()) {
+            return false;
+        }
+        let mut ancestors = scope.ancestors();
+        self.parents.iter().all(|parent| {
+            ancestors.any(|(kind, name)| parent.matches_kind_and_name(kind, Some(name)))
+        })
+    }
+
+    fn matches_kind_and_name(&self, kind: &str, name: Option<&CamelCaseString>) -> bool {
+        if let Some(expected) = &self.kind {
+            if expected != kind {
+                return false;
+            }
+        }
+        match &self.name {
+            Some(expected) => name == Some(expected),
+            None => true,
+        }
+    }
+}
+
+impl From<&str> for NodeSelector {
+    fn from(selector: &str) -> Self {
+        Self::new(selector)
+    }
+}
+
+impl From<String> for NodeSelector {
+    fn from(selector: String) -> Self {
+        Self::new(&selector)
+    }
+}
+
+/// The program and instruction containing the nodes being traversed top-down,
+/// using their original names.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NodeScope {
+    pub program: Option<CamelCaseString>,
+    pub instruction: Option<CamelCaseString>,
+    /// The instructions containing the current one, from outermost to innermost.
+    outer_instructions: Vec<CamelCaseString>,
+}
+
+impl NodeScope {
+    pub fn enter_program(&mut self, node: &ProgramNode) {
+        self.program = Some(node.name.clone());
+        self.instruction = None;
+        self.outer_instructions.clear();
+    }
+
+    pub fn enter_instruction(&mut self, node: &InstructionNode) {
+        if let Some(outer) = self.instruction.replace(node.name.clone()) {
+            self.outer_instructions.push(outer);
+        }
+    }
+
+    /// Leaves the program or instruction entered last, given the kind of the node being left.
+    pub fn leave(&mut self, kind: &str) {
+        match kind {
+            "programNode" => *self = Self::default(),
+            "instructionNode" => self.instruction = self.outer_instructions.pop(),
+            _ => {}
+        }
+    }
+
+    fn ancestors(&self) -> impl Iterator<Item = (&'static str, &CamelCaseString)> {
+        let program = self.program.iter().map(|name| ("programNode", name));
+        let instruction = self
+            .instruction
+            .iter()
+            .map(|name| ("instructionNode", name));
+        program.chain(instruction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountNode, ErrorNode, InstructionAccountNode, NumberTypeNode, StructTypeNode, U8,
+    };
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            NodeSelector::new("[accountNode]mint"),
+            NodeSelector {
+                kind: Some("accountNode".to_string()),
+                name: Some("mint".into()),
+                parents: vec![],
+            }
+        );
+        assert_eq!(
+            NodeSelector::new("[accountNode]"),
+            NodeSelector::kind("accountNode")
+        );
+        assert_eq!(
+            NodeSelector::new("token_account"),
+            NodeSelector {
+                kind: None,
+                name: Some("tokenAccount".into()),
+                parents: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn new_with_parents() {
+        assert_eq!(
+            NodeSelector::new("token.[instructionNode]transfer.source"),
+            NodeSelector {
+                kind: None,
+                name: Some("source".into()),
+                parents: vec![
+                    NodeSelector::new("token"),
+                    NodeSelector::new("[instructionNode]transfer"),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn matches_in() {
+        let account = InstructionAccountNode::new("source", true, false);
+        let scope = NodeScope {
+            program: Some("token".into()),
+            instruction: Some("transfer".into()),
+            ..NodeScope::default()
+        };
+        assert!(NodeSelector::new("transfer.source").matches_in(&account, &scope));
+        assert!(NodeSelector::new("token.transfer.source").matches_in(&account, &scope));
+        assert!(NodeSelector::new("[programNode]token.source").matches_in(&account, &scope));
+        assert!(!NodeSelector::new("burn.source").matches_in(&account, &scope));
+        assert!(!NodeSelector::new("transfer.token.source").matches_in(&account, &scope));
+        assert!(!NodeSelector::new("transfer.source").matches(&account));
+    }
+
+    #[test]
+    fn matches() {
+        let account = AccountNode::new("mint", StructTypeNode::new(vec![]));
+        let error = ErrorNode::new("mint", 42, "Invalid mint");
+        assert!(NodeSelector::new("[accountNode]mint").matches(&account));
+        assert!(NodeSelector::new("[accountNode]").matches(&account));
+        assert!(NodeSelector::new("mint").matches(&account));
+        assert!(NodeSelector::new("mint").matches(&error));
+        assert!(!NodeSelector::new("[accountNode]mint").matches(&error));
+        assert!(!NodeSelector::new("[accountNode]token").matches(&account));
+        assert!(!NodeSelector::new("mint").matches(&NumberTypeNode::le(U8)));
+        assert!(NodeSelector::new("[numberTypeNode]").matches(&NumberTypeNode::le(U8)));
+    }
+}
@@ -0,0 +1,703 @@
+use crate::{
+    AccountBumpValueNode, AccountLinkNode, AccountNode, AccountValueNode, AmountTypeNode,
+    ArgumentValueNode, ArrayTypeNode, ArrayValueNode, BooleanTypeNode, BooleanValueNode,
+    BytesTypeNode, BytesValueNode, ConditionalValueNode, ConstantDiscriminatorNode,
+    ConstantPdaSeedNode, ConstantValueNode, DateTimeTypeNode, DefinedTypeLinkNode, DefinedTypeNode,
+    EnumEmptyVariantTypeNode, EnumStructVariantTypeNode, EnumTupleVariantTypeNode, EnumTypeNode,
+    EnumValueNode, ErrorNode, FieldDiscriminatorNode, FixedCountNode, FixedSizeTypeNode, HasKind,
+    HiddenPrefixTypeNode, HiddenSuffixTypeNode, IdentityValueNode, InstructionAccountLinkNode,
+    InstructionAccountNode, InstructionArgumentLinkNode, InstructionArgumentNode,
+    InstructionByteDeltaNode, InstructionInputValueNode, InstructionLinkNode, InstructionNode,
+    InstructionRemainingAccountsNode, InstructionStatusNode, MapEntryValueNode, MapTypeNode,
+    MapValueNode, NodeVisitable, NoneValueNode, NumberTypeNode, NumberValueNode, OptionTypeNode,
+    PayerValueNode, PdaLinkNode, PdaNode, PdaSeedValueNode, PdaValueNode, PostOffsetTypeNode,
+    PreOffsetTypeNode, PrefixedCountNode, ProgramIdValueNode, ProgramLinkNode, ProgramNode,
+    PublicKeyTypeNode, PublicKeyValueNode, RemainderCountNode, RemainderOptionTypeNode,
+    ResolverValueNode, RootNode, SentinelTypeNode, SetTypeNode, SetValueNode,
+    SizeDiscriminatorNode, SizePrefixTypeNode, SolAmountTypeNode, SomeValueNode, StringTypeNode,
+    StringValueNode, StructFieldTypeNode, StructFieldValueNode, StructTypeNode, StructValueNode,
+    TraversalMode, TupleTypeNode, TupleValueNode, TypeNode, ValueNode, VariablePdaSeedNode,
+    ZeroableOptionTypeNode,
+};
+use codama_errors::CodamaResult;
+
+/// Transforms a tree of nodes by taking ownership of each node and returning a new one.
+///
+/// Every `transform_*` hook defaults to [`NodeTransformer::transform_node`] which
+/// keeps the node as-is. Returning `Ok(None)` from a hook deletes the node:
+/// optional fields become `None`, list items are removed and parents
+/// whose required child was deleted are deleted as well.
+pub trait NodeTransformer: Sized {
+    /// Whether children are transformed before (default) or after their parent.
+    fn mode(&self) -> TraversalMode {
+        TraversalMode::BottomUp
+    }
+
+    /// Fallback hook called for every concrete node.
+    fn transform_node<N: NodeVisitable + HasKind>(&mut self, node: N) -> CodamaResult<Option<N>> {
+        Ok(Some(node))
+    }
+
+    /// Called with the original kind of a node once its children have been transformed,
+    /// even if the node ends up deleted. Nodes deleted top-down before their children
+    /// are transformed are not left.
+    fn leave_node(&mut self, _kind: &'static str) -> CodamaResult<()> {
+        Ok(())
+    }
+
+    /// Called for every `TypeNode`, allowing it to be replaced by another variant.
+    fn transform_type(&mut self, node: TypeNode) -> CodamaResult<Option<TypeNode>> {
+        Ok(Some(node))
+    }
+
+    /// Called for every `ValueNode`, allowing it to be replaced by another variant.
+    fn transform_value(&mut self, node: ValueNode) -> CodamaResult<Option<ValueNode>> {
+        Ok(Some(node))
+    }
+
+    /// Called for every `InstructionInputValueNode`, allowing it to be replaced by another variant.
+    fn transform_instruction_input_value(
+        &mut self,
+        node: InstructionInputValueNode,
+    ) -> CodamaResult<Option<InstructionInputValueNode>> {
+        Ok(Some(node))
+    }
+
+    fn transform_root(&mut self, node: RootNode) -> CodamaResult<Option<RootNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_program(&mut self, node: ProgramNode) -> CodamaResult<Option<ProgramNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_pda(&mut self, node: PdaNode) -> CodamaResult<Option<PdaNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_account(&mut self, node: AccountNode) -> CodamaResult<Option<AccountNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_instruction(
+        &mut self,
+        node: InstructionNode,
+    ) -> CodamaResult<Option<InstructionNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_instruction_account(
+        &mut self,
+        node: InstructionAccountNode,
+    ) -> CodamaResult<Option<InstructionAccountNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_instruction_argument(
+        &mut self,
+        node: InstructionArgumentNode,
+    ) -> CodamaResult<Option<InstructionArgumentNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_instruction_byte_delta(
+        &mut self,
+        node: InstructionByteDeltaNode,
+    ) -> CodamaResult<Option<InstructionByteDeltaNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_instruction_remaining_accounts(
+        &mut self,
+        node: InstructionRemainingAccountsNode,
+    ) -> CodamaResult<Option<InstructionRemainingAccountsNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_instruction_status(
+        &mut self,
+        node: InstructionStatusNode,
+    ) -> CodamaResult<Option<InstructionStatusNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_defined_type(
+        &mut self,
+        node: DefinedTypeNode,
+    ) -> CodamaResult<Option<DefinedTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_error(&mut self, node: ErrorNode) -> CodamaResult<Option<ErrorNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_account_link(
+        &mut self,
+        node: AccountLinkNode,
+    ) -> CodamaResult<Option<AccountLinkNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_defined_type_link(
+        &mut self,
+        node: DefinedTypeLinkNode,
+    ) -> CodamaResult<Option<DefinedTypeLinkNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_instruction_link(
+        &mut self,
+        node: InstructionLinkNode,
+    ) -> CodamaResult<Option<InstructionLinkNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_instruction_account_link(
+        &mut self,
+        node: InstructionAccountLinkNode,
+    ) -> CodamaResult<Option<InstructionAccountLinkNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_instruction_argument_link(
+        &mut self,
+        node: InstructionArgumentLinkNode,
+    ) -> CodamaResult<Option<InstructionArgumentLinkNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_pda_link(&mut self, node: PdaLinkNode) -> CodamaResult<Option<PdaLinkNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_program_link(
+        &mut self,
+        node: ProgramLinkNode,
+    ) -> CodamaResult<Option<ProgramLinkNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_constant_pda_seed(
+        &mut self,
+        node: ConstantPdaSeedNode,
+    ) -> CodamaResult<Option<ConstantPdaSeedNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_variable_pda_seed(
+        &mut self,
+        node: VariablePdaSeedNode,
+    ) -> CodamaResult<Option<VariablePdaSeedNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_constant_discriminator(
+        &mut self,
+        node: ConstantDiscriminatorNode,
+    ) -> CodamaResult<Option<ConstantDiscriminatorNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_field_discriminator(
+        &mut self,
+        node: FieldDiscriminatorNode,
+    ) -> CodamaResult<Option<FieldDiscriminatorNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_size_discriminator(
+        &mut self,
+        node: SizeDiscriminatorNode,
+    ) -> CodamaResult<Option<SizeDiscriminatorNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_fixed_count(
+        &mut self,
+        node: FixedCountNode,
+    ) -> CodamaResult<Option<FixedCountNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_prefixed_count(
+        &mut self,
+        node: PrefixedCountNode,
+    ) -> CodamaResult<Option<PrefixedCountNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_remainder_count(
+        &mut self,
+        node: RemainderCountNode,
+    ) -> CodamaResult<Option<RemainderCountNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_account_bump_value(
+        &mut self,
+        node: AccountBumpValueNode,
+    ) -> CodamaResult<Option<AccountBumpValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_account_value(
+        &mut self,
+        node: AccountValueNode,
+    ) -> CodamaResult<Option<AccountValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_argument_value(
+        &mut self,
+        node: ArgumentValueNode,
+    ) -> CodamaResult<Option<ArgumentValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_conditional_value(
+        &mut self,
+        node: ConditionalValueNode,
+    ) -> CodamaResult<Option<ConditionalValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_identity_value(
+        &mut self,
+        node: IdentityValueNode,
+    ) -> CodamaResult<Option<IdentityValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_payer_value(
+        &mut self,
+        node: PayerValueNode,
+    ) -> CodamaResult<Option<PayerValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_pda_seed_value(
+        &mut self,
+        node: PdaSeedValueNode,
+    ) -> CodamaResult<Option<PdaSeedValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_pda_value(&mut self, node: PdaValueNode) -> CodamaResult<Option<PdaValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_program_id_value(
+        &mut self,
+        node: ProgramIdValueNode,
+    ) -> CodamaResult<Option<ProgramIdValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_resolver_value(
+        &mut self,
+        node: ResolverValueNode,
+    ) -> CodamaResult<Option<ResolverValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_array_value(
+        &mut self,
+        node: ArrayValueNode,
+    ) -> CodamaResult<Option<ArrayValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_boolean_value(
+        &mut self,
+        node: BooleanValueNode,
+    ) -> CodamaResult<Option<BooleanValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_bytes_value(
+        &mut self,
+        node: BytesValueNode,
+    ) -> CodamaResult<Option<BytesValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_constant_value(
+        &mut self,
+        node: ConstantValueNode,
+    ) -> CodamaResult<Option<ConstantValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_enum_value(&mut self, node: EnumValueNode) -> CodamaResult<Option<EnumValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_map_entry_value(
+        &mut self,
+        node: MapEntryValueNode,
+    ) -> CodamaResult<Option<MapEntryValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_map_value(&mut self, node: MapValueNode) -> CodamaResult<Option<MapValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_none_value(&mut self, node: NoneValueNode) -> CodamaResult<Option<NoneValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_number_value(
+        &mut self,
+        node: NumberValueNode,
+    ) -> CodamaResult<Option<NumberValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_public_key_value(
+        &mut self,
+        node: PublicKeyValueNode,
+    ) -> CodamaResult<Option<PublicKeyValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_set_value(&mut self, node: SetValueNode) -> CodamaResult<Option<SetValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_some_value(&mut self, node: SomeValueNode) -> CodamaResult<Option<SomeValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_string_value(
+        &mut self,
+        node: StringValueNode,
+    ) -> CodamaResult<Option<StringValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_struct_field_value(
+        &mut self,
+        node: StructFieldValueNode,
+    ) -> CodamaResult<Option<StructFieldValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_struct_value(
+        &mut self,
+        node: StructValueNode,
+    ) -> CodamaResult<Option<StructValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_tuple_value(
+        &mut self,
+        node: TupleValueNode,
+    ) -> CodamaResult<Option<TupleValueNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_amount_type(
+        &mut self,
+        node: AmountTypeNode,
+    ) -> CodamaResult<Option<AmountTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_array_type(&mut self, node: ArrayTypeNode) -> CodamaResult<Option<ArrayTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_boolean_type(
+        &mut self,
+        node: BooleanTypeNode,
+    ) -> CodamaResult<Option<BooleanTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_bytes_type(&mut self, node: BytesTypeNode) -> CodamaResult<Option<BytesTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_date_time_type(
+        &mut self,
+        node: DateTimeTypeNode,
+    ) -> CodamaResult<Option<DateTimeTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_enum_empty_variant_type(
+        &mut self,
+        node: EnumEmptyVariantTypeNode,
+    ) -> CodamaResult<Option<EnumEmptyVariantTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_enum_struct_variant_type(
+        &mut self,
+        node: EnumStructVariantTypeNode,
+    ) -> CodamaResult<Option<EnumStructVariantTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_enum_tuple_variant_type(
+        &mut self,
+        node: EnumTupleVariantTypeNode,
+    ) -> CodamaResult<Option<EnumTupleVariantTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_enum_type(&mut self, node: EnumTypeNode) -> CodamaResult<Option<EnumTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_fixed_size_type(
+        &mut self,
+        node: FixedSizeTypeNode<TypeNode>,
+    ) -> CodamaResult<Option<FixedSizeTypeNode<TypeNode>>> {
+        self.transform_node(node)
+    }
+
+    fn transform_hidden_prefix_type(
+        &mut self,
+        node: HiddenPrefixTypeNode<TypeNode>,
+    ) -> CodamaResult<Option<HiddenPrefixTypeNode<TypeNode>>> {
+        self.transform_node(node)
+    }
+
+    fn transform_hidden_suffix_type(
+        &mut self,
+        node: HiddenSuffixTypeNode<TypeNode>,
+    ) -> CodamaResult<Option<HiddenSuffixTypeNode<TypeNode>>> {
+        self.transform_node(node)
+    }
+
+    fn transform_map_type(&mut self, node: MapTypeNode) -> CodamaResult<Option<MapTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_number_type(
+        &mut self,
+        node: NumberTypeNode,
+    ) -> CodamaResult<Option<NumberTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_option_type(
+        &mut self,
+        node: OptionTypeNode,
+    ) -> CodamaResult<Option<OptionTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_post_offset_type(
+        &mut self,
+        node: PostOffsetTypeNode<TypeNode>,
+    ) -> CodamaResult<Option<PostOffsetTypeNode<TypeNode>>> {
+        self.transform_node(node)
+    }
+
+    fn transform_pre_offset_type(
+        &mut self,
+        node: PreOffsetTypeNode<TypeNode>,
+    ) -> CodamaResult<Option<PreOffsetTypeNode<TypeNode>>> {
+        self.transform_node(node)
+    }
+
+    fn transform_public_key_type(
+        &mut self,
+        node: PublicKeyTypeNode,
+    ) -> CodamaResult<Option<PublicKeyTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_remainder_option_type(
+        &mut self,
+        node: RemainderOptionTypeNode,
+    ) -> CodamaResult<Option<RemainderOptionTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_sentinel_type(
+        &mut self,
+        node: SentinelTypeNode<TypeNode>,
+    ) -> CodamaResult<Option<SentinelTypeNode<TypeNode>>> {
+        self.transform_node(node)
+    }
+
+    fn transform_set_type(&mut self, node: SetTypeNode) -> CodamaResult<Option<SetTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_size_prefix_type(
+        &mut self,
+        node: SizePrefixTypeNode<TypeNode>,
+    ) -> CodamaResult<Option<SizePrefixTypeNode<TypeNode>>> {
+        self.transform_node(node)
+    }
+
+    fn transform_sol_amount_type(
+        &mut self,
+        node: SolAmountTypeNode,
+    ) -> CodamaResult<Option<SolAmountTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_string_type(
+        &mut self,
+        node: StringTypeNode,
+    ) -> CodamaResult<Option<StringTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_struct_field_type(
+        &mut self,
+        node: StructFieldTypeNode,
+    ) -> CodamaResult<Option<StructFieldTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_struct_type(
+        &mut self,
+        node: StructTypeNode,
+    ) -> CodamaResult<Option<StructTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_tuple_type(&mut self, node: TupleTypeNode) -> CodamaResult<Option<TupleTypeNode>> {
+        self.transform_node(node)
+    }
+
+    fn transform_zeroable_option_type(
+        &mut self,
+        node: ZeroableOptionTypeNode,
+    ) -> CodamaResult<Option<ZeroableOptionTypeNode>> {
+        self.transform_node(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PdaLinkNode, U64};
+
+    fn account() -> AccountNode {
+        AccountNode {
+            pda: Some(PdaLinkNode::new("token")),
+            ..AccountNode::new(
+                "token",
+                StructTypeNode::new(vec![
+                    StructFieldTypeNode::new("amount", NumberTypeNode::le(U64)),
+                    StructFieldTypeNode::new("owner", PublicKeyTypeNode::new()),
+                ]),
+            )
+        }
+    }
+
+    struct Identity;
+    impl NodeTransformer for Identity {}
+
+    #[test]
+    fn default_hooks_keep_nodes() {
+        assert_eq!(account().transform(&mut Identity).unwrap(), Some(account()));
+    }
+
+    #[test]
+    fn default_mode_is_bottom_up() {
+        assert_eq!(Identity.mode(), TraversalMode::BottomUp);
+    }
+
+    /// Deletes every node of the given kind and records the kinds of the nodes left.
+    struct KindDeleter {
+        kind: &'static str,
+        mode: TraversalMode,
+        left: Vec<&'static str>,
+    }
+
+    impl KindDeleter {
+        fn new(kind: &'static str, mode: TraversalMode) -> Self {
+            Self {
+                kind,
+                mode,
+                left: vec![],
+            }
+        }
+    }
+
+    impl NodeTransformer for KindDeleter {
+        fn mode(&self) -> TraversalMode {
+            self.mode
+        }
+
+        fn transform_node<N: NodeVisitable + HasKind>(
+            &mut self,
+            node: N,
+        ) -> CodamaResult<Option<N>> {
+            match node.kind() == self.kind {
+                true => Ok(None),
+                false => Ok(Some(node)),
+            }
+        }
+
+        fn leave_node(&mut self, kind: &'static str) -> CodamaResult<()> {
+            self.left.push(kind);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn delete_optional_child() {
+        let mut transformer = KindDeleter::new("pdaLinkNode", TraversalMode::BottomUp);
+        let node = account().transform(&mut transformer).unwrap().unwrap();
+        assert_eq!(node.pda, None);
+        assert_eq!(node.data, account().data);
+    }
+
+    #[test]
+    fn delete_list_items() {
+        let mut transformer = KindDeleter::new("publicKeyTypeNode", TraversalMode::BottomUp);
+        let node = account().transform(&mut transformer).unwrap().unwrap();
+        assert_eq!(
+            node.data,
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "amount",
+                NumberTypeNode::le(U64)
+            )])
+            .into()
+        );
+    }
+
+    #[test]
+    fn delete_parent_of_required_child() {
+        let mut transformer = KindDeleter::new("publicKeyTypeNode", TraversalMode::BottomUp);
+        let node = OptionTypeNode::new(PublicKeyTypeNode::new());
+        assert_eq!(node.transform(&mut transformer).unwrap(), None);
+        // The option is left even though it is deleted with its item.
+        assert_eq!(
+            transformer.left,
+            vec!["publicKeyTypeNode", "optionTypeNode"]
+        );
+    }
+
+    #[test]
+    fn leave_nodes_after_their_children() {
+        let mut transformer = KindDeleter::new("none", TraversalMode::TopDown);
+        OptionTypeNode::new(PublicKeyTypeNode::new())
+            .transform(&mut transformer)
+            .unwrap();
+        assert_eq!(
+            transformer.left,
+            vec!["publicKeyTypeNode", "numberTypeNode", "optionTypeNode"]
+        );
+    }
+
+    #[test]
+    fn nodes_deleted_top_down_are_not_left() {
+        let mut transformer = KindDeleter::new("optionTypeNode", TraversalMode::TopDown);
+        let node =
+            StructFieldTypeNode::new("delegate", OptionTypeNode::new(PublicKeyTypeNode::new()));
+        assert_eq!(node.transform(&mut transformer).unwrap(), None);
+        assert_eq!(transformer.left, vec!["structFieldTypeNode"]);
+    }
+}
@@ -0,0 +1,1247 @@
+use crate::{
+    AccountBumpValueNode, AccountLinkNode, AccountNode, AccountValueNode, AmountTypeNode,
+    ArgumentValueNode, ArrayTypeNode, ArrayValueNode, BooleanTypeNode, BooleanValueNode,
+    BytesTypeNode, BytesValueNode, CamelCaseString, ConditionNode, ConditionalValueNode,
+    ConstantDiscriminatorNode, ConstantPdaSeedNode, ConstantPdaSeedValueNode, ConstantValueNode,
+    ContextualValueNode, CountNode, DateTimeTypeNode, DefinedTypeLinkNode, DefinedTypeNode,
+    DiscriminatorNode, EnumEmptyVariantTypeNode, EnumStructVariantTypeNode,
+    EnumTupleVariantTypeNode, EnumTypeNode, EnumValueNode, EnumVariantData, EnumVariantTypeNode,
+    ErrorNode, FieldDiscriminatorNode, FixedCountNode, FixedSizeTypeNode, HasKind,
+    HiddenPrefixTypeNode, HiddenSuffixTypeNode, IdentityValueNode, InstructionAccountLinkNode,
+    InstructionAccountNode, InstructionArgumentLinkNode, InstructionArgumentNode,
+    InstructionByteDeltaNode, InstructionByteDeltaNodeValue, InstructionInputValueNode,
+    InstructionLinkNode, InstructionNode, InstructionRemainingAccountsNode,
+    InstructionRemainingAccountsNodeValue, InstructionStatusNode, LinkNode, MapEntryValueNode,
+    MapTypeNode, MapValueNode, NestedTypeNode, Node, NodeTransformer, NodeVisitor, NoneValueNode,
+    NumberTypeNode, NumberValueNode, OptionTypeNode, PayerValueNode, PdaLinkNode, PdaNode,
+    PdaProgramIdValueNode, PdaSeedNode, PdaSeedValueNode, PdaSeedValueValueNode, PdaValue,
+    PdaValueNode, PostOffsetTypeNode, PreOffsetTypeNode, PrefixedCountNode, ProgramIdValueNode,
+    ProgramLinkNode, ProgramNode, PublicKeyTypeNode, PublicKeyValueNode,
+    RegisteredContextualValueNode, RegisteredTypeNode, RegisteredValueNode, RemainderCountNode,
+    RemainderOptionTypeNode, ResolverDependency, ResolverValueNode, RootNode, SentinelTypeNode,
+    SetTypeNode, SetValueNode, SizeDiscriminatorNode, SizePrefixTypeNode, SolAmountTypeNode,
+    SomeValueNode, StringTypeNode, StringValueNode, StructFieldTypeNode, StructFieldValueNode,
+    StructTypeNode, StructValueNode, TupleTypeNode, TupleValueNode, TypeNode, TypeNodeTrait,
+    ValueNode, VariablePdaSeedNode, ZeroableOptionTypeNode,
+};
+use codama_errors::{CodamaResult, IteratorCombineErrors};
+
+/// The order in which a parent node and its children are visited or transformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraversalMode {
+    /// The parent is handled before its children.
+    TopDown,
+    /// The children are handled before their parent.
+    BottomUp,
+}
+
+/// A node that can be walked by a [`NodeVisitor`] and rebuilt by a [`NodeTransformer`].
+pub trait NodeVisitable: Sized {
+    /// Calls the visitor hook of this node and visits all of its children.
+    fn accept<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()>;
+
+    /// Visits the direct children of this node, without calling its own hook.
+    fn visit_children<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()>;
+
+    /// Calls the transformer hook of this node and transforms all of its children.
+    fn transform<T: NodeTransformer>(self, transformer: &mut T) -> CodamaResult<Option<Self>>;
+
+    /// Transforms the direct children of this node, without calling its own hook.
+    fn transform_children<T: NodeTransformer>(
+        self,
+        transformer: &mut T,
+    ) -> CodamaResult<Option<Self>>;
+
+    /// The name of this node, if it has one.
+    fn node_name(&self) -> Option<&CamelCaseString> {
+        None
+    }
+
+    /// A mutable reference to the name of this node, if it has one.
+    fn node_name_mut(&mut self) -> Option<&mut CamelCaseString> {
+        None
+    }
+}
+
+/// Calls the hook of a node and visits its children in the order of the visitor's mode.
+/// Concrete nodes are then left, unlike the unions wrapping them.
+fn accept_with_mode<N: NodeVisitable + HasKind, V: NodeVisitor>(
+    node: &N,
+    visitor: &mut V,
+    hook: fn(&mut V, &N) -> CodamaResult<()>,
+    leave: bool,
+) -> CodamaResult<()> {
+    match visitor.mode() {
+        TraversalMode::TopDown => {
+            hook(visitor, node)?;
+            node.visit_children(visitor)?;
+        }
+        TraversalMode::BottomUp => {
+            node.visit_children(visitor)?;
+            hook(visitor, node)?;
+        }
+    }
+    match leave {
+        true => visitor.leave_node(node.kind()),
+        false => Ok(()),
+    }
+}
+
+/// Same as `accept_with_mode` for transformers.
+fn transform_with_mode<N: NodeVisitable + HasKind, T: NodeTransformer>(
+    node: N,
+    transformer: &mut T,
+    hook: fn(&mut T, N) -> CodamaResult<Option<N>>,
+    leave: bool,
+) -> CodamaResult<Option<N>> {
+    let kind = node.kind();
+    let node = match transformer.mode() {
+        TraversalMode::TopDown => match hook(transformer, node)? {
+            Some(node) => node.transform_children(transformer)?,
+            None => return Ok(None),
+        },
+        TraversalMode::BottomUp => match node.transform_children(transformer)? {
+            Some(node) => hook(transformer, node)?,
+            None => None,
+        },
+    };
+    if leave {
+        transformer.leave_node(kind)?;
+    }
+    Ok(node)
+}
+
+/// The fields of a node that hold other nodes.
+///
+/// Transforming a child returns `None` when the parent itself must be deleted,
+/// which only happens when a required child gets deleted.
+trait NodeChild: Sized {
+    fn accept_child<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()>;
+    fn transform_child<T: NodeTransformer>(self, transformer: &mut T)
+        -> CodamaResult<Option<Self>>;
+}
+
+macro_rules! node_child {
+    ($node:ty) => {
+        impl NodeChild for $node {
+            fn accept_child<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+                self.accept(visitor)
+            }
+
+            fn transform_child<T: NodeTransformer>(
+                self,
+                transformer: &mut T,
+            ) -> CodamaResult<Option<Self>> {
+                self.transform(transformer)
+            }
+        }
+    };
+}
+
+impl<N: NodeChild> NodeChild for Box<N> {
+    fn accept_child<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+        self.as_ref().accept_child(visitor)
+    }
+
+    fn transform_child<T: NodeTransformer>(
+        self,
+        transformer: &mut T,
+    ) -> CodamaResult<Option<Self>> {
+        Ok((*self).transform_child(transformer)?.map(Box::new))
+    }
+}
+
+impl<N: NodeChild> NodeChild for Option<N> {
+    fn accept_child<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+        match self {
+            Some(node) => node.accept_child(visitor),
+            None => Ok(()),
+        }
+    }
+
+    fn transform_child<T: NodeTransformer>(
+        self,
+        transformer: &mut T,
+    ) -> CodamaResult<Option<Self>> {
+        match self {
+            Some(node) => Ok(Some(node.transform_child(transformer)?)),
+            None => Ok(Some(None)),
+        }
+    }
+}
+
+impl<N: NodeChild> NodeChild for Vec<N> {
+    fn accept_child<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+        self.iter()
+            .map(|node| node.accept_child(visitor))
+            .collect_and_combine_errors()?;
+        Ok(())
+    }
+
+    fn transform_child<T: NodeTransformer>(
+        self,
+        transformer: &mut T,
+    ) -> CodamaResult<Option<Self>> {
+        let nodes = self
+            .into_iter()
+            .map(|node| node.transform_child(transformer))
+            .collect_and_combine_errors()?;
+        Ok(Some(nodes.into_iter().flatten().collect()))
+    }
+}
+
+// Nested type nodes are visited and transformed as regular type nodes.
+impl<U: TypeNodeTrait> NodeVisitable for NestedTypeNode<U> {
+    fn accept<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+        TypeNode::from(self.clone()).accept(visitor)
+    }
+
+    fn visit_children<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+        TypeNode::from(self.clone()).visit_children(visitor)
+    }
+
+    fn transform<T: NodeTransformer>(self, transformer: &mut T) -> CodamaResult<Option<Self>> {
+        TypeNode::from(self)
+            .transform(transformer)?
+            .map(TryInto::try_into)
+            .transpose()
+    }
+
+    fn transform_children<T: NodeTransformer>(
+        self,
+        transformer: &mut T,
+    ) -> CodamaResult<Option<Self>> {
+        TypeNode::from(self)
+            .transform_children(transformer)?
+            .map(TryInto::try_into)
+            .transpose()
+    }
+}
+
+impl<U: TypeNodeTrait> NodeChild for NestedTypeNode<U> {
+    fn accept_child<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+        self.accept(visitor)
+    }
+
+    fn transform_child<T: NodeTransformer>(
+        self,
+        transformer: &mut T,
+    ) -> CodamaResult<Option<Self>> {
+        self.transform(transformer)
+    }
+}
+
+macro_rules! visitable_node {
+    ($node:ty, $visit:ident, $transform:ident, [$($child:ident),*] $(, $name:ident)?) => {
+        impl NodeVisitable for $node {
+            fn accept<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+                accept_with_mode(self, visitor, V::$visit, true)
+            }
+
+            #[allow(unused_variables)]
+            fn visit_children<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+                $(self.$child.accept_child(visitor)?;)*
+                Ok(())
+            }
+
+            fn transform<T: NodeTransformer>(self, transformer: &mut T) -> CodamaResult<Option<Self>> {
+                transform_with_mode(self, transformer, T::$transform, true)
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn transform_children<T: NodeTransformer>(
+                mut self,
+                transformer: &mut T,
+            ) -> CodamaResult<Option<Self>> {
+                $(
+                    let Some(child) = self.$child.transform_child(transformer)? else {
+                        return Ok(None);
+                    };
+                    self.$child = child;
+                )*
+                Ok(Some(self))
+            }
+
+            $(
+                fn node_name(&self) -> Option<&CamelCaseString> {
+                    Some(&self.$name)
+                }
+
+                fn node_name_mut(&mut self) -> Option<&mut CamelCaseString> {
+                    Some(&mut self.$name)
+                }
+            )?
+        }
+
+        node_child!($node);
+    };
+}
+
+macro_rules! visitable_union {
+    ($union:ty, $visit:ident, $transform:ident, [$($variant:ident),*]) => {
+        impl NodeVisitable for $union {
+            fn accept<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+                accept_with_mode(self, visitor, V::$visit, false)
+            }
+
+            fn transform<T: NodeTransformer>(self, transformer: &mut T) -> CodamaResult<Option<Self>> {
+                transform_with_mode(self, transformer, T::$transform, false)
+            }
+
+            visitable_union!(@dispatch $union, [$($variant),*]);
+        }
+
+        node_child!($union);
+    };
+    ($union:ty, [$($variant:ident),*]) => {
+        impl NodeVisitable for $union {
+            fn accept<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+                self.visit_children(visitor)
+            }
+
+            fn transform<T: NodeTransformer>(self, transformer: &mut T) -> CodamaResult<Option<Self>> {
+                self.transform_children(transformer)
+            }
+
+            visitable_union!(@dispatch $union, [$($variant),*]);
+        }
+
+        node_child!($union);
+    };
+    (@dispatch $union:ty, [$($variant:ident),*]) => {
+        fn visit_children<V: NodeVisitor>(&self, visitor: &mut V) -> CodamaResult<()> {
+            match self {
+                $(Self::$variant(node) => node.accept(visitor),)*
+            }
+        }
+
+        fn transform_children<T: NodeTransformer>(
+            self,
+            transformer: &mut T,
+        ) -> CodamaResult<Option<Self>> {
+            match self {
+                $(Self::$variant(node) => Ok(node.transform(transformer)?.map(Self::$variant)),)*
+            }
+        }
+
+        fn node_name(&self) -> Option<&CamelCaseString> {
+            match self {
+                $(Self::$variant(node) => node.node_name(),)*
+            }
+        }
+
+        fn node_name_mut(&mut self) -> Option<&mut CamelCaseString> {
+            match self {
+                $(Self::$variant(node) => node.node_name_mut(),)*
+            }
+        }
+    };
+}
+
+// Nodes.
+visitable_node!(
+    RootNode,
+    visit_root,
+    transform_root,
+    [program, additional_programs]
+);
+visitable_node!(
+    ProgramNode,
+    visit_program,
+    transform_program,
+    [accounts, instructions, defined_types, pdas, errors],
+    name
+);
+visitable_node!(PdaNode, visit_pda, transform_pda, [seeds], name);
+visitable_node!(
+    AccountNode,
+    visit_account,
+    transform_account,
+    [data, pda, discriminators],
+    name
+);
+visitable_node!(
+    InstructionNode,
+    visit_instruction,
+    transform_instruction,
+    [
+        accounts,
+        arguments,
+        extra_arguments,
+        remaining_accounts,
+        byte_deltas,
+        discriminators,
+        status,
+        sub_instructions
+    ],
+    name
+);
+visitable_node!(
+    InstructionAccountNode,
+    visit_instruction_account,
+    transform_instruction_account,
+    [default_value],
+    name
+);
+visitable_node!(
+    InstructionArgumentNode,
+    visit_instruction_argument,
+    transform_instruction_argument,
+    [r#type, default_value],
+    name
+);
+visitable_node!(
+    InstructionByteDeltaNode,
+    visit_instruction_byte_delta,
+    transform_instruction_byte_delta,
+    [value]
+);
+visitable_node!(
+    InstructionRemainingAccountsNode,
+    visit_instruction_remaining_accounts,
+    transform_instruction_remaining_accounts,
+    [value]
+);
+visitable_node!(
+    InstructionStatusNode,
+    visit_instruction_status,
+    transform_instruction_status,
+    []
+);
+visitable_node!(
+    DefinedTypeNode,
+    visit_defined_type,
+    transform_defined_type,
+    [r#type],
+    name
+);
+visitable_node!(ErrorNode, visit_error, transform_error, [], name);
+visitable_node!(
+    AccountLinkNode,
+    visit_account_link,
+    transform_account_link,
+    [program],
+    name
+);
+visitable_node!(
+    DefinedTypeLinkNode,
+    visit_defined_type_link,
+    transform_defined_type_link,
+    [program],
+    name
+);
+visitable_node!(
+    InstructionLinkNode,
+    visit_instruction_link,
+    transform_instruction_link,
+    [program],
+    name
+);
+visitable_node!(
+    InstructionAccountLinkNode,
+    visit_instruction_account_link,
+    transform_instruction_account_link,
+    [instruction],
+    name
+);
+visitable_node!(
+    InstructionArgumentLinkNode,
+    visit_instruction_argument_link,
+    transform_instruction_argument_link,
+    [instruction],
+    name
+);
+visitable_node!(
+    PdaLinkNode,
+    visit_pda_link,
+    transform_pda_link,
+    [program],
+    name
+);
+visitable_node!(
+    ProgramLinkNode,
+    visit_program_link,
+    transform_program_link,
+    [],
+    name
+);
+visitable_node!(
+    ConstantPdaSeedNode,
+    visit_constant_pda_seed,
+    transform_constant_pda_seed,
+    [r#type, value]
+);
+visitable_node!(
+    VariablePdaSeedNode,
+    visit_variable_pda_seed,
+    transform_variable_pda_seed,
+    [r#type],
+    name
+);
+visitable_node!(
+    ConstantDiscriminatorNode,
+    visit_constant_discriminator,
+    transform_constant_discriminator,
+    [constant]
+);
+visitable_node!(
+    FieldDiscriminatorNode,
+    visit_field_discriminator,
+    transform_field_discriminator,
+    [],
+    name
+);
+visitable_node!(
+    SizeDiscriminatorNode,
+    visit_size_discriminator,
+    transform_size_discriminator,
+    []
+);
+visitable_node!(FixedCountNode, visit_fixed_count, transform_fixed_count, []);
+visitable_node!(
+    PrefixedCountNode,
+    visit_prefixed_count,
+    transform_prefixed_count,
+    [prefix]
+);
+visitable_node!(
+    RemainderCountNode,
+    visit_remainder_count,
+    transform_remainder_count,
+    []
+);
+visitable_node!(
+    AccountBumpValueNode,
+    visit_account_bump_value,
+    transform_account_bump_value,
+    [],
+    name
+);
+visitable_node!(
+    AccountValueNode,
+    visit_account_value,
+    transform_account_value,
+    [],
+    name
+);
+visitable_node!(
+    ArgumentValueNode,
+    visit_argument_value,
+    transform_argument_value,
+    [],
+    name
+);
+visitable_node!(
+    ConditionalValueNode,
+    visit_conditional_value,
+    transform_conditional_value,
+    [condition, value, if_true, if_false]
+);
+visitable_node!(
+    IdentityValueNode,
+    visit_identity_value,
+    transform_identity_value,
+    []
+);
+visitable_node!(PayerValueNode, visit_payer_value, transform_payer_value, []);
+visitable_node!(
+    PdaSeedValueNode,
+    visit_pda_seed_value,
+    transform_pda_seed_value,
+    [value],
+    name
+);
+visitable_node!(
+    PdaValueNode,
+    visit_pda_value,
+    transform_pda_value,
+    [pda, seeds, program_id]
+);
+visitable_node!(
+    ProgramIdValueNode,
+    visit_program_id_value,
+    transform_program_id_value,
+    []
+);
+visitable_node!(
+    ResolverValueNode,
+    visit_resolver_value,
+    transform_resolver_value,
+    [depends_on],
+    name
+);
+visitable_node!(
+    ArrayValueNode,
+    visit_array_value,
+    transform_array_value,
+    [items]
+);
+visitable_node!(
+    BooleanValueNode,
+    visit_boolean_value,
+    transform_boolean_value,
+    []
+);
+visitable_node!(BytesValueNode, visit_bytes_value, transform_bytes_value, []);
+visitable_node!(
+    ConstantValueNode,
+    visit_constant_value,
+    transform_constant_value,
+    [r#type, value]
+);
+visitable_node!(
+    EnumValueNode,
+    visit_enum_value,
+    transform_enum_value,
+    [r#enum, value]
+);
+visitable_node!(
+    MapEntryValueNode,
+    visit_map_entry_value,
+    transform_map_entry_value,
+    [key, value]
+);
+visitable_node!(
+    MapValueNode,
+    visit_map_value,
+    transform_map_value,
+    [entries]
+);
+visitable_node!(NoneValueNode, visit_none_value, transform_none_value, []);
+visitable_node!(
+    NumberValueNode,
+    visit_number_value,
+    transform_number_value,
+    []
+);
+visitable_node!(
+    PublicKeyValueNode,
+    visit_public_key_value,
+    transform_public_key_value,
+    []
+);
+visitable_node!(SetValueNode, visit_set_value, transform_set_value, [items]);
+visitable_node!(
+    SomeValueNode,
+    visit_some_value,
+    transform_some_value,
+    [value]
+);
+visitable_node!(
+    StringValueNode,
+    visit_string_value,
+    transform_string_value,
+    []
+);
+visitable_node!(
+    StructFieldValueNode,
+    visit_struct_field_value,
+    transform_struct_field_value,
+    [value],
+    name
+);
+visitable_node!(
+    StructValueNode,
+    visit_struct_value,
+    transform_struct_value,
+    [fields]
+);
+visitable_node!(
+    TupleValueNode,
+    visit_tuple_value,
+    transform_tuple_value,
+    [items]
+);
+visitable_node!(
+    AmountTypeNode,
+    visit_amount_type,
+    transform_amount_type,
+    [number]
+);
+visitable_node!(
+    ArrayTypeNode,
+    visit_array_type,
+    transform_array_type,
+    [item, count]
+);
+visitable_node!(
+    BooleanTypeNode,
+    visit_boolean_type,
+    transform_boolean_type,
+    [size]
+);
+visitable_node!(BytesTypeNode, visit_bytes_type, transform_bytes_type, []);
+visitable_node!(
+    DateTimeTypeNode,
+    visit_date_time_type,
+    transform_date_time_type,
+    [number]
+);
+visitable_node!(
+    EnumEmptyVariantTypeNode,
+    visit_enum_empty_variant_type,
+    transform_enum_empty_variant_type,
+    [],
+    name
+);
+visitable_node!(
+    EnumStructVariantTypeNode,
+    visit_enum_struct_variant_type,
+    transform_enum_struct_variant_type,
+    [r#struct],
+    name
+);
+visitable_node!(
+    EnumTupleVariantTypeNode,
+    visit_enum_tuple_variant_type,
+    transform_enum_tuple_variant_type,
+    [tuple],
+    name
+);
+visitable_node!(
+    EnumTypeNode,
+    visit_enum_type,
+    transform_enum_type,
+    [variants, size]
+);
+visitable_node!(
+    FixedSizeTypeNode<TypeNode>,
+    visit_fixed_size_type,
+    transform_fixed_size_type,
+    [r#type]
+);
+visitable_node!(
+    HiddenPrefixTypeNode<TypeNode>,
+    visit_hidden_prefix_type,
+    transform_hidden_prefix_type,
+    [r#type, prefix]
+);
+visitable_node!(
+    HiddenSuffixTypeNode<TypeNode>,
+    visit_hidden_suffix_type,
+    transform_hidden_suffix_type,
+    [r#type, suffix]
+);
+visitable_node!(
+    MapTypeNode,
+    visit_map_type,
+    transform_map_type,
+    [key, value, count]
+);
+visitable_node!(NumberTypeNode, visit_number_type, transform_number_type, []);
+visitable_node!(
+    OptionTypeNode,
+    visit_option_type,
+    transform_option_type,
+    [item, prefix]
+);
+visitable_node!(
+    PostOffsetTypeNode<TypeNode>,
+    visit_post_offset_type,
+    transform_post_offset_type,
+    [r#type]
+);
+visitable_node!(
+    PreOffsetTypeNode<TypeNode>,
+    visit_pre_offset_type,
+    transform_pre_offset_type,
+    [r#type]
+);
+visitable_node!(
+    PublicKeyTypeNode,
+    visit_public_key_type,
+    transform_public_key_type,
+    []
+);
+visitable_node!(
+    RemainderOptionTypeNode,
+    visit_remainder_option_type,
+    transform_remainder_option_type,
+    [item]
+);
+visitable_node!(
+    SentinelTypeNode<TypeNode>,
+    visit_sentinel_type,
+    transform_sentinel_type,
+    [r#type, sentinel]
+);
+visitable_node!(
+    SetTypeNode,
+    visit_set_type,
+    transform_set_type,
+    [item, count]
+);
+visitable_node!(
+    SizePrefixTypeNode<TypeNode>,
+    visit_size_prefix_type,
+    transform_size_prefix_type,
+    [r#type, prefix]
+);
+visitable_node!(
+    SolAmountTypeNode,
+    visit_sol_amount_type,
+    transform_sol_amount_type,
+    [number]
+);
+visitable_node!(StringTypeNode, visit_string_type, transform_string_type, []);
+visitable_node!(
+    StructFieldTypeNode,
+    visit_struct_field_type,
+    transform_struct_field_type,
+    [r#type, default_value],
+    name
+);
+visitable_node!(
+    StructTypeNode,
+    visit_struct_type,
+    transform_struct_type,
+    [fields]
+);
+visitable_node!(
+    TupleTypeNode,
+    visit_tuple_type,
+    transform_tuple_type,
+    [items]
+);
+visitable_node!(
+    ZeroableOptionTypeNode,
+    visit_zeroable_option_type,
+    transform_zeroable_option_type,
+    [item, zero_value]
+);
+
+// Node unions.
+visitable_union!(
+    Node,
+    [
+        ContextualValue,
+        Count,
+        Discriminator,
+        Link,
+        PdaSeed,
+        Type,
+        Value,
+        Account,
+        DefinedType,
+        Error,
+        Instruction,
+        InstructionAccount,
+        InstructionArgument,
+        InstructionByteDelta,
+        InstructionRemainingAccounts,
+        InstructionStatus,
+        Pda,
+        Program,
+        Root
+    ]
+);
+visitable_union!(
+    ContextualValueNode,
+    [
+        Account,
+        AccountBump,
+        Argument,
+        Conditional,
+        Identity,
+        Payer,
+        Pda,
+        ProgramId,
+        Resolver
+    ]
+);
+visitable_union!(
+    RegisteredContextualValueNode,
+    [
+        Account,
+        AccountBump,
+        Argument,
+        Conditional,
+        Identity,
+        Payer,
+        Pda,
+        ProgramId,
+        Resolver,
+        PdaSeed
+    ]
+);
+visitable_union!(CountNode, [Fixed, Prefixed, Remainder]);
+visitable_union!(DiscriminatorNode, [Constant, Field, Size]);
+visitable_union!(
+    InstructionInputValueNode,
+    visit_instruction_input_value,
+    transform_instruction_input_value,
+    [
+        Account,
+        AccountBump,
+        Argument,
+        Conditional,
+        Identity,
+        Payer,
+        Pda,
+        ProgramId,
+        Resolver,
+        Array,
+        Boolean,
+        Bytes,
+        Constant,
+        Enum,
+        Map,
+        None,
+        Number,
+        PublicKey,
+        Set,
+        Some,
+        String,
+        Struct,
+        Tuple,
+        ProgramLink
+    ]
+);
+visitable_union!(
+    LinkNode,
+    [
+        Account,
+        DefinedType,
+        Instruction,
+        InstructionAccount,
+        InstructionArgument,
+        Pda,
+        Program
+    ]
+);
+visitable_union!(PdaSeedNode, [Constant, Variable]);
+visitable_union!(
+    TypeNode,
+    visit_type,
+    transform_type,
+    [
+        Amount,
+        Array,
+        Boolean,
+        Bytes,
+        DateTime,
+        Enum,
+        FixedSize,
+        HiddenPrefix,
+        HiddenSuffix,
+        Map,
+        Number,
+        Option,
+        PostOffset,
+        PreOffset,
+        PublicKey,
+        RemainderOption,
+        Sentinel,
+        Set,
+        SizePrefix,
+        SolAmount,
+        String,
+        Struct,
+        Tuple,
+        ZeroableOption,
+        Link
+    ]
+);
+visitable_union!(
+    RegisteredTypeNode,
+    [
+        Amount,
+        Array,
+        Boolean,
+        Bytes,
+        DateTime,
+        Enum,
+        FixedSize,
+        HiddenPrefix,
+        HiddenSuffix,
+        Map,
+        Number,
+        Option,
+        PostOffset,
+        PreOffset,
+        PublicKey,
+        RemainderOption,
+        Sentinel,
+        Set,
+        SizePrefix,
+        SolAmount,
+        String,
+        Struct,
+        Tuple,
+        ZeroableOption,
+        EnumEmptyVariant,
+        EnumStructVariant,
+        EnumTupleVariant,
+        StructField
+    ]
+);
+visitable_union!(EnumVariantTypeNode, [Empty, Struct, Tuple]);
+visitable_union!(
+    ValueNode,
+    visit_value,
+    transform_value,
+    [
+        Array, Boolean, Bytes, Constant, Enum, Map, None, Number, PublicKey, Set, Some, String,
+        Struct, Tuple
+    ]
+);
+visitable_union!(
+    RegisteredValueNode,
+    [
+        Array,
+        Boolean,
+        Bytes,
+        Constant,
+        Enum,
+        Map,
+        None,
+        Number,
+        PublicKey,
+        Set,
+        Some,
+        String,
+        Struct,
+        Tuple,
+        StructField,
+        MapEntry
+    ]
+);
+
+// Field-specific unions.
+visitable_union!(ConditionNode, [Account, Argument, Resolver]);
+visitable_union!(
+    ConstantPdaSeedValueNode,
+    [
+        ProgramId, Array, Boolean, Bytes, Constant, Enum, Map, None, Number, PublicKey, Set, Some,
+        String, Struct, Tuple
+    ]
+);
+visitable_union!(EnumVariantData, [Struct, Tuple]);
+visitable_union!(
+    InstructionByteDeltaNodeValue,
+    [Account, Argument, Number, Resolver]
+);
+visitable_union!(InstructionRemainingAccountsNodeValue, [Argument, Resolver]);
+visitable_union!(PdaProgramIdValueNode, [Account, Argument]);
+visitable_union!(
+    PdaSeedValueValueNode,
+    [
+        Account, Argument, Array, Boolean, Bytes, Constant, Enum, Map, None, Number, PublicKey,
+        Set, Some, String, Struct, Tuple
+    ]
+);
+visitable_union!(PdaValue, [Linked, Nested]);
+visitable_union!(ResolverDependency, [Account, Argument]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HasKind, StringValueNode, U32, U64};
+
+    fn account() -> AccountNode {
+        AccountNode::new(
+            "token",
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("amount", NumberTypeNode::le(U64)),
+                StructFieldTypeNode::new("delegate", OptionTypeNode::new(PublicKeyTypeNode::new())),
+            ]),
+        )
+    }
+
+    #[derive(Default)]
+    struct KindCollector {
+        mode: Option<TraversalMode>,
+        kinds: Vec<&'static str>,
+    }
+
+    impl NodeVisitor for KindCollector {
+        fn mode(&self) -> TraversalMode {
+            self.mode.unwrap_or(TraversalMode::TopDown)
+        }
+
+        fn visit_node<N: NodeVisitable + HasKind>(&mut self, node: &N) -> CodamaResult<()> {
+            self.kinds.push(node.kind());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn visit_top_down() {
+        let mut visitor = KindCollector::default();
+        account().accept(&mut visitor).unwrap();
+        assert_eq!(
+            visitor.kinds,
+            vec![
+                "accountNode",
+                "structTypeNode",
+                "structFieldTypeNode",
+                "numberTypeNode",
+                "structFieldTypeNode",
+                "optionTypeNode",
+                "publicKeyTypeNode",
+                "numberTypeNode",
+            ]
+        );
+    }
+
+    #[test]
+    fn visit_bottom_up() {
+        let mut visitor = KindCollector {
+            mode: Some(TraversalMode::BottomUp),
+            ..KindCollector::default()
+        };
+        account().accept(&mut visitor).unwrap();
+        assert_eq!(
+            visitor.kinds,
+            vec![
+                "numberTypeNode",
+                "structFieldTypeNode",
+                "publicKeyTypeNode",
+                "numberTypeNode",
+                "optionTypeNode",
+                "structFieldTypeNode",
+                "structTypeNode",
+                "accountNode",
+            ]
+        );
+    }
+
+    #[test]
+    fn visit_children_only() {
+        let mut visitor = KindCollector::default();
+        OptionTypeNode::new(PublicKeyTypeNode::new())
+            .visit_children(&mut visitor)
+            .unwrap();
+        assert_eq!(visitor.kinds, vec!["publicKeyTypeNode", "numberTypeNode"]);
+    }
+
+    struct NumberWidener;
+
+    impl NodeTransformer for NumberWidener {
+        fn transform_number_type(
+            &mut self,
+            node: NumberTypeNode,
+        ) -> CodamaResult<Option<NumberTypeNode>> {
+            Ok(Some(NumberTypeNode {
+                format: U64,
+                ..node
+            }))
+        }
+    }
+
+    #[test]
+    fn transform_concrete_nodes() {
+        let node = OptionTypeNode::new(NumberTypeNode::le(U32));
+        assert_eq!(
+            node.transform(&mut NumberWidener).unwrap(),
+            Some(OptionTypeNode {
+                prefix: NumberTypeNode::le(U64).into(),
+                ..OptionTypeNode::new(NumberTypeNode::le(U64))
+            })
+        );
+    }
+
+    struct PublicKeyToString;
+
+    impl NodeTransformer for PublicKeyToString {
+        fn transform_type(&mut self, node: TypeNode) -> CodamaResult<Option<TypeNode>> {
+            match node {
+                TypeNode::PublicKey(_) => Ok(Some(StringTypeNode::base58().into())),
+                node => Ok(Some(node)),
+            }
+        }
+
+        fn transform_value(&mut self, node: ValueNode) -> CodamaResult<Option<ValueNode>> {
+            match node {
+                ValueNode::PublicKey(node) => {
+                    Ok(Some(StringValueNode::new(node.public_key).into()))
+                }
+                node => Ok(Some(node)),
+            }
+        }
+    }
+
+    #[test]
+    fn transform_union_nodes() {
+        let node = account()
+            .transform(&mut PublicKeyToString)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            node.data,
+            StructTypeNode::new(vec![
+                StructFieldTypeNode::new("amount", NumberTypeNode::le(U64)),
+                StructFieldTypeNode::new("delegate", OptionTypeNode::new(StringTypeNode::base58())),
+            ])
+            .into()
+        );
+
+        let node: ValueNode = PublicKeyValueNode::new("1111").into();
+        assert_eq!(
+            node.transform(&mut PublicKeyToString).unwrap(),
+            Some(StringValueNode::new("1111").into())
+        );
+    }
+
+    #[test]
+    fn transform_invalid_nested_type_node() {
+        struct StructToTuple;
+        impl NodeTransformer for StructToTuple {
+            fn transform_type(&mut self, node: TypeNode) -> CodamaResult<Option<TypeNode>> {
+                match node {
+                    TypeNode::Struct(_) => Ok(Some(TupleTypeNode::new(vec![]).into())),
+                    node => Ok(Some(node)),
+                }
+            }
+        }
+        assert!(account().transform(&mut StructToTuple).is_err());
+    }
+
+    #[derive(Default)]
+    struct OrderRecorder {
+        mode: Option<TraversalMode>,
+        names: Vec<String>,
+    }
+
+    impl NodeTransformer for OrderRecorder {
+        fn mode(&self) -> TraversalMode {
+            self.mode.unwrap_or(TraversalMode::BottomUp)
+        }
+
+        fn transform_struct_field_type(
+            &mut self,
+            node: StructFieldTypeNode,
+        ) -> CodamaResult<Option<StructFieldTypeNode>> {
+            self.names.push(node.name.to_string());
+            Ok(Some(node))
+        }
+
+        fn transform_account(&mut self, node: AccountNode) -> CodamaResult<Option<AccountNode>> {
+            self.names.push(node.name.to_string());
+            Ok(Some(node))
+        }
+    }
+
+    #[test]
+    fn transform_modes() {
+        let mut transformer = OrderRecorder::default();
+        account().transform(&mut transformer).unwrap();
+        assert_eq!(transformer.names, vec!["amount", "delegate", "token"]);
+
+        let mut transformer = OrderRecorder {
+            mode: Some(TraversalMode::TopDown),
+            ..OrderRecorder::default()
+        };
+        account().transform(&mut transformer).unwrap();
+        assert_eq!(transformer.names, vec!["token", "amount", "delegate"]);
+    }
+
+    #[test]
+    fn node_name() {
+        assert_eq!(account().node_name(), Some(&CamelCaseString::new("token")));
+        assert_eq!(NumberTypeNode::le(U64).node_name(), None);
+
+        let node: Node = account().into();
+        assert_eq!(node.node_name(), Some(&CamelCaseString::new("token")));
+    }
+}
@@ -0,0 +1,417 @@
+use crate::{
+    AccountBumpValueNode, AccountLinkNode, AccountNode, AccountValueNode, AmountTypeNode,
+    ArgumentValueNode, ArrayTypeNode, ArrayValueNode, BooleanTypeNode, BooleanValueNode,
+    BytesTypeNode, BytesValueNode, ConditionalValueNode, ConstantDiscriminatorNode,
+    ConstantPdaSeedNode, ConstantValueNode, DateTimeTypeNode, DefinedTypeLinkNode, DefinedTypeNode,
+    EnumEmptyVariantTypeNode, EnumStructVariantTypeNode, EnumTupleVariantTypeNode, EnumTypeNode,
+    EnumValueNode, ErrorNode, FieldDiscriminatorNode, FixedCountNode, FixedSizeTypeNode, HasKind,
+    HiddenPrefixTypeNode, HiddenSuffixTypeNode, IdentityValueNode, InstructionAccountLinkNode,
+    InstructionAccountNode, InstructionArgumentLinkNode, InstructionArgumentNode,
+    InstructionByteDeltaNode, InstructionInputValueNode, InstructionLinkNode, InstructionNode,
+    InstructionRemainingAccountsNode, InstructionStatusNode, MapEntryValueNode, MapTypeNode,
+    MapValueNode, NodeVisitable, NoneValueNode, NumberTypeNode, NumberValueNode, OptionTypeNode,
+    PayerValueNode, PdaLinkNode, PdaNode, PdaSeedValueNode, PdaValueNode, PostOffsetTypeNode,
+    PreOffsetTypeNode, PrefixedCountNode, ProgramIdValueNode, ProgramLinkNode, ProgramNode,
+    PublicKeyTypeNode, PublicKeyValueNode, RemainderCountNode, RemainderOptionTypeNode,
+    ResolverValueNode, RootNode, SentinelTypeNode, SetTypeNode, SetValueNode,
+    SizeDiscriminatorNode, SizePrefixTypeNode, SolAmountTypeNode, SomeValueNode, StringTypeNode,
+    StringValueNode, StructFieldTypeNode, StructFieldValueNode, StructTypeNode, StructValueNode,
+    TraversalMode, TupleTypeNode, TupleValueNode, TypeNode, ValueNode, VariablePdaSeedNode,
+    ZeroableOptionTypeNode,
+};
+use codama_errors::CodamaResult;
+
+/// Visits a tree of nodes without modifying it.
+///
+/// Every `visit_*` hook defaults to [`NodeVisitor::visit_node`] which does nothing,
+/// so implementors only override the hooks they care about. Children are always
+/// visited, either after or before their parent depending on [`NodeVisitor::mode`].
+pub trait NodeVisitor: Sized {
+    /// Whether parents are visited before (default) or after their children.
+    fn mode(&self) -> TraversalMode {
+        TraversalMode::TopDown
+    }
+
+    /// Fallback hook called for every concrete node.
+    fn visit_node<N: NodeVisitable + HasKind>(&mut self, _node: &N) -> CodamaResult<()> {
+        Ok(())
+    }
+
+    /// Called with the kind of a node once it and all of its children have been visited.
+    /// E.g. to leave the scope entered by the hook of a parent visited top-down.
+    fn leave_node(&mut self, _kind: &'static str) -> CodamaResult<()> {
+        Ok(())
+    }
+
+    /// Called for every `TypeNode` in addition to the hook of its variant.
+    fn visit_type(&mut self, _node: &TypeNode) -> CodamaResult<()> {
+        Ok(())
+    }
+
+    /// Called for every `ValueNode` in addition to the hook of its variant.
+    fn visit_value(&mut self, _node: &ValueNode) -> CodamaResult<()> {
+        Ok(())
+    }
+
+    /// Called for every `InstructionInputValueNode` in addition to the hook of its variant.
+    fn visit_instruction_input_value(
+        &mut self,
+        _node: &InstructionInputValueNode,
+    ) -> CodamaResult<()> {
+        Ok(())
+    }
+
+    fn visit_root(&mut self, node: &RootNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_program(&mut self, node: &ProgramNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_pda(&mut self, node: &PdaNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_account(&mut self, node: &AccountNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_instruction(&mut self, node: &InstructionNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_instruction_account(&mut self, node: &InstructionAccountNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_instruction_argument(&mut self, node: &InstructionArgumentNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_instruction_byte_delta(
+        &mut self,
+        node: &InstructionByteDeltaNode,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_instruction_remaining_accounts(
+        &mut self,
+        node: &InstructionRemainingAccountsNode,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_instruction_status(&mut self, node: &InstructionStatusNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_defined_type(&mut self, node: &DefinedTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_error(&mut self, node: &ErrorNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_account_link(&mut self, node: &AccountLinkNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_defined_type_link(&mut self, node: &DefinedTypeLinkNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_instruction_link(&mut self, node: &InstructionLinkNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_instruction_account_link(
+        &mut self,
+        node: &InstructionAccountLinkNode,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_instruction_argument_link(
+        &mut self,
+        node: &InstructionArgumentLinkNode,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_pda_link(&mut self, node: &PdaLinkNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_program_link(&mut self, node: &ProgramLinkNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_constant_pda_seed(&mut self, node: &ConstantPdaSeedNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_variable_pda_seed(&mut self, node: &VariablePdaSeedNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_constant_discriminator(
+        &mut self,
+        node: &ConstantDiscriminatorNode,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_field_discriminator(&mut self, node: &FieldDiscriminatorNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_size_discriminator(&mut self, node: &SizeDiscriminatorNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_fixed_count(&mut self, node: &FixedCountNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_prefixed_count(&mut self, node: &PrefixedCountNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_remainder_count(&mut self, node: &RemainderCountNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_account_bump_value(&mut self, node: &AccountBumpValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_account_value(&mut self, node: &AccountValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_argument_value(&mut self, node: &ArgumentValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_conditional_value(&mut self, node: &ConditionalValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_identity_value(&mut self, node: &IdentityValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_payer_value(&mut self, node: &PayerValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_pda_seed_value(&mut self, node: &PdaSeedValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_pda_value(&mut self, node: &PdaValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_program_id_value(&mut self, node: &ProgramIdValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_resolver_value(&mut self, node: &ResolverValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_array_value(&mut self, node: &ArrayValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_boolean_value(&mut self, node: &BooleanValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_bytes_value(&mut self, node: &BytesValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_constant_value(&mut self, node: &ConstantValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_enum_value(&mut self, node: &EnumValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_map_entry_value(&mut self, node: &MapEntryValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_map_value(&mut self, node: &MapValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_none_value(&mut self, node: &NoneValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_number_value(&mut self, node: &NumberValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_public_key_value(&mut self, node: &PublicKeyValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_set_value(&mut self, node: &SetValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_some_value(&mut self, node: &SomeValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_string_value(&mut self, node: &StringValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_struct_field_value(&mut self, node: &StructFieldValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_struct_value(&mut self, node: &StructValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_tuple_value(&mut self, node: &TupleValueNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_amount_type(&mut self, node: &AmountTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_array_type(&mut self, node: &ArrayTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_boolean_type(&mut self, node: &BooleanTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_bytes_type(&mut self, node: &BytesTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_date_time_type(&mut self, node: &DateTimeTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_enum_empty_variant_type(
+        &mut self,
+        node: &EnumEmptyVariantTypeNode,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_enum_struct_variant_type(
+        &mut self,
+        node: &EnumStructVariantTypeNode,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_enum_tuple_variant_type(
+        &mut self,
+        node: &EnumTupleVariantTypeNode,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_enum_type(&mut self, node: &EnumTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_fixed_size_type(&mut self, node: &FixedSizeTypeNode<TypeNode>) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_hidden_prefix_type(
+        &mut self,
+        node: &HiddenPrefixTypeNode<TypeNode>,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_hidden_suffix_type(
+        &mut self,
+        node: &HiddenSuffixTypeNode<TypeNode>,
+    ) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_map_type(&mut self, node: &MapTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_number_type(&mut self, node: &NumberTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_option_type(&mut self, node: &OptionTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_post_offset_type(&mut self, node: &PostOffsetTypeNode<TypeNode>) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_pre_offset_type(&mut self, node: &PreOffsetTypeNode<TypeNode>) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_public_key_type(&mut self, node: &PublicKeyTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_remainder_option_type(&mut self, node: &RemainderOptionTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_sentinel_type(&mut self, node: &SentinelTypeNode<TypeNode>) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_set_type(&mut self, node: &SetTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_size_prefix_type(&mut self, node: &SizePrefixTypeNode<TypeNode>) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_sol_amount_type(&mut self, node: &SolAmountTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_string_type(&mut self, node: &StringTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_struct_field_type(&mut self, node: &StructFieldTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_struct_type(&mut self, node: &StructTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_tuple_type(&mut self, node: &TupleTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+
+    fn visit_zeroable_option_type(&mut self, node: &ZeroableOptionTypeNode) -> CodamaResult<()> {
+        self.visit_node(node)
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{
+    CamelCaseString, HasKind, InstructionAccountLinkNode, InstructionArgumentLinkNode,
+    InstructionLinkNode, InstructionNode, NodeScope, NodeSelector, NodeTransformer, NodeVisitable,
+    NodeVisitor, ProgramNode, TraversalMode,
+};
+use codama_errors::{CodamaError, CodamaResult};
+use std::collections::HashMap;
+
+/// Renames all nodes matching the given selector, as well as
+/// all link and value nodes referencing them by name.
+///
+/// Instruction accounts and arguments are only renamed — along with their
+/// references — within the instruction that defines them. Use a path selector
+/// such as `"transfer.[instructionAccountNode]source"` to select a single instruction.
+pub fn rename<N, S, T>(node: N, selector: S, new_name: T) -> CodamaResult<N>
+where
+    N: NodeVisitable,
+    S: Into<NodeSelector>,
+    T: Into<CamelCaseString>,
+{
+    let mut collector = SelectedNodesCollector {
+        selector: selector.into(),
+        scope: NodeScope::default(),
+        nodes: vec![],
+    };
+    node.accept(&mut collector)?;
+
+    let new_name = new_name.into();
+    let mut renames = NodeRenames::default();
+    for (kind, name, scope) in collector.nodes {
+        renames.insert(kind, scope.clone(), name.clone(), new_name.clone());
+        renames.insert_references_in(kind, scope, name, new_name.clone());
+    }
+    renames.apply(node)
+}
+
+/// The instruction in which an instruction account or argument is defined.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct InstructionScope {
+    program: Option<CamelCaseString>,
+    instruction: CamelCaseString,
+}
+
+/// Maps the kind, current name and scope of nodes to their new name.
+#[derive(Debug, Default)]
+pub(crate) struct NodeRenames(
+    HashMap<(&'static str, CamelCaseString, Option<InstructionScope>), CamelCaseString>,
+);
+
+impl NodeRenames {
+    pub fn insert(
+        &mut self,
+        kind: &'static str,
+        scope: Option<InstructionScope>,
+        from: CamelCaseString,
+        to: CamelCaseString,
+    ) {
+        self.0.insert((kind, from, scope), to);
+    }
+
+    /// Renames all nodes that reference a node of the given kind by name.
+    pub fn insert_references(&mut self, kind: &str, from: CamelCaseString, to: CamelCaseString) {
+        self.insert_references_in(kind, None, from, to);
+    }
+
+    pub fn insert_references_in(
+        &mut self,
+        kind: &str,
+        scope: Option<InstructionScope>,
+        from: CamelCaseString,
+        to: CamelCaseString,
+    ) {
+        for reference_kind in reference_kinds(kind) {
+            self.insert(reference_kind, scope.clone(), from.clone(), to.clone());
+        }
+    }
+
+    pub fn apply<N: NodeVisitable>(self, node: N) -> CodamaResult<N> {
+        if self.0.is_empty() {
+            return Ok(node);
+        }
+        let mut transformer = RenameTransformer {
+            renames: self,
+            scope: NodeScope::default(),
+        };
+        // Renaming nodes never deletes them.
+        node.transform(&mut transformer)?
+            .ok_or(CodamaError::NodeNotFound)
+    }
+}
+
+/// The kinds of the nodes referencing a node of the given kind by name.
+fn reference_kinds(kind: &str) -> &'static [&'static str] {
+    match kind {
+        "accountNode" => &["accountLinkNode"],
+        "definedTypeNode" => &["definedTypeLinkNode"],
+        "instructionNode" => &["instructionLinkNode"],
+        "instructionAccountNode" => &[
+            "instructionAccountLinkNode",
+            "accountValueNode",
+            "accountBumpValueNode",
+        ],
+        "instructionArgumentNode" => &["instructionArgumentLinkNode", "argumentValueNode"],
+        "pdaNode" => &["pdaLinkNode"],
+        "programNode" => &["programLinkNode"],
+        _ => &[],
+    }
+}
+
+/// Whether nodes of the given kind are scoped to their parent instruction.
+fn is_instruction_scoped(kind: &str) -> bool {
+    matches!(
+        kind,
+        "instructionAccountNode"
+            | "instructionAccountLinkNode"
+            | "instructionArgumentNode"
+            | "instructionArgumentLinkNode"
+            | "accountValueNode"
+            | "accountBumpValueNode"
+            | "argumentValueNode"
+    )
+}
+
+/// The instruction scope of a node of the given kind, if it is scoped to its instruction.
+fn get_instruction_scope(kind: &str, scope: &NodeScope) -> Option<InstructionScope> {
+    if !is_instruction_scoped(kind) {
+        return None;
+    }
+    Some(InstructionScope {
+        program: scope.program.clone(),
+        instruction: scope.instruction.clone()?,
+    })
+}
+
+/// The instruction scope of a link that may explicitly reference its instruction.
+fn get_link_instruction_scope(
+    kind: &str,
+    instruction: &Option<InstructionLinkNode>,
+    scope: &NodeScope,
+) -> Option<InstructionScope> {
+    match instruction {
+        Some(link) => Some(InstructionScope {
+            program: match &link.program {
+                Some(program) => Some(program.name.clone()),
+                None => scope.program.clone(),
+            },
+            instruction: link.name.clone(),
+        }),
+        None => get_instruction_scope(kind, scope),
+    }
+}
+
+struct SelectedNodesCollector {
+    selector: NodeSelector,
+    scope: NodeScope,
+    nodes: Vec<(&'static str, CamelCaseString, Option<InstructionScope>)>,
+}
+
+impl NodeVisitor for SelectedNodesCollector {
+    fn visit_node<N: NodeVisitable + HasKind>(&mut self, node: &N) -> CodamaResult<()> {
+        if let (true, Some(name)) = (
+            self.selector.matches_in(node, &self.scope),
+            node.node_name(),
+        ) {
+            let scope = get_instruction_scope(node.kind(), &self.scope);
+            self.nodes.push((node.kind(), name.clone(), scope));
+        }
+        Ok(())
+    }
+
+    fn visit_program(&mut self, node: &ProgramNode) -> CodamaResult<()> {
+        self.visit_node(node)?;
+        self.scope.enter_program(node);
+        Ok(())
+    }
+
+    fn visit_instruction(&mut self, node: &InstructionNode) -> CodamaResult<()> {
+        self.visit_node(node)?;
+        self.scope.enter_instruction(node);
+        Ok(())
+    }
+
+    fn leave_node(&mut self, kind: &'static str) -> CodamaResult<()> {
+        self.scope.leave(kind);
+        Ok(())
+    }
+}
+
+struct RenameTransformer {
+    renames: NodeRenames,
+    scope: NodeScope,
+}
+
+impl RenameTransformer {
+    fn rename<N: NodeVisitable + HasKind>(
+        &self,
+        mut node: N,
+        scope: Option<InstructionScope>,
+    ) -> N {
+        let kind = node.kind();
+        if let Some(name) = node.node_name_mut() {
+            if let Some(new_name) = self.renames.0.get(&(kind, name.clone(), scope)) {
+                *name = new_name.clone();
+            }
+        }
+        node
+    }
+}
+
+impl NodeTransformer for RenameTransformer {
+    fn mode(&self) -> TraversalMode {
+        // Programs and instructions must be entered before their children to scope them.
+        TraversalMode::TopDown
+    }
+
+    fn transform_node<N: NodeVisitable + HasKind>(&mut self, node: N) -> CodamaResult<Option<N>> {
+        let scope = get_instruction_scope(node.kind(), &self.scope);
+        Ok(Some(self.rename(node, scope)))
+    }
+
+    fn transform_program(&mut self, node: ProgramNode) -> CodamaResult<Option<ProgramNode>> {
+        self.scope.enter_program(&node);
+        self.transform_node(node)
+    }
+
+    fn transform_instruction(
+        &mut self,
+        node: InstructionNode,
+    ) -> CodamaResult<Option<InstructionNode>> {
+        self.scope.enter_instruction(&node);
+        self.transform_node(node)
+    }
+
+    fn leave_node(&mut self, kind: &'static str) -> CodamaResult<()> {
+        self.scope.leave(kind);
+        Ok(())
+    }
+
+    fn transform_instruction_account_link(
+        &mut self,
+        node: InstructionAccountLinkNode,
+    ) -> CodamaResult<Option<InstructionAccountLinkNode>> {
+        let scope = get_link_instruction_scope(node.kind(), &node.instruction, &self.scope);
+        Ok(Some(self.rename(node, scope)))
+    }
+
+    fn transform_instruction_argument_link(
+        &mut self,
+        node: InstructionArgumentLinkNode,
+    ) -> CodamaResult<Option<InstructionArgumentLinkNode>> {
+        let scope = get_link_instruction_scope(node.kind(), &node.instruction, &self.scope);
+        Ok(Some(self.rename(node, scope)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountNode, AccountValueNode, InstructionAccountNode, InstructionNode, PdaLinkNode,
+        PdaNode, ProgramNode, RootNode, StructTypeNode,
+    };
+
+    #[test]
+    fn rename_nodes_and_links() {
+        let root = RootNode::new(
+            ProgramNode::new("token", "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+                .add_pda(PdaNode::new("associatedToken", vec![]))
+                .add_account(AccountNode {
+                    pda: Some(PdaLinkNode::new("associatedToken")),
+                    ..AccountNode::new("token", StructTypeNode::new(vec![]))
+                }),
+        );
+
+        let root = rename(root, "[pdaNode]associatedToken", "ata").unwrap();
+        assert_eq!(root.program.pdas, vec![PdaNode::new("ata", vec![])]);
+        assert_eq!(root.program.accounts[0].pda, Some(PdaLinkNode::new("ata")));
+    }
+
+    #[test]
+    fn rename_instruction_accounts_and_values() {
+        let instruction = InstructionNode {
+            name: "transfer".into(),
+            accounts: vec![
+                InstructionAccountNode::new("source", true, false),
+                InstructionAccountNode {
+                    default_value: Some(AccountValueNode::new("source").into()),
+                    ..InstructionAccountNode::new("authority", false, true)
+                },
+            ],
+            ..InstructionNode::default()
+        };
+
+        let instruction = rename(instruction, "[instructionAccountNode]source", "from").unwrap();
+        assert_eq!(instruction.accounts[0].name, CamelCaseString::new("from"));
+        assert_eq!(
+            instruction.accounts[1].default_value,
+            Some(AccountValueNode::new("from").into())
+        );
+    }
+
+    #[test]
+    fn rename_instruction_accounts_within_their_instruction() {
+        let instruction = |name: &str| InstructionNode {
+            name: name.into(),
+            accounts: vec![
+                InstructionAccountNode::new("source", true, false),
+                InstructionAccountNode {
+                    default_value: Some(AccountValueNode::new("source").into()),
+                    ..InstructionAccountNode::new("authority", false, true)
+                },
+            ],
+            ..InstructionNode::default()
+        };
+        let program = |name: &str| {
+            ProgramNode::new(name, "1111")
+                .add_instruction(instruction("transfer"))
+                .add_instruction(instruction("burn"))
+        };
+        let root = RootNode::new(program("token")).add_program(program("token2022"));
+
+        let root = rename(
+            root,
+            "token.transfer.[instructionAccountNode]source",
+            "from",
+        )
+        .unwrap();
+        let transfer = &root.program.instructions[0];
+        assert_eq!(transfer.accounts[0].name, CamelCaseString::new("from"));
+        assert_eq!(
+            transfer.accounts[1].default_value,
+            Some(AccountValueNode::new("from").into())
+        );
+        assert_eq!(root.program.instructions[1], instruction("burn"));
+        assert_eq!(
+            root.additional_programs[0].instructions,
+            vec![instruction("transfer"), instruction("burn")]
+        );
+    }
+
+    #[test]
+    fn leave_instruction_scope_after_its_children() {
+        // PDAs are visited after the instructions of their program.
+        let root = RootNode::new(
+            ProgramNode::new("token", "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+                .add_instruction(InstructionNode {
+                    name: "transfer".into(),
+                    ..InstructionNode::default()
+                })
+                .add_pda(PdaNode::new("counter", vec![])),
+        );
+
+        let renamed = rename(root.clone(), "transfer.[pdaNode]counter", "vault").unwrap();
+        assert_eq!(renamed, root);
+
+        let renamed = rename(root, "token.[pdaNode]counter", "vault").unwrap();
+        assert_eq!(renamed.program.pdas, vec![PdaNode::new("vault", vec![])]);
+    }
+
+    #[test]
+    fn unmatched_selector() {
+        let account = AccountNode::new("token", StructTypeNode::new(vec![]));
+        assert_eq!(
+            rename(account.clone(), "[accountNode]mint", "tokenMint").unwrap(),
+            account
+        );
+    }
+}
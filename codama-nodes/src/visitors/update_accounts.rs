@@ -0,0 +1,112 @@
+use crate::{AccountNode, NodeRenames, NodeTransformer, NodeVisitable};
+use codama_errors::CodamaResult;
+
+/// Updates or deletes all accounts using the provided function.
+///
+/// Renamed accounts also update the account link nodes referencing them.
+pub fn update_accounts<N, F>(node: N, update: F) -> CodamaResult<Option<N>>
+where
+    N: NodeVisitable,
+    F: FnMut(AccountNode) -> Option<AccountNode>,
+{
+    let mut transformer = UpdateAccountsTransformer {
+        update,
+        renames: NodeRenames::default(),
+    };
+    match node.transform(&mut transformer)? {
+        Some(node) => transformer.renames.apply(node).map(Some),
+        None => Ok(None),
+    }
+}
+
+struct UpdateAccountsTransformer<F> {
+    update: F,
+    renames: NodeRenames,
+}
+
+impl<F: FnMut(AccountNode) -> Option<AccountNode>> NodeTransformer
+    for UpdateAccountsTransformer<F>
+{
+    fn transform_account(&mut self, node: AccountNode) -> CodamaResult<Option<AccountNode>> {
+        let name = node.name.clone();
+        let node = (self.update)(node);
+        if let Some(node) = &node {
+            if node.name != name {
+                self.renames
+                    .insert_references("accountNode", name, node.name.clone());
+            }
+        }
+        Ok(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountLinkNode, InstructionByteDeltaNode, InstructionNode, ProgramNode, RootNode,
+        StructTypeNode,
+    };
+
+    fn root() -> RootNode {
+        RootNode::new(
+            ProgramNode::new("token", "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+                .add_account(AccountNode::new("mint", StructTypeNode::new(vec![])))
+                .add_account(AccountNode::new("token", StructTypeNode::new(vec![])))
+                .add_instruction(InstructionNode {
+                    name: "createMint".into(),
+                    byte_deltas: vec![InstructionByteDeltaNode::new(
+                        AccountLinkNode::new("mint"),
+                        true,
+                    )],
+                    ..InstructionNode::default()
+                }),
+        )
+    }
+
+    #[test]
+    fn update() {
+        let root = update_accounts(root(), |account| {
+            Some(AccountNode {
+                size: Some(82),
+                ..account
+            })
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(root.program.accounts[0].size, Some(82));
+        assert_eq!(root.program.accounts[1].size, Some(82));
+    }
+
+    #[test]
+    fn delete() {
+        let root = update_accounts(root(), |account| match account.name.as_str() {
+            "mint" => None,
+            _ => Some(account),
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            root.program.accounts,
+            vec![AccountNode::new("token", StructTypeNode::new(vec![]))]
+        );
+    }
+
+    #[test]
+    fn rename_updates_links() {
+        let root = update_accounts(root(), |account| match account.name.as_str() {
+            "mint" => Some(AccountNode {
+                name: "tokenMint".into(),
+                ..account
+            }),
+            _ => Some(account),
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(root.program.accounts[0].name.as_str(), "tokenMint");
+        assert_eq!(
+            root.program.instructions[0].byte_deltas[0],
+            InstructionByteDeltaNode::new(AccountLinkNode::new("tokenMint"), true)
+        );
+    }
+}
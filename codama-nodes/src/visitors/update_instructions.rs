@@ -0,0 +1,127 @@
+use crate::{InstructionNode, NodeRenames, NodeTransformer, NodeVisitable};
+use codama_errors::CodamaResult;
+
+/// Updates or deletes all instructions — including sub-instructions —
+/// using the provided function.
+///
+/// Renamed instructions also update the instruction link nodes referencing them.
+pub fn update_instructions<N, F>(node: N, update: F) -> CodamaResult<Option<N>>
+where
+    N: NodeVisitable,
+    F: FnMut(InstructionNode) -> Option<InstructionNode>,
+{
+    let mut transformer = UpdateInstructionsTransformer {
+        update,
+        renames: NodeRenames::default(),
+    };
+    match node.transform(&mut transformer)? {
+        Some(node) => transformer.renames.apply(node).map(Some),
+        None => Ok(None),
+    }
+}
+
+struct UpdateInstructionsTransformer<F> {
+    update: F,
+    renames: NodeRenames,
+}
+
+impl<F: FnMut(InstructionNode) -> Option<InstructionNode>> NodeTransformer
+    for UpdateInstructionsTransformer<F>
+{
+    fn transform_instruction(
+        &mut self,
+        node: InstructionNode,
+    ) -> CodamaResult<Option<InstructionNode>> {
+        let name = node.name.clone();
+        let node = (self.update)(node);
+        if let Some(node) = &node {
+            if node.name != name {
+                self.renames
+                    .insert_references("instructionNode", name, node.name.clone());
+            }
+        }
+        Ok(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InstructionAccountLinkNode, InstructionLinkNode, ProgramNode, RootNode};
+
+    fn instruction(name: &str) -> InstructionNode {
+        InstructionNode {
+            name: name.into(),
+            ..InstructionNode::default()
+        }
+    }
+
+    #[test]
+    fn update_sub_instructions() {
+        let root = RootNode::new(
+            ProgramNode::new("token", "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+                .add_instruction(InstructionNode {
+                    sub_instructions: vec![instruction("transferV1"), instruction("transferV2")],
+                    ..instruction("transfer")
+                }),
+        );
+
+        let root = update_instructions(root, |instruction| match instruction.name.as_str() {
+            "transferV1" => None,
+            _ => Some(InstructionNode {
+                docs: vec!["Updated.".to_string()].into(),
+                ..instruction
+            }),
+        })
+        .unwrap()
+        .unwrap();
+
+        let transfer = &root.program.instructions[0];
+        assert_eq!(transfer.docs, vec!["Updated.".to_string()].into());
+        assert_eq!(transfer.sub_instructions.len(), 1);
+        assert_eq!(transfer.sub_instructions[0].name.as_str(), "transferV2");
+        assert_eq!(
+            transfer.sub_instructions[0].docs,
+            vec!["Updated.".to_string()].into()
+        );
+    }
+
+    #[test]
+    fn rename_updates_links() {
+        let link = InstructionAccountLinkNode {
+            name: "source".into(),
+            instruction: Some(InstructionLinkNode::new("transfer")),
+        };
+        let node = InstructionNode {
+            name: "transfer".into(),
+            ..InstructionNode::default()
+        };
+        let program = ProgramNode::new("token", "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+            .add_instruction(node);
+
+        let root = update_instructions(RootNode::new(program), |instruction| {
+            Some(InstructionNode {
+                name: "transferChecked".into(),
+                ..instruction
+            })
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            root.program.instructions[0].name.as_str(),
+            "transferChecked"
+        );
+
+        // Links are updated on any visitable node.
+        let mut renames = NodeRenames::default();
+        renames.insert_references(
+            "instructionNode",
+            "transfer".into(),
+            "transferChecked".into(),
+        );
+        assert_eq!(
+            renames.apply(link).unwrap().instruction,
+            Some(InstructionLinkNode::new("transferChecked"))
+        );
+    }
+}
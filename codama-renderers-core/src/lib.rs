@@ -0,0 +1,3 @@
+mod render_map;
+
+pub use render_map::*;
@@ -0,0 +1,89 @@
+use codama_errors::CodamaResult;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Rendered files indexed by their path, relative to the output directory.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RenderMap(BTreeMap<PathBuf, String>);
+
+impl RenderMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add<P: Into<PathBuf>, T: Into<String>>(&mut self, path: P, content: T) {
+        self.0.insert(path.into(), content.into());
+    }
+
+    pub fn get<P: AsRef<Path>>(&self, path: P) -> Option<&str> {
+        self.0.get(path.as_ref()).map(String::as_str)
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.0.keys().map(PathBuf::as_path)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, &str)> {
+        self.0
+            .iter()
+            .map(|(path, content)| (path.as_path(), content.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Writes all files inside the given directory, creating it if needed.
+    pub fn write_to_directory<P: AsRef<Path>>(&self, directory: P) -> CodamaResult<()> {
+        for (path, content) in &self.0 {
+            let path = directory.as_ref().join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, content)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_get() {
+        let mut map = RenderMap::new();
+        map.add("mod.rs", "pub mod programs;");
+        map.add("programs.rs", "");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("mod.rs"), Some("pub mod programs;"));
+        assert_eq!(map.get("missing.rs"), None);
+        assert_eq!(
+            map.paths().collect::<Vec<_>>(),
+            vec![Path::new("mod.rs"), Path::new("programs.rs")]
+        );
+    }
+
+    #[test]
+    fn write_to_directory() {
+        let directory = std::env::temp_dir().join(format!(
+            "codama_render_map_{:?}",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+
+        let mut map = RenderMap::new();
+        map.add("accounts/mod.rs", "mod counter;");
+        map.write_to_directory(&directory).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(directory.join("accounts/mod.rs")).unwrap(),
+            "mod counter;"
+        );
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,180 @@
+use crate::defined_type_renderer::render_data;
+use crate::import_map::{ImportMap, KIT};
+use crate::render_context::RenderContext;
+use crate::utils::{pascal_case, shouty_case};
+use crate::value_renderer::render_value;
+use codama_errors::{CodamaError, CodamaResult};
+use codama_nodes::{AccountNode, DiscriminatorNode, NestedTypeNode, TypeNode};
+use std::collections::HashMap;
+
+pub(crate) fn render_account(
+    ctx: &RenderContext,
+    account: &AccountNode,
+    imports: &mut ImportMap,
+) -> CodamaResult<String> {
+    let NestedTypeNode::Value(data) = &account.data else {
+        return Err(CodamaError::Rendering(format!(
+            "account `{}` must use a plain struct type",
+            account.name.as_str()
+        )));
+    };
+    let name = pascal_case(&account.name);
+
+    // Discriminator constant.
+    let mut code = String::new();
+    let mut overrides = HashMap::new();
+    for discriminator in &account.discriminators {
+        let DiscriminatorNode::Field(discriminator) = discriminator else {
+            continue;
+        };
+        let field = data
+            .fields
+            .iter()
+            .find(|field| field.name == discriminator.name);
+        if let Some(default_value) = field.and_then(|field| field.default_value.as_ref()) {
+            let constant = format!("{}_DISCRIMINATOR", shouty_case(&account.name));
+            let value = render_value(ctx, default_value, imports)?;
+            code.push_str(&format!("export const {constant} = {value};\n\n"));
+            overrides.insert(discriminator.name.as_str(), constant);
+            break;
+        }
+    }
+
+    code.push_str(&render_data(
+        ctx,
+        &name,
+        &account.docs,
+        &data.fields,
+        &overrides,
+        imports,
+    )?);
+
+    for name in [
+        "assertAccountExists",
+        "assertAccountsExist",
+        "decodeAccount",
+        "fetchEncodedAccount",
+        "fetchEncodedAccounts",
+    ] {
+        imports.add(KIT, name);
+    }
+    for name in [
+        "Account",
+        "Address",
+        "EncodedAccount",
+        "FetchAccountConfig",
+        "FetchAccountsConfig",
+        "MaybeAccount",
+        "MaybeEncodedAccount",
+    ] {
+        imports.add_type(KIT, name);
+    }
+    code.push_str(&format!(
+        r#"
+export function decode{name}<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<{name}, TAddress>;
+export function decode{name}<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<{name}, TAddress>;
+export function decode{name}<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<{name}, TAddress> | MaybeAccount<{name}, TAddress> {{
+  return decodeAccount(encodedAccount as MaybeEncodedAccount<TAddress>, get{name}Decoder());
+}}
+
+export async function fetch{name}<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<{name}, TAddress>> {{
+  const maybeAccount = await fetchMaybe{name}(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}}
+
+export async function fetchMaybe{name}<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<{name}, TAddress>> {{
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decode{name}(maybeAccount);
+}}
+
+export async function fetchAll{name}(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<{name}>[]> {{
+  const maybeAccounts = await fetchAllMaybe{name}(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}}
+
+export async function fetchAllMaybe{name}(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<{name}>[]> {{
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decode{name}(maybeAccount));
+}}
+"#
+    ));
+
+    let size = match account.size {
+        Some(size) => Some(size),
+        None => ctx
+            .codec
+            .fixed_size(&TypeNode::from(account.data.clone()))?,
+    };
+    if let Some(size) = size {
+        code.push_str(&format!(
+            "\nexport function get{name}Size(): number {{\n  return {size};\n}}\n"
+        ));
+    }
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        BytesTypeNode, BytesValueNode, DefaultValueStrategy, FieldDiscriminatorNode,
+        FixedSizeTypeNode, NumberTypeNode, ProgramNode, RootNode, StructFieldTypeNode,
+        StructTypeNode, U64,
+    };
+
+    #[test]
+    fn accounts() {
+        let account = AccountNode {
+            discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+            ..AccountNode::new(
+                "counter",
+                StructTypeNode::new(vec![
+                    StructFieldTypeNode {
+                        default_value_strategy: Some(DefaultValueStrategy::Omitted),
+                        default_value: Some(BytesValueNode::base16("ffb0").into()),
+                        ..StructFieldTypeNode::new(
+                            "discriminator",
+                            FixedSizeTypeNode::new(BytesTypeNode::new(), 2),
+                        )
+                    },
+                    StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+                ]),
+            )
+        };
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let ctx = RenderContext::new(&root);
+        let code = render_account(&ctx, &account, &mut ImportMap::new()).unwrap();
+        assert!(code.starts_with(
+            "export const COUNTER_DISCRIMINATOR = new Uint8Array([255, 176]);\n\nexport type Counter = {\n  discriminator: ReadonlyUint8Array;\n  count: bigint;\n};\n\nexport type CounterArgs = {\n  count: number | bigint;\n};\n"
+        ));
+        assert!(code.contains("(value) => ({ ...value, discriminator: COUNTER_DISCRIMINATOR })"));
+        assert!(code.contains(
+            "export async function fetchMaybeCounter<TAddress extends string = string>("
+        ));
+        assert!(code.ends_with("export function getCounterSize(): number {\n  return 10;\n}\n"));
+    }
+}
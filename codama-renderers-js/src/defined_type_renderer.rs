@@ -0,0 +1,329 @@
+use crate::import_map::{ImportMap, KIT};
+use crate::render_context::RenderContext;
+use crate::type_renderer::{render_nested_number, render_type, TypeManifest};
+use crate::utils::{camel_case, pascal_case, render_docs};
+use crate::value_renderer::{is_scalar_enum, render_value};
+use codama_errors::CodamaResult;
+use codama_nodes::{
+    DefaultValueStrategy, DefinedTypeNode, Docs, EnumTypeNode, EnumVariantTypeNode, NestedTypeNode,
+    NumberFormat, StructFieldTypeNode, TypeNode,
+};
+use std::collections::HashMap;
+
+pub(crate) fn render_defined_type(
+    ctx: &RenderContext,
+    node: &DefinedTypeNode,
+    imports: &mut ImportMap,
+) -> CodamaResult<String> {
+    let name = pascal_case(&node.name);
+    match &node.r#type {
+        TypeNode::Struct(data) => render_data(
+            ctx,
+            &name,
+            &node.docs,
+            &data.fields,
+            &HashMap::new(),
+            imports,
+        ),
+        TypeNode::Enum(data) if is_scalar_enum(&node.r#type) => {
+            Ok(render_scalar_enum(&name, &node.docs, data, imports))
+        }
+        r#type => {
+            let manifest = render_type(ctx, r#type, imports)?;
+            let docs = render_docs(&node.docs, "");
+            let types = format!(
+                "{docs}export type {name} = {};\n\nexport type {name}Args = {};\n\n",
+                manifest.strict, manifest.loose
+            );
+            let manifest = TypeManifest {
+                strict: name.clone(),
+                loose: format!("{name}Args"),
+                ..manifest
+            };
+            Ok(types + &render_codec_functions(&name, manifest, imports))
+        }
+    }
+}
+
+/// Renders the types and codecs of a struct, injecting default values into its encoder.
+///
+/// Fields with omitted default values can be overridden with an expression, e.g. a constant.
+pub(crate) fn render_data(
+    ctx: &RenderContext,
+    name: &str,
+    docs: &Docs,
+    fields: &[StructFieldTypeNode],
+    overrides: &HashMap<&str, String>,
+    imports: &mut ImportMap,
+) -> CodamaResult<String> {
+    let mut strict = vec![];
+    let mut loose = vec![];
+    let mut encoders = vec![];
+    let mut decoders = vec![];
+    let mut defaults = vec![];
+    for field in fields {
+        let docs = render_docs(&field.docs, "  ");
+        let key = camel_case(&field.name);
+        let manifest = render_type(ctx, &field.r#type, imports)?;
+        strict.push(format!("{docs}  {key}: {};\n", manifest.strict));
+        encoders.push(format!("    ['{key}', {}],\n", manifest.encoder));
+        decoders.push(format!("    ['{key}', {}],\n", manifest.decoder));
+        let Some(default_value) = &field.default_value else {
+            loose.push(format!("{docs}  {key}: {};\n", manifest.loose));
+            continue;
+        };
+        let value = match overrides.get(field.name.as_str()) {
+            Some(value) => value.clone(),
+            None => render_value(ctx, default_value, imports)?,
+        };
+        match field.default_value_strategy {
+            Some(DefaultValueStrategy::Omitted) => defaults.push(format!("{key}: {value}")),
+            _ => {
+                loose.push(format!("{docs}  {key}?: {};\n", manifest.loose));
+                defaults.push(format!("{key}: value.{key} ?? {value}"));
+            }
+        }
+    }
+
+    let object = |fields: Vec<String>| match fields.is_empty() {
+        true => "{}".to_string(),
+        false => format!("{{\n{}}}", fields.concat()),
+    };
+    let docs = render_docs(docs, "");
+    let types = format!(
+        "{docs}export type {name} = {};\n\nexport type {name}Args = {};\n\n",
+        object(strict),
+        object(loose)
+    );
+
+    imports.add(KIT, "getStructEncoder");
+    imports.add(KIT, "getStructDecoder");
+    let list = |items: Vec<String>| match items.is_empty() {
+        true => "[]".to_string(),
+        false => format!("[\n{}  ]", items.concat()),
+    };
+    let encoder = format!("getStructEncoder({})", list(encoders));
+    let encoder = match defaults.is_empty() {
+        true => encoder,
+        false => {
+            imports.add(KIT, "transformEncoder");
+            format!(
+                "transformEncoder({encoder}, (value) => ({{ ...value, {} }}))",
+                defaults.join(", ")
+            )
+        }
+    };
+    let manifest = TypeManifest {
+        strict: name.to_string(),
+        loose: format!("{name}Args"),
+        encoder,
+        decoder: format!("getStructDecoder({})", list(decoders)),
+    };
+    Ok(types + &render_codec_functions(name, manifest, imports))
+}
+
+fn render_scalar_enum(
+    name: &str,
+    docs: &Docs,
+    node: &EnumTypeNode,
+    imports: &mut ImportMap,
+) -> String {
+    let mut variants = String::new();
+    let mut has_discriminators = false;
+    for variant in &node.variants {
+        if let EnumVariantTypeNode::Empty(variant) = variant {
+            let variant_name = pascal_case(&variant.name);
+            match variant.discriminator {
+                Some(discriminator) => {
+                    has_discriminators = true;
+                    variants.push_str(&format!("  {variant_name} = {discriminator},\n"));
+                }
+                None => variants.push_str(&format!("  {variant_name},\n")),
+            }
+        }
+    }
+
+    let mut options = vec![];
+    let mut decoder_options = vec![];
+    if !matches!(&node.size, NestedTypeNode::Value(size) if size.format == NumberFormat::U8) {
+        let size = render_nested_number(&node.size, imports);
+        options.push(format!("size: {}", size.encoder));
+        decoder_options.push(format!("size: {}", size.decoder));
+    }
+    if has_discriminators {
+        options.push("useValuesAsDiscriminators: true".to_string());
+        decoder_options.push("useValuesAsDiscriminators: true".to_string());
+    }
+    let render_options = |options: Vec<String>| match options.is_empty() {
+        true => String::new(),
+        false => format!(", {{ {} }}", options.join(", ")),
+    };
+    imports.add(KIT, "getEnumEncoder");
+    imports.add(KIT, "getEnumDecoder");
+    let manifest = TypeManifest {
+        strict: name.to_string(),
+        loose: format!("{name}Args"),
+        encoder: format!("getEnumEncoder({name}{})", render_options(options)),
+        decoder: format!("getEnumDecoder({name}{})", render_options(decoder_options)),
+    };
+    let docs = render_docs(docs, "");
+    format!(
+        "{docs}export enum {name} {{\n{variants}}}\n\nexport type {name}Args = {name};\n\n{}",
+        render_codec_functions(name, manifest, imports)
+    )
+}
+
+/// Renders the `getXEncoder`, `getXDecoder` and `getXCodec` functions of a type.
+pub(crate) fn render_codec_functions(
+    name: &str,
+    manifest: TypeManifest,
+    imports: &mut ImportMap,
+) -> String {
+    imports.add_type(KIT, "Encoder");
+    imports.add_type(KIT, "Decoder");
+    imports.add_type(KIT, "Codec");
+    imports.add(KIT, "combineCodec");
+    let TypeManifest {
+        strict,
+        loose,
+        encoder,
+        decoder,
+    } = manifest;
+    format!(
+        "export function get{name}Encoder(): Encoder<{loose}> {{
+  return {encoder};
+}}
+
+export function get{name}Decoder(): Decoder<{strict}> {{
+  return {decoder};
+}}
+
+export function get{name}Codec(): Codec<{loose}, {strict}> {{
+  return combineCodec(get{name}Encoder(), get{name}Decoder());
+}}
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        BytesValueNode, DefinedTypeLinkNode, EnumEmptyVariantTypeNode, NumberTypeNode,
+        NumberValueNode, ProgramNode, RootNode, StructTypeNode, U16, U64,
+    };
+
+    fn render(node: DefinedTypeNode) -> String {
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let ctx = RenderContext::new(&root);
+        let mut imports = ImportMap::new();
+        render_defined_type(&ctx, &node, &mut imports).unwrap()
+    }
+
+    #[test]
+    fn structs() {
+        let node = DefinedTypeNode {
+            docs: vec!["The counter configuration.".to_string()].into(),
+            ..DefinedTypeNode::new(
+                "counterConfig",
+                StructTypeNode::new(vec![
+                    StructFieldTypeNode::new("max_count", NumberTypeNode::le(U64)),
+                    StructFieldTypeNode {
+                        default_value: Some(NumberValueNode::new(1u8).into()),
+                        ..StructFieldTypeNode::new("step", NumberTypeNode::le(U16))
+                    },
+                ]),
+            )
+        };
+        assert_eq!(
+            render(node),
+            r#"/** The counter configuration. */
+export type CounterConfig = {
+  maxCount: bigint;
+  step: number;
+};
+
+export type CounterConfigArgs = {
+  maxCount: number | bigint;
+  step?: number;
+};
+
+export function getCounterConfigEncoder(): Encoder<CounterConfigArgs> {
+  return transformEncoder(getStructEncoder([
+    ['maxCount', getU64Encoder()],
+    ['step', getU16Encoder()],
+  ]), (value) => ({ ...value, step: value.step ?? 1 }));
+}
+
+export function getCounterConfigDecoder(): Decoder<CounterConfig> {
+  return getStructDecoder([
+    ['maxCount', getU64Decoder()],
+    ['step', getU16Decoder()],
+  ]);
+}
+
+export function getCounterConfigCodec(): Codec<CounterConfigArgs, CounterConfig> {
+  return combineCodec(getCounterConfigEncoder(), getCounterConfigDecoder());
+}
+"#
+        );
+    }
+
+    #[test]
+    fn omitted_fields() {
+        let fields = vec![StructFieldTypeNode {
+            default_value_strategy: Some(DefaultValueStrategy::Omitted),
+            default_value: Some(BytesValueNode::base16("ff").into()),
+            ..StructFieldTypeNode::new("discriminator", NumberTypeNode::le(codama_nodes::U8))
+        }];
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let ctx = RenderContext::new(&root);
+        let overrides = HashMap::from([("discriminator", "DISCRIMINATOR".to_string())]);
+        let code = render_data(
+            &ctx,
+            "Counter",
+            &Docs::default(),
+            &fields,
+            &overrides,
+            &mut ImportMap::new(),
+        )
+        .unwrap();
+        assert!(code.contains("export type CounterArgs = {};"));
+        assert!(code.contains("(value) => ({ ...value, discriminator: DISCRIMINATOR })"));
+    }
+
+    #[test]
+    fn scalar_enums() {
+        let node = DefinedTypeNode::new(
+            "state",
+            EnumTypeNode {
+                size: NumberTypeNode::le(U16).into(),
+                ..EnumTypeNode::new(vec![
+                    EnumEmptyVariantTypeNode::new("open").into(),
+                    EnumEmptyVariantTypeNode {
+                        discriminator: Some(5),
+                        ..EnumEmptyVariantTypeNode::new("closed")
+                    }
+                    .into(),
+                ])
+            },
+        );
+        let code = render(node);
+        assert!(code.starts_with(
+            "export enum State {\n  Open,\n  Closed = 5,\n}\n\nexport type StateArgs = State;\n\n"
+        ));
+        assert!(code.contains(
+            "return getEnumEncoder(State, { size: getU16Encoder(), useValuesAsDiscriminators: true });"
+        ));
+    }
+
+    #[test]
+    fn aliases() {
+        let code = render(DefinedTypeNode::new(
+            "config",
+            DefinedTypeLinkNode::new("state"),
+        ));
+        assert!(code
+            .starts_with("export type Config = State;\n\nexport type ConfigArgs = StateArgs;\n\n"));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::import_map::{ImportMap, KIT};
+use crate::render_context::RenderContext;
+use crate::utils::{camel_case, pascal_case, render_docs, shouty_case, string_literal};
+use codama_nodes::ProgramNode;
+
+pub(crate) fn render_program_errors(
+    ctx: &RenderContext,
+    program: &ProgramNode,
+    imports: &mut ImportMap,
+) -> String {
+    let name = pascal_case(&program.name);
+    let messages = format!("{}ErrorMessages", camel_case(&program.name));
+    let prefix = format!("{}_ERROR__", shouty_case(&program.name));
+    let program_address = ctx.program_address(program, imports);
+
+    let mut constants = String::new();
+    let mut types = vec![];
+    let mut entries = String::new();
+    for error in &program.errors {
+        let constant = format!("{prefix}{}", shouty_case(&error.name));
+        let mut docs = error.docs.to_vec();
+        docs.insert(
+            0,
+            format!("{}: {}", pascal_case(&error.name), error.message),
+        );
+        constants.push_str(&render_docs(&docs.into(), ""));
+        constants.push_str(&format!(
+            "export const {constant} = {:#x}; // {}\n",
+            error.code, error.code
+        ));
+        types.push(format!("typeof {constant}"));
+        entries.push_str(&format!(
+            "  [{constant}]: {},\n",
+            string_literal(&error.message)
+        ));
+    }
+
+    imports.add(KIT, "isProgramError");
+    imports.add_type(KIT, "Address");
+    imports.add_type(KIT, "SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM");
+    imports.add_type(KIT, "SolanaError");
+    format!(
+        "{constants}
+export type {name}Error =
+  | {};
+
+const {messages}: Record<{name}Error, string> = {{
+{entries}}};
+
+export function get{name}ErrorMessage(code: {name}Error): string {{
+  return {messages}[code];
+}}
+
+export function is{name}Error<TProgramErrorCode extends {name}Error>(
+  error: unknown,
+  transactionMessage: {{ instructions: Record<number, {{ programAddress: Address }}> }},
+  code?: TProgramErrorCode
+): error is SolanaError<typeof SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM> &
+  Readonly<{{ context: Readonly<{{ code: TProgramErrorCode }}> }}> {{
+  return isProgramError<TProgramErrorCode>(error, transactionMessage, {program_address}, code);
+}}
+",
+        types.join("\n  | ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{ErrorNode, RootNode};
+
+    #[test]
+    fn errors() {
+        let root = RootNode::new(
+            ProgramNode::new("vault", "1111")
+                .add_error(ErrorNode::new("empty", 6000, "The vault is empty"))
+                .add_error(ErrorNode::new("locked", 6001, "The vault is locked")),
+        );
+        let ctx = RenderContext::new(&root);
+        let code = render_program_errors(&ctx, &root.program, &mut ImportMap::new());
+        assert!(code.starts_with(
+            r#"/** Empty: The vault is empty */
+export const VAULT_ERROR__EMPTY = 0x1770; // 6000
+/** Locked: The vault is locked */
+export const VAULT_ERROR__LOCKED = 0x1771; // 6001
+
+export type VaultError =
+  | typeof VAULT_ERROR__EMPTY
+  | typeof VAULT_ERROR__LOCKED;
+
+const vaultErrorMessages: Record<VaultError, string> = {
+  [VAULT_ERROR__EMPTY]: 'The vault is empty',
+  [VAULT_ERROR__LOCKED]: 'The vault is locked',
+};
+"#
+        ));
+        assert!(code.contains(
+            "return isProgramError<TProgramErrorCode>(error, transactionMessage, VAULT_PROGRAM_ADDRESS, code);"
+        ));
+    }
+}
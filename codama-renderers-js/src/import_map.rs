@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+
+pub(crate) const KIT: &str = "@solana/kit";
+
+/// Names imported by a TypeScript file, indexed by module.
+#[derive(Debug, Default)]
+pub(crate) struct ImportMap(BTreeMap<String, BTreeMap<String, bool>>);
+
+impl ImportMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Imports a value, which can also be used as a type.
+    pub fn add(&mut self, module: &str, name: &str) {
+        self.entry(module, name, false);
+    }
+
+    /// Imports a name that is only used as a type.
+    pub fn add_type(&mut self, module: &str, name: &str) {
+        self.entry(module, name, true);
+    }
+
+    fn entry(&mut self, module: &str, name: &str, type_only: bool) {
+        let names = self.0.entry(module.to_string()).or_default();
+        let entry = names.entry(name.to_string()).or_insert(type_only);
+        *entry &= type_only;
+    }
+
+    /// Removes imports of the current file from itself.
+    pub fn remove(&mut self, module: &str, name: &str) {
+        if let Some(names) = self.0.get_mut(module) {
+            names.remove(name);
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut imports = String::new();
+        let mut modules = self
+            .0
+            .iter()
+            .filter(|(_, names)| !names.is_empty())
+            .collect::<Vec<_>>();
+        modules.sort_by_key(|(module, _)| module.starts_with('.'));
+        for (module, names) in modules {
+            let names = names
+                .iter()
+                .map(|(name, type_only)| match type_only {
+                    true => format!("type {name}"),
+                    false => name.clone(),
+                })
+                .collect::<Vec<_>>();
+            let line = format!("import {{ {} }} from '{module}';\n", names.join(", "));
+            match line.len() <= 81 {
+                true => imports.push_str(&line),
+                false => imports.push_str(&format!(
+                    "import {{\n{}}} from '{module}';\n",
+                    names
+                        .iter()
+                        .map(|name| format!("  {name},\n"))
+                        .collect::<String>()
+                )),
+            }
+        }
+        imports
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut imports = ImportMap::new();
+        imports.add_type(KIT, "Address");
+        imports.add(KIT, "getAddressEncoder");
+        imports.add_type("../types", "State");
+        imports.add("../types", "State");
+        assert_eq!(
+            imports.render(),
+            "import { type Address, getAddressEncoder } from '@solana/kit';\nimport { State } from '../types';\n"
+        );
+    }
+
+    #[test]
+    fn long_imports() {
+        let mut imports = ImportMap::new();
+        for name in [
+            "getStructEncoder",
+            "getStructDecoder",
+            "getU64Encoder",
+            "getU64Decoder",
+        ] {
+            imports.add(KIT, name);
+        }
+        assert_eq!(
+            imports.render(),
+            "import {\n  getStructDecoder,\n  getStructEncoder,\n  getU64Decoder,\n  getU64Encoder,\n} from '@solana/kit';\n"
+        );
+    }
+}
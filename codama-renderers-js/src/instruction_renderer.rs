@@ -0,0 +1,466 @@
+use crate::defined_type_renderer::render_data;
+use crate::import_map::{ImportMap, KIT};
+use crate::render_context::{RenderContext, PDAS, SHARED};
+use crate::utils::{camel_case, local_name, pascal_case, render_docs, shouty_case, string_literal};
+use crate::value_renderer::render_value;
+use codama_errors::CodamaResult;
+use codama_nodes::{
+    DefaultValueStrategy, DiscriminatorNode, Docs, InstructionAccountNode,
+    InstructionInputValueNode, InstructionNode, InstructionOptionalAccountStrategy,
+    IsAccountSigner, PdaSeedNode, PdaValue, PdaValueNode, ProgramNode, StructFieldTypeNode,
+    ValueNode,
+};
+use std::collections::HashMap;
+
+pub(crate) fn render_instruction(
+    ctx: &RenderContext,
+    program: &ProgramNode,
+    instruction: &InstructionNode,
+    imports: &mut ImportMap,
+) -> CodamaResult<String> {
+    let name = pascal_case(&instruction.name);
+    let data_name = format!("{name}InstructionData");
+    let fields = instruction
+        .arguments
+        .iter()
+        .map(|argument| StructFieldTypeNode {
+            name: argument.name.clone(),
+            default_value_strategy: argument.default_value_strategy,
+            docs: argument.docs.clone(),
+            r#type: argument.r#type.clone(),
+            default_value: argument
+                .default_value
+                .clone()
+                .and_then(|value| ValueNode::try_from(value).ok()),
+        })
+        .collect::<Vec<_>>();
+
+    // Discriminator constant.
+    let mut code = String::new();
+    let mut overrides = HashMap::new();
+    let discriminator =
+        instruction
+            .discriminators
+            .iter()
+            .find_map(|discriminator| match discriminator {
+                DiscriminatorNode::Field(discriminator) if discriminator.offset == 0 => {
+                    Some(discriminator)
+                }
+                _ => None,
+            });
+    let discriminator_field = discriminator
+        .and_then(|discriminator| fields.iter().find(|field| field.name == discriminator.name));
+    if let Some(field) = discriminator_field {
+        if let Some(default_value) = &field.default_value {
+            let constant = format!("{}_DISCRIMINATOR", shouty_case(&instruction.name));
+            let value = render_value(ctx, default_value, imports)?;
+            code.push_str(&format!("export const {constant} = {value};\n\n"));
+            overrides.insert(field.name.as_str(), constant);
+        }
+    }
+    code.push_str(&render_data(
+        ctx,
+        &data_name,
+        &Docs::default(),
+        &fields,
+        &overrides,
+        imports,
+    )?);
+
+    // Input keys of the arguments provided by the caller.
+    let mut arguments = vec![];
+    for field in &fields {
+        let omitted = field.default_value.is_some()
+            && field.default_value_strategy == Some(DefaultValueStrategy::Omitted);
+        if omitted {
+            continue;
+        }
+        let key = camel_case(&field.name);
+        let input_key = match instruction
+            .accounts
+            .iter()
+            .any(|account| account.name == field.name)
+        {
+            true => format!("{key}Arg"),
+            false => key.clone(),
+        };
+        arguments.push(InputArgument {
+            name: field.name.as_str(),
+            key,
+            input_key,
+            optional: field.default_value.is_some(),
+            docs: render_docs(&field.docs, "  "),
+        });
+    }
+
+    let renderer = InstructionRenderer {
+        ctx,
+        program,
+        instruction,
+        name: &name,
+        data_name: &data_name,
+        arguments: &arguments,
+    };
+    let resolution = renderer.resolve_accounts(true, imports)?;
+    if resolution.uses_pdas {
+        code.push_str(&renderer.render_function(&resolution, true, imports));
+    }
+    let resolution = renderer.resolve_accounts(false, imports)?;
+    code.push_str(&renderer.render_function(&resolution, false, imports));
+    Ok(code)
+}
+
+struct InputArgument<'a> {
+    name: &'a str,
+    key: String,
+    input_key: String,
+    optional: bool,
+    docs: String,
+}
+
+struct Resolution {
+    locals: Vec<String>,
+    defaulted: Vec<bool>,
+    uses_pdas: bool,
+}
+
+struct InstructionRenderer<'a> {
+    ctx: &'a RenderContext<'a>,
+    program: &'a ProgramNode,
+    instruction: &'a InstructionNode,
+    name: &'a str,
+    data_name: &'a str,
+    arguments: &'a [InputArgument<'a>],
+}
+
+impl InstructionRenderer<'_> {
+    /// Declares a local for each account, using default values when they can be resolved.
+    fn resolve_accounts(&self, async_: bool, imports: &mut ImportMap) -> CodamaResult<Resolution> {
+        let accounts = &self.instruction.accounts;
+        let mut locals = vec![];
+        let mut defaulted = vec![false; accounts.len()];
+        let mut available = HashMap::new();
+        let mut pending = vec![];
+        let mut uses_pdas = false;
+        for (index, account) in accounts.iter().enumerate() {
+            let local = local_name(&account.name);
+            let key = camel_case(&account.name);
+            match (&account.default_value, account.is_optional) {
+                (Some(_), false) => pending.push(index),
+                (_, is_optional) => {
+                    locals.push(format!("const {local} = input.{key};"));
+                    if !is_optional {
+                        available.insert(account.name.as_str(), local);
+                    }
+                }
+            }
+        }
+
+        while !pending.is_empty() {
+            let mut resolved = None;
+            for (position, index) in pending.iter().enumerate() {
+                let account = &accounts[*index];
+                let default_value = account.default_value.as_ref().unwrap();
+                if let Some(value) =
+                    self.render_default(default_value, &available, async_, imports)?
+                {
+                    uses_pdas |= matches!(default_value, InstructionInputValueNode::Pda(_));
+                    resolved = Some((position, Some(value)));
+                    break;
+                }
+            }
+            // Require the first unresolved account so the accounts depending on it can be resolved.
+            let (position, value) = resolved.unwrap_or((0, None));
+            let index = pending.remove(position);
+            let account = &accounts[index];
+            let local = local_name(&account.name);
+            let key = camel_case(&account.name);
+            match value {
+                Some(value) => {
+                    defaulted[index] = true;
+                    locals.push(format!("const {local} = input.{key} ?? {value};"));
+                }
+                None => locals.push(format!("const {local} = input.{key};")),
+            }
+            available.insert(account.name.as_str(), local);
+        }
+        Ok(Resolution {
+            locals,
+            defaulted,
+            uses_pdas,
+        })
+    }
+
+    /// Renders the default value of an account, if all of its dependencies are available.
+    fn render_default(
+        &self,
+        value: &InstructionInputValueNode,
+        available: &HashMap<&str, String>,
+        async_: bool,
+        imports: &mut ImportMap,
+    ) -> CodamaResult<Option<String>> {
+        Ok(match value {
+            InstructionInputValueNode::PublicKey(value) => {
+                imports.add(KIT, "address");
+                Some(format!("address({})", string_literal(&value.public_key)))
+            }
+            InstructionInputValueNode::ProgramId(_) => Some("programAddress".to_string()),
+            InstructionInputValueNode::ProgramLink(link) => self
+                .ctx
+                .find_program(&link.name)
+                .map(|program| self.ctx.program_address(program, imports)),
+            InstructionInputValueNode::Account(value) => {
+                available.get(value.name.as_str()).map(|local| {
+                    imports.add(SHARED, "getAddress");
+                    format!("getAddress({local})")
+                })
+            }
+            InstructionInputValueNode::Argument(value) => self
+                .required_argument(&value.name)
+                .map(|argument| format!("input.{}", argument.input_key)),
+            InstructionInputValueNode::Pda(value) if async_ => {
+                self.render_pda(value, available, imports)?
+            }
+            _ => None,
+        })
+    }
+
+    fn render_pda(
+        &self,
+        value: &PdaValueNode,
+        available: &HashMap<&str, String>,
+        imports: &mut ImportMap,
+    ) -> CodamaResult<Option<String>> {
+        let PdaValue::Linked(link) = &value.pda else {
+            return Ok(None);
+        };
+        if value.program_id.is_some() {
+            return Ok(None);
+        }
+        let program = match &link.program {
+            Some(program) => self.ctx.find_program(&program.name),
+            None => Some(self.program),
+        };
+        let Some(pda) =
+            program.and_then(|program| program.pdas.iter().find(|pda| pda.name == link.name))
+        else {
+            return Ok(None);
+        };
+
+        let mut seeds = vec![];
+        for seed in &pda.seeds {
+            let PdaSeedNode::Variable(seed) = seed else {
+                continue;
+            };
+            let Some(seed_value) = value.seeds.iter().find(|value| value.name == seed.name) else {
+                return Ok(None);
+            };
+            let expression = match &seed_value.value {
+                codama_nodes::PdaSeedValueValueNode::Account(account) => {
+                    match available.get(account.name.as_str()) {
+                        Some(local) => {
+                            imports.add(SHARED, "getAddress");
+                            format!("getAddress({local})")
+                        }
+                        None => return Ok(None),
+                    }
+                }
+                codama_nodes::PdaSeedValueValueNode::Argument(argument) => {
+                    match self.required_argument(&argument.name) {
+                        Some(argument) => format!("input.{}", argument.input_key),
+                        None => return Ok(None),
+                    }
+                }
+                value => render_value(self.ctx, &ValueNode::try_from(value.clone())?, imports)?,
+            };
+            seeds.push(format!("{}: {expression}", camel_case(&seed.name)));
+        }
+
+        let find = format!("find{}Pda", pascal_case(&pda.name));
+        imports.add(PDAS, &find);
+        Ok(Some(match seeds.is_empty() {
+            true => format!("(await {find}())[0]"),
+            false => format!("(await {find}({{ {} }}))[0]", seeds.join(", ")),
+        }))
+    }
+
+    fn required_argument(&self, name: &str) -> Option<&InputArgument<'_>> {
+        self.arguments
+            .iter()
+            .find(|argument| argument.name == name && !argument.optional)
+    }
+
+    fn render_function(
+        &self,
+        resolution: &Resolution,
+        async_: bool,
+        imports: &mut ImportMap,
+    ) -> String {
+        let name = self.name;
+        let data_name = self.data_name;
+        let (input_name, function_name) = match async_ {
+            true => (
+                format!("{name}AsyncInput"),
+                format!("get{name}InstructionAsync"),
+            ),
+            false => (format!("{name}Input"), format!("get{name}Instruction")),
+        };
+
+        // Input type.
+        imports.add_type(KIT, "Address");
+        let mut input = String::new();
+        for (account, defaulted) in self.instruction.accounts.iter().zip(&resolution.defaulted) {
+            let docs = render_docs(&account.docs, "  ");
+            let optional = match account.is_optional || *defaulted {
+                true => "?",
+                false => "",
+            };
+            let r#type = match account.is_signer {
+                IsAccountSigner::True => "TransactionSigner",
+                IsAccountSigner::Either => "Address | TransactionSigner",
+                IsAccountSigner::False => "Address",
+            };
+            if account.is_signer != IsAccountSigner::False {
+                imports.add_type(KIT, "TransactionSigner");
+            }
+            let key = camel_case(&account.name);
+            input.push_str(&format!("{docs}  {key}{optional}: {type};\n"));
+        }
+        for argument in self.arguments {
+            let optional = match argument.optional {
+                true => "?",
+                false => "",
+            };
+            input.push_str(&format!(
+                "{}  {}{optional}: {data_name}Args['{}'];\n",
+                argument.docs, argument.input_key, argument.key
+            ));
+        }
+        let input = match input.is_empty() {
+            true => "{}".to_string(),
+            false => format!("{{\n{input}}}"),
+        };
+
+        // Account metas.
+        imports.add(KIT, "AccountRole");
+        let mut metas = String::new();
+        for account in &self.instruction.accounts {
+            metas.push_str(&format!(
+                "      {},\n",
+                self.render_account_meta(account, imports)
+            ));
+        }
+
+        let data = self
+            .arguments
+            .iter()
+            .map(|argument| format!("{}: input.{}", argument.key, argument.input_key))
+            .collect::<Vec<_>>();
+        let data = match data.is_empty() {
+            true => "{}".to_string(),
+            false => format!("{{ {} }}", data.join(", ")),
+        };
+
+        let program_address = self.ctx.program_address(self.program, imports);
+        imports.add_type(KIT, "Instruction");
+        let docs = render_docs(&self.instruction.docs, "");
+        let locals = resolution
+            .locals
+            .iter()
+            .map(|local| format!("  {local}\n"))
+            .collect::<String>();
+        let (async_keyword, return_type) = match async_ {
+            true => ("async ", "Promise<Instruction>"),
+            false => ("", "Instruction"),
+        };
+        format!(
+            "
+export type {input_name} = {input};
+
+{docs}export {async_keyword}function {function_name}(
+  input: {input_name},
+  config?: {{ programAddress?: Address }}
+): {return_type} {{
+  const programAddress = config?.programAddress ?? {program_address};
+{locals}  return Object.freeze({{
+    programAddress,
+    accounts: [
+{metas}    ],
+    data: get{data_name}Encoder().encode({data}),
+  }});
+}}
+"
+        )
+    }
+
+    fn render_account_meta(
+        &self,
+        account: &InstructionAccountNode,
+        imports: &mut ImportMap,
+    ) -> String {
+        imports.add(SHARED, "getAccountMeta");
+        let local = local_name(&account.name);
+        let role = match account.is_writable {
+            true => "AccountRole.WRITABLE",
+            false => "AccountRole.READONLY",
+        };
+        let meta = format!("getAccountMeta({local}, {role})");
+        match (
+            account.is_optional,
+            self.instruction.optional_account_strategy,
+        ) {
+            (false, _) => meta,
+            (true, InstructionOptionalAccountStrategy::ProgramId) => {
+                format!("{local} ? {meta} : getAccountMeta(programAddress, AccountRole.READONLY)")
+            }
+            (true, InstructionOptionalAccountStrategy::Omitted) => {
+                format!("...({local} ? [{meta}] : [])")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        AccountValueNode, InstructionArgumentNode, NumberTypeNode, PublicKeyTypeNode, RootNode, U64,
+    };
+
+    #[test]
+    fn omitted_accounts_and_argument_collisions() {
+        let instruction = InstructionNode {
+            name: "approve".into(),
+            optional_account_strategy: InstructionOptionalAccountStrategy::Omitted,
+            accounts: vec![
+                InstructionAccountNode::new("authority", false, IsAccountSigner::Either),
+                InstructionAccountNode {
+                    default_value: Some(AccountValueNode::new("authority").into()),
+                    ..InstructionAccountNode::new("payer", true, IsAccountSigner::False)
+                },
+                InstructionAccountNode {
+                    is_optional: true,
+                    ..InstructionAccountNode::new("delegate", false, IsAccountSigner::False)
+                },
+            ],
+            arguments: vec![
+                InstructionArgumentNode::new("delegate", PublicKeyTypeNode::new()),
+                InstructionArgumentNode::new("amount", NumberTypeNode::le(U64)),
+            ],
+            ..InstructionNode::default()
+        };
+        let root = RootNode::new(ProgramNode::new("token", "1111"));
+        let ctx = RenderContext::new(&root);
+        let code =
+            render_instruction(&ctx, &root.program, &instruction, &mut ImportMap::new()).unwrap();
+        assert!(!code.contains("getApproveInstructionAsync"));
+        assert!(code.contains(
+            "export type ApproveInput = {\n  authority: Address | TransactionSigner;\n  payer?: Address;\n  delegate?: Address;\n  delegateArg: ApproveInstructionDataArgs['delegate'];\n  amount: ApproveInstructionDataArgs['amount'];\n};"
+        ));
+        assert!(code.contains("  const payer = input.payer ?? getAddress(authority);\n"));
+        assert!(code.contains(
+            "      ...(delegate ? [getAccountMeta(delegate, AccountRole.READONLY)] : []),\n"
+        ));
+        assert!(code.contains(".encode({ delegate: input.delegateArg, amount: input.amount })"));
+    }
+}
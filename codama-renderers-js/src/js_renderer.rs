@@ -0,0 +1,192 @@
+use crate::account_renderer::render_account;
+use crate::defined_type_renderer::render_defined_type;
+use crate::error_renderer::render_program_errors;
+use crate::import_map::{ImportMap, KIT};
+use crate::instruction_renderer::render_instruction;
+use crate::pda_renderer::render_pda;
+use crate::render_context::{program_address_name, RenderContext, TYPES};
+use crate::utils::{camel_case, pascal_case, render_file, string_literal};
+use crate::RenderMap;
+use codama_errors::CodamaResult;
+use codama_nodes::RootNode;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Renders a TypeScript client for `@solana/kit` from a `RootNode`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct JsRenderer {}
+
+impl JsRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn render(&self, root: &RootNode) -> CodamaResult<RenderMap> {
+        let ctx = RenderContext::new(root);
+        let mut folders = BTreeMap::<&str, Vec<(String, ImportMap, String)>>::new();
+        let mut add = |folder, name: &str, imports, code| {
+            folders
+                .entry(folder)
+                .or_default()
+                .push((camel_case(name), imports, code));
+        };
+
+        for program in ctx.programs() {
+            let mut imports = ImportMap::new();
+            imports.add_type(KIT, "Address");
+            let address = string_literal(&program.public_key);
+            let code = format!(
+                "export const {} = {address} as Address<{address}>;\n",
+                program_address_name(program)
+            );
+            add("programs", &program.name, imports, code);
+
+            for defined_type in &program.defined_types {
+                let mut imports = ImportMap::new();
+                let code = render_defined_type(&ctx, defined_type, &mut imports)?;
+                let name = pascal_case(&defined_type.name);
+                for own in [
+                    name.clone(),
+                    format!("{name}Args"),
+                    format!("get{name}Encoder"),
+                    format!("get{name}Decoder"),
+                ] {
+                    imports.remove(TYPES, &own);
+                }
+                add("types", &defined_type.name, imports, code);
+            }
+            for account in &program.accounts {
+                let mut imports = ImportMap::new();
+                let code = render_account(&ctx, account, &mut imports)?;
+                add("accounts", &account.name, imports, code);
+            }
+            for instruction in &program.instructions {
+                let mut imports = ImportMap::new();
+                let code = render_instruction(&ctx, program, instruction, &mut imports)?;
+                add("instructions", &instruction.name, imports, code);
+            }
+            for pda in &program.pdas {
+                let mut imports = ImportMap::new();
+                let code = render_pda(&ctx, program, pda, &mut imports)?;
+                add("pdas", &pda.name, imports, code);
+            }
+            if !program.errors.is_empty() {
+                let mut imports = ImportMap::new();
+                let code = render_program_errors(&ctx, program, &mut imports);
+                add("errors", &program.name, imports, code);
+            }
+        }
+
+        let mut map = RenderMap::new();
+        let mut root_exports = String::new();
+        for (folder, files) in folders {
+            root_exports.push_str(&format!("export * from './{folder}';\n"));
+            let mut exports = String::new();
+            for (name, imports, code) in files {
+                map.add(format!("{folder}/{name}.ts"), render_file(&imports, &code));
+                exports.push_str(&format!("export * from './{name}';\n"));
+            }
+            map.add(
+                format!("{folder}/index.ts"),
+                render_file(&ImportMap::new(), &exports),
+            );
+        }
+        map.add("index.ts", render_file(&ImportMap::new(), &root_exports));
+        map.add("shared/index.ts", render_shared());
+        Ok(map)
+    }
+
+    pub fn render_to_directory<P: AsRef<Path>>(
+        &self,
+        root: &RootNode,
+        directory: P,
+    ) -> CodamaResult<()> {
+        self.render(root)?.write_to_directory(directory)
+    }
+}
+
+/// Renders the helpers shared by the generated instructions.
+fn render_shared() -> String {
+    let mut imports = ImportMap::new();
+    imports.add(KIT, "upgradeRoleToSigner");
+    imports.add(KIT, "AccountRole");
+    for name in [
+        "AccountMeta",
+        "AccountSignerMeta",
+        "Address",
+        "TransactionSigner",
+    ] {
+        imports.add_type(KIT, name);
+    }
+    render_file(
+        &imports,
+        "/** Returns the address of an account that may be provided as a signer. */
+export function getAddress(value: Address | TransactionSigner): Address {
+  return typeof value === 'string' ? value : value.address;
+}
+
+/** Returns the meta of an account, upgrading its role when it is provided as a signer. */
+export function getAccountMeta(
+  value: Address | TransactionSigner,
+  role: AccountRole
+): AccountMeta | AccountSignerMeta {
+  if (typeof value === 'string') {
+    return Object.freeze({ address: value, role });
+  }
+  return Object.freeze({
+    address: value.address,
+    role: upgradeRoleToSigner(role),
+    signer: value,
+  } as AccountSignerMeta);
+}
+",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        DefinedTypeNode, ErrorNode, NumberTypeNode, ProgramNode, StructFieldTypeNode,
+        StructTypeNode, U64,
+    };
+
+    #[test]
+    fn files() {
+        let root = RootNode::new(
+            ProgramNode::new("counter", "CounterProgram111111111111111111111111111111")
+                .add_defined_type(DefinedTypeNode::new(
+                    "counterConfig",
+                    StructTypeNode::new(vec![StructFieldTypeNode::new(
+                        "maxCount",
+                        NumberTypeNode::le(U64),
+                    )]),
+                ))
+                .add_error(ErrorNode::new("overflow", 6000, "Counter overflow")),
+        );
+        let map = JsRenderer::new().render(&root).unwrap();
+        assert_eq!(
+            map.paths().collect::<Vec<_>>(),
+            vec![
+                Path::new("errors/counter.ts"),
+                Path::new("errors/index.ts"),
+                Path::new("index.ts"),
+                Path::new("programs/counter.ts"),
+                Path::new("programs/index.ts"),
+                Path::new("shared/index.ts"),
+                Path::new("types/counterConfig.ts"),
+                Path::new("types/index.ts"),
+            ]
+        );
+        assert!(map.get("index.ts").unwrap().ends_with(
+            "export * from './errors';\nexport * from './programs';\nexport * from './types';\n"
+        ));
+        assert!(map.get("programs/counter.ts").unwrap().ends_with(
+            "export const COUNTER_PROGRAM_ADDRESS = 'CounterProgram111111111111111111111111111111' as Address<'CounterProgram111111111111111111111111111111'>;\n"
+        ));
+        assert!(!map
+            .get("types/counterConfig.ts")
+            .unwrap()
+            .contains("from '../types'"));
+    }
+}
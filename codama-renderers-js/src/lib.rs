@@ -0,0 +1,14 @@
+mod account_renderer;
+mod defined_type_renderer;
+mod error_renderer;
+mod import_map;
+mod instruction_renderer;
+mod js_renderer;
+mod pda_renderer;
+mod render_context;
+mod type_renderer;
+mod utils;
+mod value_renderer;
+
+pub use codama_renderers_core::RenderMap;
+pub use js_renderer::*;
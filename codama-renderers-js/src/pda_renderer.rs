@@ -0,0 +1,124 @@
+use crate::import_map::{ImportMap, KIT};
+use crate::render_context::RenderContext;
+use crate::type_renderer::render_type;
+use crate::utils::{camel_case, pascal_case, render_docs, string_literal};
+use crate::value_renderer::render_value;
+use codama_errors::CodamaResult;
+use codama_nodes::{PdaNode, PdaSeedNode, ProgramNode, ValueNode};
+
+pub(crate) fn render_pda(
+    ctx: &RenderContext,
+    program: &ProgramNode,
+    pda: &PdaNode,
+    imports: &mut ImportMap,
+) -> CodamaResult<String> {
+    let name = pascal_case(&pda.name);
+    let program_address = match &pda.program_id {
+        Some(program_id) => {
+            imports.add(KIT, "address");
+            format!("address({})", string_literal(program_id))
+        }
+        None => ctx.program_address(program, imports),
+    };
+
+    let mut seed_types = String::new();
+    let mut seeds = vec![];
+    for seed in &pda.seeds {
+        match seed {
+            PdaSeedNode::Constant(seed) if seed.value.is_program_id() => {
+                let manifest = render_type(ctx, &seed.r#type, imports)?;
+                seeds.push(format!("{}.encode(programAddress)", manifest.encoder));
+            }
+            PdaSeedNode::Constant(seed) => {
+                let manifest = render_type(ctx, &seed.r#type, imports)?;
+                let value = ValueNode::try_from(seed.value.clone())?;
+                let value = render_value(ctx, &value, imports)?;
+                seeds.push(format!("{}.encode({value})", manifest.encoder));
+            }
+            PdaSeedNode::Variable(seed) => {
+                let manifest = render_type(ctx, &seed.r#type, imports)?;
+                let key = camel_case(&seed.name);
+                let docs = render_docs(&seed.docs, "  ");
+                seed_types.push_str(&format!("{docs}  {key}: {};\n", manifest.loose));
+                seeds.push(format!("{}.encode(seeds.{key})", manifest.encoder));
+            }
+        }
+    }
+
+    imports.add(KIT, "getProgramDerivedAddress");
+    imports.add_type(KIT, "Address");
+    imports.add_type(KIT, "ProgramDerivedAddress");
+    let docs = render_docs(&pda.docs, "");
+    let (types, params) = match seed_types.is_empty() {
+        true => (String::new(), String::new()),
+        false => (
+            format!("export type {name}Seeds = {{\n{seed_types}}};\n\n"),
+            format!("\n  seeds: {name}Seeds,"),
+        ),
+    };
+    let seeds = seeds
+        .iter()
+        .map(|seed| format!("      {seed},\n"))
+        .collect::<String>();
+    Ok(format!(
+        "{types}{docs}export async function find{name}Pda({params}
+  config: {{ programAddress?: Address }} = {{}}
+): Promise<ProgramDerivedAddress> {{
+  const {{ programAddress = {program_address} }} = config;
+  return await getProgramDerivedAddress({{
+    programAddress,
+    seeds: [
+{seeds}    ],
+  }});
+}}
+"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        ConstantPdaSeedNode, NumberTypeNode, PublicKeyTypeNode, RootNode, StringTypeNode,
+        StringValueNode, VariablePdaSeedNode, U64,
+    };
+
+    #[test]
+    fn seeds() {
+        let pda = PdaNode::new(
+            "counter",
+            vec![
+                ConstantPdaSeedNode::new(StringTypeNode::utf8(), StringValueNode::new("counter"))
+                    .into(),
+                VariablePdaSeedNode::new("authority", PublicKeyTypeNode::new()).into(),
+                VariablePdaSeedNode::new("index", NumberTypeNode::le(U64)).into(),
+            ],
+        );
+        let root = RootNode::new(ProgramNode::new("counter", "1111").add_pda(pda.clone()));
+        let ctx = RenderContext::new(&root);
+        let code = render_pda(&ctx, &root.program, &pda, &mut ImportMap::new()).unwrap();
+        assert_eq!(
+            code,
+            r#"export type CounterSeeds = {
+  authority: Address;
+  index: number | bigint;
+};
+
+export async function findCounterPda(
+  seeds: CounterSeeds,
+  config: { programAddress?: Address } = {}
+): Promise<ProgramDerivedAddress> {
+  const { programAddress = COUNTER_PROGRAM_ADDRESS } = config;
+  return await getProgramDerivedAddress({
+    programAddress,
+    seeds: [
+      getUtf8Encoder().encode('counter'),
+      getAddressEncoder().encode(seeds.authority),
+      getU64Encoder().encode(seeds.index),
+    ],
+  });
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use crate::import_map::ImportMap;
+use crate::utils::shouty_case;
+use codama_nodes::{DefinedTypeNode, ProgramNode, RootNode, TypeCodec};
+
+pub(crate) const TYPES: &str = "../types";
+pub(crate) const PROGRAMS: &str = "../programs";
+pub(crate) const PDAS: &str = "../pdas";
+pub(crate) const SHARED: &str = "../shared";
+
+/// Shared state used to render the items of a `RootNode`.
+pub(crate) struct RenderContext<'a> {
+    pub root: &'a RootNode,
+    pub codec: TypeCodec<'a>,
+}
+
+impl<'a> RenderContext<'a> {
+    pub fn new(root: &'a RootNode) -> Self {
+        Self {
+            root,
+            codec: TypeCodec::from_root(root),
+        }
+    }
+
+    pub fn programs(&self) -> impl Iterator<Item = &'a ProgramNode> {
+        std::iter::once(&self.root.program).chain(self.root.additional_programs.iter())
+    }
+
+    pub fn find_program(&self, name: &str) -> Option<&'a ProgramNode> {
+        self.programs()
+            .find(|program| program.name.as_str() == name)
+    }
+
+    pub fn find_defined_type(&self, name: &str) -> Option<&'a DefinedTypeNode> {
+        self.programs()
+            .flat_map(|program| program.defined_types.iter())
+            .find(|defined_type| defined_type.name.as_str() == name)
+    }
+
+    /// Imports and returns the constant holding the address of the given program.
+    pub fn program_address(&self, program: &ProgramNode, imports: &mut ImportMap) -> String {
+        let name = program_address_name(program);
+        imports.add(PROGRAMS, &name);
+        name
+    }
+}
+
+pub(crate) fn program_address_name(program: &ProgramNode) -> String {
+    format!("{}_PROGRAM_ADDRESS", shouty_case(&program.name))
+}
@@ -0,0 +1,661 @@
+use crate::import_map::{ImportMap, KIT};
+use crate::render_context::{RenderContext, TYPES};
+use crate::utils::{camel_case, pascal_case, render_bytes, string_literal};
+use codama_errors::{CodamaError, CodamaResult};
+use codama_nodes::{
+    BytesEncoding, ConstantValueNode, CountNode, Endian, EnumTypeNode, EnumVariantTypeNode,
+    HasKind, NestedTypeNode, NestedTypeNodeTrait, NumberFormat, NumberTypeNode,
+    StructFieldTypeNode, TypeNode,
+};
+
+/// The TypeScript types and codecs of a type node.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TypeManifest {
+    /// The type returned by the decoder.
+    pub strict: String,
+    /// The type accepted by the encoder.
+    pub loose: String,
+    pub encoder: String,
+    pub decoder: String,
+}
+
+impl TypeManifest {
+    fn new<T: Into<String>>(strict: T, loose: T, encoder: String, decoder: String) -> Self {
+        Self {
+            strict: strict.into(),
+            loose: loose.into(),
+            encoder,
+            decoder,
+        }
+    }
+
+    fn wrap(self, name: &str, imports: &mut ImportMap, options: &Options) -> Self {
+        let (encoder, decoder) = codec(imports, name);
+        Self {
+            encoder: format!("{encoder}({}{})", self.encoder, options.encoder()),
+            decoder: format!("{decoder}({}{})", self.decoder, options.decoder()),
+            ..self
+        }
+    }
+}
+
+/// Encoder and decoder options of a codec, e.g. `{ size: getU16Encoder() }`.
+#[derive(Default)]
+struct Options(Vec<(&'static str, String, String)>);
+
+impl Options {
+    fn add<T: Into<String>>(&mut self, key: &'static str, encoder: T, decoder: T) {
+        self.0.push((key, encoder.into(), decoder.into()));
+    }
+
+    fn add_size(&mut self, value: String) {
+        self.add("size", value.clone(), value);
+    }
+
+    fn encoder(&self) -> String {
+        self.render(|(key, encoder, _)| format!("{key}: {encoder}"))
+    }
+
+    fn decoder(&self) -> String {
+        self.render(|(key, _, decoder)| format!("{key}: {decoder}"))
+    }
+
+    fn render(&self, f: impl Fn(&(&'static str, String, String)) -> String) -> String {
+        match self.0.is_empty() {
+            true => String::new(),
+            false => format!(
+                ", {{ {} }}",
+                self.0.iter().map(f).collect::<Vec<_>>().join(", ")
+            ),
+        }
+    }
+}
+
+pub(crate) fn render_type(
+    ctx: &RenderContext,
+    node: &TypeNode,
+    imports: &mut ImportMap,
+) -> CodamaResult<TypeManifest> {
+    Ok(match node {
+        TypeNode::Number(node) => render_number(node, imports),
+        TypeNode::Amount(node) => render_nested_number(&node.number, imports),
+        TypeNode::SolAmount(node) => render_nested_number(&node.number, imports),
+        TypeNode::DateTime(node) => render_nested_number(&node.number, imports),
+        TypeNode::Boolean(node) => {
+            let mut options = Options::default();
+            if !is_u8(&node.size) {
+                let size = render_nested_number(&node.size, imports);
+                options.add("size", size.encoder, size.decoder);
+            }
+            let (encoder, decoder) = codec(imports, "Boolean");
+            let encoder = format!("{encoder}({})", options.encoder().trim_start_matches(", "));
+            let decoder = format!("{decoder}({})", options.decoder().trim_start_matches(", "));
+            TypeManifest::new("boolean", "boolean", encoder, decoder)
+        }
+        TypeNode::PublicKey(_) => {
+            imports.add_type(KIT, "Address");
+            let (encoder, decoder) = codec(imports, "Address");
+            TypeManifest::new(
+                "Address",
+                "Address",
+                format!("{encoder}()"),
+                format!("{decoder}()"),
+            )
+        }
+        TypeNode::String(node) => {
+            let name = match node.encoding {
+                BytesEncoding::Base16 => "Base16",
+                BytesEncoding::Base58 => "Base58",
+                BytesEncoding::Base64 => "Base64",
+                BytesEncoding::Utf8 => "Utf8",
+            };
+            let (encoder, decoder) = codec(imports, name);
+            TypeManifest::new(
+                "string",
+                "string",
+                format!("{encoder}()"),
+                format!("{decoder}()"),
+            )
+        }
+        TypeNode::Bytes(_) => {
+            imports.add_type(KIT, "ReadonlyUint8Array");
+            let (encoder, decoder) = codec(imports, "Bytes");
+            TypeManifest::new(
+                "ReadonlyUint8Array",
+                "ReadonlyUint8Array",
+                format!("{encoder}()"),
+                format!("{decoder}()"),
+            )
+        }
+        TypeNode::SizePrefix(node) => {
+            let prefix = render_nested_number(&node.prefix, imports);
+            let item = render_type(ctx, &node.r#type, imports)?;
+            imports.add(KIT, "addEncoderSizePrefix");
+            imports.add(KIT, "addDecoderSizePrefix");
+            TypeManifest {
+                encoder: format!("addEncoderSizePrefix({}, {})", item.encoder, prefix.encoder),
+                decoder: format!("addDecoderSizePrefix({}, {})", item.decoder, prefix.decoder),
+                ..item
+            }
+        }
+        TypeNode::FixedSize(node) => {
+            let item = render_type(ctx, &node.r#type, imports)?;
+            imports.add(KIT, "fixEncoderSize");
+            imports.add(KIT, "fixDecoderSize");
+            TypeManifest {
+                encoder: format!("fixEncoderSize({}, {})", item.encoder, node.size),
+                decoder: format!("fixDecoderSize({}, {})", item.decoder, node.size),
+                ..item
+            }
+        }
+        TypeNode::HiddenPrefix(node) => {
+            let item = render_type(ctx, &node.r#type, imports)?;
+            let constants = render_constants(ctx, &node.prefix, imports)?;
+            let (encoder, decoder) = codec(imports, "HiddenPrefix");
+            TypeManifest {
+                encoder: format!("{encoder}({}, [{}])", item.encoder, constants.0),
+                decoder: format!("{decoder}({}, [{}])", item.decoder, constants.1),
+                ..item
+            }
+        }
+        TypeNode::HiddenSuffix(node) => {
+            let item = render_type(ctx, &node.r#type, imports)?;
+            let constants = render_constants(ctx, &node.suffix, imports)?;
+            let (encoder, decoder) = codec(imports, "HiddenSuffix");
+            TypeManifest {
+                encoder: format!("{encoder}({}, [{}])", item.encoder, constants.0),
+                decoder: format!("{decoder}({}, [{}])", item.decoder, constants.1),
+                ..item
+            }
+        }
+        TypeNode::Sentinel(node) => {
+            let item = render_type(ctx, &node.r#type, imports)?;
+            let sentinel = render_bytes(&encode_constant(ctx, &node.sentinel)?);
+            imports.add(KIT, "addEncoderSentinel");
+            imports.add(KIT, "addDecoderSentinel");
+            TypeManifest {
+                encoder: format!("addEncoderSentinel({}, {sentinel})", item.encoder),
+                decoder: format!("addDecoderSentinel({}, {sentinel})", item.decoder),
+                ..item
+            }
+        }
+        TypeNode::Array(node) => {
+            let item = render_type(ctx, &node.item, imports)?;
+            let options = count_options(&node.count, imports);
+            TypeManifest {
+                strict: format!("Array<{}>", item.strict),
+                loose: format!("Array<{}>", item.loose),
+                ..item.wrap("Array", imports, &options)
+            }
+        }
+        TypeNode::Set(node) => {
+            let item = render_type(ctx, &node.item, imports)?;
+            let options = count_options(&node.count, imports);
+            TypeManifest {
+                strict: format!("Set<{}>", item.strict),
+                loose: format!("Set<{}>", item.loose),
+                ..item.wrap("Set", imports, &options)
+            }
+        }
+        TypeNode::Map(node) => {
+            let key = render_type(ctx, &node.key, imports)?;
+            let value = render_type(ctx, &node.value, imports)?;
+            let options = count_options(&node.count, imports);
+            let (encoder, decoder) = codec(imports, "Map");
+            TypeManifest {
+                strict: format!("Map<{}, {}>", key.strict, value.strict),
+                loose: format!("Map<{}, {}>", key.loose, value.loose),
+                encoder: format!(
+                    "{encoder}({}, {}{})",
+                    key.encoder,
+                    value.encoder,
+                    options.encoder()
+                ),
+                decoder: format!(
+                    "{decoder}({}, {}{})",
+                    key.decoder,
+                    value.decoder,
+                    options.decoder()
+                ),
+            }
+        }
+        TypeNode::Option(node) => {
+            let mut options = Options::default();
+            if !is_u8(&node.prefix) {
+                let prefix = render_nested_number(&node.prefix, imports);
+                options.add("prefix", prefix.encoder, prefix.decoder);
+            }
+            if node.fixed {
+                options.add("noneValue", "'zeroes'", "'zeroes'");
+            }
+            render_option(ctx, &node.item, options, imports)?
+        }
+        TypeNode::RemainderOption(node) => {
+            let mut options = Options::default();
+            options.add("prefix", "null", "null");
+            render_option(ctx, &node.item, options, imports)?
+        }
+        TypeNode::ZeroableOption(node) => {
+            let mut options = Options::default();
+            options.add("prefix", "null", "null");
+            let none_value = match &node.zero_value {
+                Some(zero_value) => render_bytes(&encode_constant(ctx, zero_value)?),
+                None => "'zeroes'".to_string(),
+            };
+            options.add("noneValue", none_value.clone(), none_value);
+            render_option(ctx, &node.item, options, imports)?
+        }
+        TypeNode::Tuple(node) => {
+            let items = node
+                .items
+                .iter()
+                .map(|item| render_type(ctx, item, imports))
+                .collect::<CodamaResult<Vec<_>>>()?;
+            let join = |f: fn(&TypeManifest) -> &String| {
+                items.iter().map(f).cloned().collect::<Vec<_>>().join(", ")
+            };
+            let (encoder, decoder) = codec(imports, "Tuple");
+            TypeManifest {
+                strict: format!("readonly [{}]", join(|item| &item.strict)),
+                loose: format!("readonly [{}]", join(|item| &item.loose)),
+                encoder: format!("{encoder}([{}])", join(|item| &item.encoder)),
+                decoder: format!("{decoder}([{}])", join(|item| &item.decoder)),
+            }
+        }
+        TypeNode::Struct(node) => render_struct(ctx, &node.fields, imports)?,
+        TypeNode::Enum(node) => render_data_enum(ctx, node, imports)?,
+        TypeNode::Link(node) => {
+            let name = pascal_case(&node.name);
+            let args = format!("{name}Args");
+            let encoder = format!("get{name}Encoder");
+            let decoder = format!("get{name}Decoder");
+            imports.add_type(TYPES, &name);
+            imports.add_type(TYPES, &args);
+            imports.add(TYPES, &encoder);
+            imports.add(TYPES, &decoder);
+            TypeManifest::new(name, args, format!("{encoder}()"), format!("{decoder}()"))
+        }
+        node => return Err(unsupported(node)),
+    })
+}
+
+/// Renders an inline struct whose fields are all provided by the caller.
+pub(crate) fn render_struct(
+    ctx: &RenderContext,
+    fields: &[StructFieldTypeNode],
+    imports: &mut ImportMap,
+) -> CodamaResult<TypeManifest> {
+    let mut strict = vec![];
+    let mut loose = vec![];
+    let mut encoders = vec![];
+    let mut decoders = vec![];
+    for field in fields {
+        let name = camel_case(&field.name);
+        let manifest = render_type(ctx, &field.r#type, imports)?;
+        strict.push(format!("{name}: {}", manifest.strict));
+        loose.push(format!("{name}: {}", manifest.loose));
+        encoders.push(format!("['{name}', {}]", manifest.encoder));
+        decoders.push(format!("['{name}', {}]", manifest.decoder));
+    }
+    let object = |fields: Vec<String>| match fields.is_empty() {
+        true => "{}".to_string(),
+        false => format!("{{ {} }}", fields.join("; ")),
+    };
+    let (encoder, decoder) = codec(imports, "Struct");
+    Ok(TypeManifest {
+        strict: object(strict),
+        loose: object(loose),
+        encoder: format!("{encoder}([{}])", encoders.join(", ")),
+        decoder: format!("{decoder}([{}])", decoders.join(", ")),
+    })
+}
+
+/// Renders an enum as a discriminated union of `__kind` objects.
+fn render_data_enum(
+    ctx: &RenderContext,
+    node: &EnumTypeNode,
+    imports: &mut ImportMap,
+) -> CodamaResult<TypeManifest> {
+    let mut strict = vec![];
+    let mut loose = vec![];
+    let mut encoders = vec![];
+    let mut decoders = vec![];
+    for variant in &node.variants {
+        let (name, fields) = match variant {
+            EnumVariantTypeNode::Empty(variant) if variant.discriminator.is_none() => {
+                (&variant.name, None)
+            }
+            EnumVariantTypeNode::Struct(variant) if variant.discriminator.is_none() => (
+                &variant.name,
+                Some(render_struct(
+                    ctx,
+                    &variant.r#struct.get_nested_type_node().fields,
+                    imports,
+                )?),
+            ),
+            EnumVariantTypeNode::Tuple(variant) if variant.discriminator.is_none() => {
+                let tuple = TypeNode::Tuple(variant.tuple.get_nested_type_node().clone());
+                let tuple = render_type(ctx, &tuple, imports)?;
+                let (encoder, decoder) = codec(imports, "Struct");
+                let fields = TypeManifest {
+                    strict: format!("{{ fields: {} }}", tuple.strict),
+                    loose: format!("{{ fields: {} }}", tuple.loose),
+                    encoder: format!("{encoder}([['fields', {}]])", tuple.encoder),
+                    decoder: format!("{decoder}([['fields', {}]])", tuple.decoder),
+                };
+                (&variant.name, Some(fields))
+            }
+            _ => {
+                return Err(CodamaError::Rendering(
+                    "explicit discriminators are only supported on scalar enums".to_string(),
+                ))
+            }
+        };
+        let name = pascal_case(name);
+        let kind = |object: &str| match object.strip_prefix("{ ") {
+            Some(fields) => format!("{{ __kind: '{name}'; {fields}"),
+            None => format!("{{ __kind: '{name}' }}"),
+        };
+        match fields {
+            Some(fields) => {
+                strict.push(kind(&fields.strict));
+                loose.push(kind(&fields.loose));
+                encoders.push(format!("['{name}', {}]", fields.encoder));
+                decoders.push(format!("['{name}', {}]", fields.decoder));
+            }
+            None => {
+                let (encoder, decoder) = codec(imports, "Unit");
+                strict.push(kind(""));
+                loose.push(kind(""));
+                encoders.push(format!("['{name}', {encoder}()]"));
+                decoders.push(format!("['{name}', {decoder}()]"));
+            }
+        }
+    }
+    let mut options = Options::default();
+    if !is_u8(&node.size) {
+        let size = render_nested_number(&node.size, imports);
+        options.add("size", size.encoder, size.decoder);
+    }
+    let (encoder, decoder) = codec(imports, "DiscriminatedUnion");
+    Ok(TypeManifest {
+        strict: strict.join(" | "),
+        loose: loose.join(" | "),
+        encoder: format!("{encoder}([{}]{})", encoders.join(", "), options.encoder()),
+        decoder: format!("{decoder}([{}]{})", decoders.join(", "), options.decoder()),
+    })
+}
+
+fn render_option(
+    ctx: &RenderContext,
+    item: &TypeNode,
+    options: Options,
+    imports: &mut ImportMap,
+) -> CodamaResult<TypeManifest> {
+    let item = render_type(ctx, item, imports)?;
+    imports.add_type(KIT, "Option");
+    imports.add_type(KIT, "OptionOrNullable");
+    Ok(TypeManifest {
+        strict: format!("Option<{}>", item.strict),
+        loose: format!("OptionOrNullable<{}>", item.loose),
+        ..item.wrap("Option", imports, &options)
+    })
+}
+
+pub(crate) fn render_number(node: &NumberTypeNode, imports: &mut ImportMap) -> TypeManifest {
+    let (name, strict, loose) = match node.format {
+        NumberFormat::U8 => ("U8", "number", "number"),
+        NumberFormat::U16 => ("U16", "number", "number"),
+        NumberFormat::U32 => ("U32", "number", "number"),
+        NumberFormat::U64 => ("U64", "bigint", "number | bigint"),
+        NumberFormat::U128 => ("U128", "bigint", "number | bigint"),
+        NumberFormat::I8 => ("I8", "number", "number"),
+        NumberFormat::I16 => ("I16", "number", "number"),
+        NumberFormat::I32 => ("I32", "number", "number"),
+        NumberFormat::I64 => ("I64", "bigint", "number | bigint"),
+        NumberFormat::I128 => ("I128", "bigint", "number | bigint"),
+        NumberFormat::F32 => ("F32", "number", "number"),
+        NumberFormat::F64 => ("F64", "number", "number"),
+        NumberFormat::ShortU16 => ("ShortU16", "number", "number"),
+    };
+    let options = match node.endian {
+        Endian::Big if node.format != NumberFormat::ShortU16 && node.format != NumberFormat::U8 => {
+            imports.add(KIT, "Endian");
+            "{ endian: Endian.Big }"
+        }
+        _ => "",
+    };
+    let (encoder, decoder) = codec(imports, name);
+    TypeManifest::new(
+        strict,
+        loose,
+        format!("{encoder}({options})"),
+        format!("{decoder}({options})"),
+    )
+}
+
+pub(crate) fn render_nested_number(
+    node: &NestedTypeNode<NumberTypeNode>,
+    imports: &mut ImportMap,
+) -> TypeManifest {
+    render_number(node.get_nested_type_node(), imports)
+}
+
+fn count_options(count: &CountNode, imports: &mut ImportMap) -> Options {
+    let mut options = Options::default();
+    match count {
+        CountNode::Fixed(count) => options.add_size(count.value.to_string()),
+        CountNode::Prefixed(count) if !is_number(&count.prefix, NumberFormat::U32) => {
+            let prefix = render_nested_number(&count.prefix, imports);
+            options.add("size", prefix.encoder, prefix.decoder);
+        }
+        CountNode::Prefixed(_) => {}
+        CountNode::Remainder(_) => options.add_size(string_literal("remainder")),
+    }
+    options
+}
+
+fn render_constants(
+    ctx: &RenderContext,
+    constants: &[ConstantValueNode],
+    imports: &mut ImportMap,
+) -> CodamaResult<(String, String)> {
+    let (encoder, decoder) = codec(imports, "Constant");
+    let mut encoders = vec![];
+    let mut decoders = vec![];
+    for constant in constants {
+        let bytes = render_bytes(&encode_constant(ctx, constant)?);
+        encoders.push(format!("{encoder}({bytes})"));
+        decoders.push(format!("{decoder}({bytes})"));
+    }
+    Ok((encoders.join(", "), decoders.join(", ")))
+}
+
+fn encode_constant(ctx: &RenderContext, constant: &ConstantValueNode) -> CodamaResult<Vec<u8>> {
+    ctx.codec.encode(&constant.value, &constant.r#type)
+}
+
+/// Imports and returns the encoder and decoder functions of a `@solana/kit` codec.
+pub(crate) fn codec(imports: &mut ImportMap, name: &str) -> (String, String) {
+    let encoder = format!("get{name}Encoder");
+    let decoder = format!("get{name}Decoder");
+    imports.add(KIT, &encoder);
+    imports.add(KIT, &decoder);
+    (encoder, decoder)
+}
+
+fn is_u8(node: &NestedTypeNode<NumberTypeNode>) -> bool {
+    is_number(node, NumberFormat::U8)
+}
+
+fn is_number(node: &NestedTypeNode<NumberTypeNode>, format: NumberFormat) -> bool {
+    matches!(node, NestedTypeNode::Value(number) if number.format == format)
+}
+
+fn unsupported<T: HasKind>(node: &T) -> CodamaError {
+    CodamaError::Rendering(format!(
+        "`{}` cannot be rendered as a TypeScript codec",
+        node.kind()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        ArrayTypeNode, BooleanTypeNode, BytesTypeNode, DefinedTypeLinkNode,
+        EnumEmptyVariantTypeNode, EnumStructVariantTypeNode, EnumTupleVariantTypeNode,
+        FixedCountNode, FixedSizeTypeNode, MapTypeNode, OptionTypeNode, PreOffsetTypeNode,
+        ProgramNode, PublicKeyTypeNode, RootNode, SizePrefixTypeNode, StringTypeNode,
+        StructTypeNode, TupleTypeNode, I64, U16, U32, U64, U8,
+    };
+
+    fn render(node: TypeNode) -> CodamaResult<(TypeManifest, String)> {
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let ctx = RenderContext::new(&root);
+        let mut imports = ImportMap::new();
+        let manifest = render_type(&ctx, &node, &mut imports)?;
+        Ok((manifest, imports.render()))
+    }
+
+    #[test]
+    fn numbers() {
+        let (manifest, imports) = render(NumberTypeNode::le(U64).into()).unwrap();
+        assert_eq!(
+            manifest,
+            TypeManifest::new(
+                "bigint",
+                "number | bigint",
+                "getU64Encoder()".to_string(),
+                "getU64Decoder()".to_string()
+            )
+        );
+        assert_eq!(
+            imports,
+            "import { getU64Decoder, getU64Encoder } from '@solana/kit';\n"
+        );
+
+        let (manifest, _) = render(NumberTypeNode::be(U16).into()).unwrap();
+        assert_eq!(manifest.encoder, "getU16Encoder({ endian: Endian.Big })");
+    }
+
+    #[test]
+    fn strings_and_bytes() {
+        let string = SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U32));
+        let (manifest, _) = render(string.into()).unwrap();
+        assert_eq!(manifest.strict, "string");
+        assert_eq!(
+            manifest.encoder,
+            "addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())"
+        );
+
+        let bytes = FixedSizeTypeNode::new(BytesTypeNode::new(), 32);
+        let (manifest, _) = render(bytes.into()).unwrap();
+        assert_eq!(manifest.strict, "ReadonlyUint8Array");
+        assert_eq!(manifest.decoder, "fixDecoderSize(getBytesDecoder(), 32)");
+    }
+
+    #[test]
+    fn collections() {
+        let array = ArrayTypeNode::new(PublicKeyTypeNode::new(), FixedCountNode::new(3));
+        let (manifest, _) = render(array.into()).unwrap();
+        assert_eq!(manifest.strict, "Array<Address>");
+        assert_eq!(
+            manifest.encoder,
+            "getArrayEncoder(getAddressEncoder(), { size: 3 })"
+        );
+
+        let map = MapTypeNode::new(
+            StringTypeNode::utf8(),
+            NumberTypeNode::le(U8),
+            CountNode::Remainder(Default::default()),
+        );
+        let (manifest, _) = render(map.into()).unwrap();
+        assert_eq!(manifest.loose, "Map<string, number>");
+        assert_eq!(
+            manifest.decoder,
+            "getMapDecoder(getUtf8Decoder(), getU8Decoder(), { size: 'remainder' })"
+        );
+
+        let tuple = TupleTypeNode::new(vec![
+            BooleanTypeNode::default().into(),
+            NumberTypeNode::le(I64).into(),
+        ]);
+        let (manifest, _) = render(tuple.into()).unwrap();
+        assert_eq!(manifest.loose, "readonly [boolean, number | bigint]");
+        assert_eq!(
+            manifest.encoder,
+            "getTupleEncoder([getBooleanEncoder(), getI64Encoder()])"
+        );
+    }
+
+    #[test]
+    fn options() {
+        let option = OptionTypeNode::new(NumberTypeNode::le(U64));
+        let (manifest, _) = render(option.into()).unwrap();
+        assert_eq!(manifest.strict, "Option<bigint>");
+        assert_eq!(manifest.loose, "OptionOrNullable<number | bigint>");
+        assert_eq!(manifest.encoder, "getOptionEncoder(getU64Encoder())");
+
+        let option = OptionTypeNode {
+            fixed: true,
+            prefix: NumberTypeNode::le(U16).into(),
+            ..OptionTypeNode::new(NumberTypeNode::le(U64))
+        };
+        let (manifest, _) = render(option.into()).unwrap();
+        assert_eq!(
+            manifest.decoder,
+            "getOptionDecoder(getU64Decoder(), { prefix: getU16Decoder(), noneValue: 'zeroes' })"
+        );
+    }
+
+    #[test]
+    fn structs_and_enums() {
+        let node = EnumTypeNode::new(vec![
+            EnumEmptyVariantTypeNode::new("open").into(),
+            EnumStructVariantTypeNode::new(
+                "locked",
+                StructTypeNode::new(vec![StructFieldTypeNode::new(
+                    "until",
+                    NumberTypeNode::le(I64),
+                )]),
+            )
+            .into(),
+            EnumTupleVariantTypeNode::new(
+                "frozen",
+                TupleTypeNode::new(vec![PublicKeyTypeNode::new().into()]),
+            )
+            .into(),
+        ]);
+        let (manifest, _) = render(node.into()).unwrap();
+        assert_eq!(
+            manifest.strict,
+            "{ __kind: 'Open' } | { __kind: 'Locked'; until: bigint } | { __kind: 'Frozen'; fields: readonly [Address] }"
+        );
+        assert_eq!(
+            manifest.encoder,
+            "getDiscriminatedUnionEncoder([['Open', getUnitEncoder()], ['Locked', getStructEncoder([['until', getI64Encoder()]])], ['Frozen', getStructEncoder([['fields', getTupleEncoder([getAddressEncoder()])]])]])"
+        );
+    }
+
+    #[test]
+    fn links() {
+        let (manifest, imports) = render(DefinedTypeLinkNode::new("vaultState").into()).unwrap();
+        assert_eq!(manifest.strict, "VaultState");
+        assert_eq!(manifest.loose, "VaultStateArgs");
+        assert_eq!(manifest.encoder, "getVaultStateEncoder()");
+        assert_eq!(
+            imports,
+            "import {\n  type VaultState,\n  type VaultStateArgs,\n  getVaultStateDecoder,\n  getVaultStateEncoder,\n} from '../types';\n"
+        );
+    }
+
+    #[test]
+    fn unsupported_types() {
+        let node = PreOffsetTypeNode::absolute(NumberTypeNode::le(U8), 4);
+        assert_eq!(
+            render(node.into()).unwrap_err().to_string(),
+            "Could not render code: `preOffsetTypeNode` cannot be rendered as a TypeScript codec"
+        );
+    }
+}
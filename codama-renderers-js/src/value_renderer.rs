@@ -0,0 +1,190 @@
+use crate::import_map::{ImportMap, KIT};
+use crate::render_context::{RenderContext, TYPES};
+use crate::utils::{camel_case, pascal_case, render_bytes, string_literal};
+use codama_errors::CodamaResult;
+use codama_nodes::{
+    EnumVariantData, EnumVariantTypeNode, Number, StructValueNode, TypeNode, ValueNode,
+};
+
+/// Renders a value node as a TypeScript expression matching the loose type of its type node.
+pub(crate) fn render_value(
+    ctx: &RenderContext,
+    value: &ValueNode,
+    imports: &mut ImportMap,
+) -> CodamaResult<String> {
+    let items = |items: &[ValueNode], imports: &mut ImportMap| -> CodamaResult<String> {
+        Ok(items
+            .iter()
+            .map(|item| render_value(ctx, item, imports))
+            .collect::<CodamaResult<Vec<_>>>()?
+            .join(", "))
+    };
+    Ok(match value {
+        ValueNode::Array(value) => format!("[{}]", items(&value.items, imports)?),
+        ValueNode::Tuple(value) => format!("[{}]", items(&value.items, imports)?),
+        ValueNode::Set(value) => format!("new Set([{}])", items(&value.items, imports)?),
+        ValueNode::Map(value) => {
+            let entries = value
+                .entries
+                .iter()
+                .map(|entry| {
+                    let key = render_value(ctx, &entry.key, imports)?;
+                    let value = render_value(ctx, &entry.value, imports)?;
+                    Ok(format!("[{key}, {value}]"))
+                })
+                .collect::<CodamaResult<Vec<_>>>()?;
+            format!("new Map([{}])", entries.join(", "))
+        }
+        ValueNode::Boolean(value) => value.boolean.to_string(),
+        ValueNode::Bytes(value) => render_bytes(&value.encoding.string_to_bytes(&value.data)?),
+        ValueNode::Constant(value) => render_value(ctx, &value.value, imports)?,
+        ValueNode::Enum(value) => {
+            let name = pascal_case(&value.r#enum.name);
+            let variant = pascal_case(&value.variant);
+            let is_scalar = ctx
+                .find_defined_type(&value.r#enum.name)
+                .is_some_and(|defined_type| is_scalar_enum(&defined_type.r#type));
+            match (&value.value, is_scalar) {
+                (None, true) => {
+                    imports.add(TYPES, &name);
+                    format!("{name}.{variant}")
+                }
+                (None, false) => format!("{{ __kind: '{variant}' }}"),
+                (Some(EnumVariantData::Struct(data)), _) => {
+                    let fields = render_struct_fields(ctx, data, imports)?;
+                    format!("{{ __kind: '{variant}', {} }}", fields.join(", "))
+                }
+                (Some(EnumVariantData::Tuple(data)), _) => {
+                    format!(
+                        "{{ __kind: '{variant}', fields: [{}] }}",
+                        items(&data.items, imports)?
+                    )
+                }
+            }
+        }
+        ValueNode::None(_) => {
+            imports.add(KIT, "none");
+            "none()".to_string()
+        }
+        ValueNode::Some(value) => {
+            imports.add(KIT, "some");
+            format!("some({})", render_value(ctx, &value.value, imports)?)
+        }
+        ValueNode::Number(value) => match value.number {
+            Number::UnsignedInteger(number) => number.to_string(),
+            Number::SignedInteger(number) => number.to_string(),
+            Number::Float(number) => format!("{number:?}"),
+        },
+        ValueNode::PublicKey(value) => {
+            imports.add(KIT, "address");
+            format!("address({})", string_literal(&value.public_key))
+        }
+        ValueNode::String(value) => string_literal(&value.string),
+        ValueNode::Struct(value) => {
+            let fields = render_struct_fields(ctx, value, imports)?;
+            match fields.is_empty() {
+                true => "{}".to_string(),
+                false => format!("{{ {} }}", fields.join(", ")),
+            }
+        }
+    })
+}
+
+fn render_struct_fields(
+    ctx: &RenderContext,
+    value: &StructValueNode,
+    imports: &mut ImportMap,
+) -> CodamaResult<Vec<String>> {
+    value
+        .fields
+        .iter()
+        .map(|field| {
+            let value = render_value(ctx, &field.value, imports)?;
+            Ok(format!("{}: {value}", camel_case(&field.name)))
+        })
+        .collect()
+}
+
+/// Whether the given type is an enum rendered as a TypeScript `enum`.
+pub(crate) fn is_scalar_enum(node: &TypeNode) -> bool {
+    match node {
+        TypeNode::Enum(node) => node
+            .variants
+            .iter()
+            .all(|variant| matches!(variant, EnumVariantTypeNode::Empty(_))),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        ArrayValueNode, BytesValueNode, DefinedTypeNode, EnumEmptyVariantTypeNode, EnumTypeNode,
+        EnumValueNode, NoneValueNode, NumberValueNode, ProgramNode, PublicKeyValueNode, RootNode,
+        SomeValueNode, StringValueNode, StructFieldValueNode,
+    };
+
+    fn render(root: &RootNode, value: ValueNode) -> (String, String) {
+        let ctx = RenderContext::new(root);
+        let mut imports = ImportMap::new();
+        let value = render_value(&ctx, &value, &mut imports).unwrap();
+        (value, imports.render())
+    }
+
+    #[test]
+    fn values() {
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let value = |value: ValueNode| render(&root, value).0;
+        assert_eq!(value(NumberValueNode::new(42u64).into()), "42");
+        assert_eq!(value(StringValueNode::new("it's").into()), "'it\\'s'");
+        assert_eq!(
+            value(BytesValueNode::base16("ff01").into()),
+            "new Uint8Array([255, 1])"
+        );
+        assert_eq!(
+            value(ArrayValueNode::new(vec![NumberValueNode::new(1u8).into()]).into()),
+            "[1]"
+        );
+        assert_eq!(
+            value(SomeValueNode::new(NumberValueNode::new(1u8)).into()),
+            "some(1)"
+        );
+        assert_eq!(value(NoneValueNode::new().into()), "none()");
+        assert_eq!(
+            value(
+                StructValueNode::new(vec![StructFieldValueNode::new(
+                    "max_count",
+                    NumberValueNode::new(1u8)
+                )])
+                .into()
+            ),
+            "{ maxCount: 1 }"
+        );
+        assert_eq!(
+            render(&root, PublicKeyValueNode::new("1111").into()),
+            (
+                "address('1111')".to_string(),
+                "import { address } from '@solana/kit';\n".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let root = RootNode::new(ProgramNode::new("counter", "1111").add_defined_type(
+            DefinedTypeNode::new(
+                "state",
+                EnumTypeNode::new(vec![EnumEmptyVariantTypeNode::new("open").into()]),
+            ),
+        ));
+        assert_eq!(
+            render(&root, EnumValueNode::new("state", "open", None).into()).0,
+            "State.Open"
+        );
+        assert_eq!(
+            render(&root, EnumValueNode::new("missing", "open", None).into()).0,
+            "{ __kind: 'Open' }"
+        );
+    }
+}
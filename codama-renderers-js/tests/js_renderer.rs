@@ -0,0 +1,61 @@
+use codama_nodes::RootNode;
+use codama_renderers_js::JsRenderer;
+use std::path::Path;
+
+fn vault_root() -> RootNode {
+    let json = std::fs::read_to_string(fixture_path("fixtures/vault.json")).unwrap();
+    RootNode::from_anchor_json(&json).unwrap()
+}
+
+fn fixture_path(path: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(path)
+}
+
+#[test]
+fn generated_code_is_up_to_date() {
+    let map = JsRenderer::new().render(&vault_root()).unwrap();
+    let directory = fixture_path("generated");
+    if std::env::var("UPDATE_GENERATED").is_ok() {
+        let _ = std::fs::remove_dir_all(&directory);
+        map.write_to_directory(&directory).unwrap();
+    }
+    for (path, content) in map.iter() {
+        let expected = std::fs::read_to_string(directory.join(path)).unwrap();
+        pretty_assertions::assert_eq!(content, expected, "{} is outdated", path.display());
+    }
+}
+
+#[test]
+fn deposit_instruction() {
+    let map = JsRenderer::new().render(&vault_root()).unwrap();
+    let code = map.get("instructions/deposit.ts").unwrap();
+    assert!(code.contains("export async function getDepositInstructionAsync("));
+    assert!(code.contains(
+        "const vault = input.vault ?? (await findVaultPda({ owner: getAddress(owner), index: input.index }))[0];"
+    ));
+    assert!(code.contains(
+        "const systemProgram = input.systemProgram ?? address('11111111111111111111111111111111');"
+    ));
+    assert!(code.contains("export function getDepositInstruction("));
+    assert!(code.contains("  vault: Address;\n"));
+}
+
+#[test]
+fn optional_accounts() {
+    let map = JsRenderer::new().render(&vault_root()).unwrap();
+    let code = map.get("instructions/close.ts").unwrap();
+    assert!(code.contains("  recipient?: Address;\n"));
+    assert!(code.contains(
+        "recipient ? getAccountMeta(recipient, AccountRole.WRITABLE) : getAccountMeta(programAddress, AccountRole.READONLY)"
+    ));
+}
+
+#[test]
+fn program_errors() {
+    let map = JsRenderer::new().render(&vault_root()).unwrap();
+    let code = map.get("errors/vault.ts").unwrap();
+    assert!(code.contains("export const VAULT_ERROR__EMPTY = 0x1770; // 6000\n"));
+    assert!(code.contains("export function isVaultError<"));
+}
@@ -0,0 +1,250 @@
+use crate::defined_type_renderer::{borsh_derives, render_struct_fields};
+use crate::render_context::RenderContext;
+use crate::utils::{pascal_ident, render_bytes, render_docs};
+use codama_errors::{CodamaError, CodamaResult};
+use codama_nodes::{AccountNode, DiscriminatorNode, NestedTypeNode, StructFieldTypeNode, TypeNode};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+pub(crate) fn render_account(
+    ctx: &RenderContext,
+    account: &AccountNode,
+) -> CodamaResult<TokenStream> {
+    let NestedTypeNode::Value(data) = &account.data else {
+        return Err(CodamaError::Rendering(format!(
+            "account `{}` must use a plain struct type",
+            account.name.as_str()
+        )));
+    };
+    let docs = render_docs(&account.docs);
+    let name = pascal_ident(&account.name);
+    let derives = borsh_derives();
+    let fields = render_struct_fields(ctx, &data.fields, true)?;
+
+    let size = match account.size {
+        Some(size) => Some(size),
+        None => ctx
+            .codec
+            .fixed_size(&TypeNode::from(account.data.clone()))?,
+    };
+    let size = size.map(|size| {
+        let size = Literal::usize_unsuffixed(size);
+        quote! { pub const LEN: usize = #size; }
+    });
+
+    let mut constant = None;
+    let mut conditions = vec![];
+    for discriminator in &account.discriminators {
+        let (offset, bytes) = match discriminator {
+            DiscriminatorNode::Size(discriminator) => {
+                let size = Literal::usize_unsuffixed(discriminator.size);
+                conditions.push(quote! { data.len() == #size });
+                continue;
+            }
+            DiscriminatorNode::Constant(discriminator) => (
+                discriminator.offset,
+                ctx.codec.encode(
+                    &discriminator.constant.value,
+                    &discriminator.constant.r#type,
+                )?,
+            ),
+            DiscriminatorNode::Field(discriminator) => (
+                discriminator.offset,
+                field_discriminator_bytes(ctx, &data.fields, &discriminator.name)?,
+            ),
+        };
+        let start = Literal::usize_unsuffixed(offset);
+        let end = Literal::usize_unsuffixed(offset + bytes.len());
+        match constant {
+            None => {
+                let length = Literal::usize_unsuffixed(bytes.len());
+                let bytes = render_bytes(&bytes);
+                constant = Some(quote! { pub const DISCRIMINATOR: [u8; #length] = #bytes; });
+                conditions
+                    .push(quote! { data.get(#start..#end) == Some(&Self::DISCRIMINATOR[..]) });
+            }
+            Some(_) => {
+                let bytes = render_bytes(&bytes);
+                conditions.push(quote! { data.get(#start..#end) == Some(&#bytes[..]) });
+            }
+        }
+    }
+
+    let (is_discriminated, check) = match conditions.is_empty() {
+        true => (None, None),
+        false => {
+            let doc = format!(
+                " Whether the given data matches the discriminators of the `{}` account.",
+                account.name.as_str()
+            );
+            let error = format!(
+                "invalid discriminator for the `{}` account",
+                account.name.as_str()
+            );
+            (
+                Some(quote! {
+                    #[doc = #doc]
+                    pub fn is_discriminated(data: &[u8]) -> bool {
+                        #(#conditions)&&*
+                    }
+                }),
+                Some(quote! {
+                    if !Self::is_discriminated(data) {
+                        return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, #error));
+                    }
+                }),
+            )
+        }
+    };
+
+    Ok(quote! {
+        #docs
+        #derives
+        pub struct #name { #(#fields),* }
+
+        impl #name {
+            #size
+            #constant
+            #is_discriminated
+
+            pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+                #check
+                let mut data = data;
+                Self::deserialize(&mut data)
+            }
+        }
+    })
+}
+
+/// Encodes the default value of the field used as a discriminator.
+pub(crate) fn field_discriminator_bytes(
+    ctx: &RenderContext,
+    fields: &[StructFieldTypeNode],
+    name: &str,
+) -> CodamaResult<Vec<u8>> {
+    let field = fields.iter().find(|field| field.name.as_str() == name);
+    match field.and_then(|field| Some((field, field.default_value.as_ref()?))) {
+        Some((field, value)) => ctx.codec.encode(value, &field.r#type),
+        None => Err(CodamaError::Rendering(format!(
+            "discriminator field `{name}` must have a default value"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::render_file;
+    use codama_nodes::{
+        Base16, BytesTypeNode, BytesValueNode, ConstantDiscriminatorNode, ConstantValueNode,
+        FieldDiscriminatorNode, FixedSizeTypeNode, NumberTypeNode, ProgramNode, RootNode,
+        SizeDiscriminatorNode, StructTypeNode, U64,
+    };
+
+    fn render(account: AccountNode) -> String {
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let ctx = RenderContext::new(&root, "crate::generated").unwrap();
+        let file = render_file(render_account(&ctx, &account).unwrap()).unwrap();
+        file.lines().skip(3).collect::<Vec<_>>().join("\n")
+    }
+
+    #[test]
+    fn field_discriminator() {
+        let account = AccountNode {
+            discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+            ..AccountNode::new(
+                "counter",
+                StructTypeNode::new(vec![
+                    StructFieldTypeNode {
+                        default_value: Some(BytesValueNode::base16("ffb0").into()),
+                        ..StructFieldTypeNode::new(
+                            "discriminator",
+                            FixedSizeTypeNode::new(BytesTypeNode::new(), 2),
+                        )
+                    },
+                    StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+                ]),
+            )
+        };
+        assert_eq!(
+            render(account),
+            r#"#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct Counter {
+    pub discriminator: [u8; 2],
+    pub count: u64,
+}
+impl Counter {
+    pub const LEN: usize = 10;
+    pub const DISCRIMINATOR: [u8; 2] = [255, 176];
+    /// Whether the given data matches the discriminators of the `counter` account.
+    pub fn is_discriminated(data: &[u8]) -> bool {
+        data.get(0..2) == Some(&Self::DISCRIMINATOR[..])
+    }
+    pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        if !Self::is_discriminated(data) {
+            return Err(
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "invalid discriminator for the `counter` account",
+                ),
+            );
+        }
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}"#
+        );
+    }
+
+    #[test]
+    fn multiple_discriminators() {
+        let account = AccountNode {
+            discriminators: vec![
+                ConstantDiscriminatorNode::new(ConstantValueNode::bytes(Base16, "01"), 0).into(),
+                ConstantDiscriminatorNode::new(ConstantValueNode::bytes(Base16, "02"), 8).into(),
+                SizeDiscriminatorNode::new(9).into(),
+            ],
+            ..AccountNode::new("counter", StructTypeNode::new(vec![]))
+        };
+        assert!(render(account).contains(
+            "data.get(0..1) == Some(&Self::DISCRIMINATOR[..])\n            && data.get(8..9) == Some(&[2][..]) && data.len() == 9"
+        ));
+    }
+
+    #[test]
+    fn no_discriminators() {
+        let account = AccountNode::new(
+            "counter",
+            StructTypeNode::new(vec![StructFieldTypeNode::new(
+                "count",
+                NumberTypeNode::le(U64),
+            )]),
+        );
+        assert!(render(account).contains(
+            r#"    pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }"#
+        ));
+    }
+
+    #[test]
+    fn missing_field_discriminator_value() {
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let ctx = RenderContext::new(&root, "crate::generated").unwrap();
+        let account = AccountNode {
+            discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+            ..AccountNode::new(
+                "counter",
+                StructTypeNode::new(vec![StructFieldTypeNode::new(
+                    "discriminator",
+                    NumberTypeNode::le(U64),
+                )]),
+            )
+        };
+        assert_eq!(
+            render_account(&ctx, &account).unwrap_err().to_string(),
+            "Could not render code: discriminator field `discriminator` must have a default value"
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::render_context::RenderContext;
+use crate::type_renderer::render_type;
+use crate::utils::{is_number, pascal_ident, render_docs, snake_ident};
+use codama_errors::{CodamaError, CodamaResult};
+use codama_nodes::{
+    DefinedTypeNode, EnumTypeNode, EnumVariantTypeNode, NestedTypeNodeTrait, NumberFormat::U8,
+    StructFieldTypeNode, TypeNode,
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+pub(crate) fn render_defined_type(
+    ctx: &RenderContext,
+    node: &DefinedTypeNode,
+) -> CodamaResult<TokenStream> {
+    let docs = render_docs(&node.docs);
+    let name = pascal_ident(&node.name);
+    let derives = borsh_derives();
+    Ok(match &node.r#type {
+        TypeNode::Struct(node) => {
+            let fields = render_struct_fields(ctx, &node.fields, true)?;
+            quote! {
+                #docs
+                #derives
+                pub struct #name { #(#fields),* }
+            }
+        }
+        TypeNode::Tuple(node) => {
+            let items = node
+                .items
+                .iter()
+                .map(|item| render_type(ctx, item))
+                .collect::<CodamaResult<Vec<_>>>()?;
+            quote! {
+                #docs
+                #derives
+                pub struct #name(#(pub #items),*);
+            }
+        }
+        TypeNode::Enum(node) => {
+            let (attributes, variants) = render_enum(ctx, node)?;
+            quote! {
+                #docs
+                #derives
+                #attributes
+                pub enum #name { #(#variants),* }
+            }
+        }
+        r#type => {
+            let r#type = render_type(ctx, r#type)?;
+            quote! {
+                #docs
+                pub type #name = #r#type;
+            }
+        }
+    })
+}
+
+/// The derives that make generated structs and enums Borsh-compatible.
+pub(crate) fn borsh_derives() -> TokenStream {
+    quote! { #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)] }
+}
+
+pub(crate) fn render_struct_fields(
+    ctx: &RenderContext,
+    fields: &[StructFieldTypeNode],
+    public: bool,
+) -> CodamaResult<Vec<TokenStream>> {
+    let visibility = public.then(|| quote! { pub });
+    fields
+        .iter()
+        .map(|field| {
+            let docs = render_docs(&field.docs);
+            let name = snake_ident(&field.name);
+            let r#type = render_type(ctx, &field.r#type)?;
+            Ok(quote! { #docs #visibility #name: #r#type })
+        })
+        .collect()
+}
+
+fn render_enum(
+    ctx: &RenderContext,
+    node: &EnumTypeNode,
+) -> CodamaResult<(TokenStream, Vec<TokenStream>)> {
+    if !is_number(&node.size, U8) {
+        return Err(CodamaError::Rendering(
+            "enums with a non-`u8` size are not supported by Borsh".to_string(),
+        ));
+    }
+
+    // Explicit discriminators are only supported by Borsh on scalar enums.
+    let explicit = node.variants.iter().any(|variant| match variant {
+        EnumVariantTypeNode::Empty(variant) => variant.discriminator.is_some(),
+        EnumVariantTypeNode::Struct(variant) => variant.discriminator.is_some(),
+        EnumVariantTypeNode::Tuple(variant) => variant.discriminator.is_some(),
+    });
+    let scalar = node
+        .variants
+        .iter()
+        .all(|variant| matches!(variant, EnumVariantTypeNode::Empty(_)));
+    if explicit && !scalar {
+        return Err(CodamaError::Rendering(
+            "explicit variant discriminators are only supported by Borsh on scalar enums"
+                .to_string(),
+        ));
+    }
+
+    let variants = node
+        .variants
+        .iter()
+        .map(|variant| {
+            Ok(match variant {
+                EnumVariantTypeNode::Empty(variant) => {
+                    let name = pascal_ident(&variant.name);
+                    match variant.discriminator.filter(|_| explicit) {
+                        Some(discriminator) => {
+                            let discriminator = Literal::usize_unsuffixed(discriminator);
+                            quote! { #name = #discriminator }
+                        }
+                        None => quote! { #name },
+                    }
+                }
+                EnumVariantTypeNode::Struct(variant) => {
+                    let name = pascal_ident(&variant.name);
+                    let fields = render_struct_fields(
+                        ctx,
+                        &variant.r#struct.get_nested_type_node().fields,
+                        false,
+                    )?;
+                    quote! { #name { #(#fields),* } }
+                }
+                EnumVariantTypeNode::Tuple(variant) => {
+                    let name = pascal_ident(&variant.name);
+                    let items = variant
+                        .tuple
+                        .get_nested_type_node()
+                        .items
+                        .iter()
+                        .map(|item| render_type(ctx, item))
+                        .collect::<CodamaResult<Vec<_>>>()?;
+                    quote! { #name(#(#items),*) }
+                }
+            })
+        })
+        .collect::<CodamaResult<Vec<_>>>()?;
+
+    let attributes = explicit.then(|| quote! { #[borsh(use_discriminant = true)] });
+    Ok((quote! { #attributes }, variants))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::render_file;
+    use codama_nodes::{
+        EnumEmptyVariantTypeNode, EnumStructVariantTypeNode, EnumTupleVariantTypeNode,
+        NumberTypeNode, ProgramNode, RootNode, StructTypeNode, TupleTypeNode, U64,
+    };
+
+    fn render(node: DefinedTypeNode) -> CodamaResult<String> {
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let ctx = RenderContext::new(&root, "crate::generated").unwrap();
+        let file = render_file(render_defined_type(&ctx, &node)?)?;
+        Ok(file.lines().skip(3).collect::<Vec<_>>().join("\n"))
+    }
+
+    #[test]
+    fn structs() {
+        let node = DefinedTypeNode {
+            docs: vec!["The counter state.".to_string()].into(),
+            ..DefinedTypeNode::new(
+                "counterState",
+                StructTypeNode::new(vec![
+                    StructFieldTypeNode::new("count", NumberTypeNode::le(U64)),
+                    StructFieldTypeNode::new("type", NumberTypeNode::le(U8)),
+                ]),
+            )
+        };
+        assert_eq!(
+            render(node).unwrap(),
+            r#"/// The counter state.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterState {
+    pub count: u64,
+    pub r#type: u8,
+}"#
+        );
+    }
+
+    #[test]
+    fn tuples_and_aliases() {
+        let node = DefinedTypeNode::new(
+            "pair",
+            TupleTypeNode::new(vec![
+                NumberTypeNode::le(U64).into(),
+                NumberTypeNode::le(U8).into(),
+            ]),
+        );
+        assert_eq!(
+            render(node).unwrap(),
+            r#"#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct Pair(pub u64, pub u8);"#
+        );
+        let node = DefinedTypeNode::new("amount", NumberTypeNode::le(U64));
+        assert_eq!(render(node).unwrap(), "pub type Amount = u64;");
+    }
+
+    #[test]
+    fn enums() {
+        let node = DefinedTypeNode::new(
+            "state",
+            EnumTypeNode::new(vec![
+                EnumEmptyVariantTypeNode::new("open").into(),
+                EnumStructVariantTypeNode::new(
+                    "locked",
+                    StructTypeNode::new(vec![StructFieldTypeNode::new(
+                        "until",
+                        NumberTypeNode::le(U64),
+                    )]),
+                )
+                .into(),
+                EnumTupleVariantTypeNode::new(
+                    "frozen",
+                    TupleTypeNode::new(vec![NumberTypeNode::le(U8).into()]),
+                )
+                .into(),
+            ]),
+        );
+        assert_eq!(
+            render(node).unwrap(),
+            r#"#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum State {
+    Open,
+    Locked { until: u64 },
+    Frozen(u8),
+}"#
+        );
+    }
+
+    #[test]
+    fn scalar_enums_with_discriminators() {
+        let node = DefinedTypeNode::new(
+            "state",
+            EnumTypeNode::new(vec![
+                EnumEmptyVariantTypeNode {
+                    discriminator: Some(1),
+                    ..EnumEmptyVariantTypeNode::new("open")
+                }
+                .into(),
+                EnumEmptyVariantTypeNode {
+                    discriminator: Some(5),
+                    ..EnumEmptyVariantTypeNode::new("closed")
+                }
+                .into(),
+            ]),
+        );
+        assert_eq!(
+            render(node).unwrap(),
+            r#"#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+#[borsh(use_discriminant = true)]
+pub enum State {
+    Open = 1,
+    Closed = 5,
+}"#
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::utils::{pascal_ident, render_docs};
+use codama_errors::CodamaResult;
+use codama_nodes::ProgramNode;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+pub(crate) fn render_program_errors(program: &ProgramNode) -> CodamaResult<TokenStream> {
+    let name = format_ident!("{}Error", pascal_ident(&program.name).to_string());
+    let variants = program.errors.iter().map(|error| {
+        let docs = render_docs(&error.docs);
+        let summary = format!(" {} (0x{:x}) - {}", error.code, error.code, error.message);
+        let variant = pascal_ident(&error.name);
+        let code = Literal::u32_unsuffixed(error.code as u32);
+        quote! {
+            #[doc = #summary]
+            #docs
+            #variant = #code
+        }
+    });
+    let from_codes = program.errors.iter().map(|error| {
+        let variant = pascal_ident(&error.name);
+        let code = Literal::u32_unsuffixed(error.code as u32);
+        quote! { #code => Some(Self::#variant) }
+    });
+    let messages = program.errors.iter().map(|error| {
+        let variant = pascal_ident(&error.name);
+        let message = &error.message;
+        quote! { Self::#variant => #message }
+    });
+
+    Ok(quote! {
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        #[repr(u32)]
+        pub enum #name {
+            #(#variants),*
+        }
+
+        impl #name {
+            pub fn code(self) -> u32 {
+                self as u32
+            }
+
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    #(#from_codes,)*
+                    _ => None,
+                }
+            }
+
+            pub fn message(self) -> &'static str {
+                match self {
+                    #(#messages),*
+                }
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.message())
+            }
+        }
+
+        impl std::error::Error for #name {}
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::render_file;
+    use codama_nodes::ErrorNode;
+
+    #[test]
+    fn errors() {
+        let program = ProgramNode {
+            errors: vec![ErrorNode::new("overflow", 6000, "Counter overflowed")],
+            ..ProgramNode::new("counter", "1111")
+        };
+        let file = render_file(render_program_errors(&program).unwrap()).unwrap();
+        assert!(file.contains(
+            r#"#[repr(u32)]
+pub enum CounterError {
+    /// 6000 (0x1770) - Counter overflowed
+    Overflow = 6000,
+}"#
+        ));
+        assert!(file.contains("6000 => Some(Self::Overflow),"));
+        assert!(file.contains("Self::Overflow => \"Counter overflowed\","));
+    }
+}
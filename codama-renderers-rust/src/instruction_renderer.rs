@@ -0,0 +1,509 @@
+use crate::defined_type_renderer::borsh_derives;
+use crate::pda_renderer::{find_pda_ident, is_seed_passed_by_value};
+use crate::render_context::RenderContext;
+use crate::type_renderer::render_type;
+use crate::utils::{
+    pascal_ident, render_bytes, render_docs, render_pubkey, shouty_ident, snake_ident,
+};
+use codama_errors::{CodamaError, CodamaResult};
+use codama_nodes::{
+    CountNode, DefaultValueStrategy, DiscriminatorNode, InstructionAccountNode,
+    InstructionArgumentNode, InstructionInputValueNode, InstructionNode,
+    InstructionOptionalAccountStrategy, IsAccountSigner, PdaNode, PdaSeedNode,
+    PdaSeedValueValueNode, PdaValue, PdaValueNode, ProgramNode, TypeNode, ValueNode,
+    VariablePdaSeedNode,
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+
+pub(crate) fn render_instruction(
+    ctx: &RenderContext,
+    program: &ProgramNode,
+    instruction: &InstructionNode,
+) -> CodamaResult<TokenStream> {
+    let docs = render_docs(&instruction.docs);
+    let name = pascal_ident(&instruction.name);
+    let args_name = format_ident!("{name}InstructionArgs");
+    let builder_name = format_ident!("{name}Builder");
+    let program_id = ctx.program_id(program);
+
+    // Discriminator constant.
+    let discriminator =
+        instruction
+            .discriminators
+            .iter()
+            .find_map(|discriminator| match discriminator {
+                DiscriminatorNode::Field(discriminator) if discriminator.offset == 0 => {
+                    Some(discriminator.name.as_str())
+                }
+                _ => None,
+            });
+    let discriminator_name = format_ident!(
+        "{}_DISCRIMINATOR",
+        shouty_ident(&instruction.name).to_string()
+    );
+    let mut discriminator_constant = None;
+
+    // Arguments.
+    let mut data = vec![];
+    let mut args = vec![];
+    for argument in &instruction.arguments {
+        match omitted_bytes(ctx, argument)? {
+            Some(bytes) if Some(argument.name.as_str()) == discriminator => {
+                let length = Literal::usize_unsuffixed(bytes.len());
+                let bytes = render_bytes(&bytes);
+                discriminator_constant = Some(quote! {
+                    pub const #discriminator_name: [u8; #length] = #bytes;
+                });
+                data.push(quote! { data.extend_from_slice(&#discriminator_name); });
+            }
+            Some(bytes) => {
+                let bytes = render_bytes(&bytes);
+                data.push(quote! { data.extend_from_slice(&#bytes); });
+            }
+            None => {
+                let field = snake_ident(&argument.name);
+                data.push(quote! { borsh::to_writer(&mut data, &args.#field).unwrap(); });
+                args.push(argument);
+            }
+        }
+    }
+    let accounts = &instruction.accounts;
+    let arg_local =
+        |argument: &InstructionArgumentNode| match accounts.iter().any(|a| a.name == argument.name)
+        {
+            true => format_ident!("{}_arg", snake_ident(&argument.name).to_string()),
+            false => snake_ident(&argument.name),
+        };
+
+    let args_struct = match args.is_empty() {
+        true => None,
+        false => {
+            let derives = borsh_derives();
+            let fields = args
+                .iter()
+                .map(|argument| {
+                    let docs = render_docs(&argument.docs);
+                    let name = snake_ident(&argument.name);
+                    let r#type = render_type(ctx, &argument.r#type)?;
+                    Ok(quote! { #docs pub #name: #r#type })
+                })
+                .collect::<CodamaResult<Vec<_>>>()?;
+            Some(quote! {
+                #derives
+                pub struct #args_name { #(#fields),* }
+            })
+        }
+    };
+    let args_param = args_struct.as_ref().map(|_| quote! { args: #args_name, });
+    let args_value = args_struct.as_ref().map(|_| quote! { args, });
+    let borsh = args_struct
+        .as_ref()
+        .map(|_| quote! { use borsh::{BorshDeserialize, BorshSerialize}; });
+
+    // Accounts struct.
+    let mut account_fields = vec![];
+    let mut account_metas = vec![];
+    for account in accounts {
+        let docs = render_docs(&account.docs);
+        let field = snake_ident(&account.name);
+        let r#type = account_type(account);
+        account_fields.push(quote! { #docs pub #field: #r#type });
+        let meta = |pubkey: TokenStream| {
+            let (pubkey, is_signer) = match account.is_signer {
+                IsAccountSigner::True => (pubkey, quote! { true }),
+                IsAccountSigner::False => (pubkey, quote! { false }),
+                IsAccountSigner::Either => (quote! { #pubkey.0 }, quote! { #pubkey.1 }),
+            };
+            match account.is_writable {
+                true => quote! { solana_instruction::AccountMeta::new(#pubkey, #is_signer) },
+                false => {
+                    quote! { solana_instruction::AccountMeta::new_readonly(#pubkey, #is_signer) }
+                }
+            }
+        };
+        account_metas.push(match account.is_optional {
+            false => {
+                let meta = meta(quote! { self.#field });
+                quote! { accounts.push(#meta); }
+            }
+            true => {
+                let meta = meta(quote! { #field });
+                match instruction.optional_account_strategy {
+                    InstructionOptionalAccountStrategy::ProgramId => quote! {
+                        if let Some(#field) = self.#field {
+                            accounts.push(#meta);
+                        } else {
+                            accounts.push(solana_instruction::AccountMeta::new_readonly(#program_id, false));
+                        }
+                    },
+                    InstructionOptionalAccountStrategy::Omitted => quote! {
+                        if let Some(#field) = self.#field {
+                            accounts.push(#meta);
+                        }
+                    },
+                }
+            }
+        });
+    }
+    let capacity = Literal::usize_unsuffixed(accounts.len());
+
+    // Builder.
+    let mut builder_fields = vec![];
+    let mut setters = vec![];
+    for account in accounts {
+        let docs = render_docs(&account.docs);
+        let field = snake_ident(&account.name);
+        let r#type = account_type(account);
+        let (params, value) = match (account.is_optional, account.is_signer) {
+            (false, IsAccountSigner::Either) => (
+                quote! { #field: solana_pubkey::Pubkey, as_signer: bool },
+                quote! { Some((#field, as_signer)) },
+            ),
+            (false, _) => (quote! { #field: #r#type }, quote! { Some(#field) }),
+            (true, _) => (quote! { #field: #r#type }, quote! { #field }),
+        };
+        builder_fields.push(match account.is_optional {
+            true => quote! { #field: #r#type },
+            false => quote! { #field: Option<#r#type> },
+        });
+        setters.push(quote! {
+            #docs
+            pub fn #field(&mut self, #params) -> &mut Self {
+                self.#field = #value;
+                self
+            }
+        });
+    }
+    for argument in &args {
+        let docs = render_docs(&argument.docs);
+        let field = arg_local(argument);
+        let r#type = render_type(ctx, &argument.r#type)?;
+        builder_fields.push(quote! { #field: Option<#r#type> });
+        setters.push(quote! {
+            #docs
+            pub fn #field(&mut self, #field: #r#type) -> &mut Self {
+                self.#field = Some(#field);
+                self
+            }
+        });
+    }
+
+    // Builder locals: arguments, accounts and then PDA accounts.
+    let mut locals = vec![];
+    for argument in &args {
+        let local = arg_local(argument);
+        let message = format!("{} is not set", local);
+        let value = match is_copy(&argument.r#type) {
+            true => quote! { self.#local },
+            false => quote! { self.#local.clone() },
+        };
+        locals.push(quote! { let #local = #value.expect(#message); });
+    }
+    let mut resolved = HashMap::new();
+    let mut pending = vec![];
+    for account in accounts {
+        let field = snake_ident(&account.name);
+        if account.is_optional {
+            locals.push(quote! { let #field = self.#field; });
+            continue;
+        }
+        let default = match &account.default_value {
+            Some(InstructionInputValueNode::Pda(pda)) => {
+                pending.push((account, pda));
+                continue;
+            }
+            Some(value) => default_account(ctx, program, value, &resolved)?,
+            None => None,
+        };
+        locals.push(account_local(account, default));
+        resolved.insert(account.name.as_str(), pubkey_expression(account));
+    }
+    let arg_locals = args
+        .iter()
+        .map(|argument| (argument.name.as_str(), (*argument, arg_local(argument))))
+        .collect::<HashMap<_, _>>();
+    for (account, pda) in pending {
+        let default = default_pda(ctx, program, pda, &resolved, &arg_locals)?;
+        locals.push(account_local(account, default));
+        resolved.insert(account.name.as_str(), pubkey_expression(account));
+    }
+    let account_values = accounts.iter().map(|account| snake_ident(&account.name));
+    let arg_values = args.iter().map(|argument| {
+        let field = snake_ident(&argument.name);
+        let local = arg_local(argument);
+        match field == local {
+            true => quote! { #field },
+            false => quote! { #field: #local },
+        }
+    });
+    let builder_args = args_struct.as_ref().map(|_| {
+        quote! { let args = #args_name { #(#arg_values),* }; }
+    });
+
+    let instruction_doc = format!(
+        " Accounts of the `{}` instruction.",
+        instruction.name.as_str()
+    );
+    let builder_doc = format!(
+        " Builder for the `{}` instruction.",
+        instruction.name.as_str()
+    );
+    Ok(quote! {
+        #borsh
+        #discriminator_constant
+
+        #docs
+        #[doc = #instruction_doc]
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct #name { #(#account_fields),* }
+
+        impl #name {
+            pub fn instruction(&self, #args_param) -> solana_instruction::Instruction {
+                self.instruction_with_remaining_accounts(#args_value &[])
+            }
+
+            pub fn instruction_with_remaining_accounts(
+                &self,
+                #args_param
+                remaining_accounts: &[solana_instruction::AccountMeta],
+            ) -> solana_instruction::Instruction {
+                let mut accounts = Vec::with_capacity(#capacity + remaining_accounts.len());
+                #(#account_metas)*
+                accounts.extend_from_slice(remaining_accounts);
+                let mut data = Vec::new();
+                #(#data)*
+                solana_instruction::Instruction {
+                    program_id: #program_id,
+                    accounts,
+                    data,
+                }
+            }
+        }
+
+        #args_struct
+
+        #[doc = #builder_doc]
+        #[derive(Clone, Debug, Default)]
+        pub struct #builder_name {
+            #(#builder_fields,)*
+            __remaining_accounts: Vec<solana_instruction::AccountMeta>,
+        }
+
+        impl #builder_name {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #(#setters)*
+
+            pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
+                self.__remaining_accounts.push(account);
+                self
+            }
+
+            pub fn add_remaining_accounts(&mut self, accounts: &[solana_instruction::AccountMeta]) -> &mut Self {
+                self.__remaining_accounts.extend_from_slice(accounts);
+                self
+            }
+
+            pub fn instruction(&self) -> solana_instruction::Instruction {
+                #(#locals)*
+                let accounts = #name { #(#account_values),* };
+                #builder_args
+                accounts.instruction_with_remaining_accounts(#args_value &self.__remaining_accounts)
+            }
+        }
+    })
+}
+
+/// The bytes of an argument that is omitted from the instruction inputs, if any.
+fn omitted_bytes(
+    ctx: &RenderContext,
+    argument: &InstructionArgumentNode,
+) -> CodamaResult<Option<Vec<u8>>> {
+    if argument.default_value_strategy != Some(DefaultValueStrategy::Omitted) {
+        return Ok(None);
+    }
+    match argument.default_value.clone().map(ValueNode::try_from) {
+        Some(Ok(value)) => Ok(Some(ctx.codec.encode(&value, &argument.r#type)?)),
+        _ => Err(CodamaError::Rendering(format!(
+            "omitted argument `{}` must have a constant default value",
+            argument.name.as_str()
+        ))),
+    }
+}
+
+/// Whether the rendered type of an argument implements `Copy`.
+fn is_copy(r#type: &TypeNode) -> bool {
+    match r#type {
+        TypeNode::Boolean(_) | TypeNode::Number(_) | TypeNode::PublicKey(_) => true,
+        TypeNode::FixedSize(_) => true,
+        TypeNode::Array(node) => matches!(node.count, CountNode::Fixed(_)) && is_copy(&node.item),
+        TypeNode::Option(node) => is_copy(&node.item),
+        TypeNode::Tuple(node) => node.items.iter().all(is_copy),
+        _ => false,
+    }
+}
+
+fn account_type(account: &InstructionAccountNode) -> TokenStream {
+    let r#type = match account.is_signer {
+        IsAccountSigner::Either => quote! { (solana_pubkey::Pubkey, bool) },
+        _ => quote! { solana_pubkey::Pubkey },
+    };
+    match account.is_optional {
+        true => quote! { Option<#r#type> },
+        false => r#type,
+    }
+}
+
+/// Declares the builder local of a required account, using its default public key if any.
+fn account_local(account: &InstructionAccountNode, default: Option<TokenStream>) -> TokenStream {
+    let field = snake_ident(&account.name);
+    let message = format!("{} is not set", field);
+    match (default, account.is_signer) {
+        (None, _) => quote! { let #field = self.#field.expect(#message); },
+        (Some(default), IsAccountSigner::Either) => {
+            quote! { let #field = self.#field.unwrap_or_else(|| (#default, false)); }
+        }
+        (Some(default), _) => quote! { let #field = self.#field.unwrap_or_else(|| #default); },
+    }
+}
+
+/// The expression returning the public key of a resolved account local.
+fn pubkey_expression(account: &InstructionAccountNode) -> TokenStream {
+    let field = snake_ident(&account.name);
+    match account.is_signer {
+        IsAccountSigner::Either => quote! { #field.0 },
+        _ => quote! { #field },
+    }
+}
+
+/// Renders the default public key of an account, if it can be resolved offline.
+fn default_account(
+    ctx: &RenderContext,
+    program: &ProgramNode,
+    value: &InstructionInputValueNode,
+    resolved: &HashMap<&str, TokenStream>,
+) -> CodamaResult<Option<TokenStream>> {
+    Ok(match value {
+        InstructionInputValueNode::PublicKey(value) => Some(render_pubkey(&value.public_key)?),
+        InstructionInputValueNode::ProgramId(_) => Some(ctx.program_id(program)),
+        InstructionInputValueNode::ProgramLink(link) => ctx
+            .programs()
+            .find(|program| program.name == link.name)
+            .map(|program| ctx.program_id(program)),
+        InstructionInputValueNode::Account(value) => resolved.get(value.name.as_str()).cloned(),
+        _ => None,
+    })
+}
+
+/// Renders a call to the PDA helper of an account, if all of its seeds can be resolved.
+fn default_pda(
+    ctx: &RenderContext,
+    program: &ProgramNode,
+    value: &PdaValueNode,
+    resolved: &HashMap<&str, TokenStream>,
+    args: &HashMap<&str, (&InstructionArgumentNode, syn::Ident)>,
+) -> CodamaResult<Option<TokenStream>> {
+    let PdaValue::Linked(link) = &value.pda else {
+        return Ok(None);
+    };
+    if value.program_id.is_some() {
+        return Ok(None);
+    }
+    let pda_program = match &link.program {
+        Some(link) => ctx.programs().find(|program| program.name == link.name),
+        None => Some(program),
+    };
+    let Some(pda) =
+        pda_program.and_then(|program| program.pdas.iter().find(|pda| pda.name == link.name))
+    else {
+        return Ok(None);
+    };
+    let mut params = vec![];
+    for seed in variable_seeds(pda) {
+        let seed_value = value.seeds.iter().find(|value| value.name == seed.name);
+        let param = match seed_value.map(|value| &value.value) {
+            Some(PdaSeedValueValueNode::Account(account)) => resolved
+                .get(account.name.as_str())
+                .map(|pubkey| quote! { &#pubkey }),
+            Some(PdaSeedValueValueNode::Argument(argument)) => args
+                .get(argument.name.as_str())
+                .map(|(_, local)| match is_seed_passed_by_value(&seed.r#type) {
+                    true => quote! { #local },
+                    false => quote! { &#local },
+                }),
+            _ => None,
+        };
+        match param {
+            Some(param) => params.push(param),
+            None => return Ok(None),
+        }
+    }
+    let pdas = ctx.module("pdas");
+    let find = find_pda_ident(pda);
+    Ok(Some(quote! { #pdas::#find(#(#params),*).0 }))
+}
+
+fn variable_seeds(pda: &PdaNode) -> impl Iterator<Item = &VariablePdaSeedNode> {
+    pda.seeds.iter().filter_map(|seed| match seed {
+        PdaSeedNode::Variable(seed) => Some(seed),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::render_file;
+    use codama_nodes::{NumberTypeNode, RootNode, U8};
+
+    fn render(instruction: InstructionNode) -> CodamaResult<String> {
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let ctx = RenderContext::new(&root, "crate::generated")?;
+        render_file(render_instruction(&ctx, &root.program, &instruction)?)
+    }
+
+    #[test]
+    fn either_signers_and_argument_collisions() {
+        let code = render(InstructionNode {
+            name: "increment".into(),
+            accounts: vec![InstructionAccountNode::new(
+                "counter",
+                true,
+                IsAccountSigner::Either,
+            )],
+            arguments: vec![InstructionArgumentNode::new(
+                "counter",
+                NumberTypeNode::le(U8),
+            )],
+            ..InstructionNode::default()
+        })
+        .unwrap();
+        assert!(code.contains("pub counter: (solana_pubkey::Pubkey, bool),"));
+        assert!(
+            code.contains("solana_instruction::AccountMeta::new(self.counter.0, self.counter.1)")
+        );
+        assert!(code.contains("pub fn counter_arg(&mut self, counter_arg: u8) -> &mut Self {"));
+        assert!(code.contains("counter: counter_arg"));
+    }
+
+    #[test]
+    fn omitted_arguments_require_constant_values() {
+        let error = render(InstructionNode {
+            name: "increment".into(),
+            arguments: vec![InstructionArgumentNode {
+                default_value_strategy: Some(DefaultValueStrategy::Omitted),
+                ..InstructionArgumentNode::new("discriminator", NumberTypeNode::le(U8))
+            }],
+            ..InstructionNode::default()
+        })
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Could not render code: omitted argument `discriminator` must have a constant default value"
+        );
+    }
+}
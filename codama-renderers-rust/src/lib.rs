@@ -0,0 +1,12 @@
+mod account_renderer;
+mod defined_type_renderer;
+mod error_renderer;
+mod instruction_renderer;
+mod pda_renderer;
+mod render_context;
+mod rust_renderer;
+mod type_renderer;
+mod utils;
+
+pub use codama_renderers_core::RenderMap;
+pub use rust_renderer::*;
@@ -0,0 +1,175 @@
+use crate::render_context::RenderContext;
+use crate::type_renderer::render_type;
+use crate::utils::{
+    is_number, render_bytes, render_docs, render_number_type, render_pubkey, snake_ident,
+};
+use codama_errors::CodamaResult;
+use codama_nodes::{
+    Endian, NumberFormat, NumberFormat::U8, NumberTypeNode, PdaNode, PdaSeedNode, ProgramNode,
+    TypeNode, Utf8, ValueNode,
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+pub(crate) fn render_pda(
+    ctx: &RenderContext,
+    program: &ProgramNode,
+    pda: &PdaNode,
+) -> CodamaResult<TokenStream> {
+    let docs = render_docs(&pda.docs);
+    let find = find_pda_ident(pda);
+    let create = format_ident!("create_{}_pda", snake_ident(&pda.name).to_string());
+    let program_id = match &pda.program_id {
+        Some(program_id) => render_pubkey(program_id)?,
+        None => ctx.program_id(program),
+    };
+
+    let mut params = vec![];
+    let mut seeds = vec![];
+    for seed in &pda.seeds {
+        match seed {
+            PdaSeedNode::Constant(seed) if seed.value.is_program_id() => {
+                seeds.push(quote! { #program_id.as_ref() });
+            }
+            PdaSeedNode::Constant(seed) => {
+                let value = ValueNode::try_from(seed.value.clone())?;
+                let bytes = ctx.codec.encode(&value, &seed.r#type)?;
+                seeds.push(match String::from_utf8(bytes.clone()) {
+                    Ok(string) if !string.chars().any(char::is_control) => {
+                        let literal = Literal::byte_string(&bytes);
+                        quote! { #literal }
+                    }
+                    _ => {
+                        let bytes = render_bytes(&bytes);
+                        quote! { &#bytes }
+                    }
+                });
+            }
+            PdaSeedNode::Variable(seed) => {
+                let name = snake_ident(&seed.name);
+                let (r#type, bytes) = render_seed(ctx, &seed.r#type, &name)?;
+                params.push(quote! { #name: #r#type });
+                seeds.push(bytes);
+            }
+        }
+    }
+
+    Ok(quote! {
+        #docs
+        pub fn #find(#(#params),*) -> (solana_pubkey::Pubkey, u8) {
+            solana_pubkey::Pubkey::find_program_address(&[#(#seeds),*], &#program_id)
+        }
+
+        #docs
+        pub fn #create(
+            #(#params,)*
+            bump: u8,
+        ) -> Result<solana_pubkey::Pubkey, solana_pubkey::PubkeyError> {
+            solana_pubkey::Pubkey::create_program_address(&[#(#seeds,)* &[bump]], &#program_id)
+        }
+    })
+}
+
+pub(crate) fn find_pda_ident(pda: &PdaNode) -> syn::Ident {
+    format_ident!("find_{}_pda", snake_ident(&pda.name).to_string())
+}
+
+/// Whether variable seeds of the given type are passed by value to PDA helpers.
+pub(crate) fn is_seed_passed_by_value(r#type: &TypeNode) -> bool {
+    matches!(r#type, TypeNode::Number(_) | TypeNode::Boolean(_))
+}
+
+/// Renders the parameter type of a variable seed and the expression returning its bytes.
+fn render_seed(
+    ctx: &RenderContext,
+    r#type: &TypeNode,
+    name: &syn::Ident,
+) -> CodamaResult<(TokenStream, TokenStream)> {
+    Ok(match r#type {
+        TypeNode::PublicKey(_) => (quote! { &solana_pubkey::Pubkey }, quote! { #name.as_ref() }),
+        TypeNode::String(string) if string.encoding == Utf8 => {
+            (quote! { &str }, quote! { #name.as_bytes() })
+        }
+        TypeNode::Bytes(_) => (quote! { &[u8] }, quote! { #name }),
+        TypeNode::FixedSize(node) if matches!(node.r#type.as_ref(), TypeNode::Bytes(_)) => {
+            let size = Literal::usize_unsuffixed(node.size);
+            (quote! { &[u8; #size] }, quote! { #name.as_ref() })
+        }
+        TypeNode::Number(node) if node.format != NumberFormat::ShortU16 => {
+            let r#type = render_number_type(&NumberTypeNode::le(node.format))?;
+            let bytes = match node.endian {
+                Endian::Little => quote! { &#name.to_le_bytes() },
+                Endian::Big => quote! { &#name.to_be_bytes() },
+            };
+            (r#type, bytes)
+        }
+        TypeNode::Boolean(node) if is_number(&node.size, U8) => {
+            (quote! { bool }, quote! { &[#name as u8] })
+        }
+        r#type => {
+            let r#type = render_type(ctx, r#type)?;
+            (
+                quote! { &#r#type },
+                quote! { &borsh::to_vec(#name).unwrap() },
+            )
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::render_file;
+    use codama_nodes::{
+        ConstantPdaSeedNode, PublicKeyTypeNode, RootNode, StringTypeNode, StringValueNode,
+        VariablePdaSeedNode, U64,
+    };
+
+    #[test]
+    fn seeds() {
+        let program = ProgramNode::new("counter", "Counter111111111111111111111111111111111111");
+        let root = RootNode::new(program.clone());
+        let ctx = RenderContext::new(&root, "crate::generated").unwrap();
+        let pda = PdaNode::new(
+            "counter",
+            vec![
+                ConstantPdaSeedNode::new(StringTypeNode::utf8(), StringValueNode::new("counter"))
+                    .into(),
+                VariablePdaSeedNode::new("authority", PublicKeyTypeNode::new()).into(),
+                VariablePdaSeedNode::new("index", NumberTypeNode::le(U64)).into(),
+                VariablePdaSeedNode::new("label", StringTypeNode::utf8()).into(),
+            ],
+        );
+        let file = render_file(render_pda(&ctx, &program, &pda).unwrap()).unwrap();
+        assert_eq!(
+            file.lines().skip(3).collect::<Vec<_>>().join("\n"),
+            r#"pub fn find_counter_pda(
+    authority: &solana_pubkey::Pubkey,
+    index: u64,
+    label: &str,
+) -> (solana_pubkey::Pubkey, u8) {
+    solana_pubkey::Pubkey::find_program_address(
+        &[b"counter", authority.as_ref(), &index.to_le_bytes(), label.as_bytes()],
+        &crate::generated::programs::COUNTER_ID,
+    )
+}
+pub fn create_counter_pda(
+    authority: &solana_pubkey::Pubkey,
+    index: u64,
+    label: &str,
+    bump: u8,
+) -> Result<solana_pubkey::Pubkey, solana_pubkey::PubkeyError> {
+    solana_pubkey::Pubkey::create_program_address(
+        &[
+            b"counter",
+            authority.as_ref(),
+            &index.to_le_bytes(),
+            label.as_bytes(),
+            &[bump],
+        ],
+        &crate::generated::programs::COUNTER_ID,
+    )
+}"#
+        );
+    }
+}
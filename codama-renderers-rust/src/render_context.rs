@@ -0,0 +1,44 @@
+use crate::utils::{shouty_ident, snake_ident};
+use codama_errors::CodamaResult;
+use codama_nodes::{ProgramNode, RootNode, TypeCodec};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Shared state used to render the items of a `RootNode`.
+pub(crate) struct RenderContext<'a> {
+    pub root: &'a RootNode,
+    pub codec: TypeCodec<'a>,
+    module_path: syn::Path,
+}
+
+impl<'a> RenderContext<'a> {
+    pub fn new(root: &'a RootNode, module_path: &str) -> CodamaResult<Self> {
+        Ok(Self {
+            root,
+            codec: TypeCodec::from_root(root),
+            module_path: syn::parse_str(module_path)?,
+        })
+    }
+
+    pub fn programs(&self) -> impl Iterator<Item = &'a ProgramNode> {
+        std::iter::once(&self.root.program).chain(self.root.additional_programs.iter())
+    }
+
+    /// The path of the given generated module — e.g. `crate::generated::types`.
+    pub fn module(&self, name: &str) -> TokenStream {
+        let module_path = &self.module_path;
+        let name = snake_ident(name);
+        quote! { #module_path::#name }
+    }
+
+    /// The path of the constant holding the address of the given program.
+    pub fn program_id(&self, program: &ProgramNode) -> TokenStream {
+        let programs = self.module("programs");
+        let id = program_id_ident(program);
+        quote! { #programs::#id }
+    }
+}
+
+pub(crate) fn program_id_ident(program: &ProgramNode) -> syn::Ident {
+    format_ident!("{}_ID", shouty_ident(&program.name).to_string())
+}
@@ -0,0 +1,270 @@
+use crate::account_renderer::render_account;
+use crate::defined_type_renderer::render_defined_type;
+use crate::error_renderer::render_program_errors;
+use crate::instruction_renderer::render_instruction;
+use crate::pda_renderer::render_pda;
+use crate::render_context::{program_id_ident, RenderContext};
+use crate::utils::{render_file, render_pubkey, snake_ident};
+use crate::RenderMap;
+use codama_errors::CodamaResult;
+use codama_nodes::{Diagnostic, HasKind, NodePath, RootNode};
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The files of a Rust client, along with warnings for the items that were skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustClient {
+    pub files: RenderMap,
+    pub warnings: Vec<Diagnostic>,
+}
+
+/// Renders a Rust client from a `RootNode`.
+///
+/// The generated code depends on the `borsh`, `solana-instruction`
+/// and `solana-pubkey` crates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RustRenderer {
+    module_path: String,
+    crate_name: Option<String>,
+}
+
+impl Default for RustRenderer {
+    fn default() -> Self {
+        Self {
+            module_path: "crate::generated".to_string(),
+            crate_name: None,
+        }
+    }
+}
+
+impl RustRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The path of the module the files are rendered into, e.g. `crate::generated`.
+    pub fn with_module_path<T: Into<String>>(mut self, module_path: T) -> Self {
+        self.module_path = module_path.into();
+        self
+    }
+
+    /// Renders a standalone crate with its own `Cargo.toml` instead of a module.
+    pub fn with_crate_name<T: Into<String>>(mut self, crate_name: T) -> Self {
+        self.module_path = "crate".to_string();
+        self.crate_name = Some(crate_name.into());
+        self
+    }
+
+    pub fn render(&self, root: &RootNode) -> CodamaResult<RenderMap> {
+        Ok(self.render_client(root)?.files)
+    }
+
+    /// Renders the client files along with warnings for the items that could not be
+    /// rendered — e.g. accounts using a variable-size layout that Borsh cannot describe.
+    /// These items are skipped instead of failing the whole render.
+    pub fn render_client(&self, root: &RootNode) -> CodamaResult<RustClient> {
+        let ctx = RenderContext::new(root, &self.module_path)?;
+        let mut folders = BTreeMap::<&str, Vec<(String, TokenStream)>>::new();
+        let mut warnings = vec![];
+        let mut add =
+            |folder, path: NodePath, name: &str, tokens: CodamaResult<TokenStream>| match tokens {
+                Ok(tokens) => folders
+                    .entry(folder)
+                    .or_default()
+                    .push((name.to_snake_case(), tokens)),
+                Err(error) => warnings.push(Diagnostic::warning(
+                    format!("skipped because it could not be rendered: {error}"),
+                    &path,
+                )),
+            };
+        let borsh = quote! { use borsh::{BorshDeserialize, BorshSerialize}; };
+
+        let mut program_ids = vec![];
+        for program in ctx.programs() {
+            let id = program_id_ident(program);
+            let address = render_pubkey(&program.public_key)?;
+            program_ids.push(quote! {
+                pub const #id: solana_pubkey::Pubkey = #address;
+            });
+
+            let path = NodePath::new().child(program.kind(), Some(&program.name));
+            let child = |kind: &'static str, name| path.child(kind, Some(name));
+            for defined_type in &program.defined_types {
+                let tokens = render_defined_type(&ctx, defined_type);
+                add(
+                    "types",
+                    child(defined_type.kind(), &defined_type.name),
+                    &defined_type.name,
+                    tokens.map(|tokens| quote! { #borsh #tokens }),
+                );
+            }
+            for account in &program.accounts {
+                let tokens = render_account(&ctx, account);
+                add(
+                    "accounts",
+                    child(account.kind(), &account.name),
+                    &account.name,
+                    tokens.map(|tokens| quote! { #borsh #tokens }),
+                );
+            }
+            for instruction in &program.instructions {
+                add(
+                    "instructions",
+                    child(instruction.kind(), &instruction.name),
+                    &instruction.name,
+                    render_instruction(&ctx, program, instruction),
+                );
+            }
+            for pda in &program.pdas {
+                add(
+                    "pdas",
+                    child(pda.kind(), &pda.name),
+                    &pda.name,
+                    render_pda(&ctx, program, pda),
+                );
+            }
+            if !program.errors.is_empty() {
+                add(
+                    "errors",
+                    path.clone(),
+                    &program.name,
+                    render_program_errors(program),
+                );
+            }
+        }
+
+        let (source, root_module) = match self.crate_name {
+            Some(_) => ("src/", "lib.rs"),
+            None => ("", "mod.rs"),
+        };
+        let mut map = RenderMap::new();
+        let mut modules = vec![quote! { pub mod programs; }];
+        map.add(
+            format!("{source}programs.rs"),
+            render_file(quote! { #(#program_ids)* })?,
+        );
+        for (folder, files) in folders {
+            let folder_ident = snake_ident(folder);
+            modules.push(quote! { pub mod #folder_ident; });
+            let mut names = vec![];
+            for (name, tokens) in files {
+                map.add(format!("{source}{folder}/{name}.rs"), render_file(tokens)?);
+                names.push(snake_ident(&name));
+            }
+            map.add(
+                format!("{source}{folder}/mod.rs"),
+                render_file(quote! {
+                    #(pub(crate) mod #names;)*
+                    #(pub use self::#names::*;)*
+                })?,
+            );
+        }
+        map.add(
+            format!("{source}{root_module}"),
+            render_file(quote! { #(#modules)* })?,
+        );
+        if let Some(crate_name) = &self.crate_name {
+            map.add("Cargo.toml", cargo_manifest(crate_name));
+        }
+        Ok(RustClient {
+            files: map,
+            warnings,
+        })
+    }
+
+    pub fn render_to_directory<P: AsRef<Path>>(
+        &self,
+        root: &RootNode,
+        directory: P,
+    ) -> CodamaResult<()> {
+        self.render(root)?.write_to_directory(directory)
+    }
+}
+
+fn cargo_manifest(crate_name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{crate_name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+borsh = {{ version = "1.5", features = ["derive"] }}
+solana-instruction = "2.2"
+solana-pubkey = {{ version = "2.2", features = ["borsh", "curve25519"] }}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        ErrorNode, NumberTypeNode, ProgramNode, StructFieldTypeNode, StructTypeNode, U64,
+    };
+
+    fn root() -> RootNode {
+        RootNode::new(
+            ProgramNode::new("counter", "CounterProgram111111111111111111111111111111")
+                .add_defined_type(codama_nodes::DefinedTypeNode::new(
+                    "counterConfig",
+                    StructTypeNode::new(vec![StructFieldTypeNode::new(
+                        "maxCount",
+                        NumberTypeNode::le(U64),
+                    )]),
+                ))
+                .add_error(ErrorNode::new("overflow", 6000, "Counter overflow")),
+        )
+    }
+
+    #[test]
+    fn module_files() {
+        let map = RustRenderer::new().render(&root()).unwrap();
+        assert_eq!(
+            map.paths().collect::<Vec<_>>(),
+            vec![
+                Path::new("errors/counter.rs"),
+                Path::new("errors/mod.rs"),
+                Path::new("mod.rs"),
+                Path::new("programs.rs"),
+                Path::new("types/counter_config.rs"),
+                Path::new("types/mod.rs"),
+            ]
+        );
+        assert!(map
+            .get("mod.rs")
+            .unwrap()
+            .ends_with("pub mod programs;\npub mod errors;\npub mod types;\n"));
+        assert!(map
+            .get("types/mod.rs")
+            .unwrap()
+            .ends_with("pub(crate) mod counter_config;\npub use self::counter_config::*;\n"));
+        assert!(map.get("programs.rs").unwrap().ends_with(
+            "pub const COUNTER_ID: solana_pubkey::Pubkey = solana_pubkey::pubkey!(\n    \"CounterProgram111111111111111111111111111111\"\n);\n"
+        ));
+    }
+
+    #[test]
+    fn crate_files() {
+        let map = RustRenderer::new()
+            .with_crate_name("counter-client")
+            .render(&root())
+            .unwrap();
+        assert!(map
+            .get("Cargo.toml")
+            .unwrap()
+            .contains("name = \"counter-client\""));
+        assert!(map.get("src/lib.rs").is_some());
+        assert!(map.get("src/types/counter_config.rs").is_some());
+        assert!(map.get("mod.rs").is_none());
+    }
+
+    #[test]
+    fn invalid_program_address() {
+        let root = RootNode::new(ProgramNode::new("counter", "not-a-key"));
+        assert!(RustRenderer::new().render(&root).is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::render_context::RenderContext;
+use crate::utils::{is_number, pascal_ident, render_number_type};
+use codama_errors::{CodamaError, CodamaResult};
+use codama_nodes::{
+    CountNode, HasKind, NestedTypeNodeTrait, NumberFormat::U32, NumberFormat::U8, TypeNode, Utf8,
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// Renders the Rust type whose Borsh serialization matches the given type node.
+/// Fixed-size layouts that Borsh cannot describe — e.g. zeroable options, padding or
+/// hidden prefixes — are rendered as raw byte arrays of the same size.
+pub(crate) fn render_type(ctx: &RenderContext, node: &TypeNode) -> CodamaResult<TokenStream> {
+    match render_borsh_type(ctx, node) {
+        Ok(tokens) => Ok(tokens),
+        Err(error) => match ctx.codec.fixed_size(node) {
+            Ok(Some(size)) => {
+                let size = Literal::usize_unsuffixed(size);
+                Ok(quote! { [u8; #size] })
+            }
+            _ => Err(error),
+        },
+    }
+}
+
+fn render_borsh_type(ctx: &RenderContext, node: &TypeNode) -> CodamaResult<TokenStream> {
+    Ok(match node {
+        TypeNode::Boolean(node) if is_number(&node.size, U8) => quote! { bool },
+        TypeNode::Number(node) => render_number_type(node)?,
+        TypeNode::Amount(node) => render_number_type(node.number.get_nested_type_node())?,
+        TypeNode::SolAmount(node) => render_number_type(node.number.get_nested_type_node())?,
+        TypeNode::DateTime(node) => render_number_type(node.number.get_nested_type_node())?,
+        TypeNode::PublicKey(_) => quote! { solana_pubkey::Pubkey },
+        TypeNode::SizePrefix(node) if is_number(&node.prefix, U32) => match node.r#type.as_ref() {
+            TypeNode::String(string) if string.encoding == Utf8 => quote! { String },
+            TypeNode::Bytes(_) => quote! { Vec<u8> },
+            _ => return Err(unsupported(node)),
+        },
+        TypeNode::FixedSize(node) => match node.r#type.as_ref() {
+            TypeNode::Bytes(_) | TypeNode::String(_) => {
+                let size = Literal::usize_unsuffixed(node.size);
+                quote! { [u8; #size] }
+            }
+            _ => return Err(unsupported(node)),
+        },
+        TypeNode::Array(node) => {
+            let item = render_type(ctx, &node.item)?;
+            match &node.count {
+                CountNode::Fixed(count) => {
+                    let size = Literal::usize_unsuffixed(count.value);
+                    quote! { [#item; #size] }
+                }
+                CountNode::Prefixed(count) if is_number(&count.prefix, U32) => {
+                    quote! { Vec<#item> }
+                }
+                _ => return Err(unsupported(node)),
+            }
+        }
+        TypeNode::Set(node) => match &node.count {
+            CountNode::Prefixed(count) if is_number(&count.prefix, U32) => {
+                let item = render_type(ctx, &node.item)?;
+                quote! { std::collections::BTreeSet<#item> }
+            }
+            _ => return Err(unsupported(node)),
+        },
+        TypeNode::Map(node) => match &node.count {
+            CountNode::Prefixed(count) if is_number(&count.prefix, U32) => {
+                let key = render_type(ctx, &node.key)?;
+                let value = render_type(ctx, &node.value)?;
+                quote! { std::collections::BTreeMap<#key, #value> }
+            }
+            _ => return Err(unsupported(node)),
+        },
+        TypeNode::Option(node) if !node.fixed && is_number(&node.prefix, U8) => {
+            let item = render_type(ctx, &node.item)?;
+            quote! { Option<#item> }
+        }
+        TypeNode::Tuple(node) => {
+            let items = node
+                .items
+                .iter()
+                .map(|item| render_type(ctx, item))
+                .collect::<CodamaResult<Vec<_>>>()?;
+            quote! { (#(#items,)*) }
+        }
+        TypeNode::Link(node) => {
+            let types = ctx.module("types");
+            let name = pascal_ident(&node.name);
+            quote! { #types::#name }
+        }
+        node => return Err(unsupported(node)),
+    })
+}
+
+fn unsupported<T: HasKind>(node: &T) -> CodamaError {
+    CodamaError::Rendering(format!(
+        "`{}` cannot be rendered as a Borsh type",
+        node.kind()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codama_nodes::{
+        ArrayTypeNode, BooleanTypeNode, BytesTypeNode, DefinedTypeLinkNode, FixedSizeTypeNode,
+        HiddenPrefixTypeNode, MapTypeNode, NumberTypeNode, OptionTypeNode, PostOffsetTypeNode,
+        ProgramNode, PublicKeyTypeNode, RootNode, SizePrefixTypeNode, StringTypeNode,
+        TupleTypeNode, ZeroableOptionTypeNode, U16, U64,
+    };
+
+    fn render(node: TypeNode) -> CodamaResult<String> {
+        let root = RootNode::new(ProgramNode::new("counter", "1111"));
+        let ctx = RenderContext::new(&root, "crate::generated").unwrap();
+        Ok(render_type(&ctx, &node)?.to_string())
+    }
+
+    #[test]
+    fn primitives() {
+        assert_eq!(render(BooleanTypeNode::default().into()).unwrap(), "bool");
+        assert_eq!(render(NumberTypeNode::le(U64).into()).unwrap(), "u64");
+        assert_eq!(
+            render(PublicKeyTypeNode::new().into()).unwrap(),
+            "solana_pubkey :: Pubkey"
+        );
+        assert_eq!(
+            render(SizePrefixTypeNode::new(StringTypeNode::utf8(), NumberTypeNode::le(U32)).into())
+                .unwrap(),
+            "String"
+        );
+        assert_eq!(
+            render(FixedSizeTypeNode::new(BytesTypeNode::new(), 32).into()).unwrap(),
+            "[u8 ; 32]"
+        );
+    }
+
+    #[test]
+    fn collections() {
+        assert_eq!(
+            render(
+                ArrayTypeNode::prefixed(NumberTypeNode::le(U16), NumberTypeNode::le(U32)).into()
+            )
+            .unwrap(),
+            "Vec < u16 >"
+        );
+        assert_eq!(
+            render(ArrayTypeNode::fixed(NumberTypeNode::le(U16), 4).into()).unwrap(),
+            "[u16 ; 4]"
+        );
+        assert_eq!(
+            render(
+                MapTypeNode::prefixed(
+                    PublicKeyTypeNode::new(),
+                    NumberTypeNode::le(U64),
+                    NumberTypeNode::le(U32)
+                )
+                .into()
+            )
+            .unwrap(),
+            "std :: collections :: BTreeMap < solana_pubkey :: Pubkey , u64 >"
+        );
+        assert_eq!(
+            render(OptionTypeNode::new(NumberTypeNode::le(U64)).into()).unwrap(),
+            "Option < u64 >"
+        );
+        assert_eq!(
+            render(
+                TupleTypeNode::new(vec![
+                    NumberTypeNode::le(U64).into(),
+                    BooleanTypeNode::default().into()
+                ])
+                .into()
+            )
+            .unwrap(),
+            "(u64 , bool ,)"
+        );
+    }
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            render(DefinedTypeLinkNode::new("tokenState").into()).unwrap(),
+            "crate :: generated :: types :: TokenState"
+        );
+    }
+
+    #[test]
+    fn fixed_size_layouts_as_bytes() {
+        assert_eq!(
+            render(OptionTypeNode::fixed(NumberTypeNode::le(U64)).into()).unwrap(),
+            "[u8 ; 9]"
+        );
+        assert_eq!(
+            render(ZeroableOptionTypeNode::new(PublicKeyTypeNode::new()).into()).unwrap(),
+            "[u8 ; 32]"
+        );
+        assert_eq!(
+            render(HiddenPrefixTypeNode::new(NumberTypeNode::le(U64), vec![]).into()).unwrap(),
+            "[u8 ; 8]"
+        );
+        assert_eq!(
+            render(PostOffsetTypeNode::padded(NumberTypeNode::le(U16), 6).into()).unwrap(),
+            "[u8 ; 8]"
+        );
+        assert_eq!(
+            render(BooleanTypeNode::new(NumberTypeNode::le(U64)).into()).unwrap(),
+            "[u8 ; 8]"
+        );
+    }
+
+    #[test]
+    fn unsupported_types() {
+        assert_eq!(
+            render(ArrayTypeNode::remainder(NumberTypeNode::le(U16)).into())
+                .unwrap_err()
+                .to_string(),
+            "Could not render code: `arrayTypeNode` cannot be rendered as a Borsh type"
+        );
+        assert_eq!(
+            render(OptionTypeNode::fixed(StringTypeNode::utf8()).into())
+                .unwrap_err()
+                .to_string(),
+            "Could not render code: `optionTypeNode` cannot be rendered as a Borsh type"
+        );
+    }
+}
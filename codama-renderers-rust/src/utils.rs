@@ -0,0 +1,137 @@
+use codama_errors::{CodamaError, CodamaResult};
+use codama_nodes::{Docs, Endian, NestedTypeNode, NumberFormat, NumberTypeNode};
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+
+const HEADER: [&str; 3] = [
+    " This code was AUTOGENERATED using the Codama library.",
+    " Please DO NOT EDIT THIS FILE, instead use visitors",
+    " to add features, then rerun Codama to update it.",
+];
+
+/// Formats the given items into a Rust file, prefixed with the generated code header.
+pub(crate) fn render_file(tokens: TokenStream) -> CodamaResult<String> {
+    let header = HEADER;
+    let file: syn::File = syn::parse2(quote! {
+        #(#![doc = #header])*
+        #tokens
+    })?;
+    Ok(prettyplease::unparse(&file))
+}
+
+/// A `snake_case` identifier, escaped when it is a Rust keyword.
+pub(crate) fn snake_ident(name: &str) -> Ident {
+    ident(&name.to_snake_case())
+}
+
+/// A `PascalCase` identifier, escaped when it is a Rust keyword.
+pub(crate) fn pascal_ident(name: &str) -> Ident {
+    ident(&name.to_upper_camel_case())
+}
+
+/// A `SCREAMING_SNAKE_CASE` identifier.
+pub(crate) fn shouty_ident(name: &str) -> Ident {
+    ident(&name.to_shouty_snake_case())
+}
+
+fn ident(name: &str) -> Ident {
+    match syn::parse_str::<Ident>(name) {
+        Ok(_) => Ident::new(name, Span::call_site()),
+        Err(_) => Ident::new_raw(name, Span::call_site()),
+    }
+}
+
+pub(crate) fn render_docs(docs: &Docs) -> TokenStream {
+    let docs = docs.iter().map(|doc| format!(" {doc}"));
+    quote! { #(#[doc = #docs])* }
+}
+
+/// A byte array literal — e.g. `[1, 2, 3]`.
+pub(crate) fn render_bytes(bytes: &[u8]) -> TokenStream {
+    let bytes = bytes.iter().map(|byte| Literal::u8_unsuffixed(*byte));
+    quote! { [#(#bytes),*] }
+}
+
+/// A `Pubkey` constant for the given base58 address.
+pub(crate) fn render_pubkey(address: &str) -> CodamaResult<TokenStream> {
+    match bs58::decode(address).into_vec() {
+        Ok(bytes) if bytes.len() == 32 => Ok(quote! { solana_pubkey::pubkey!(#address) }),
+        _ => Err(CodamaError::Rendering(format!(
+            "`{address}` is not a valid public key"
+        ))),
+    }
+}
+
+pub(crate) fn render_number_type(node: &NumberTypeNode) -> CodamaResult<TokenStream> {
+    if node.endian == Endian::Big {
+        return Err(CodamaError::Rendering(
+            "big-endian numbers are not supported by Borsh".to_string(),
+        ));
+    }
+    Ok(match node.format {
+        NumberFormat::U8 => quote! { u8 },
+        NumberFormat::U16 => quote! { u16 },
+        NumberFormat::U32 => quote! { u32 },
+        NumberFormat::U64 => quote! { u64 },
+        NumberFormat::U128 => quote! { u128 },
+        NumberFormat::I8 => quote! { i8 },
+        NumberFormat::I16 => quote! { i16 },
+        NumberFormat::I32 => quote! { i32 },
+        NumberFormat::I64 => quote! { i64 },
+        NumberFormat::I128 => quote! { i128 },
+        NumberFormat::F32 => quote! { f32 },
+        NumberFormat::F64 => quote! { f64 },
+        NumberFormat::ShortU16 => {
+            return Err(CodamaError::Rendering(
+                "`shortU16` numbers are not supported by Borsh".to_string(),
+            ))
+        }
+    })
+}
+
+/// Whether the given number is a little-endian number of the given format.
+pub(crate) fn is_number(node: &NestedTypeNode<NumberTypeNode>, format: NumberFormat) -> bool {
+    matches!(node, NestedTypeNode::Value(number) if number.format == format && number.endian == Endian::Little)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(snake_ident("systemProgram").to_string(), "system_program");
+        assert_eq!(pascal_ident("systemProgram").to_string(), "SystemProgram");
+        assert_eq!(shouty_ident("systemProgram").to_string(), "SYSTEM_PROGRAM");
+        assert_eq!(snake_ident("type").to_string(), "r#type");
+    }
+
+    #[test]
+    fn file_header() {
+        let file = render_file(quote! { pub struct Counter; }).unwrap();
+        assert_eq!(
+            file,
+            "//! This code was AUTOGENERATED using the Codama library.\n//! Please DO NOT EDIT THIS FILE, instead use visitors\n//! to add features, then rerun Codama to update it.\npub struct Counter;\n"
+        );
+    }
+
+    #[test]
+    fn pubkeys() {
+        assert_eq!(
+            render_pubkey("11111111111111111111111111111111")
+                .unwrap()
+                .to_string(),
+            "solana_pubkey :: pubkey ! (\"11111111111111111111111111111111\")"
+        );
+        assert_eq!(
+            render_pubkey("1111").unwrap_err().to_string(),
+            "Could not render code: `1111` is not a valid public key"
+        );
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(render_bytes(&[1, 2, 255]).to_string(), "[1 , 2 , 255]");
+    }
+}
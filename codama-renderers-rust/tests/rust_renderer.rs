@@ -0,0 +1,184 @@
+use codama_nodes::{
+    AccountNode, ArrayTypeNode, BytesTypeNode, BytesValueNode, DefaultValueStrategy,
+    FieldDiscriminatorNode, FixedSizeTypeNode, NumberTypeNode, PostOffsetTypeNode,
+    PublicKeyTypeNode, RootNode, StructFieldTypeNode, StructTypeNode, ZeroableOptionTypeNode, U64,
+    U8,
+};
+use codama_renderers_rust::RustRenderer;
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[rustfmt::skip]
+mod generated;
+
+fn vault_root() -> RootNode {
+    let json = std::fs::read_to_string(fixture_path("fixtures/vault.json")).unwrap();
+    let mut root = RootNode::from_anchor_json(&json).unwrap();
+    root.program.accounts.push(oracle_account());
+    root
+}
+
+/// A zero-copy account, using a zeroable option and padding.
+fn oracle_account() -> AccountNode {
+    let discriminator = StructFieldTypeNode {
+        default_value_strategy: Some(DefaultValueStrategy::Omitted),
+        default_value: Some(BytesValueNode::base16("0102030405060708").into()),
+        ..StructFieldTypeNode::new(
+            "discriminator",
+            FixedSizeTypeNode::new(BytesTypeNode::new(), 8),
+        )
+    };
+    AccountNode {
+        discriminators: vec![FieldDiscriminatorNode::new("discriminator", 0).into()],
+        ..AccountNode::new(
+            "oracle",
+            StructTypeNode::new(vec![
+                discriminator,
+                StructFieldTypeNode::new(
+                    "authority",
+                    ZeroableOptionTypeNode::new(PublicKeyTypeNode::new()),
+                ),
+                StructFieldTypeNode::new(
+                    "decimals",
+                    PostOffsetTypeNode::padded(NumberTypeNode::le(U8), 7),
+                ),
+                StructFieldTypeNode::new("price", NumberTypeNode::le(U64)),
+            ]),
+        )
+    }
+}
+
+fn fixture_path(path: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(path)
+}
+
+#[test]
+fn generated_code_is_up_to_date() {
+    let map = RustRenderer::new().render(&vault_root()).unwrap();
+    let directory = fixture_path("generated");
+    if std::env::var("UPDATE_GENERATED").is_ok() {
+        let _ = std::fs::remove_dir_all(&directory);
+        map.write_to_directory(&directory).unwrap();
+    }
+    for (path, content) in map.iter() {
+        let expected = std::fs::read_to_string(directory.join(path)).unwrap();
+        pretty_assertions::assert_eq!(content, expected, "{} is outdated", path.display());
+    }
+}
+
+#[test]
+fn deposit_instruction() {
+    let owner = Pubkey::new_unique();
+    let instruction = generated::instructions::DepositBuilder::new()
+        .owner(owner)
+        .index(3)
+        .amount(Some(1_000))
+        .instruction();
+
+    let (vault, _) = generated::pdas::find_vault_pda(&owner, 3);
+    assert_eq!(instruction.program_id, generated::programs::VAULT_ID);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(
+                solana_pubkey::pubkey!("11111111111111111111111111111111"),
+                false
+            ),
+        ]
+    );
+    let mut data = generated::instructions::DEPOSIT_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&[3, 1, 232, 3, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(instruction.data, data);
+}
+
+#[test]
+fn optional_accounts() {
+    let owner = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    let instruction = generated::instructions::CloseBuilder::new()
+        .owner(owner)
+        .vault(vault)
+        .instruction();
+    assert_eq!(
+        instruction.accounts[2],
+        AccountMeta::new_readonly(generated::programs::VAULT_ID, false)
+    );
+    assert_eq!(
+        instruction.data,
+        generated::instructions::CLOSE_DISCRIMINATOR
+    );
+}
+
+#[test]
+fn account_decoding() {
+    let vault = generated::accounts::Vault {
+        discriminator: generated::accounts::Vault::DISCRIMINATOR,
+        owner: Pubkey::new_unique(),
+        state: generated::types::State::Locked { until: 42 },
+        seed: [7; 32],
+        history: vec![1, 2],
+        label: "savings".to_string(),
+    };
+    let data = borsh::to_vec(&vault).unwrap();
+    assert!(generated::accounts::Vault::is_discriminated(&data));
+    assert_eq!(
+        generated::accounts::Vault::from_bytes(&data).unwrap(),
+        vault
+    );
+
+    let mut invalid = data.clone();
+    invalid[0] = 0;
+    assert!(generated::accounts::Vault::from_bytes(&invalid).is_err());
+}
+
+#[test]
+fn zero_copy_account_decoding() {
+    assert_eq!(generated::accounts::Oracle::LEN, 56);
+    let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    data.extend_from_slice(&[9; 32]);
+    data.extend_from_slice(&[6, 0, 0, 0, 0, 0, 0, 0]);
+    data.extend_from_slice(&42u64.to_le_bytes());
+
+    let oracle = generated::accounts::Oracle::from_bytes(&data).unwrap();
+    assert_eq!(oracle.authority, [9; 32]);
+    assert_eq!(oracle.decimals, [6, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(oracle.price, 42);
+}
+
+#[test]
+fn unsupported_items_are_skipped() {
+    let mut root = vault_root();
+    root.program.accounts.push(AccountNode::new(
+        "journal",
+        StructTypeNode::new(vec![StructFieldTypeNode::new(
+            "entries",
+            ArrayTypeNode::remainder(NumberTypeNode::le(U64)),
+        )]),
+    ));
+    let client = RustRenderer::new().render_client(&root).unwrap();
+    assert_eq!(
+        client
+            .warnings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec!["warning: skipped because it could not be rendered: Could not render code: `arrayTypeNode` cannot be rendered as a Borsh type (at [programNode]vault.[accountNode]journal)"]
+    );
+    assert!(client.files.get("accounts/journal.rs").is_none());
+    assert!(client.files.get("accounts/oracle.rs").is_some());
+    assert!(client.files.get("instructions/deposit.rs").is_some());
+    assert!(client.files.get("errors/vault.rs").is_some());
+}
+
+#[test]
+fn program_errors() {
+    let error = generated::errors::VaultError::from_code(6001).unwrap();
+    assert_eq!(error, generated::errors::VaultError::Locked);
+    assert_eq!(error.to_string(), "The vault is locked");
+}
@@ -0,0 +1,449 @@
+use cargo_toml::Manifest;
+use std::collections::BTreeSet;
+use syn::{punctuated::Punctuated, visit_mut::VisitMut, Token};
+
+/// The configuration used to evaluate `#[cfg(...)]` and `#[cfg_attr(...)]` attributes.
+///
+/// By default, all features are enabled so that `#[cfg_attr(feature = "...", ...)]`
+/// directives apply, no `target_os` is set and custom options — including `test` — are
+/// disabled. Use [`CfgContext::with_default_features`] to only enable the features
+/// that the crate enables by default.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CfgContext {
+    features: Features,
+    target_os: Option<String>,
+    options: BTreeSet<(String, Option<String>)>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+enum Features {
+    #[default]
+    All,
+    /// The default features of the crate, resolved from its manifest.
+    Default,
+    Only(BTreeSet<String>),
+}
+
+impl CfgContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable all features.
+    pub fn with_all_features(mut self) -> Self {
+        self.features = Features::All;
+        self
+    }
+
+    /// Only enable the default features of the crate, resolved from its manifest when it is loaded.
+    pub fn with_default_features(mut self) -> Self {
+        self.features = Features::Default;
+        self
+    }
+
+    /// Only enable the given features.
+    pub fn with_features<I, T>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.features = Features::Only(features.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Enable a feature, disabling all features that are not explicitly enabled.
+    pub fn with_feature<T: Into<String>>(mut self, feature: T) -> Self {
+        match &mut self.features {
+            Features::Only(features) => {
+                features.insert(feature.into());
+            }
+            _ => self.features = Features::Only(BTreeSet::from([feature.into()])),
+        }
+        self
+    }
+
+    pub fn with_target_os<T: Into<String>>(mut self, target_os: T) -> Self {
+        self.target_os = Some(target_os.into());
+        self
+    }
+
+    /// Enable a custom option, e.g. `test` or `devnet`.
+    pub fn with_cfg<T: Into<String>>(mut self, name: T) -> Self {
+        self.options.insert((name.into(), None));
+        self
+    }
+
+    /// Enable a custom key/value option, e.g. `network = "devnet"`.
+    pub fn with_cfg_value<T: Into<String>, U: Into<String>>(mut self, name: T, value: U) -> Self {
+        self.options.insert((name.into(), Some(value.into())));
+        self
+    }
+
+    /// Resolve the default features from the given manifest, including the features
+    /// they enable, when the default features were requested.
+    pub fn with_manifest(mut self, manifest: &Manifest) -> Self {
+        if self.features != Features::Default {
+            return self;
+        }
+        let mut enabled = BTreeSet::new();
+        let mut pending = vec!["default".to_string()];
+        while let Some(feature) = pending.pop() {
+            let Some(children) = manifest.features.get(&feature) else {
+                continue;
+            };
+            for child in children {
+                // Skip dependency features such as `dep:serde` or `serde/std`.
+                if child.starts_with("dep:") || child.contains('/') {
+                    continue;
+                }
+                if enabled.insert(child.clone()) {
+                    pending.push(child.clone());
+                }
+            }
+        }
+        self.features = Features::Only(enabled);
+        self
+    }
+
+    /// Whether a single configuration option — e.g. `test` or `feature = "idl"` — is active.
+    /// Features are disabled when the default features were not resolved from a manifest.
+    pub fn is_option_active(&self, name: &str, value: Option<&str>) -> bool {
+        match (name, value) {
+            ("feature", Some(feature)) => match &self.features {
+                Features::Default => false,
+                Features::All => true,
+                Features::Only(features) => features.contains(feature),
+            },
+            ("target_os", Some(target_os)) => self.target_os.as_deref() == Some(target_os),
+            _ => self
+                .options
+                .contains(&(name.to_string(), value.map(str::to_string))),
+        }
+    }
+
+    /// Whether a configuration predicate, including `all`, `any` and `not`, is active.
+    pub fn is_active(&self, predicate: &syn::Meta) -> syn::Result<bool> {
+        match predicate {
+            syn::Meta::Path(path) => Ok(self.is_option_active(&path_name(path), None)),
+            syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(value),
+                        ..
+                    }),
+                ..
+            }) => Ok(self.is_option_active(&path_name(path), Some(&value.value()))),
+            syn::Meta::NameValue(meta) => Err(syn::Error::new_spanned(
+                &meta.value,
+                "expected a string literal",
+            )),
+            syn::Meta::List(list) => {
+                let predicates =
+                    list.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)?;
+                match path_name(&list.path).as_str() {
+                    "all" => predicates.iter().try_fold(true, |active, predicate| {
+                        Ok(self.is_active(predicate)? && active)
+                    }),
+                    "any" => predicates.iter().try_fold(false, |active, predicate| {
+                        Ok(self.is_active(predicate)? || active)
+                    }),
+                    "not" => match predicates.first() {
+                        Some(predicate) if predicates.len() == 1 => Ok(!self.is_active(predicate)?),
+                        _ => Err(syn::Error::new_spanned(list, "expected a single predicate")),
+                    },
+                    _ => Err(syn::Error::new_spanned(
+                        &list.path,
+                        "expected `all`, `any` or `not`",
+                    )),
+                }
+            }
+        }
+    }
+
+    /// Whether all `#[cfg(...)]` attributes of an item are active.
+    pub fn is_enabled(&self, attrs: &[syn::Attribute]) -> syn::Result<bool> {
+        attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .try_fold(true, |enabled, attr| {
+                Ok(self.is_active(&attr.parse_args::<syn::Meta>()?)? && enabled)
+            })
+    }
+
+    /// Remove the items, fields and variants disabled by `#[cfg(...)]` attributes
+    /// and replace `#[cfg_attr(...)]` attributes with their content when active.
+    pub fn apply(&self, file: &mut syn::File) -> syn::Result<()> {
+        let mut stripper = CfgStripper {
+            cfg: self,
+            errors: vec![],
+        };
+        stripper.visit_file_mut(file);
+        match stripper.errors.into_iter().reduce(|mut error, other| {
+            error.combine(other);
+            error
+        }) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Replace `#[cfg_attr(...)]` attributes with their content when active, or remove them.
+    fn expand_cfg_attrs(&self, attrs: &mut Vec<syn::Attribute>) -> syn::Result<()> {
+        let mut expanded = Vec::with_capacity(attrs.len());
+        for attr in std::mem::take(attrs) {
+            if !attr.path().is_ident("cfg_attr") {
+                expanded.push(attr);
+                continue;
+            }
+            let metas =
+                attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)?;
+            let mut metas = metas.into_iter();
+            let Some(predicate) = metas.next() else {
+                return Err(syn::Error::new_spanned(attr, "expected a predicate"));
+            };
+            if !self.is_active(&predicate)? {
+                continue;
+            }
+            let mut inner = metas
+                .map(|meta| syn::Attribute {
+                    meta,
+                    ..attr.clone()
+                })
+                .collect::<Vec<_>>();
+            // Nested `cfg_attr` attributes are expanded recursively.
+            self.expand_cfg_attrs(&mut inner)?;
+            expanded.extend(inner);
+        }
+        *attrs = expanded;
+        Ok(())
+    }
+
+    /// Expand the attributes of a node and tell whether it is enabled.
+    fn configure(&self, attrs: Option<&mut Vec<syn::Attribute>>) -> syn::Result<bool> {
+        match attrs {
+            Some(attrs) => {
+                self.expand_cfg_attrs(attrs)?;
+                self.is_enabled(attrs)
+            }
+            None => Ok(true),
+        }
+    }
+}
+
+/// Strips disabled nodes, keeping the errors of invalid predicates until the end.
+/// Nodes with invalid predicates are kept.
+struct CfgStripper<'a> {
+    cfg: &'a CfgContext,
+    errors: Vec<syn::Error>,
+}
+
+impl CfgStripper<'_> {
+    fn configure(&mut self, attrs: Option<&mut Vec<syn::Attribute>>) -> bool {
+        self.cfg.configure(attrs).unwrap_or_else(|error| {
+            self.errors.push(error);
+            true
+        })
+    }
+
+    fn retain_items(&mut self, items: &mut Vec<syn::Item>) {
+        items.retain_mut(|item| self.configure(item_attributes_mut(item)));
+    }
+}
+
+impl VisitMut for CfgStripper<'_> {
+    fn visit_file_mut(&mut self, file: &mut syn::File) {
+        if let Err(error) = self.cfg.expand_cfg_attrs(&mut file.attrs) {
+            self.errors.push(error);
+        }
+        self.retain_items(&mut file.items);
+        syn::visit_mut::visit_file_mut(self, file);
+    }
+
+    fn visit_item_mod_mut(&mut self, item: &mut syn::ItemMod) {
+        if let Some((_, items)) = &mut item.content {
+            self.retain_items(items);
+        }
+        syn::visit_mut::visit_item_mod_mut(self, item);
+    }
+
+    fn visit_item_impl_mut(&mut self, item: &mut syn::ItemImpl) {
+        item.items
+            .retain_mut(|item| self.configure(impl_item_attributes_mut(item)));
+        syn::visit_mut::visit_item_impl_mut(self, item);
+    }
+
+    fn visit_item_enum_mut(&mut self, item: &mut syn::ItemEnum) {
+        retain_punctuated(&mut item.variants, |variant| {
+            self.configure(Some(&mut variant.attrs))
+        });
+        syn::visit_mut::visit_item_enum_mut(self, item);
+    }
+
+    fn visit_fields_mut(&mut self, fields: &mut syn::Fields) {
+        let punctuated = match fields {
+            syn::Fields::Named(fields) => &mut fields.named,
+            syn::Fields::Unnamed(fields) => &mut fields.unnamed,
+            syn::Fields::Unit => return,
+        };
+        retain_punctuated(punctuated, |field| self.configure(Some(&mut field.attrs)));
+        syn::visit_mut::visit_fields_mut(self, fields);
+    }
+}
+
+/// Retain the matching elements of a punctuated list, preserving its trailing punctuation.
+fn retain_punctuated<T, P: Default>(
+    punctuated: &mut Punctuated<T, P>,
+    mut f: impl FnMut(&mut T) -> bool,
+) {
+    let trailing = punctuated.trailing_punct();
+    let mut retained = std::mem::take(punctuated)
+        .into_iter()
+        .filter_map(|mut value| f(&mut value).then_some(value))
+        .collect::<Punctuated<T, P>>();
+    if trailing && !retained.empty_or_trailing() {
+        retained.push_punct(P::default());
+    }
+    *punctuated = retained;
+}
+
+fn item_attributes_mut(item: &mut syn::Item) -> Option<&mut Vec<syn::Attribute>> {
+    match item {
+        syn::Item::Const(item) => Some(&mut item.attrs),
+        syn::Item::Enum(item) => Some(&mut item.attrs),
+        syn::Item::ExternCrate(item) => Some(&mut item.attrs),
+        syn::Item::Fn(item) => Some(&mut item.attrs),
+        syn::Item::ForeignMod(item) => Some(&mut item.attrs),
+        syn::Item::Impl(item) => Some(&mut item.attrs),
+        syn::Item::Macro(item) => Some(&mut item.attrs),
+        syn::Item::Mod(item) => Some(&mut item.attrs),
+        syn::Item::Static(item) => Some(&mut item.attrs),
+        syn::Item::Struct(item) => Some(&mut item.attrs),
+        syn::Item::Trait(item) => Some(&mut item.attrs),
+        syn::Item::TraitAlias(item) => Some(&mut item.attrs),
+        syn::Item::Type(item) => Some(&mut item.attrs),
+        syn::Item::Union(item) => Some(&mut item.attrs),
+        syn::Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+fn impl_item_attributes_mut(item: &mut syn::ImplItem) -> Option<&mut Vec<syn::Attribute>> {
+    match item {
+        syn::ImplItem::Const(item) => Some(&mut item.attrs),
+        syn::ImplItem::Fn(item) => Some(&mut item.attrs),
+        syn::ImplItem::Type(item) => Some(&mut item.attrs),
+        syn::ImplItem::Macro(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+fn path_name(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(cfg: &CfgContext, predicate: &str) -> syn::Result<bool> {
+        cfg.is_active(&syn::parse_str::<syn::Meta>(predicate).unwrap())
+    }
+
+    fn manifest(features: &str) -> Manifest {
+        Manifest::from_str(&format!(
+            "[package]\nname = \"test\"\nversion = \"0.0.0\"\n\n[features]\n{features}"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn all_features_by_default() {
+        let cfg = CfgContext::new().with_manifest(&manifest("default = []\ncodama = []"));
+        assert!(evaluate(&cfg, "feature = \"codama\"").unwrap());
+        assert!(!evaluate(&cfg, "not(feature = \"codama\")").unwrap());
+    }
+
+    #[test]
+    fn default_features_are_disabled_without_manifest() {
+        let cfg = CfgContext::new().with_default_features();
+        assert!(!evaluate(&cfg, "feature = \"idl\"").unwrap());
+        assert!(evaluate(&cfg, "not(feature = \"idl\")").unwrap());
+    }
+
+    #[test]
+    fn default_features_from_manifest() {
+        let cfg = CfgContext::new().with_default_features().with_manifest(&manifest(
+            "default = [\"client\", \"dep:serde\", \"serde/std\"]\nclient = [\"idl\"]\nidl = []\nmainnet = []",
+        ));
+        assert!(evaluate(&cfg, "feature = \"client\"").unwrap());
+        assert!(evaluate(&cfg, "feature = \"idl\"").unwrap());
+        assert!(!evaluate(&cfg, "feature = \"mainnet\"").unwrap());
+        assert!(!evaluate(&cfg, "feature = \"serde\"").unwrap());
+    }
+
+    #[test]
+    fn explicit_features_ignore_manifest() {
+        let manifest = manifest("default = [\"client\"]\nclient = []\nmainnet = []");
+        let cfg = CfgContext::new()
+            .with_feature("mainnet")
+            .with_manifest(&manifest);
+        assert!(!evaluate(&cfg, "feature = \"client\"").unwrap());
+        assert!(evaluate(&cfg, "feature = \"mainnet\"").unwrap());
+    }
+
+    #[test]
+    fn all_features() {
+        let cfg = CfgContext::new()
+            .with_default_features()
+            .with_all_features()
+            .with_manifest(&manifest("default = []"));
+        assert!(evaluate(&cfg, "feature = \"anything\"").unwrap());
+        assert!(!evaluate(&cfg, "not(feature = \"anything\")").unwrap());
+    }
+
+    #[test]
+    fn invalid_predicates() {
+        let cfg = CfgContext::new();
+        assert!(evaluate(&cfg, "feature = 42").is_err());
+        assert!(evaluate(&cfg, "not(test, unix)").is_err());
+        assert!(evaluate(&cfg, "either(test, unix)").is_err());
+        assert!(evaluate(&cfg, "all(test, any(unix, feature = 42))").is_err());
+    }
+
+    #[test]
+    fn is_enabled() {
+        let cfg = CfgContext::new().with_cfg("test");
+        let item: syn::ItemStruct = syn::parse_quote! {
+            #[cfg(test)]
+            #[cfg(not(unix))]
+            struct Foo;
+        };
+        assert!(cfg.is_enabled(&item.attrs).unwrap());
+        let item: syn::ItemStruct = syn::parse_quote! {
+            #[cfg(test)]
+            #[cfg(unix)]
+            struct Foo;
+        };
+        assert!(!cfg.is_enabled(&item.attrs).unwrap());
+    }
+
+    #[test]
+    fn apply_with_invalid_predicates() {
+        let mut file: syn::File = syn::parse_quote! {
+            #[cfg(feature = 42)]
+            pub struct Foo;
+
+            pub struct Bar {
+                #[cfg_attr(either(test), codama(name = "baz"))]
+                pub baz: u8,
+            }
+        };
+        let error = CfgContext::new().apply(&mut file).unwrap_err();
+        assert_eq!(error.into_iter().count(), 2);
+    }
+}
@@ -5,7 +5,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::FileModuleStore;
+use crate::{CfgContext, FileModuleStore};
 
 #[derive(Debug, PartialEq)]
 pub struct CrateStore {
@@ -17,6 +17,11 @@ pub struct CrateStore {
 
 impl CrateStore {
     pub fn load<P: AsRef<Path>>(path: P) -> CodamaResult<Self> {
+        Self::load_with_cfg(path, &CfgContext::default())
+    }
+
+    /// Load a crate, removing everything disabled by the given `cfg` context.
+    pub fn load_with_cfg<P: AsRef<Path>>(path: P, cfg: &CfgContext) -> CodamaResult<Self> {
         // Find and load the closest Cargo.toml file — a.k.a. the crate's manifest.
         let manifest_path = get_closest_manifest_path(path.as_ref())?;
         let mut manifest = Manifest::from_path(&manifest_path)?;
@@ -26,12 +31,14 @@ impl CrateStore {
         let relative_product_path = get_product_path(&manifest)?;
         let product_path = manifest_path.parent().unwrap().join(relative_product_path);
 
-        // Load the crate's content and parse it.
+        // Load the crate's content and parse it using the configured features.
+        let cfg = cfg.clone().with_manifest(&manifest);
         let content = fs::read_to_string(&product_path)?;
-        let file = syn::parse_file(&content)?;
+        let mut file = syn::parse_file(&content)?;
+        cfg.apply(&mut file)?;
 
         // Load all external modules from the crate's content.
-        let modules = FileModuleStore::load_all(&product_path, &file.items)?;
+        let modules = FileModuleStore::load_all(&product_path, &file.items, &cfg)?;
 
         Ok(Self {
             file,
@@ -49,6 +56,23 @@ impl CrateStore {
             path: PathBuf::new(),
         })
     }
+
+    /// The package name of the crate, as defined in its manifest.
+    pub fn name(&self) -> Option<&str> {
+        self.manifest
+            .as_ref()
+            .and_then(|manifest| manifest.package.as_ref())
+            .map(|package| package.name.as_str())
+    }
+
+    /// Whether any item of the crate can be read by Codama, Anchor or Shank plugins.
+    pub fn has_program_items(&self) -> bool {
+        has_program_items(&self.file.items)
+            || self
+                .file_modules
+                .iter()
+                .any(FileModuleStore::has_program_items)
+    }
 }
 
 /// Given a path, get the closest available path to a Cargo.toml file.
@@ -81,6 +105,80 @@ pub fn get_closest_manifest_path<P: AsRef<Path>>(path: P) -> CodamaResult<PathBu
     Err(std::io::Error::new(std::io::ErrorKind::NotFound, "Cargo.toml not found").into())
 }
 
+/// Whether any of the given items — or their nested inline modules —
+/// can be read by one of the default plugins. That is, items using a `Codama*`
+/// derive or a `codama` attribute, Anchor markers such as `#[program]`,
+/// `#[account]`, `#[error_code]` or `declare_id!`, or `Shank*` derives.
+pub(crate) fn has_program_items(items: &[syn::Item]) -> bool {
+    items.iter().any(|item| match item {
+        syn::Item::Mod(syn::ItemMod {
+            attrs,
+            content: Some((_, items)),
+            ..
+        }) => has_program_attributes(attrs) || has_program_items(items),
+        syn::Item::Macro(syn::ItemMacro { mac, .. }) => mac.path.is_ident("declare_id"),
+        syn::Item::Struct(syn::ItemStruct { attrs, .. })
+        | syn::Item::Enum(syn::ItemEnum { attrs, .. })
+        | syn::Item::Union(syn::ItemUnion { attrs, .. })
+        | syn::Item::Const(syn::ItemConst { attrs, .. })
+        | syn::Item::Type(syn::ItemType { attrs, .. })
+        | syn::Item::Mod(syn::ItemMod { attrs, .. }) => has_program_attributes(attrs),
+        _ => false,
+    })
+}
+
+/// Anchor attributes read by the Anchor plugin, e.g. `#[account]` or `#[anchor_lang::account]`.
+const ANCHOR_ATTRIBUTES: [&str; 4] = ["program", "account", "zero_copy", "error_code"];
+
+/// Derives read by the Anchor plugin, besides `Codama*` and `Shank*` derives.
+const ANCHOR_DERIVES: [&str; 3] = ["Accounts", "AnchorSerialize", "AnchorDeserialize"];
+
+fn has_program_attributes(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let path = attr.path();
+        if is_program_attribute(path) {
+            return true;
+        }
+        // E.g. `#[derive(CodamaAccount)]` or `#[cfg_attr(feature = "idl", derive(CodamaType))]`.
+        match &attr.meta {
+            syn::Meta::List(list) if path.is_ident("derive") || path.is_ident("cfg_attr") => {
+                has_program_idents(list.tokens.clone())
+            }
+            _ => false,
+        }
+    })
+}
+
+/// Whether an attribute path is `codama` or one of the `ANCHOR_ATTRIBUTES`, either used
+/// directly or through the `anchor_lang` crate — e.g. `anchor_lang::prelude::account`.
+fn is_program_attribute(path: &syn::Path) -> bool {
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    match segments.as_slice() {
+        [name] => name == "codama" || ANCHOR_ATTRIBUTES.contains(&name.as_str()),
+        [first, .., last] if first == "codama" => last == "codama",
+        [first, .., last] if first == "anchor_lang" => ANCHOR_ATTRIBUTES.contains(&last.as_str()),
+        _ => false,
+    }
+}
+
+fn has_program_idents(tokens: proc_macro2::TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => {
+            let ident = ident.to_string();
+            ident == "codama"
+                || ident.starts_with("Codama")
+                || ident.starts_with("Shank")
+                || ANCHOR_DERIVES.contains(&ident.as_str())
+        }
+        proc_macro2::TokenTree::Group(group) => has_program_idents(group.stream()),
+        _ => false,
+    })
+}
+
 fn get_product_path(manifest: &Manifest) -> CodamaResult<PathBuf> {
     let product = get_product_candidates(manifest)
         .iter()
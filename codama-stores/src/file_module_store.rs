@@ -1,3 +1,5 @@
+use crate::crate_store::has_program_items;
+use crate::CfgContext;
 use codama_errors::{CodamaResult, IteratorCombineErrors};
 use std::path::{Path, PathBuf};
 
@@ -6,44 +8,57 @@ pub struct FileModuleStore {
     pub file: syn::File,
     pub file_modules: Vec<FileModuleStore>,
     pub path: PathBuf,
+    /// The `mod` declaration that loaded this file.
+    pub item: syn::ItemMod,
 }
 
 impl FileModuleStore {
-    pub fn load_all(path: &Path, items: &[syn::Item]) -> CodamaResult<Vec<Self>> {
+    pub fn load_all(path: &Path, items: &[syn::Item], cfg: &CfgContext) -> CodamaResult<Vec<Self>> {
         find_nested_file_modules(items)
             .iter()
-            .map(|&item| FileModuleStore::load(path, item))
+            .map(|&item| FileModuleStore::load(path, item, cfg))
             .collect_and_combine_errors()
     }
 
-    pub fn load(path: &Path, item: &syn::ItemMod) -> CodamaResult<Self> {
+    pub fn load(path: &Path, item: &syn::ItemMod, cfg: &CfgContext) -> CodamaResult<Self> {
         let parent_directory = path.parent().unwrap();
         let filename = path.file_stem().unwrap().to_str().unwrap();
         let current_directory = parent_directory.join(filename);
 
-        let candidates = vec![
-            // If we are in a mod.rs or lib.rs file, the modules will be in a sibling directory.
-            parent_directory.join(format!("{}.rs", item.ident)),
-            parent_directory.join(format!("{}/mod.rs", item.ident)),
-            // Otherwise, the modules will be in a child directory.
-            current_directory.join(format!("{}.rs", item.ident)),
-            current_directory.join(format!("{}/mod.rs", item.ident)),
-        ];
+        let candidates = match get_path_attribute(item) {
+            // A `#[path = "..."]` attribute is relative to the directory of the declaring file.
+            Some(relative_path) => vec![parent_directory.join(relative_path)],
+            None => vec![
+                // If we are in a mod.rs or lib.rs file, the modules will be in a sibling directory.
+                parent_directory.join(format!("{}.rs", item.ident)),
+                parent_directory.join(format!("{}/mod.rs", item.ident)),
+                // Otherwise, the modules will be in a child directory.
+                current_directory.join(format!("{}.rs", item.ident)),
+                current_directory.join(format!("{}/mod.rs", item.ident)),
+            ],
+        };
 
         let path = candidates
             .into_iter()
             .find(|p| p.exists())
             .ok_or_else(|| syn::Error::new_spanned(item, "could not read file"))?;
         let content = std::fs::read_to_string(&path)?;
-        let file = syn::parse_file(&content)?;
-        let modules = Self::load_all(&path, &file.items)?;
+        let mut file = syn::parse_file(&content)?;
+        cfg.apply(&mut file)?;
+        let modules = Self::load_all(&path, &file.items, cfg)?;
 
         Ok(Self {
             file,
             file_modules: modules,
             path,
+            item: item.clone(),
         })
     }
+
+    /// Whether any item of the module can be read by Codama, Anchor or Shank plugins.
+    pub fn has_program_items(&self) -> bool {
+        has_program_items(&self.file.items) || self.file_modules.iter().any(Self::has_program_items)
+    }
 }
 
 fn find_nested_file_modules(items: &[syn::Item]) -> Vec<&syn::ItemMod> {
@@ -60,3 +75,18 @@ fn find_nested_file_modules(items: &[syn::Item]) -> Vec<&syn::ItemMod> {
         .flatten()
         .collect()
 }
+
+fn get_path_attribute(item: &syn::ItemMod) -> Option<String> {
+    item.attrs.iter().find_map(|attr| match &attr.meta {
+        syn::Meta::NameValue(syn::MetaNameValue {
+            path,
+            value:
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                }),
+            ..
+        }) if path.is_ident("path") => Some(value.value()),
+        _ => None,
+    })
+}
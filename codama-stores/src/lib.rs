@@ -1,7 +1,9 @@
+mod cfg_context;
 mod crate_store;
 mod file_module_store;
 mod root_store;
 
+pub use cfg_context::*;
 pub use crate_store::*;
 pub use file_module_store::*;
 pub use root_store::*;
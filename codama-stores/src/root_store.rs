@@ -1,6 +1,7 @@
-use crate::CrateStore;
+use crate::{get_closest_manifest_path, CfgContext, CrateStore};
+use cargo_toml::Manifest;
 use codama_errors::{CodamaResult, IteratorCombineErrors};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, PartialEq)]
 pub struct RootStore {
@@ -13,17 +14,105 @@ impl RootStore {
     }
 
     pub fn load_all<P: AsRef<Path>>(paths: &[P]) -> CodamaResult<Self> {
+        Self::load_all_with_cfg(paths, &CfgContext::default())
+    }
+
+    /// Load crates, removing everything disabled by the given `cfg` context.
+    pub fn load_all_with_cfg<P: AsRef<Path>>(paths: &[P], cfg: &CfgContext) -> CodamaResult<Self> {
         Ok(Self {
             crates: paths
                 .iter()
-                .map(CrateStore::load)
+                .map(|path| CrateStore::load_with_cfg(path, cfg))
                 .collect_and_combine_errors()?,
         })
     }
 
+    /// Load all members of a Cargo workspace that Codama, Anchor or Shank plugins can read.
+    ///
+    /// Members are resolved from the `members` and `exclude` globs of the
+    /// `[workspace]` table, in the order they are declared.
+    pub fn load_workspace<P: AsRef<Path>>(path: P) -> CodamaResult<Self> {
+        Self::load_workspace_with_cfg(path, &CfgContext::default())
+    }
+
+    /// Load all members of a Cargo workspace using the given `cfg` context.
+    pub fn load_workspace_with_cfg<P: AsRef<Path>>(
+        path: P,
+        cfg: &CfgContext,
+    ) -> CodamaResult<Self> {
+        let manifest_path = get_closest_manifest_path(path)?;
+        let manifest = Manifest::from_path(&manifest_path)?;
+        let Some(workspace) = &manifest.workspace else {
+            return Err(cargo_toml::Error::Other("No [workspace] found in Cargo.toml").into());
+        };
+        let workspace_path = manifest_path.parent().unwrap();
+
+        // The root manifest is a member too when it also defines a package.
+        let mut members = Vec::new();
+        if manifest.package.is_some() {
+            members.push(workspace_path.to_path_buf());
+        }
+        for member in expand_globs(workspace_path, &workspace.members)? {
+            if !members.contains(&member) {
+                members.push(member);
+            }
+        }
+        let excluded = expand_globs(workspace_path, &workspace.exclude)?;
+        members.retain(|member| !excluded.iter().any(|path| member.starts_with(path)));
+
+        // Members that fail to load are skipped unless they depend on Codama, Anchor or Shank.
+        let crates = members
+            .iter()
+            .filter_map(|member| match CrateStore::load_with_cfg(member, cfg) {
+                Ok(crate_store) => Some(Ok(crate_store)),
+                Err(error) if has_program_dependencies(member) => Some(Err(error)),
+                Err(_) => None,
+            })
+            .collect_and_combine_errors()?;
+        Ok(Self {
+            crates: crates
+                .into_iter()
+                .filter(CrateStore::has_program_items)
+                .collect(),
+        })
+    }
+
     pub fn hydrate(tt: proc_macro2::TokenStream) -> CodamaResult<Self> {
         Ok(Self {
             crates: vec![CrateStore::hydrate(tt)?],
         })
     }
 }
+
+/// Whether the manifest of a crate depends on Codama, Anchor or Shank crates.
+/// Unreadable manifests are assumed to do so.
+fn has_program_dependencies(path: &Path) -> bool {
+    let Ok(manifest) = std::fs::read(path.join("Cargo.toml"))
+        .map_err(cargo_toml::Error::from)
+        .and_then(|content| Manifest::from_slice(&content))
+    else {
+        return true;
+    };
+    manifest.dependencies.iter().any(|(name, dependency)| {
+        let name = dependency.package().unwrap_or(name);
+        name.starts_with("codama") || name.starts_with("anchor-") || name.starts_with("shank")
+    })
+}
+
+/// Expand workspace globs — e.g. `programs/*` — into the crate directories they match.
+fn expand_globs(workspace_path: &Path, patterns: &[String]) -> CodamaResult<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for pattern in patterns {
+        let pattern = workspace_path.join(pattern);
+        let entries = glob::glob(&pattern.to_string_lossy()).map_err(|error| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, error.to_string())
+        })?;
+        for entry in entries {
+            let path = entry.map_err(glob::GlobError::into_error)?;
+            if path.join("Cargo.toml").is_file() {
+                paths.push(path);
+            }
+        }
+    }
+    Ok(paths)
+}
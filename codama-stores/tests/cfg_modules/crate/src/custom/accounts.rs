@@ -0,0 +1,3 @@
+pub struct Counter {
+    pub count: u64,
+}
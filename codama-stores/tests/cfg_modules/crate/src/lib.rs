@@ -0,0 +1,19 @@
+#[path = "custom/accounts.rs"]
+pub mod accounts;
+mod utils;
+
+#[cfg(feature = "client")]
+mod client;
+
+#[cfg(not(test))]
+mod runtime;
+
+// This file does not exist.
+#[cfg(test)]
+mod tests;
+
+#[cfg(all(test, feature = "client"))]
+mod client_tests {
+    // This file does not exist either.
+    mod helpers;
+}
@@ -0,0 +1,131 @@
+use crate::get_path;
+use codama_stores::{CfgContext, CrateStore, RootStore};
+
+#[test]
+fn load_cfg_modules() {
+    let path = get_path("cfg_modules/crate");
+    let root_store =
+        RootStore::load_all_with_cfg(&[&path], &CfgContext::new().with_default_features()).unwrap();
+    let crate_store = &root_store.crates[0];
+
+    // Path attributes are resolved, default features are enabled and test modules are skipped.
+    let paths = crate_store
+        .file_modules
+        .iter()
+        .map(|module| module.path.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            get_path("cfg_modules/crate/src/custom/accounts.rs"),
+            get_path("cfg_modules/crate/src/utils.rs"),
+            get_path("cfg_modules/crate/src/client.rs"),
+            get_path("cfg_modules/crate/src/runtime.rs"),
+        ]
+    );
+    assert_eq!(crate_store.file_modules[0].item.ident, "accounts");
+}
+
+#[test]
+fn load_cfg_modules_without_features() {
+    let path = get_path("cfg_modules/crate");
+    let crate_store = CrateStore::load_with_cfg(
+        &path,
+        &CfgContext::new().with_features(Vec::<String>::new()),
+    )
+    .unwrap();
+    let idents = crate_store
+        .file_modules
+        .iter()
+        .map(|module| module.item.ident.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(idents, vec!["accounts", "utils", "runtime"]);
+}
+
+#[test]
+fn evaluate_predicates() {
+    let evaluate = |predicate: &str| {
+        let predicate = syn::parse_str::<syn::Meta>(predicate).unwrap();
+        CfgContext::default().is_active(&predicate).unwrap()
+    };
+    assert!(!evaluate("test"));
+    assert!(evaluate("not(test)"));
+    assert!(evaluate("feature = \"idl\""));
+    assert!(!evaluate("all(feature = \"idl\", test)"));
+    assert!(!evaluate("any(test, target_os = \"solana\")"));
+    assert!(!evaluate("any(test, unix)"));
+    assert!(evaluate("any(test, not(unix))"));
+}
+
+#[test]
+fn evaluate_predicates_with_context() {
+    let cfg = CfgContext::new()
+        .with_feature("devnet")
+        .with_target_os("solana")
+        .with_cfg("test")
+        .with_cfg_value("network", "devnet");
+    let evaluate = |predicate: &str| {
+        let predicate = syn::parse_str::<syn::Meta>(predicate).unwrap();
+        cfg.is_active(&predicate).unwrap()
+    };
+    assert!(evaluate("test"));
+    assert!(evaluate("feature = \"devnet\""));
+    assert!(!evaluate("feature = \"mainnet\""));
+    assert!(evaluate("target_os = \"solana\""));
+    assert!(!evaluate("target_os = \"linux\""));
+    assert!(evaluate("network = \"devnet\""));
+    assert!(!evaluate("network = \"mainnet\""));
+    assert!(evaluate("all(test, not(feature = \"mainnet\"))"));
+}
+
+#[test]
+fn apply_to_file() {
+    let mut file: syn::File = syn::parse_quote! {
+        #[cfg_attr(feature = "devnet", derive(CodamaAccount))]
+        pub struct Faucet {
+            #[cfg_attr(feature = "devnet", cfg_attr(test, codama(name = "test_amount")))]
+            pub amount: u64,
+            #[cfg(feature = "mainnet")]
+            pub fee: u64,
+        }
+
+        #[cfg(feature = "mainnet")]
+        pub struct Fees;
+
+        pub enum Cluster {
+            #[cfg(feature = "devnet")]
+            Devnet,
+            #[cfg(feature = "mainnet")]
+            Mainnet,
+        }
+
+        mod inline {
+            #[cfg(test)]
+            pub struct Helper(#[cfg(feature = "devnet")] u8, u16);
+        }
+    };
+    CfgContext::new()
+        .with_feature("devnet")
+        .with_cfg("test")
+        .apply(&mut file)
+        .unwrap();
+
+    let expected: syn::File = syn::parse_quote! {
+        #[derive(CodamaAccount)]
+        pub struct Faucet {
+            #[codama(name = "test_amount")]
+            pub amount: u64,
+        }
+
+        pub enum Cluster {
+            #[cfg(feature = "devnet")]
+            Devnet,
+        }
+
+        mod inline {
+            #[cfg(test)]
+            pub struct Helper(#[cfg(feature = "devnet")] u8, u16);
+        }
+    };
+    assert_eq!(file, expected);
+}
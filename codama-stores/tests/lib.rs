@@ -1,6 +1,8 @@
+mod cfg_modules;
 mod from_tokens;
 mod nested_modules;
 mod single_crate;
+mod workspace;
 
 pub fn get_path(relative_path: &str) -> std::path::PathBuf {
     let project_dir = env!("CARGO_MANIFEST_DIR");
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Broken {
+    pub amount: u64,,
+}
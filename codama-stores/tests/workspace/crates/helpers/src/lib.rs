@@ -0,0 +1,8 @@
+pub fn add(left: u64, right: u64) -> u64 {
+    left + right
+}
+
+#[diesel::account]
+pub struct Record {
+    pub id: u64,
+}
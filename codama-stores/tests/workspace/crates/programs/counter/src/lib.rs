@@ -0,0 +1,6 @@
+use codama::CodamaAccount;
+
+#[derive(CodamaAccount)]
+pub struct Counter {
+    pub count: u64,
+}
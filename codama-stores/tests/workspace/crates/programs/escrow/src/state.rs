@@ -0,0 +1,5 @@
+#[cfg_attr(feature = "codama", derive(codama::CodamaType))]
+pub enum EscrowState {
+    Open,
+    Closed,
+}
@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Stake11111111111111111111111111111111111111");
+
+#[account]
+pub struct Stake {
+    pub amount: u64,
+}
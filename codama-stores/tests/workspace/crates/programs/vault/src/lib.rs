@@ -0,0 +1,6 @@
+use shank::ShankAccount;
+
+#[derive(ShankAccount)]
+pub struct Vault {
+    pub balance: u64,
+}
@@ -0,0 +1,4 @@
+pub fn main() {
+    // Not valid Rust, but not a program crate either.
+    let = 42;
+}
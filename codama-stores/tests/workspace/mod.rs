@@ -0,0 +1,41 @@
+use crate::get_path;
+use codama_stores::RootStore;
+
+#[test]
+fn load_workspace() {
+    let root_store = RootStore::load_workspace(get_path("workspace/crates")).unwrap();
+
+    // Only non-excluded members with Codama, Anchor or Shank items are loaded, in declaration order.
+    // The `scripts` member does not parse but is skipped as it does not depend on these crates.
+    let names = root_store
+        .crates
+        .iter()
+        .map(|crate_store| crate_store.name())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            Some("counter"),
+            Some("escrow"),
+            Some("staking"),
+            Some("vault")
+        ]
+    );
+
+    // Each crate is loaded from its own manifest.
+    assert_eq!(
+        root_store.crates[0].path,
+        get_path("workspace/crates/programs/counter/src/lib.rs")
+    );
+    assert_eq!(root_store.crates[1].file_modules.len(), 1);
+}
+
+#[test]
+fn load_workspace_without_workspace_table() {
+    assert!(RootStore::load_workspace(get_path("single_crate/crate")).is_err());
+}
+
+#[test]
+fn load_workspace_with_broken_program_members() {
+    assert!(RootStore::load_workspace(get_path("workspace/broken")).is_err());
+}
@@ -0,0 +1,489 @@
+use crate::{extensions::*, Imports, Meta, PathList, PathResolver, PathValue, ResolveError};
+use quote::ToTokens;
+use std::{collections::HashMap, sync::Arc};
+use syn::{spanned::Spanned, BinOp, Expr, Token, UnOp};
+
+/// The maximum number of constants followed when evaluating a single expression.
+const MAX_DEPTH: usize = 64;
+
+/// Evaluates constant integer expressions such as `2 * MAX_LEN + size_of::<u64>()`.
+///
+/// Named constants are resolved from the `const` items registered in the evaluator,
+/// keyed by their path from the crate root — e.g. `state::MAX_LEN` or `state::Vault::LEN`
+/// for associated constants. Expressions are evaluated from the current module.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConstEvaluator {
+    constants: Arc<HashMap<String, Constant>>,
+    imports: Arc<Imports>,
+    module: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Constant {
+    expr: Expr,
+    module: Vec<String>,
+    self_type: Option<String>,
+}
+
+/// Where an expression is evaluated from, used to resolve relative paths.
+#[derive(Clone, Copy)]
+struct Scope<'a> {
+    module: &'a [String],
+    self_type: Option<&'a str>,
+}
+
+impl ConstEvaluator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The same evaluator, evaluating expressions from the given child module.
+    pub fn in_module<T: Into<String>>(&self, name: T) -> Self {
+        let mut evaluator = self.clone();
+        evaluator.module.push(name.into());
+        evaluator
+    }
+
+    /// Register a named constant in the current module. The first registered constant wins.
+    pub fn add_constant<T: Into<String>>(&mut self, name: T, expr: Expr) {
+        self.insert_constant(name.into(), expr, None);
+    }
+
+    fn insert_constant(&mut self, name: String, expr: Expr, self_type: Option<String>) {
+        let path = self.module.iter().cloned().chain(self_type.clone());
+        let key = path.chain([name]).collect::<Vec<_>>().join("::");
+        let constant = Constant {
+            expr,
+            module: self.module.clone(),
+            self_type,
+        };
+        Arc::make_mut(&mut self.constants)
+            .entry(key)
+            .or_insert(constant);
+    }
+
+    /// Register all `const` items, associated constants and imports of
+    /// the given items of the current module and their inline modules.
+    pub fn add_items(&mut self, items: &[syn::Item]) {
+        for item in items {
+            match item {
+                syn::Item::Const(item) => {
+                    self.add_constant(item.ident.to_string(), (*item.expr).clone())
+                }
+                syn::Item::Impl(item) => {
+                    let syn::Type::Path(syn::TypePath { qself: None, path }) =
+                        item.self_ty.as_ref()
+                    else {
+                        continue;
+                    };
+                    for impl_item in &item.items {
+                        if let syn::ImplItem::Const(impl_item) = impl_item {
+                            let name = impl_item.ident.to_string();
+                            self.insert_constant(
+                                name,
+                                impl_item.expr.clone(),
+                                Some(path.last_str()),
+                            );
+                        }
+                    }
+                }
+                syn::Item::Use(item) => {
+                    Arc::make_mut(&mut self.imports).add(&self.module, &item.tree)
+                }
+                syn::Item::Mod(syn::ItemMod {
+                    ident,
+                    content: Some((_, items)),
+                    ..
+                }) => self.with_module(ident.to_string(), |this| this.add_items(items)),
+                _ => {}
+            }
+        }
+    }
+
+    /// Run the given function from a child module, e.g. to register the items of its file.
+    pub fn with_module<T: Into<String>, R>(
+        &mut self,
+        name: T,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        self.module.push(name.into());
+        let result = f(self);
+        self.module.pop();
+        result
+    }
+
+    /// Replace a constant expression with its integer value, if it can be evaluated.
+    /// E.g. `2 * MAX_LEN` becomes `64` given `const MAX_LEN: usize = 32;`.
+    /// Expressions that do not refer to any constant are kept as-is to preserve their spans.
+    pub fn resolve_expr(&self, expr: Expr) -> Expr {
+        if let Expr::Lit(_) = expr {
+            return expr;
+        }
+        if Self::default().evaluate(&expr).is_ok() {
+            return expr;
+        }
+        match self.evaluate(&expr) {
+            Ok(value) => integer_expr(value, expr.span()),
+            Err(_) => expr,
+        }
+    }
+
+    /// Replace the constant expressions of a meta — including those of its nested lists —
+    /// with their integer value. E.g. `fixed_size = MAX_LEN + 2` becomes `fixed_size = 34`.
+    pub fn resolve_meta(&self, meta: Meta) -> Meta {
+        match meta {
+            Meta::Expr(expr) => Meta::Expr(self.resolve_expr(expr)),
+            Meta::Verbatim(_) => meta,
+            Meta::PathValue(pv) => Meta::PathValue(PathValue {
+                value: Box::new(self.resolve_meta(*pv.value)),
+                ..pv
+            }),
+            Meta::PathList(pl) => match pl.parse_metas() {
+                Ok(metas) => {
+                    let mut tokens = proc_macro2::TokenStream::new();
+                    for (index, meta) in metas.into_iter().enumerate() {
+                        if index > 0 {
+                            <Token![,]>::default().to_tokens(&mut tokens);
+                        }
+                        self.resolve_meta(meta).to_tokens(&mut tokens);
+                    }
+                    Meta::PathList(PathList { tokens, ..pl })
+                }
+                Err(_) => Meta::PathList(pl),
+            },
+        }
+    }
+
+    pub fn evaluate(&self, expr: &Expr) -> syn::Result<i128> {
+        let scope = Scope {
+            module: &self.module,
+            self_type: None,
+        };
+        self.evaluate_with_depth(expr, scope, 0)
+    }
+
+    fn evaluate_with_depth(&self, expr: &Expr, scope: Scope, depth: usize) -> syn::Result<i128> {
+        let evaluate = |expr: &Expr| self.evaluate_with_depth(expr, scope, depth);
+        let overflow = || expr.error("invalid arithmetic in constant expression");
+        match expr {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(value),
+                ..
+            }) => value.base10_parse::<i128>(),
+            Expr::Paren(syn::ExprParen { expr, .. }) | Expr::Group(syn::ExprGroup { expr, .. }) => {
+                evaluate(expr)
+            }
+            Expr::Unary(syn::ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => evaluate(expr)?.checked_neg().ok_or_else(overflow),
+            Expr::Binary(syn::ExprBinary {
+                left, op, right, ..
+            }) => {
+                let (left, right) = (evaluate(left)?, evaluate(right)?);
+                let result = match op {
+                    BinOp::Add(_) => left.checked_add(right),
+                    BinOp::Sub(_) => left.checked_sub(right),
+                    BinOp::Mul(_) => left.checked_mul(right),
+                    BinOp::Div(_) => left.checked_div(right),
+                    BinOp::Rem(_) => left.checked_rem(right),
+                    BinOp::Shl(_) => u32::try_from(right)
+                        .ok()
+                        .and_then(|right| left.checked_shl(right)),
+                    BinOp::Shr(_) => u32::try_from(right)
+                        .ok()
+                        .and_then(|right| left.checked_shr(right)),
+                    BinOp::BitAnd(_) => Some(left & right),
+                    BinOp::BitOr(_) => Some(left | right),
+                    BinOp::BitXor(_) => Some(left ^ right),
+                    _ => return Err(expr.error("unsupported operator in constant expression")),
+                };
+                result.ok_or_else(overflow)
+            }
+            Expr::Cast(syn::ExprCast {
+                expr: inner, ty, ..
+            }) => {
+                let value = evaluate(inner)?;
+                match integer_bits(ty) {
+                    Some((bits, signed)) => Ok(cast(value, bits, signed)),
+                    None => Err(ty.error("unsupported cast in constant expression")),
+                }
+            }
+            Expr::Call(syn::ExprCall { func, args, .. }) if args.is_empty() => {
+                let Expr::Path(syn::ExprPath { path, .. }) = func.as_ref() else {
+                    return Err(expr.error("unsupported call in constant expression"));
+                };
+                match (path.prefix().as_str(), path.last_str().as_str()) {
+                    ("" | "mem" | "core::mem" | "std::mem", "size_of") => {
+                        match path.generic_types().as_slice() {
+                            [ty] => self.size_of(ty, scope, depth),
+                            _ => Err(expr.error("expected a single type argument")),
+                        }
+                    }
+                    _ => Err(expr.error("unsupported call in constant expression")),
+                }
+            }
+            Expr::Path(syn::ExprPath {
+                qself: None, path, ..
+            }) => {
+                let constant = self.get_constant(path, scope)?;
+                if depth >= MAX_DEPTH {
+                    return Err(expr.error("recursive constant in constant expression"));
+                }
+                let scope = Scope {
+                    module: &constant.module,
+                    self_type: constant.self_type.as_deref(),
+                };
+                self.evaluate_with_depth(&constant.expr, scope, depth + 1)
+            }
+            _ => Err(expr.error("unsupported constant expression")),
+        }
+    }
+
+    /// The size of primitive types — and arrays of them — on 64-bit targets.
+    fn size_of(&self, ty: &syn::Type, scope: Scope, depth: usize) -> syn::Result<i128> {
+        if let syn::Type::Array(syn::TypeArray { elem, len, .. }) = ty {
+            let len = self.evaluate_with_depth(len, scope, depth)?;
+            return self
+                .size_of(elem, scope, depth)?
+                .checked_mul(len)
+                .ok_or_else(|| ty.error("invalid arithmetic in constant expression"));
+        }
+        if let Some((bits, _)) = integer_bits(ty) {
+            return Ok(i128::from(bits / 8));
+        }
+        let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+            return Err(ty.error("unsupported type in `size_of`"));
+        };
+        match path.last_str().as_str() {
+            "bool" => Ok(1),
+            "char" | "f32" => Ok(4),
+            "f64" => Ok(8),
+            "Pubkey" | "Address" => Ok(32),
+            _ => Err(ty.error("unsupported type in `size_of`")),
+        }
+    }
+
+    /// The constant a path refers to from the given scope, see `PathResolver::resolve`.
+    fn get_constant(&self, path: &syn::Path, scope: Scope) -> syn::Result<&Constant> {
+        let segments = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>();
+        let name = segments.join("::");
+        PathResolver::new(&self.constants, &self.imports)
+            .resolve(&segments, scope.module, scope.self_type)
+            .map_err(|error| match error {
+                ResolveError::Unknown => path.error(format!("unknown constant `{name}`")),
+                ResolveError::Ambiguous => path.error(format!("ambiguous constant `{name}`")),
+            })
+    }
+}
+
+/// An integer literal expression, negated if needed.
+fn integer_expr(value: i128, span: proc_macro2::Span) -> Expr {
+    let lit = syn::LitInt::new(&value.unsigned_abs().to_string(), span);
+    let expr = Expr::Lit(syn::ExprLit {
+        attrs: vec![],
+        lit: lit.into(),
+    });
+    match value < 0 {
+        true => Expr::Unary(syn::ExprUnary {
+            attrs: vec![],
+            op: UnOp::Neg(<Token![-]>::default()),
+            expr: Box::new(expr),
+        }),
+        false => expr,
+    }
+}
+
+/// The number of bits and signedness of a primitive integer type.
+fn integer_bits(ty: &syn::Type) -> Option<(u32, bool)> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    match path.get_ident()?.to_string().as_str() {
+        "u8" => Some((8, false)),
+        "u16" => Some((16, false)),
+        "u32" => Some((32, false)),
+        "u64" | "usize" => Some((64, false)),
+        "u128" => Some((128, false)),
+        "i8" => Some((8, true)),
+        "i16" => Some((16, true)),
+        "i32" => Some((32, true)),
+        "i64" | "isize" => Some((64, true)),
+        "i128" => Some((128, true)),
+        _ => None,
+    }
+}
+
+/// Truncate or sign-extend a value like an `as` cast would.
+fn cast(value: i128, bits: u32, signed: bool) -> i128 {
+    if bits == 128 {
+        return match signed {
+            true => value,
+            false => (value as u128).min(i128::MAX as u128) as i128,
+        };
+    }
+    let truncated = value & ((1i128 << bits) - 1);
+    match signed && truncated >> (bits - 1) == 1 {
+        true => truncated - (1i128 << bits),
+        false => truncated,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(expr: Expr) -> syn::Result<i128> {
+        let mut evaluator = ConstEvaluator::new();
+        evaluator.add_items(&[
+            syn::parse_quote! { const MAX_LEN: usize = 32; },
+            syn::parse_quote! {
+                mod sizes {
+                    pub const DOUBLE_LEN: usize = 2 * crate::MAX_LEN;
+                }
+            },
+            syn::parse_quote! { const RECURSIVE: usize = RECURSIVE + 1; },
+        ]);
+        evaluator.evaluate(&expr)
+    }
+
+    #[test]
+    fn literals_and_arithmetic() {
+        assert_eq!(evaluate(syn::parse_quote! { 42 }).unwrap(), 42);
+        assert_eq!(
+            evaluate(syn::parse_quote! { (1 + 2) * 3 - 4 / 2 }).unwrap(),
+            7
+        );
+        assert_eq!(evaluate(syn::parse_quote! { 10 % 4 }).unwrap(), 2);
+        assert_eq!(evaluate(syn::parse_quote! { -(2 * 3) }).unwrap(), -6);
+        assert_eq!(evaluate(syn::parse_quote! { 1 << 4 | 1 }).unwrap(), 17);
+        assert_eq!(
+            evaluate(syn::parse_quote! { 0xff >> 4 & 0b1010 ^ 1 }).unwrap(),
+            11
+        );
+    }
+
+    #[test]
+    fn casts() {
+        assert_eq!(evaluate(syn::parse_quote! { 300 as u8 }).unwrap(), 44);
+        assert_eq!(evaluate(syn::parse_quote! { 255 as i8 }).unwrap(), -1);
+        assert_eq!(evaluate(syn::parse_quote! { -1 as u16 }).unwrap(), 65535);
+        assert_eq!(evaluate(syn::parse_quote! { MAX_LEN as u64 }).unwrap(), 32);
+    }
+
+    #[test]
+    fn size_of_primitives() {
+        assert_eq!(evaluate(syn::parse_quote! { size_of::<u64>() }).unwrap(), 8);
+        assert_eq!(
+            evaluate(syn::parse_quote! { core::mem::size_of::<bool>() }).unwrap(),
+            1
+        );
+        assert_eq!(
+            evaluate(syn::parse_quote! { std::mem::size_of::<[u16; MAX_LEN]>() }).unwrap(),
+            64
+        );
+        assert_eq!(
+            evaluate(syn::parse_quote! { size_of::<Pubkey>() * 2 }).unwrap(),
+            64
+        );
+    }
+
+    #[test]
+    fn named_constants() {
+        assert_eq!(evaluate(syn::parse_quote! { MAX_LEN + 1 }).unwrap(), 33);
+        assert_eq!(
+            evaluate(syn::parse_quote! { sizes::DOUBLE_LEN }).unwrap(),
+            64
+        );
+    }
+
+    #[test]
+    fn module_paths() {
+        let mut evaluator = ConstEvaluator::new();
+        evaluator.add_items(&[
+            syn::parse_quote! {
+                mod a {
+                    pub const LEN: usize = 1;
+                    pub struct Vault;
+                    impl Vault { pub const SIZE: usize = Self::EXTRA + LEN; const EXTRA: usize = 10; }
+                }
+            },
+            syn::parse_quote! { mod b { pub const LEN: usize = 2; } },
+            syn::parse_quote! { mod c { use crate::b::LEN as B_LEN; pub const LEN: usize = B_LEN * 3; } },
+        ]);
+        let evaluate = |evaluator: &ConstEvaluator, expr: Expr| evaluator.evaluate(&expr);
+        let a = evaluator.in_module("a");
+        assert_eq!(evaluate(&a, syn::parse_quote! { LEN }).unwrap(), 1);
+        assert_eq!(evaluate(&a, syn::parse_quote! { Vault::SIZE }).unwrap(), 11);
+        assert_eq!(
+            evaluate(&a, syn::parse_quote! { super::b::LEN }).unwrap(),
+            2
+        );
+        let b = evaluator.in_module("b");
+        assert_eq!(evaluate(&b, syn::parse_quote! { LEN }).unwrap(), 2);
+        assert_eq!(
+            evaluate(&b, syn::parse_quote! { a::Vault::SIZE }).unwrap(),
+            11
+        );
+        let c = evaluator.in_module("c");
+        assert_eq!(evaluate(&c, syn::parse_quote! { LEN }).unwrap(), 6);
+        assert_eq!(evaluate(&c, syn::parse_quote! { B_LEN }).unwrap(), 2);
+        assert_eq!(
+            evaluate(&evaluator, syn::parse_quote! { LEN })
+                .unwrap_err()
+                .to_string(),
+            "ambiguous constant `LEN`"
+        );
+        assert_eq!(
+            evaluate(&evaluator, syn::parse_quote! { crate::LEN })
+                .unwrap_err()
+                .to_string(),
+            "unknown constant `crate::LEN`"
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            evaluate(syn::parse_quote! { UNKNOWN })
+                .unwrap_err()
+                .to_string(),
+            "unknown constant `UNKNOWN`"
+        );
+        assert_eq!(
+            evaluate(syn::parse_quote! { RECURSIVE })
+                .unwrap_err()
+                .to_string(),
+            "recursive constant in constant expression"
+        );
+        assert_eq!(
+            evaluate(syn::parse_quote! { 1 / 0 })
+                .unwrap_err()
+                .to_string(),
+            "invalid arithmetic in constant expression"
+        );
+        assert_eq!(
+            evaluate(syn::parse_quote! { size_of::<String>() })
+                .unwrap_err()
+                .to_string(),
+            "unsupported type in `size_of`"
+        );
+    }
+
+    #[test]
+    fn resolve_meta() {
+        let mut evaluator = ConstEvaluator::new();
+        evaluator.add_constant("MAX_LEN", syn::parse_quote! { 16 });
+        let meta: Meta =
+            syn::parse_quote! { type = array(number(u8), MAX_LEN * 2, offset = -MAX_LEN) };
+        assert_eq!(
+            evaluator.resolve_meta(meta).to_token_stream().to_string(),
+            "type = array (number (u8) , 32 , offset = - 16)"
+        );
+    }
+}
@@ -1,11 +1,22 @@
 use super::ToTokensExtension;
+use crate::ConstEvaluator;
 use syn::{Expr, ExprLit, ExprPath, ExprUnary};
 
 pub trait ExprExtension {
     fn get_self(&self) -> &Expr;
 
-    /// Returns the integer value of the expression if it is a literal unsigned integer.
+    /// Returns the integer value of the expression if it is a literal unsigned integer
+    /// or a constant expression that evaluates to one — see `ConstEvaluator`.
     fn as_unsigned_integer<T>(&self) -> syn::Result<T>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        self.as_unsigned_integer_with(&ConstEvaluator::default())
+    }
+
+    /// Same as `as_unsigned_integer` but resolves named constants using the given evaluator.
+    fn as_unsigned_integer_with<T>(&self, constants: &ConstEvaluator) -> syn::Result<T>
     where
         T: std::str::FromStr,
         T::Err: std::fmt::Display,
@@ -16,12 +27,27 @@ pub trait ExprExtension {
                 lit: syn::Lit::Int(value),
                 ..
             }) => value.base10_parse::<T>(),
-            _ => Err(this.error("expected an unsigned integer")),
+            _ => match constants.evaluate(this) {
+                Ok(value) if value >= 0 => {
+                    value.to_string().parse::<T>().map_err(|e| this.error(e))
+                }
+                _ => Err(this.error("expected an unsigned integer")),
+            },
         }
     }
 
-    /// Returns the integer value of the expression if it is a literal signed integer.
+    /// Returns the integer value of the expression if it is a literal signed integer
+    /// or a constant expression that evaluates to one — see `ConstEvaluator`.
     fn as_signed_integer<T>(&self) -> syn::Result<T>
+    where
+        T: std::str::FromStr + std::ops::Neg<Output = T>,
+        T::Err: std::fmt::Display,
+    {
+        self.as_signed_integer_with(&ConstEvaluator::default())
+    }
+
+    /// Same as `as_signed_integer` but resolves named constants using the given evaluator.
+    fn as_signed_integer_with<T>(&self, constants: &ConstEvaluator) -> syn::Result<T>
     where
         T: std::str::FromStr + std::ops::Neg<Output = T>,
         T::Err: std::fmt::Display,
@@ -33,9 +59,12 @@ pub trait ExprExtension {
                 expr: unsigned_expr,
                 ..
             }) => unsigned_expr
-                .as_unsigned_integer::<T>()
+                .as_unsigned_integer_with::<T>(constants)
                 .map(|value| value.neg()),
-            _ => this.as_unsigned_integer::<T>(),
+            Expr::Lit(_) => this.as_unsigned_integer::<T>(),
+            _ => constants
+                .evaluate(this)
+                .and_then(|value| value.to_string().parse::<T>().map_err(|e| this.error(e))),
         };
 
         result.map_err(|_| this.error("expected a signed integer"))
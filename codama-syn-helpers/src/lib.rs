@@ -1,4 +1,10 @@
 pub mod extensions;
 
+mod const_evaluator;
+pub use const_evaluator::*;
+
 mod meta;
 pub use meta::*;
+
+mod path_resolver;
+pub use path_resolver::*;
@@ -210,32 +210,35 @@ impl syn::parse::Parse for Meta {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let fork = input.fork();
         match fork.call(parse_meta_path) {
-            Ok(path) => {
-                if fork.peek(Paren)
-                    || fork.peek(Bracket)
-                    || fork.peek(Brace)
-                    || (fork.peek(Token![=])
-                        && (fork.peek2(Paren) || fork.peek2(Bracket) || fork.peek2(Brace)))
-                {
+            Ok(_) if fork.peek(Paren) || fork.peek(Bracket) || fork.peek(Brace) => {
+                Ok(Self::PathList(input.parse()?))
+            }
+            Ok(_) if fork.peek(Token![=]) && !fork.peek(Token![==]) => {
+                if fork.peek2(Paren) || fork.peek2(Bracket) || fork.peek2(Brace) {
                     Ok(Self::PathList(input.parse()?))
-                } else if fork.peek(Token![=]) {
-                    Ok(Self::PathValue(input.parse()?))
                 } else {
-                    input.advance_to(&fork);
-                    Ok(Self::Expr(syn::Expr::Path(syn::ExprPath {
-                        attrs: Vec::new(),
-                        qself: None,
-                        path,
-                    })))
+                    Ok(Self::PathValue(input.parse()?))
                 }
             }
-            Err(_) => match fork.parse::<Expr>() {
-                Ok(expr) => {
-                    input.advance_to(&fork);
-                    Ok(Self::Expr(expr))
+            // Paths followed by an operator, e.g. `MAX_LEN + 1`, are parsed as expressions.
+            Ok(path) if fork.is_empty() || fork.peek(Token![,]) => {
+                input.advance_to(&fork);
+                Ok(Self::Expr(syn::Expr::Path(syn::ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path,
+                })))
+            }
+            _ => {
+                let fork = input.fork();
+                match fork.parse::<Expr>() {
+                    Ok(expr) => {
+                        input.advance_to(&fork);
+                        Ok(Self::Expr(expr))
+                    }
+                    _ => Ok(Self::Verbatim(input.parse_arg()?)),
                 }
-                _ => Ok(Self::Verbatim(input.parse_arg()?)),
-            },
+            }
         }
     }
 }
@@ -396,6 +399,17 @@ mod tests {
         assert_eq!(expr.as_unsigned_integer::<usize>().unwrap(), 42);
     }
 
+    #[test]
+    fn parse_path_value_with_constant_expression() {
+        let meta: Meta = meta! { foo = MAX_LEN + 1 };
+        let Meta::PathValue(meta) = meta else {
+            panic!("expected Meta::PathValue");
+        };
+        let Meta::Expr(syn::Expr::Binary(_)) = meta.value.as_ref() else {
+            panic!("expected a binary expression");
+        };
+    }
+
     #[test]
     fn parse_path_value_with_boolean() {
         let meta: Meta = meta! { foo = true };
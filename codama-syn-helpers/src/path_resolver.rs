@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+
+/// The names imported by the `use` items of each module of a crate, keyed by module path.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Imports(HashMap<String, HashMap<String, syn::Path>>);
+
+impl Imports {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the names imported by the given `use` tree in the given module.
+    pub fn add(&mut self, module: &[String], tree: &syn::UseTree) {
+        let imports = self.0.entry(module.join("::")).or_default();
+        for (ident, path) in use_tree_imports(tree) {
+            imports.insert(ident.to_string(), path);
+        }
+    }
+
+    /// The path imported as `name` in the given module, if any.
+    pub fn get(&self, module: &[String], name: &str) -> Option<&syn::Path> {
+        self.0.get(&module.join("::"))?.get(name)
+    }
+}
+
+/// The names imported by a `use` tree with the full path they refer to.
+/// E.g. `use a::{b::C, D as E};` imports `C -> a::b::C` and `E -> a::D`.
+/// Glob imports and `self` imports are ignored.
+pub fn use_tree_imports(tree: &syn::UseTree) -> Vec<(syn::Ident, syn::Path)> {
+    let mut imports = Vec::new();
+    collect_imports(tree, &mut Vec::new(), &mut imports);
+    imports
+}
+
+fn collect_imports(
+    tree: &syn::UseTree,
+    prefix: &mut Vec<syn::Ident>,
+    imports: &mut Vec<(syn::Ident, syn::Path)>,
+) {
+    let path = |ident: &syn::Ident, prefix: &[syn::Ident]| syn::Path {
+        leading_colon: None,
+        segments: prefix
+            .iter()
+            .chain([ident])
+            .cloned()
+            .map(syn::PathSegment::from)
+            .collect(),
+    };
+    match tree {
+        syn::UseTree::Path(tree) => {
+            prefix.push(tree.ident.clone());
+            collect_imports(&tree.tree, prefix, imports);
+            prefix.pop();
+        }
+        syn::UseTree::Name(tree) if tree.ident != "self" => {
+            imports.push((tree.ident.clone(), path(&tree.ident, prefix)));
+        }
+        syn::UseTree::Rename(tree) if tree.ident != "self" => {
+            imports.push((tree.rename.clone(), path(&tree.ident, prefix)));
+        }
+        syn::UseTree::Group(group) => {
+            for tree in &group.items {
+                collect_imports(tree, prefix, imports);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Why a path could not be resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolveError {
+    Unknown,
+    Ambiguous,
+}
+
+/// Resolves paths to the items of a crate keyed by their path from the crate
+/// root — e.g. `state::MAX_LEN` — using the imports of each module.
+#[derive(Debug, Clone, Copy)]
+pub struct PathResolver<'a, T> {
+    pub items: &'a HashMap<String, T>,
+    pub imports: &'a Imports,
+}
+
+impl<'a, T> PathResolver<'a, T> {
+    pub fn new(items: &'a HashMap<String, T>, imports: &'a Imports) -> Self {
+        Self { items, imports }
+    }
+
+    /// The item a path refers to from the given module. Qualified paths — e.g. `crate::LEN`,
+    /// `super::LEN` or `Self::LEN` — must match exactly. Relative paths — e.g. `LEN` or
+    /// `state::LEN` — are looked up in the given module and its child modules, then through
+    /// its imports and finally anywhere in the crate as long as a single item matches.
+    pub fn resolve(
+        &self,
+        segments: &[String],
+        module: &[String],
+        self_type: Option<&str>,
+    ) -> Result<&'a T, ResolveError> {
+        if let Some(key) = qualified_key(segments, module, self_type) {
+            let key = key.ok_or(ResolveError::Unknown)?;
+            return self.items.get(&key).ok_or(ResolveError::Unknown);
+        }
+        if let Some(item) = self.items.get(&[module, segments].concat().join("::")) {
+            return Ok(item);
+        }
+        let Some(import) = self
+            .imports
+            .get(module, segments.first().map_or("", String::as_str))
+        else {
+            return self.find_unique(&segments.join("::"));
+        };
+        let path = import
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .chain(segments[1..].iter().cloned())
+            .collect::<Vec<_>>();
+        if let Some(key) = qualified_key(&path, module, self_type) {
+            let key = key.ok_or(ResolveError::Unknown)?;
+            return self.items.get(&key).ok_or(ResolveError::Unknown);
+        }
+        // Imports without a qualifier are relative to the module, or refer to another crate.
+        match self.items.get(&[module, &path].concat().join("::")) {
+            Some(item) => Ok(item),
+            None => self.find_unique(&path.join("::")),
+        }
+    }
+
+    /// The only item of the crate whose path ends with the given relative path.
+    fn find_unique(&self, name: &str) -> Result<&'a T, ResolveError> {
+        let suffix = format!("::{name}");
+        let mut matches = self
+            .items
+            .iter()
+            .filter(|(key, _)| *key == name || key.ends_with(&suffix));
+        match (matches.next(), matches.next()) {
+            (Some((_, item)), None) => Ok(item),
+            (Some(_), Some(_)) => Err(ResolveError::Ambiguous),
+            _ => Err(ResolveError::Unknown),
+        }
+    }
+}
+
+/// The key of a path starting with `crate`, `self`, `super` or `Self`, if any.
+/// The inner `None` means the path is qualified but cannot be resolved from this module.
+fn qualified_key(
+    segments: &[String],
+    module: &[String],
+    self_type: Option<&str>,
+) -> Option<Option<String>> {
+    let key = |module: &[String], rest: &[String]| [module, rest].concat().join("::");
+    match segments.first()?.as_str() {
+        "crate" => Some(Some(key(&[], &segments[1..]))),
+        "self" => Some(Some(key(module, &segments[1..]))),
+        "super" => {
+            let supers = segments.iter().take_while(|s| *s == "super").count();
+            Some(
+                module
+                    .len()
+                    .checked_sub(supers)
+                    .map(|len| key(&module[..len], &segments[supers..])),
+            )
+        }
+        "Self" => Some(self_type.map(|self_type| {
+            let module = [module, &[self_type.to_string()]].concat();
+            key(&module, &segments[1..])
+        })),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segments(path: &str) -> Vec<String> {
+        path.split("::").map(str::to_string).collect()
+    }
+
+    fn resolve<'a>(
+        items: &'a HashMap<String, u8>,
+        imports: &'a Imports,
+        path: &str,
+        module: &str,
+    ) -> Result<&'a u8, ResolveError> {
+        let module = match module {
+            "" => vec![],
+            module => segments(module),
+        };
+        PathResolver::new(items, imports).resolve(&segments(path), &module, None)
+    }
+
+    #[test]
+    fn resolve_paths() {
+        let items = HashMap::from([
+            ("LEN".to_string(), 0),
+            ("state::LEN".to_string(), 1),
+            ("state::types::SIZE".to_string(), 2),
+            ("other::SIZE".to_string(), 3),
+        ]);
+        let mut imports = Imports::new();
+        imports.add(
+            &segments("other"),
+            &syn::parse_quote! { crate::state::types },
+        );
+        imports.add(&segments("state"), &syn::parse_quote! { types::SIZE as S });
+
+        assert_eq!(resolve(&items, &imports, "LEN", ""), Ok(&0));
+        assert_eq!(resolve(&items, &imports, "LEN", "state"), Ok(&1));
+        assert_eq!(resolve(&items, &imports, "crate::LEN", "state"), Ok(&0));
+        assert_eq!(resolve(&items, &imports, "super::LEN", "state"), Ok(&0));
+        assert_eq!(resolve(&items, &imports, "types::SIZE", "state"), Ok(&2));
+        assert_eq!(resolve(&items, &imports, "S", "state"), Ok(&2));
+        assert_eq!(resolve(&items, &imports, "types::SIZE", "other"), Ok(&2));
+        assert_eq!(resolve(&items, &imports, "SIZE", "other"), Ok(&3));
+        assert_eq!(
+            resolve(&items, &imports, "SIZE", ""),
+            Err(ResolveError::Ambiguous)
+        );
+        assert_eq!(
+            resolve(&items, &imports, "super::LEN", ""),
+            Err(ResolveError::Unknown)
+        );
+    }
+
+    #[test]
+    fn use_tree_imports() {
+        let tree: syn::UseTree = syn::parse_quote! { a::{b::C, D as E, self, f::*} };
+        let imports = super::use_tree_imports(&tree)
+            .into_iter()
+            .map(|(ident, path)| {
+                let path = path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join("::");
+                (ident.to_string(), path)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            imports,
+            vec![
+                ("C".to_string(), "a::b::C".to_string()),
+                ("E".to_string(), "a::D".to_string()),
+            ]
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use codama_errors::{CodamaError, CodamaResult};
 use codama_korok_plugins::{resolve_plugins, DefaultPlugin, KorokPlugin};
+use codama_korok_visitors::TypeRegistry;
 use codama_koroks::RootKorok;
 use codama_nodes::{HasKind, Node, NodeTrait, RootNode};
-use codama_stores::RootStore;
+use codama_stores::{CfgContext, RootStore};
 use std::path::Path;
 
 pub struct Codama {
@@ -18,7 +19,7 @@ impl Codama {
             plugins: Vec::new(),
             with_default_plugin: true,
         }
-        .add_plugin(DefaultPlugin)
+        .add_plugin(DefaultPlugin::new())
     }
 
     pub fn load<P: AsRef<Path>>(path: P) -> CodamaResult<Self> {
@@ -29,6 +30,21 @@ impl Codama {
         Ok(Self::new(RootStore::load_all(paths)?))
     }
 
+    pub fn load_with_cfg<P: AsRef<Path>>(path: P, cfg: &CfgContext) -> CodamaResult<Self> {
+        Ok(Self::new(RootStore::load_all_with_cfg(&[path], cfg)?))
+    }
+
+    pub fn load_workspace<P: AsRef<Path>>(path: P) -> CodamaResult<Self> {
+        Ok(Self::new(RootStore::load_workspace(path)?))
+    }
+
+    pub fn load_workspace_with_cfg<P: AsRef<Path>>(
+        path: P,
+        cfg: &CfgContext,
+    ) -> CodamaResult<Self> {
+        Ok(Self::new(RootStore::load_workspace_with_cfg(path, cfg)?))
+    }
+
     pub fn hydrate(tt: proc_macro2::TokenStream) -> CodamaResult<Self> {
         Ok(Self::new(RootStore::hydrate(tt)?))
     }
@@ -38,6 +54,12 @@ impl Codama {
         self
     }
 
+    /// Identify field types using the given registry before the built-in mappings.
+    pub fn with_type_registry(mut self, registry: TypeRegistry) -> Self {
+        self.plugins[0] = Box::new(DefaultPlugin::new().with_registry(registry));
+        self
+    }
+
     pub fn add_plugin<T: KorokPlugin + 'static>(mut self, plugin: T) -> Self {
         self.plugins.push(Box::new(plugin));
         self
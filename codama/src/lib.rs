@@ -10,5 +10,6 @@ mod codama;
 #[cfg(not(target_os = "solana"))]
 pub use {
     codama::*, codama_errors::*, codama_korok_plugins::*, codama_korok_visitors::*,
-    codama_koroks::*, codama_nodes::*, codama_stores::*,
+    codama_koroks::*, codama_nodes::*, codama_renderers_js::*, codama_renderers_rust::*,
+    codama_stores::*,
 };
@@ -0,0 +1,47 @@
+use codama::{AccountSizesPlugin, Codama, SizeDiscriminatorNode};
+use quote::quote;
+
+fn recursive_program() -> proc_macro2::TokenStream {
+    quote! {
+        #[derive(CodamaType)]
+        pub struct TreeNode {
+            pub value: u8,
+            #[codama(type = option(defined_type_link("treeNode"), fixed = true))]
+            pub next: Option<Box<TreeNode>>,
+        }
+
+        #[derive(CodamaAccount)]
+        pub struct Tree {
+            pub root: TreeNode,
+        }
+
+        #[derive(CodamaAccount)]
+        pub struct Counter {
+            pub count: u64,
+        }
+    }
+}
+
+#[test]
+fn account_sizes_with_recursive_types() {
+    let codama = Codama::hydrate(recursive_program()).unwrap();
+    let program = codama.get_idl().unwrap().program;
+    assert_eq!(program.defined_types[0].name, "treeNode".into());
+    assert_eq!(program.accounts[0].name, "tree".into());
+    assert_eq!(program.accounts[0].size, None);
+    assert_eq!(program.accounts[1].name, "counter".into());
+    assert_eq!(program.accounts[1].size, Some(8));
+}
+
+#[test]
+fn account_sizes_with_size_discriminators() {
+    let codama = Codama::hydrate(recursive_program())
+        .unwrap()
+        .add_plugin(AccountSizesPlugin::with_size_discriminators());
+    let program = codama.get_idl().unwrap().program;
+    assert_eq!(program.accounts[0].discriminators, vec![]);
+    assert_eq!(
+        program.accounts[1].discriminators,
+        vec![SizeDiscriminatorNode::new(8).into()]
+    );
+}
@@ -0,0 +1,78 @@
+use codama::{AnchorPlugin, Codama, HasName};
+use quote::quote;
+
+#[test]
+fn get_idl_from_anchor_program() {
+    let codama = Codama::hydrate(quote! {
+        declare_id!("Counter111111111111111111111111111111111111");
+
+        #[program]
+        pub mod counter {
+            use super::*;
+
+            pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+                Ok(())
+            }
+
+            pub fn increment(ctx: Context<Increment>, amount: u64) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        #[derive(Accounts)]
+        pub struct Initialize<'info> {
+            #[account(init, payer = authority, space = 8 + 40, seeds = [b"counter", authority.key().as_ref()], bump)]
+            pub counter: Account<'info, Counter>,
+            #[account(mut)]
+            pub authority: Signer<'info>,
+            pub system_program: Program<'info, System>,
+        }
+
+        #[derive(Accounts)]
+        pub struct Increment<'info> {
+            #[account(mut, has_one = authority @ CounterError::Unauthorized)]
+            pub counter: Account<'info, Counter>,
+            pub authority: Signer<'info>,
+        }
+
+        #[account]
+        pub struct Counter {
+            pub authority: Pubkey,
+            pub count: u64,
+        }
+
+        #[error_code]
+        pub enum CounterError {
+            #[msg("You are not allowed to increment this counter")]
+            Unauthorized,
+        }
+    })
+    .unwrap()
+    .add_plugin(AnchorPlugin);
+    let program = codama.get_idl().unwrap().program;
+
+    assert_eq!(
+        program.public_key,
+        "Counter111111111111111111111111111111111111"
+    );
+    assert_eq!(
+        program
+            .accounts
+            .iter()
+            .map(|account| account.name().to_string())
+            .collect::<Vec<_>>(),
+        vec!["counter"]
+    );
+    assert_eq!(
+        program
+            .instructions
+            .iter()
+            .map(|instruction| instruction.name().to_string())
+            .collect::<Vec<_>>(),
+        vec!["initialize", "increment"]
+    );
+    assert_eq!(program.instructions[0].accounts.len(), 3);
+    assert!(program.instructions[0].accounts[0].default_value.is_some());
+    assert_eq!(program.errors.len(), 1);
+    assert_eq!(program.errors[0].code, 6000);
+}
@@ -1,5 +1,11 @@
+mod account_sizes;
+mod anchor;
 mod membership;
+mod networks;
+mod shank;
 mod system;
+mod type_registry;
+mod workspace;
 
 pub fn get_path(relative_path: &str) -> std::path::PathBuf {
     let project_dir = env!("CARGO_MANIFEST_DIR");
@@ -0,0 +1,22 @@
+use codama::{CodamaAccount, CodamaType};
+
+#[derive(CodamaAccount)]
+pub struct Config {
+    #[cfg_attr(feature = "devnet", codama(name = "faucet_authority"))]
+    pub authority: [u8; 32],
+    #[cfg(feature = "mainnet")]
+    pub fee: u64,
+}
+
+#[cfg_attr(feature = "devnet", derive(CodamaAccount))]
+pub struct Faucet {
+    pub amount: u64,
+}
+
+#[derive(CodamaType)]
+pub enum Cluster {
+    #[cfg(any(feature = "devnet", not(feature = "mainnet")))]
+    Devnet,
+    #[cfg(all(feature = "mainnet", not(feature = "devnet")))]
+    Mainnet,
+}
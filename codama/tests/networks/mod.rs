@@ -0,0 +1,60 @@
+use crate::get_path;
+use codama::{
+    CfgContext, Codama, EnumVariantTypeNode, NestedTypeNode, NestedTypeNodeTrait, StructTypeNode,
+    TypeNode,
+};
+
+fn get_field_names(data: &NestedTypeNode<StructTypeNode>) -> Vec<&str> {
+    data.get_nested_type_node()
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect()
+}
+
+fn get_variant_names(r#type: &TypeNode) -> Vec<&str> {
+    let TypeNode::Enum(node) = r#type else {
+        panic!("Expected an enum type");
+    };
+    node.variants
+        .iter()
+        .map(|variant| match variant {
+            EnumVariantTypeNode::Empty(variant) => variant.name.as_str(),
+            _ => panic!("Expected an empty variant"),
+        })
+        .collect()
+}
+
+#[test]
+fn get_devnet_idl() {
+    let cfg = CfgContext::new().with_feature("devnet");
+    let codama = Codama::load_with_cfg(get_path("networks/crate"), &cfg).unwrap();
+    let program = codama.get_idl().unwrap().program;
+
+    let [config, faucet] = program.accounts.as_slice() else {
+        panic!("Expected two accounts");
+    };
+    assert_eq!(config.name.as_str(), "config");
+    assert_eq!(get_field_names(&config.data), vec!["faucetAuthority"]);
+    assert_eq!(faucet.name.as_str(), "faucet");
+    assert_eq!(
+        get_variant_names(&program.defined_types[0].r#type),
+        vec!["devnet"]
+    );
+}
+
+#[test]
+fn get_mainnet_idl() {
+    let cfg = CfgContext::new().with_feature("mainnet");
+    let codama = Codama::load_with_cfg(get_path("networks/crate"), &cfg).unwrap();
+    let program = codama.get_idl().unwrap().program;
+
+    let [config] = program.accounts.as_slice() else {
+        panic!("Expected a single account");
+    };
+    assert_eq!(get_field_names(&config.data), vec!["authority", "fee"]);
+    assert_eq!(
+        get_variant_names(&program.defined_types[0].r#type),
+        vec!["mainnet"]
+    );
+}
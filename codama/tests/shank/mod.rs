@@ -0,0 +1,47 @@
+use codama::{Codama, ShankPlugin};
+use quote::quote;
+
+#[test]
+fn get_idl_from_shank_program() {
+    let codama = Codama::hydrate(quote! {
+        declare_id!("Counter111111111111111111111111111111111111");
+
+        #[derive(ShankAccount)]
+        #[seeds("counter", authority("The counter authority"))]
+        pub struct Counter {
+            pub authority: Pubkey,
+            pub count: u64,
+            #[padding]
+            pub padding: [u8; 8],
+        }
+
+        #[derive(ShankType)]
+        pub struct CreateArgs {
+            pub start: u64,
+        }
+
+        #[derive(ShankInstruction)]
+        pub enum CounterInstruction {
+            #[account(0, writable, name = "counter", desc = "The counter PDA")]
+            #[account(1, writable, signer, name = "authority")]
+            Create(CreateArgs),
+            #[account(0, writable, name = "counter")]
+            Increment,
+        }
+    })
+    .unwrap()
+    .add_plugin(ShankPlugin);
+    let program = codama.get_idl().unwrap().program;
+
+    assert_eq!(program.accounts[0].name, "counter".into());
+    assert_eq!(program.pdas[0].seeds.len(), 2);
+    assert_eq!(program.defined_types[0].name, "createArgs".into());
+    assert_eq!(
+        program
+            .instructions
+            .iter()
+            .map(|instruction| instruction.accounts.len())
+            .collect::<Vec<_>>(),
+        vec![2, 1]
+    );
+}
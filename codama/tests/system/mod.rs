@@ -21,6 +21,7 @@ fn get_idl() {
       {
         "kind": "accountNode",
         "name": "nonce",
+        "size": 80,
         "data": {
           "kind": "structTypeNode",
           "fields": [
@@ -0,0 +1,30 @@
+use codama::{
+    AmountTypeNode, Codama, NumberTypeNode, StructFieldTypeNode, StructTypeNode, TypeRegistry, U64,
+};
+use quote::quote;
+
+#[test]
+fn type_registry_with_default_plugin() {
+    let registry = TypeRegistry::parse(quote! {
+        my_crate::Amount -> amount(number(u64), decimals = 6)
+    })
+    .unwrap();
+    let codama = Codama::hydrate(quote! {
+        #[derive(CodamaAccount)]
+        pub struct Vault {
+            pub amount: my_crate::Amount,
+        }
+    })
+    .unwrap()
+    .with_type_registry(registry);
+    let program = codama.get_idl().unwrap().program;
+    assert_eq!(
+        program.accounts[0].data,
+        StructTypeNode::new(vec![StructFieldTypeNode::new(
+            "amount",
+            AmountTypeNode::new(NumberTypeNode::le(U64), 6, None)
+        )])
+        .into()
+    );
+    assert_eq!(program.accounts[0].size, Some(8));
+}
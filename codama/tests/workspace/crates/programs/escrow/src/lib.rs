@@ -0,0 +1,7 @@
+use codama::CodamaType;
+
+#[derive(CodamaType)]
+pub enum EscrowState {
+    Open,
+    Closed,
+}
@@ -0,0 +1,26 @@
+use crate::get_path;
+use codama::Codama;
+
+#[test]
+fn get_idl() {
+    let codama = Codama::load_workspace(get_path("workspace/crates")).unwrap();
+    let idl = codama.get_idl().unwrap();
+
+    // The first member is the primary program.
+    assert_eq!(idl.program.name.as_str(), "counter");
+    assert_eq!(
+        idl.program.public_key,
+        "Counter111111111111111111111111111111111111"
+    );
+    assert_eq!(idl.program.version, "1.0.0");
+    assert_eq!(idl.program.accounts.len(), 1);
+
+    // Other members become additional programs.
+    let [escrow] = idl.additional_programs.as_slice() else {
+        panic!("Expected a single additional program");
+    };
+    assert_eq!(escrow.name.as_str(), "escrow");
+    assert_eq!(escrow.version, "2.0.0");
+    assert_eq!(escrow.defined_types.len(), 1);
+    assert!(escrow.accounts.is_empty());
+}